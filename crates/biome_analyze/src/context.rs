@@ -1,11 +1,16 @@
 use crate::{registry::RuleRoot, FromServices, Queryable, Rule, RuleKey, ServiceBag};
 use biome_diagnostics::{Error, Result};
+use biome_rowan::TextRange;
 use std::ops::Deref;
 use std::path::Path;
 
 type RuleQueryResult<R> = <<R as Rule>::Query as Queryable>::Output;
 type RuleServiceBag<R> = <<R as Rule>::Query as Queryable>::Services;
 
+/// The range of the code selected in the editor, stored in the [ServiceBag]
+/// when the analysis is restricted to this range
+pub(crate) struct Selection(pub(crate) TextRange);
+
 pub struct RuleContext<'a, R>
 where
     R: ?Sized + Rule,
@@ -109,6 +114,17 @@ where
     pub fn file_path(&self) -> &Path {
         self.file_path
     }
+
+    /// Returns the range of the code selected in the editor, if the code
+    /// actions were requested for a selection.
+    ///
+    /// The refactor actions use it to find the code to rewrite, e.g. the
+    /// expression to extract to a variable.
+    pub fn selection(&self) -> Option<TextRange> {
+        self.bag
+            .get_service::<Selection>()
+            .map(|Selection(range)| *range)
+    }
 }
 
 impl<'a, R> Deref for RuleContext<'a, R>
@@ -5,12 +5,15 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
+use std::time::Instant;
 
 mod categories;
 pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+mod plugin;
+mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -29,6 +32,11 @@ pub use crate::diagnostics::AnalyzerDiagnostic;
 use crate::diagnostics::SuppressionDiagnostic;
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::plugin::{AnalyzerPlugin, PluginSignal, PLUGIN_GROUP};
+pub use crate::profiling::{
+    disable_profiling, enable_profiling, AnalyzerProfile, PhaseProfile, PhaseSpan,
+    ProfilingSession, RuleProfile, VisitorProfile,
+};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -128,8 +136,10 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let is_profiling = profiling::is_enabled();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
+            let start = is_profiling.then(Instant::now);
             let runner = PhaseRunner {
                 phase,
                 visitors: &mut visitors,
@@ -157,6 +167,10 @@ where
             };
 
             if let ControlFlow::Break(br) = result {
+                if let Some(start) = start {
+                    profiling::record_phase(phase, &ctx.options.file_path, start);
+                    profiling::flush();
+                }
                 return Some(br);
             }
 
@@ -164,11 +178,29 @@ where
             // phase runner as it needs mutable access to the service bag (the
             // runner borrows the services for the entire phase)
             for visitor in visitors {
+                let name = visitor.name();
+                let visitor_start = is_profiling.then(|| (Instant::now(), profiling::query_time()));
                 visitor.finish(VisitorFinishContext {
                     root: &ctx.root,
                     services: &mut ctx.services,
                 });
+                if let Some((visitor_start, query_time)) = visitor_start {
+                    profiling::record_visitor(
+                        phase,
+                        profiling::short_type_name(name),
+                        visitor_start,
+                        query_time,
+                    );
+                }
             }
+
+            if let Some(start) = start {
+                profiling::record_phase(phase, &ctx.options.file_path, start);
+            }
+        }
+
+        if is_profiling {
+            profiling::flush();
         }
 
         for suppression in line_suppressions {
@@ -254,6 +286,7 @@ where
     /// Runs phase 0 over nodes and tokens to process line breaks and
     /// suppression comments
     fn run_first_phase(mut self) -> ControlFlow<Break> {
+        let is_profiling = profiling::is_enabled();
         let iter = self.root.syntax().preorder_with_tokens(Direction::Next);
         for event in iter {
             let node_event = match event {
@@ -284,7 +317,7 @@ where
                     options: self.options,
                 };
 
-                visitor.visit(&node_event, ctx);
+                run_visitor(visitor.as_mut(), &node_event, ctx, is_profiling);
             }
         }
 
@@ -295,6 +328,7 @@ where
     /// Runs phases 1..N over nodes, since suppression comments were already
     /// processed and cached in `run_initial_phase`
     fn run_remaining_phases(mut self) -> ControlFlow<Break> {
+        let is_profiling = profiling::is_enabled();
         for event in self.root.syntax().preorder() {
             // Run all the active visitors for the phase on the event
             for visitor in self.visitors.iter_mut() {
@@ -309,7 +343,7 @@ where
                     options: self.options,
                 };
 
-                visitor.visit(&event, ctx);
+                run_visitor(visitor.as_mut(), &event, ctx, is_profiling);
             }
 
             // Flush all pending query signals
@@ -415,7 +449,12 @@ where
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
             } else if range_match(self.range, entry.text_range) {
-                (self.emit_signal)(&*entry.signal)?;
+                let start = profiling::is_enabled().then(Instant::now);
+                let result = (self.emit_signal)(&*entry.signal);
+                if let Some(start) = start {
+                    profiling::record_signal(entry.rule, start);
+                }
+                result?;
             }
 
             // SAFETY: This removes `query` from the queue, it is known to
@@ -475,14 +514,19 @@ where
                 SuppressionKind::Everything => None,
                 SuppressionKind::Rule(rule) => Some(rule),
                 SuppressionKind::MaybeLegacy(rule) => Some(rule),
+                SuppressionKind::Plugin(rule) => Some(rule),
                 SuppressionKind::Deprecated => None,
             };
 
             if let Some(rule) = rule {
-                let group_rule = rule.find('/').map(|index| {
-                    let (start, end) = rule.split_at(index);
-                    (start, &end[1..])
-                });
+                let group_rule = if matches!(kind, SuppressionKind::Plugin(_)) {
+                    Some((PLUGIN_GROUP, rule))
+                } else {
+                    rule.find('/').map(|index| {
+                        let (start, end) = rule.split_at(index);
+                        (start, &end[1..])
+                    })
+                };
 
                 let key = match group_rule {
                     None => self.metadata.find_group(rule).map(RuleFilter::from),
@@ -660,6 +704,31 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
     filter.map_or(true, |filter| filter.intersect(range).is_some())
 }
 
+/// Passes `event` to `visitor`, measuring the time it takes if the analyzer
+/// is profiled
+fn run_visitor<L: Language>(
+    visitor: &mut dyn Visitor<Language = L>,
+    event: &WalkEvent<biome_rowan::SyntaxNode<L>>,
+    ctx: VisitorContext<L>,
+    is_profiling: bool,
+) {
+    if !is_profiling {
+        visitor.visit(event, ctx);
+        return;
+    }
+
+    let phase = ctx.phase;
+    let start = Instant::now();
+    let query_time = profiling::query_time();
+    visitor.visit(event, ctx);
+    profiling::record_visitor(
+        phase,
+        profiling::short_type_name(visitor.name()),
+        start,
+        query_time,
+    );
+}
+
 /// Signature for a suppression comment parser function
 ///
 /// This function receives the text content of a comment and returns a list of
@@ -684,6 +753,8 @@ pub enum SuppressionKind<'a> {
     Rule(&'a str),
     /// A suppression using the legacy syntax to disable a specific rule eg. `// rome-ignore lint(style/useWhile)`
     MaybeLegacy(&'a str),
+    /// A suppression disabling a rule defined by a plugin eg. `// biome-ignore lint/plugin/noFoo`
+    Plugin(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
 }
@@ -788,6 +859,14 @@ impl RuleFilter<'_> {
             }
         }
     }
+
+    /// Return `true` if the plugin rule `name` matches this filter
+    fn match_plugin(self, name: &str) -> bool {
+        match self {
+            RuleFilter::Group(group) => group == PLUGIN_GROUP,
+            RuleFilter::Rule(group, rule) => group == PLUGIN_GROUP && rule == name,
+        }
+    }
 }
 
 impl<'a> Debug for RuleFilter<'a> {
@@ -856,6 +935,20 @@ impl<'analysis> AnalysisFilter<'analysis> {
             })
     }
 
+    /// Return `true` if the rule `name` defined by a plugin matches this filter.
+    /// Plugin rules belong to the [PLUGIN_GROUP] group of the lint category.
+    pub fn match_plugin(&self, name: &str) -> bool {
+        self.categories.contains(RuleCategories::LINT)
+            && self.enabled_rules.map_or(true, |enabled_rules| {
+                enabled_rules.iter().any(|filter| filter.match_plugin(name))
+            })
+            && self.disabled_rules.map_or(true, |disabled_rules| {
+                !disabled_rules
+                    .iter()
+                    .any(|filter| filter.match_plugin(name))
+            })
+    }
+
     /// It creates a new filter with the set of [enabled rules](RuleFilter) passed as argument
     pub fn from_enabled_rules(enabled_rules: Option<&'analysis [RuleFilter<'analysis>]>) -> Self {
         Self {
@@ -0,0 +1,130 @@
+use crate::categories::SUPPRESSION_ACTION_CATEGORY;
+use crate::signals::{AnalyzerActionIter, AnalyzerTransformationIter};
+use crate::{
+    ActionCategory, AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal, Phases, RuleAction,
+    RuleDiagnostic, ServiceBag, SuppressionCommentEmitter, SuppressionCommentEmitterPayload,
+};
+use biome_console::{markup, MarkupBuf};
+use biome_diagnostics::{Applicability, Category, DiagnosticExt, Severity};
+use biome_rowan::{AstNode, BatchMutation, Language, SyntaxNode, TextRange};
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+/// Name of the group of the rules defined by plugins. A plugin rule `noFoo`
+/// is suppressed with `// biome-ignore lint/plugin/noFoo`, and all the plugin
+/// rules are suppressed with `// biome-ignore lint/plugin`.
+pub const PLUGIN_GROUP: &str = "plugin";
+
+/// A rule that isn't compiled into Biome, but is loaded at runtime, e.g. from
+/// the plugins listed in the configuration.
+///
+/// A plugin rule is registered in the [RuleRegistry](crate::RuleRegistry)
+/// with [RuleRegistryBuilder::record_plugin](crate::RuleRegistryBuilder::record_plugin),
+/// and runs in the phase returned by [AnalyzerPlugin::phase] on the nodes of
+/// the kinds returned by [AnalyzerPlugin::query].
+pub trait AnalyzerPlugin<L: Language>: Debug + Send + Sync {
+    /// The name of the rule, unique among the plugin rules
+    fn name(&self) -> &'static str;
+
+    /// The kinds of the nodes this rule is run on
+    fn query(&self) -> Vec<L::Kind>;
+
+    /// The phase this rule is run in. A rule that uses the services built
+    /// during the syntax phase, like the semantic model, runs in a later phase.
+    fn phase(&self) -> Phases {
+        Phases::Syntax
+    }
+
+    /// Runs the rule on `node` and returns the signals it emits. `services`
+    /// holds the services built by the previous phases.
+    fn evaluate(&self, node: &SyntaxNode<L>, services: &ServiceBag) -> Vec<PluginSignal<L>>;
+}
+
+/// A diagnostic emitted by an [AnalyzerPlugin], and the action that fixes it
+pub struct PluginSignal<L: Language> {
+    /// The range of the code reported by the diagnostic
+    pub span: TextRange,
+    /// The message of the diagnostic
+    pub message: MarkupBuf,
+    /// The severity of the diagnostic
+    pub severity: Severity,
+    /// The optional action that fixes the code
+    pub action: Option<RuleAction<L>>,
+}
+
+/// Implementation of [AnalyzerSignal] for the signals emitted by an [AnalyzerPlugin]
+pub(crate) struct PluginRuleSignal<'phase, L: Language> {
+    name: &'static str,
+    root: &'phase L::Root,
+    signal: PluginSignal<L>,
+    apply_suppression_comment: SuppressionCommentEmitter<L>,
+}
+
+impl<'phase, L: Language> PluginRuleSignal<'phase, L> {
+    pub(crate) fn new(
+        name: &'static str,
+        root: &'phase L::Root,
+        signal: PluginSignal<L>,
+        apply_suppression_comment: SuppressionCommentEmitter<L>,
+    ) -> Self {
+        Self {
+            name,
+            root,
+            signal,
+            apply_suppression_comment,
+        }
+    }
+}
+
+impl<L: Language> AnalyzerSignal<L> for PluginRuleSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let name = self.name;
+        let diagnostic = RuleDiagnostic::new(
+            Category::plugin_rule(name),
+            self.signal.span,
+            markup! { {self.signal.message} },
+        )
+        .note(markup! { "This diagnostic is reported by the plugin rule "<Emphasis>{name}</Emphasis>"." });
+
+        Some(AnalyzerDiagnostic::from_error(
+            diagnostic.with_severity(self.signal.severity),
+        ))
+    }
+
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        let mut actions = Vec::new();
+        if let Some(action) = &self.signal.action {
+            actions.push(AnalyzerAction {
+                rule_name: Some((PLUGIN_GROUP, self.name)),
+                category: action.category.clone(),
+                applicability: action.applicability,
+                message: action.message.clone(),
+                mutation: action.mutation.clone(),
+            });
+        }
+
+        let rule_category = format!("lint/{PLUGIN_GROUP}/{}", self.name);
+        let suppression_text = format!("biome-ignore {rule_category}");
+        let root = self.root.syntax();
+        let mut mutation = BatchMutation::new(root.clone());
+        (self.apply_suppression_comment)(SuppressionCommentEmitterPayload {
+            suppression_text: suppression_text.as_str(),
+            mutation: &mut mutation,
+            token_offset: root.token_at_offset(self.signal.span.start()),
+            diagnostic_text_range: &self.signal.span,
+        });
+        actions.push(AnalyzerAction {
+            rule_name: Some((PLUGIN_GROUP, self.name)),
+            category: ActionCategory::Other(Cow::Borrowed(SUPPRESSION_ACTION_CATEGORY)),
+            applicability: Applicability::Always,
+            message: markup! { "Suppress rule " {rule_category} }.to_owned(),
+            mutation,
+        });
+
+        AnalyzerActionIter::new(actions)
+    }
+
+    fn transformations(&self) -> AnalyzerTransformationIter<L> {
+        AnalyzerTransformationIter::new(vec![])
+    }
+}
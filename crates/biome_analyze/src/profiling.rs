@@ -0,0 +1,329 @@
+//! Measures the time spent by the analyzer in each rule, phase and visitor.
+//!
+//! Profiling is disabled by default. [enable_profiling] starts a profiling
+//! session, which enables it for the whole process until the session ends
+//! with [disable_profiling]. Each thread accumulates its timings locally while
+//! the analyzer runs, and merges them into the profile of each session when
+//! the run completes, so that the measures don't contend on a lock. Sessions
+//! that overlap each get the runs that completed while they were active.
+
+use crate::{Phases, RuleKey};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+/// Whether a profiling session is active, checked without taking the lock of
+/// [SESSIONS]
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The identifier of the next profiling session
+static NEXT_SESSION: AtomicU64 = AtomicU64::new(0);
+
+/// The timings merged from all the threads, by active session
+static SESSIONS: Mutex<Vec<(u64, ProfileData)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The timings of the current thread, since its last merge into [SESSIONS]
+    static CURRENT: RefCell<ProfileData> = RefCell::new(ProfileData::default());
+}
+
+/// An active profiling session, see [enable_profiling].
+///
+/// The session ends when it's passed to [disable_profiling], or when it's
+/// dropped.
+#[derive(Debug)]
+pub struct ProfilingSession {
+    id: u64,
+}
+
+impl Drop for ProfilingSession {
+    fn drop(&mut self) {
+        end_session(self.id);
+    }
+}
+
+/// Enables the profiling of the analyzer, for all the threads, until the
+/// returned session ends
+pub fn enable_profiling() -> ProfilingSession {
+    let id = NEXT_SESSION.fetch_add(1, Ordering::Relaxed);
+    let mut sessions = SESSIONS.lock().unwrap();
+    sessions.push((id, ProfileData::default()));
+    ENABLED.store(true, Ordering::Relaxed);
+    ProfilingSession { id }
+}
+
+/// Ends the profiling `session`, and returns the timings of the analyzer runs
+/// that completed while it was active. The profiling is disabled once all the
+/// sessions ended.
+pub fn disable_profiling(session: ProfilingSession) -> AnalyzerProfile {
+    let data = end_session(session.id).unwrap_or_default();
+
+    let mut rules: Vec<_> = data.rules.into_values().collect();
+    rules.sort_by(|a, b| b.total().cmp(&a.total()).then(a.rule.cmp(&b.rule)));
+
+    let mut phases: Vec<_> = data.phases.into_values().collect();
+    phases.sort_by_key(|phase| phase.phase);
+
+    let mut visitors: Vec<_> = data.visitors.into_values().collect();
+    visitors.sort_by(|a, b| {
+        b.duration
+            .cmp(&a.duration)
+            .then(a.phase.cmp(&b.phase))
+            .then(a.name.cmp(b.name))
+    });
+
+    let mut spans = data.spans;
+    spans.sort_by_key(|span| span.start);
+
+    AnalyzerProfile {
+        rules,
+        phases,
+        visitors,
+        spans,
+    }
+}
+
+/// Removes the session `id` and returns its timings, or [None] if it already
+/// ended
+fn end_session(id: u64) -> Option<ProfileData> {
+    let mut sessions = SESSIONS.lock().unwrap();
+    let index = sessions.iter().position(|(session, _)| *session == id)?;
+    let (_, data) = sessions.remove(index);
+    ENABLED.store(!sessions.is_empty(), Ordering::Relaxed);
+    Some(data)
+}
+
+/// Returns `true` if the analyzer measures its timings
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// The timings of the analyzer, aggregated across all the analyzed files
+#[derive(Debug, Default)]
+pub struct AnalyzerProfile {
+    /// The timings of the rules, from the slowest to the fastest
+    pub rules: Vec<RuleProfile>,
+    /// The timings of the phases, in the order they run
+    pub phases: Vec<PhaseProfile>,
+    /// The timings of the visitors, from the slowest to the fastest
+    pub visitors: Vec<VisitorProfile>,
+    /// Each run of a phase on a file, in the order they started
+    pub spans: Vec<PhaseSpan>,
+}
+
+/// The time spent running a rule
+#[derive(Debug, Clone, Copy)]
+pub struct RuleProfile {
+    pub rule: RuleKey,
+    /// The time spent in the `run` function of the rule, when its query matched
+    pub query: Duration,
+    /// The time spent emitting the signals of the rule: its diagnostics and
+    /// its code actions
+    pub signals: Duration,
+    /// The number of times the query of the rule matched
+    pub matches: u64,
+}
+
+impl RuleProfile {
+    fn new(rule: RuleKey) -> Self {
+        Self {
+            rule,
+            query: Duration::ZERO,
+            signals: Duration::ZERO,
+            matches: 0,
+        }
+    }
+
+    /// The total time spent in the rule
+    pub fn total(&self) -> Duration {
+        self.query + self.signals
+    }
+}
+
+/// The time spent running a phase of the analyzer, including its rules
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseProfile {
+    pub phase: Phases,
+    pub duration: Duration,
+    /// The number of times the phase ran
+    pub runs: u64,
+}
+
+/// The time spent in a visitor, excluding the rules whose query it matched
+#[derive(Debug, Clone, Copy)]
+pub struct VisitorProfile {
+    pub phase: Phases,
+    /// The name of the type of the visitor, e.g. `ControlFlowVisitor`
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+/// A run of a phase of the analyzer on a file
+#[derive(Debug, Clone)]
+pub struct PhaseSpan {
+    pub phase: Phases,
+    /// The path of the analyzed file
+    pub path: PathBuf,
+    /// The thread the phase ran on
+    pub thread: ThreadId,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+#[derive(Clone, Default)]
+struct ProfileData {
+    rules: FxHashMap<RuleKey, RuleProfile>,
+    phases: FxHashMap<Phases, PhaseProfile>,
+    visitors: FxHashMap<(Phases, &'static str), VisitorProfile>,
+    spans: Vec<PhaseSpan>,
+    /// The total time spent in the queries of the rules by the thread, used
+    /// to exclude it from the time of the visitors
+    query_time: Duration,
+}
+
+impl ProfileData {
+    fn merge(&mut self, other: ProfileData) {
+        for (key, rule) in other.rules {
+            let entry = self
+                .rules
+                .entry(key)
+                .or_insert_with(|| RuleProfile::new(key));
+            entry.query += rule.query;
+            entry.signals += rule.signals;
+            entry.matches += rule.matches;
+        }
+        for (key, phase) in other.phases {
+            let entry = self.phases.entry(key).or_insert(PhaseProfile {
+                phase: key,
+                duration: Duration::ZERO,
+                runs: 0,
+            });
+            entry.duration += phase.duration;
+            entry.runs += phase.runs;
+        }
+        for (key, visitor) in other.visitors {
+            self.visitors
+                .entry(key)
+                .and_modify(|entry| entry.duration += visitor.duration)
+                .or_insert(visitor);
+        }
+        self.spans.extend(other.spans);
+    }
+}
+
+/// Records the time spent in the query of `rule`, which started at `start`
+pub(crate) fn record_query(rule: RuleKey, start: Instant) {
+    let duration = start.elapsed();
+    CURRENT.with_borrow_mut(|data| {
+        let entry = data
+            .rules
+            .entry(rule)
+            .or_insert_with(|| RuleProfile::new(rule));
+        entry.query += duration;
+        entry.matches += 1;
+        data.query_time += duration;
+    });
+}
+
+/// Records the time spent emitting a signal of `rule`, which started at `start`
+pub(crate) fn record_signal(rule: RuleKey, start: Instant) {
+    let duration = start.elapsed();
+    CURRENT.with_borrow_mut(|data| {
+        data.rules
+            .entry(rule)
+            .or_insert_with(|| RuleProfile::new(rule))
+            .signals += duration;
+    });
+}
+
+/// Returns the time spent by the current thread in the queries of the rules,
+/// to be passed to [record_visitor]
+pub(crate) fn query_time() -> Duration {
+    CURRENT.with_borrow(|data| data.query_time)
+}
+
+/// Records the time spent in the visitor `name` since `start`, excluding the
+/// queries that ran since [query_time] returned `query_time`
+pub(crate) fn record_visitor(
+    phase: Phases,
+    name: &'static str,
+    start: Instant,
+    query_time: Duration,
+) {
+    let elapsed = start.elapsed();
+    CURRENT.with_borrow_mut(|data| {
+        let duration = elapsed.saturating_sub(data.query_time.saturating_sub(query_time));
+        data.visitors
+            .entry((phase, name))
+            .or_insert(VisitorProfile {
+                phase,
+                name,
+                duration: Duration::ZERO,
+            })
+            .duration += duration;
+    });
+}
+
+/// Records a run of `phase` on the file at `path`, which started at `start`
+pub(crate) fn record_phase(phase: Phases, path: &Path, start: Instant) {
+    let duration = start.elapsed();
+    CURRENT.with_borrow_mut(|data| {
+        let entry = data.phases.entry(phase).or_insert(PhaseProfile {
+            phase,
+            duration: Duration::ZERO,
+            runs: 0,
+        });
+        entry.duration += duration;
+        entry.runs += 1;
+        data.spans.push(PhaseSpan {
+            phase,
+            path: path.to_path_buf(),
+            thread: thread::current().id(),
+            start,
+            duration,
+        });
+    });
+}
+
+/// Merges the timings of the current thread into the profiles of the active
+/// sessions
+pub(crate) fn flush() {
+    let data = CURRENT.take();
+    let mut sessions = SESSIONS.lock().unwrap();
+    if let Some(((_, last), others)) = sessions.split_last_mut() {
+        for (_, profile) in others {
+            profile.merge(data.clone());
+        }
+        last.merge(data);
+    }
+}
+
+/// Returns the name of the type `name` without its path and its generic
+/// parameters, e.g. `ControlFlowVisitor` for
+/// `biome_js_analyze::control_flow::visitor::ControlFlowVisitor`
+pub(crate) fn short_type_name(name: &'static str) -> &'static str {
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{disable_profiling, enable_profiling, is_enabled};
+
+    #[test]
+    fn profiling_is_disabled_once_all_the_sessions_ended() {
+        let first = enable_profiling();
+        let second = enable_profiling();
+        assert!(is_enabled());
+
+        disable_profiling(first);
+        assert!(is_enabled());
+
+        drop(second);
+        assert!(!is_enabled());
+    }
+}
@@ -1,10 +1,12 @@
 use crate::{
-    context::RuleContext,
+    context::{RuleContext, Selection},
     matcher::{GroupKey, MatchQueryParams},
+    plugin::{AnalyzerPlugin, PluginRuleSignal, PLUGIN_GROUP},
+    profiling,
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
-    RuleMetadata, ServiceBag, SignalEntry, Visitor,
+    RuleMetadata, ServiceBag, SignalEntry, SyntaxVisitor, Visitor,
 };
 use biome_diagnostics::Error;
 use biome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode};
@@ -13,6 +15,8 @@ use std::{
     any::TypeId,
     borrow,
     collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    time::Instant,
 };
 
 /// Defines all the phases that the [RuleRegistry] supports.
@@ -21,6 +25,7 @@ use std::{
 pub enum Phases {
     Syntax = 0,
     Semantic = 1,
+    Type = 2,
 }
 
 /// Defines which phase a rule will run. This will be defined
@@ -57,7 +62,7 @@ pub trait RegistryVisitor<L: Language> {
 
 /// Stores metadata information for all the rules in the registry, sorted
 /// alphabetically
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct MetadataRegistry {
     inner: BTreeSet<MetadataKey>,
 }
@@ -75,7 +80,8 @@ impl MetadataRegistry {
         Some(key.into_rule_key())
     }
 
-    pub(crate) fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
+    /// Adds a rule to this registry, e.g. a rule defined by a plugin
+    pub fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
         self.inner.insert(MetadataKey {
             inner: (group, rule),
         });
@@ -99,10 +105,12 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
 /// we have:
 /// - Syntax Phase: No services are offered, thus its rules can be run immediately;
 /// - Semantic Phase: Offers the semantic model, thus these rules can only run
-/// after the "SemanticModel" is ready, which demands a whole transverse of the parsed tree.
+/// after the "SemanticModel" is ready, which demands a whole transverse of the parsed tree;
+/// - Type Phase: Offers the types inferred from the semantic model, thus these rules can
+/// only run after the "TypeModel" is ready.
 pub struct RuleRegistry<L: Language> {
     /// Holds a collection of rules for each phase.
-    phase_rules: [PhaseRules<L>; 2],
+    phase_rules: [PhaseRules<L>; 3],
 }
 
 impl<L: Language + Default> RuleRegistry<L> {
@@ -110,6 +118,11 @@ impl<L: Language + Default> RuleRegistry<L> {
         filter: &'a AnalysisFilter<'a>,
         root: &'a L::Root,
     ) -> RuleRegistryBuilder<'a, L> {
+        let mut services = ServiceBag::default();
+        if let Some(range) = filter.range {
+            services.insert_service(Selection(range));
+        }
+
         RuleRegistryBuilder {
             filter,
             root,
@@ -117,7 +130,7 @@ impl<L: Language + Default> RuleRegistry<L> {
                 phase_rules: Default::default(),
             },
             visitors: BTreeMap::default(),
-            services: ServiceBag::default(),
+            services,
             diagnostics: Vec::new(),
         }
     }
@@ -221,7 +234,7 @@ impl<L: Language + Default + 'static> RegistryVisitor<L> for RuleRegistryBuilder
             }
         }
 
-        phase.rule_states.push(RuleState::default());
+        phase.rule_states.push(RuleState::new(RuleKey::rule::<R>()));
 
         <R::Query as Queryable>::build_visitor(&mut self.visitors, self.root);
     }
@@ -238,6 +251,53 @@ impl<L: Language> AddVisitor<L> for BTreeMap<(Phases, TypeId), Box<dyn Visitor<L
     }
 }
 
+impl<L: Language + Default + 'static> RuleRegistryBuilder<'_, L> {
+    /// Add the rule defined by `plugin` to the list of rules stores in this
+    /// registry instance. It runs in the phase returned by [AnalyzerPlugin::phase].
+    pub fn record_plugin(&mut self, plugin: Arc<dyn AnalyzerPlugin<L>>) {
+        if !self.filter.match_plugin(plugin.name()) {
+            return;
+        }
+
+        let plugin_phase = plugin.phase();
+        let phase = &mut self.registry.phase_rules[plugin_phase as usize];
+        let rule = RegistryRule::plugin(phase.rule_states.len());
+
+        let TypeRules::SyntaxRules { rules } = phase
+            .type_rules
+            .entry(TypeId::of::<SyntaxNode<L>>())
+            .or_insert_with(|| TypeRules::SyntaxRules { rules: Vec::new() })
+        else {
+            unreachable!("the SyntaxNode type has already been registered as a TypeRules instead of a SyntaxRules")
+        };
+
+        for kind in plugin.query() {
+            let RawSyntaxKind(index) = kind.to_raw();
+            let index = usize::from(index);
+            if rules.len() <= index {
+                rules.resize_with(index + 1, SyntaxKindRules::new);
+            }
+            rules[index].rules.push(rule);
+        }
+
+        let key = RuleKey::new(PLUGIN_GROUP, plugin.name());
+        phase.rule_states.push(RuleState {
+            plugin: Some(plugin),
+            ..RuleState::new(key)
+        });
+
+        self.visitors
+            .add_visitor(plugin_phase, SyntaxVisitor::<L>::default);
+    }
+
+    /// Add the visitors of the query `Q`, without registering any rule. This
+    /// builds the services that the rules defined by plugins use, e.g. the
+    /// semantic model.
+    pub fn record_query<Q: Queryable<Language = L>>(&mut self) {
+        Q::build_visitor(&mut self.visitors, self.root);
+    }
+}
+
 type BuilderResult<L> = (
     RuleRegistry<L>,
     ServiceBag,
@@ -284,10 +344,15 @@ impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
         };
 
         // Run all the rules registered to this QueryMatch
+        let is_profiling = profiling::is_enabled();
         for rule in rules {
             let state = &mut phase.rule_states[rule.state_index];
+            let start = is_profiling.then(Instant::now);
             // TODO: #3394 track error in the signal queue
             let _ = (rule.run)(&mut params, state);
+            if let Some(start) = start {
+                profiling::record_query(state.key, start);
+            }
         }
     }
 }
@@ -360,9 +425,25 @@ pub struct RegistryRule<L: Language> {
 }
 
 /// Internal state for a given rule
-#[derive(Default)]
 struct RuleState<L: Language> {
+    /// The key of the rule, used to report its timing when the analyzer is
+    /// profiled
+    key: RuleKey,
     suppressions: RuleSuppressions<L>,
+    /// The plugin defining the rule, if it isn't a built-in rule
+    plugin: Option<Arc<dyn AnalyzerPlugin<L>>>,
+}
+
+impl<L: Language> RuleState<L> {
+    fn new(key: RuleKey) -> Self {
+        Self {
+            key,
+            suppressions: RuleSuppressions {
+                inner: FxHashSet::default(),
+            },
+            plugin: None,
+        }
+    }
 }
 
 /// Set of nodes this rule has suppressed from matching its query
@@ -381,7 +462,47 @@ impl<L: Language> RuleSuppressions<L> {
 /// Executor for rule as a generic function pointer
 type RuleExecutor<L> = fn(&mut MatchQueryParams<L>, &mut RuleState<L>) -> Result<(), Error>;
 
-impl<L: Language + Default> RegistryRule<L> {
+impl<L: Language + Default + 'static> RegistryRule<L> {
+    /// Creates the registry entry of a rule defined by an [AnalyzerPlugin],
+    /// stored in the state at `state_index`
+    fn plugin(state_index: usize) -> Self {
+        /// Implementation of RuleExecutor for the plugin stored in the rule state
+        fn run<L: Language + 'static>(
+            params: &mut MatchQueryParams<L>,
+            state: &mut RuleState<L>,
+        ) -> Result<(), Error> {
+            let (Some(plugin), Some(node)) =
+                (&state.plugin, params.query.downcast_ref::<SyntaxNode<L>>())
+            else {
+                return Ok(());
+            };
+
+            let name = plugin.name();
+            for signal in plugin.evaluate(node, params.services) {
+                let text_range = signal.span;
+                let signal = Box::new(PluginRuleSignal::new(
+                    name,
+                    params.root,
+                    signal,
+                    params.apply_suppression_comment,
+                ));
+
+                params.signal_queue.push(SignalEntry {
+                    signal,
+                    rule: RuleKey::new(PLUGIN_GROUP, name),
+                    text_range,
+                });
+            }
+
+            Ok(())
+        }
+
+        Self {
+            run: run::<L>,
+            state_index,
+        }
+    }
+
     fn new<R>(state_index: usize) -> Self
     where
         R: Rule + 'static,
@@ -80,7 +80,11 @@ impl<L: Language + 'static> Visitor for SyntaxVisitor<L> {
         }
 
         if let Some(range) = ctx.range {
-            if node.text_range().ordering(range).is_ne() {
+            // An empty range is a cursor, which also touches the nodes
+            // starting or ending at its position
+            let node_range = node.text_range();
+            let is_touched = range.is_empty() && node_range.contains_inclusive(range.start());
+            if node_range.ordering(range).is_ne() && !is_touched {
                 self.skip_subtree = Some(node.clone());
                 return;
             }
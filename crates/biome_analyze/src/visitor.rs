@@ -54,6 +54,12 @@ pub trait Visitor {
     fn finish(self: Box<Self>, ctx: VisitorFinishContext<Self::Language>) {
         let _ = ctx;
     }
+
+    /// Returns the name of the type of the visitor, used to report its timing
+    /// when the analyzer is profiled
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 /// A node visitor is a special kind of visitor that does not have a persistent
@@ -30,3 +30,17 @@ pub(crate) fn get_changed_files(
 
     Ok(filtered_changed_files)
 }
+
+pub(crate) fn get_staged_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let staged_files = fs.get_staged_files()?;
+
+    let staged_files = staged_files.iter().map(OsString::from).collect::<Vec<_>>();
+
+    if staged_files.is_empty() {
+        return Err(CliDiagnostic::no_files_processed());
+    }
+
+    Ok(staged_files)
+}
@@ -2,6 +2,7 @@ use crate::logging::LoggingKind;
 use crate::LoggingLevel;
 use biome_diagnostics::Severity;
 use bpaf::Bpaf;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -97,3 +98,36 @@ impl FromStr for ColorsArg {
         }
     }
 }
+
+/// The format of the file written by `--profile-rules-output`
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ProfileFormat {
+    /// The timings, aggregated by rule, phase and file
+    #[default]
+    Json,
+    /// The timings of each file, in the Trace Event Format of Chrome
+    ChromeTrace,
+}
+
+impl Display for ProfileFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileFormat::Json => write!(f, "json"),
+            ProfileFormat::ChromeTrace => write!(f, "chrome-trace"),
+        }
+    }
+}
+
+impl FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "chrome-trace" => Ok(Self::ChromeTrace),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --profile-rules-format argument"
+            )),
+        }
+    }
+}
@@ -1,17 +1,19 @@
-use crate::changed::get_changed_files;
-use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
+use crate::changed::{get_changed_files, get_staged_files};
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, watch_mode, CliDiagnostic, CliSession,
+    ConfigurationArguments, Execution, TraversalMode,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{
     load_configuration, FormatterConfiguration, LinterConfiguration, LoadedConfiguration,
 };
-use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
+use biome_service::workspace::FixFileMode;
 use biome_service::{Configuration, ConfigurationBasePath, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
@@ -25,6 +27,15 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) no_cache: bool,
+    pub(crate) cache_dir: Option<String>,
+    pub(crate) profile_rules: bool,
+    pub(crate) profile_rules_output: Option<String>,
+    pub(crate) profile_rules_format: ProfileFormat,
+    pub(crate) baseline: Option<String>,
+    pub(crate) prune_baseline: bool,
 }
 
 /// Handler for the "check" command of the Biome CLI
@@ -43,7 +54,16 @@ pub(crate) fn check(
         organize_imports_enabled,
         formatter_enabled,
         since,
+        staged,
         changed,
+        watch,
+        no_cache,
+        cache_dir,
+        profile_rules,
+        profile_rules_output,
+        profile_rules_format,
+        baseline,
+        prune_baseline,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
@@ -60,54 +80,79 @@ pub(crate) fn check(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
-    let base_path = match cli_options.config_path.as_ref() {
-        None => ConfigurationBasePath::default(),
-        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
-    };
-
-    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
-    validate_configuration_diagnostics(
-        &loaded_configuration,
-        session.app.console,
-        cli_options.verbose,
-    )?;
-
-    let LoadedConfiguration {
-        configuration: mut fs_configuration,
-        directory_path: configuration_path,
-        ..
-    } = loaded_configuration;
-
-    let formatter = fs_configuration
-        .formatter
-        .get_or_insert_with(FormatterConfiguration::default);
-
-    if formatter_enabled.is_some() {
-        formatter.enabled = formatter_enabled;
+    if staged && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--stdin-file-path",
+        ));
     }
 
-    let linter = fs_configuration
-        .linter
-        .get_or_insert_with(LinterConfiguration::default);
-
-    if linter_enabled.is_some() {
-        linter.enabled = linter_enabled;
+    if staged && watch {
+        return Err(CliDiagnostic::incompatible_arguments("--staged", "--watch"));
     }
 
-    let organize_imports = fs_configuration
-        .organize_imports
-        .get_or_insert_with(OrganizeImports::default);
-
-    if organize_imports_enabled.is_some() {
-        organize_imports.enabled = organize_imports_enabled;
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
     }
 
-    fs_configuration.merge_with(configuration);
+    let arguments: ConfigurationArguments =
+        Arc::new(move |fs_configuration: &mut Configuration| {
+            let formatter = fs_configuration
+                .formatter
+                .get_or_insert_with(FormatterConfiguration::default);
+
+            if formatter_enabled.is_some() {
+                formatter.enabled = formatter_enabled;
+            }
+
+            let linter = fs_configuration
+                .linter
+                .get_or_insert_with(LinterConfiguration::default);
+
+            if linter_enabled.is_some() {
+                linter.enabled = linter_enabled;
+            }
+
+            let organize_imports = fs_configuration
+                .organize_imports
+                .get_or_insert_with(OrganizeImports::default);
+
+            if organize_imports_enabled.is_some() {
+                organize_imports.enabled = organize_imports_enabled;
+            }
+
+            fs_configuration.merge_with(configuration.clone());
+        });
+
+    let resolve_configuration = |session: &mut CliSession| {
+        let base_path = match cli_options.config_path.as_ref() {
+            None => ConfigurationBasePath::default(),
+            Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+        };
+
+        let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+        validate_configuration_diagnostics(
+            &loaded_configuration,
+            &mut *session.app.console,
+            cli_options.verbose,
+        )?;
+
+        let extended_files = loaded_configuration.extended_file_paths();
+        let LoadedConfiguration {
+            configuration: mut fs_configuration,
+            directory_path: configuration_path,
+            ..
+        } = loaded_configuration;
+        arguments(&mut fs_configuration);
+
+        Ok::<_, CliDiagnostic>((fs_configuration, configuration_path, extended_files))
+    };
 
-    // check if support of git ignore files is enabled
-    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    let (vcs_base_path, gitignore_matches) =
-        fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+    let (fs_configuration, configuration_path, extended_files) =
+        resolve_configuration(&mut session)?;
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
@@ -127,25 +172,49 @@ pub(crate) fn check(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if staged && changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--changed",
+        ));
+    }
+
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams {
-            configuration: fs_configuration,
-            vcs_base_path,
-            gitignore_matches,
-        })?;
-
-    execute_mode(
-        Execution::new(TraversalMode::Check {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    let execution = Execution::new(TraversalMode::Check {
+        fix_file_mode,
+        stdin,
+    })
+    .with_configuration(&*session.app.fs, &fs_configuration)
+    .with_configuration_arguments(arguments.clone())
+    .with_staged_files(staged)
+    .with_cache(no_cache, cache_dir)
+    .with_profile_rules(profile_rules, profile_rules_output, profile_rules_format)
+    .with_type_aware_rules(fs_configuration.has_type_aware_rules())
+    .with_baseline(baseline, prune_baseline);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, fs_configuration, configuration_path.clone())?;
+    let execution = execution
+        .with_ignore_files(ignore_files)
+        .with_nested_configurations(nested_configurations);
+
+    if watch {
+        watch_mode(
+            execution,
+            session,
+            &cli_options,
+            paths,
+            configuration_path,
+            extended_files,
+            resolve_configuration,
+        )
+    } else {
+        execute_mode(execution, session, &cli_options, paths)
+    }
 }
@@ -1,15 +1,18 @@
 use crate::changed::get_changed_files;
-use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
-use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, ConfigurationArguments,
+    Execution,
+};
 use biome_service::configuration::organize_imports::OrganizeImports;
 use biome_service::configuration::{
     load_configuration, FormatterConfiguration, LinterConfiguration, LoadedConfiguration,
 };
-use biome_service::workspace::UpdateSettingsParams;
 use biome_service::{Configuration, ConfigurationBasePath, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub(crate) struct CiCommandPayload {
     pub(crate) formatter_enabled: Option<bool>,
@@ -20,6 +23,13 @@ pub(crate) struct CiCommandPayload {
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) no_cache: bool,
+    pub(crate) cache_dir: Option<String>,
+    pub(crate) profile_rules: bool,
+    pub(crate) profile_rules_output: Option<String>,
+    pub(crate) profile_rules_format: ProfileFormat,
+    pub(crate) baseline: Option<String>,
+    pub(crate) prune_baseline: bool,
 }
 
 /// Handler for the "ci" command of the Biome CLI
@@ -47,29 +57,48 @@ pub(crate) fn ci(session: CliSession, mut payload: CiCommandPayload) -> Result<(
         directory_path: configuration_path,
         ..
     } = loaded_configuration;
-    let formatter = configuration
-        .formatter
-        .get_or_insert_with(FormatterConfiguration::default);
-
-    if payload.formatter_enabled.is_some() {
-        formatter.enabled = payload.formatter_enabled;
-    }
-
-    let linter = configuration
-        .linter
-        .get_or_insert_with(LinterConfiguration::default);
-
-    if payload.linter_enabled.is_some() {
-        linter.enabled = payload.linter_enabled;
-    }
 
-    let organize_imports = configuration
-        .organize_imports
-        .get_or_insert_with(OrganizeImports::default);
-
-    if payload.organize_imports_enabled.is_some() {
-        organize_imports.enabled = payload.organize_imports_enabled;
-    }
+    let formatter_enabled = payload.formatter_enabled;
+    let linter_enabled = payload.linter_enabled;
+    let organize_imports_enabled = payload.organize_imports_enabled;
+    let rome_configuration = payload.rome_configuration;
+    let arguments: ConfigurationArguments = Arc::new(move |configuration: &mut Configuration| {
+        let formatter = configuration
+            .formatter
+            .get_or_insert_with(FormatterConfiguration::default);
+
+        if formatter_enabled.is_some() {
+            formatter.enabled = formatter_enabled;
+        }
+
+        let linter = configuration
+            .linter
+            .get_or_insert_with(LinterConfiguration::default);
+
+        if linter_enabled.is_some() {
+            linter.enabled = linter_enabled;
+        }
+
+        let organize_imports = configuration
+            .organize_imports
+            .get_or_insert_with(OrganizeImports::default);
+
+        if organize_imports_enabled.is_some() {
+            organize_imports.enabled = organize_imports_enabled;
+        }
+
+        configuration.merge_with(rome_configuration.files.clone());
+        configuration.merge_with(rome_configuration.vcs.clone());
+        configuration.merge_with_if(
+            rome_configuration.formatter.clone(),
+            !configuration.is_formatter_disabled(),
+        );
+        configuration.merge_with_if(
+            rome_configuration.organize_imports.clone(),
+            !configuration.is_organize_imports_disabled(),
+        );
+    });
+    arguments(&mut configuration);
 
     // no point in doing the traversal if all the checks have been disabled
     if configuration.is_formatter_disabled()
@@ -79,22 +108,6 @@ pub(crate) fn ci(session: CliSession, mut payload: CiCommandPayload) -> Result<(
         return Err(CliDiagnostic::incompatible_end_configuration("Formatter, linter and organize imports are disabled, can't perform the command. This is probably and error."));
     }
 
-    configuration.merge_with(payload.rome_configuration.files);
-    configuration.merge_with(payload.rome_configuration.vcs);
-    configuration.merge_with_if(
-        payload.rome_configuration.formatter,
-        !configuration.is_formatter_disabled(),
-    );
-    configuration.merge_with_if(
-        payload.rome_configuration.organize_imports,
-        !configuration.is_organize_imports_disabled(),
-    );
-
-    // check if support of git ignore files is enabled
-    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    let (vcs_base_path, gitignore_matches) =
-        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
-
     if payload.since.is_some() && !payload.changed {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
@@ -103,19 +116,23 @@ pub(crate) fn ci(session: CliSession, mut payload: CiCommandPayload) -> Result<(
         payload.paths = get_changed_files(&session.app.fs, &configuration, payload.since)?;
     }
 
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams {
-            configuration,
-            vcs_base_path,
-            gitignore_matches,
-        })?;
-
-    execute_mode(
-        Execution::new_ci(),
-        session,
-        &payload.cli_options,
-        payload.paths,
-    )
+    let execution = Execution::new_ci()
+        .with_configuration(&*session.app.fs, &configuration)
+        .with_configuration_arguments(arguments)
+        .with_type_aware_rules(configuration.has_type_aware_rules())
+        .with_cache(payload.no_cache, payload.cache_dir)
+        .with_profile_rules(
+            payload.profile_rules,
+            payload.profile_rules_output,
+            payload.profile_rules_format,
+        )
+        .with_baseline(payload.baseline, payload.prune_baseline);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, configuration, configuration_path)?;
+    let execution = execution
+        .with_ignore_files(ignore_files)
+        .with_nested_configurations(nested_configurations);
+
+    execute_mode(execution, session, &payload.cli_options, payload.paths)
 }
@@ -0,0 +1,261 @@
+use crate::cli_options::CliOptions;
+use crate::commands::validate_configuration_diagnostics;
+use crate::execute::{configuration_root, nested_directories};
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, ConsoleExt};
+use biome_fs::FileSystem;
+use biome_service::configuration::{
+    load_configuration, load_directory_configuration, LoadedConfiguration,
+};
+use biome_service::file_handlers::resolve_configuration;
+use biome_service::settings::WorkspaceSettings;
+use biome_service::{Configuration, ConfigurationBasePath, MergeWith};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Handler for the `config print` command of the Biome CLI
+pub(crate) fn print(
+    session: CliSession,
+    cli_options: CliOptions,
+    path: PathBuf,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let base_path = match cli_options.config_path.as_ref() {
+        None => ConfigurationBasePath::default(),
+        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+    };
+    let fs = &*session.app.fs;
+    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let root = configuration_root(fs, loaded_configuration.directory_path.clone());
+    let mut layers = configuration_layers(fs, Vec::new(), &loaded_configuration)?;
+    let mut configuration = loaded_configuration.configuration;
+    for directory in nested_directories(fs, &root, &path) {
+        let Some(loaded_configuration) =
+            load_directory_configuration(fs, &directory, &configuration)?
+        else {
+            continue;
+        };
+        validate_configuration_diagnostics(
+            &loaded_configuration,
+            session.app.console,
+            cli_options.verbose,
+        )?;
+        layers = configuration_layers(fs, layers, &loaded_configuration)?;
+        configuration = loaded_configuration.configuration;
+    }
+    layers.extend(override_layers(&configuration));
+
+    let resolved_layers = layers
+        .iter()
+        .map(|layer| {
+            let mut settings = WorkspaceSettings::default();
+            settings.merge_with_configuration(layer.configuration.clone(), None, &[])?;
+            let mut values = BTreeMap::new();
+            flatten(
+                resolve_configuration(&settings, &path),
+                &mut String::new(),
+                &mut values,
+            );
+            Ok(values)
+        })
+        .collect::<Result<Vec<_>, CliDiagnostic>>()?;
+    let Some(resolved) = resolved_layers.last() else {
+        return Ok(());
+    };
+
+    // The source of a value is the layer from which it doesn't change anymore
+    let sources: BTreeMap<&str, &str> = resolved
+        .iter()
+        .map(|(key, value)| {
+            let position = resolved_layers
+                .iter()
+                .rposition(|values| values.get(key) != Some(value))
+                .map_or(0, |position| position + 1);
+            (key.as_str(), layers[position].source.as_str())
+        })
+        .collect();
+
+    let console = session.app.console;
+    if cli_options.json {
+        let output = json!({
+            "path": path.display().to_string(),
+            "configuration": unflatten(resolved),
+            "sources": sources,
+        });
+        console.log(markup! {
+            {output.to_string()}
+        });
+    } else {
+        console.log(markup! {
+            "The configuration of "<Emphasis>{path.display().to_string()}</Emphasis>":\n\n"
+            {ResolvedValues(resolved, &sources)}
+        });
+    }
+
+    Ok(())
+}
+
+/// Prints a resolved value by line, along with its source
+struct ResolvedValues<'a>(&'a BTreeMap<String, Value>, &'a BTreeMap<&'a str, &'a str>);
+
+impl Display for ResolvedValues<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let width = self.0.keys().map(String::len).max().unwrap_or_default();
+        for (key, value) in self.0 {
+            let source = self.1[key.as_str()];
+            let key = format!("{key:width$}");
+            let value = value.to_string();
+            fmt.write_markup(markup! {
+                {key}"  "{value}"  "<Dim>"("{source}")"</Dim>"\n"
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// A step of the resolution of the configuration of a file
+struct ConfigurationLayer {
+    /// Where the values of the layer come from, e.g. the path of a configuration file
+    source: String,
+    /// The configuration once the values of the layer are applied
+    configuration: Configuration,
+}
+
+/// Appends to `layers` the steps that lead to `loaded_configuration`: the
+/// files that it extends, then the file itself. Its overrides are left out,
+/// they're the last layers.
+///
+/// A configuration that doesn't inherit the one of its parent directory
+/// starts again from the defaults, otherwise the files it extends apply on top
+/// of the configuration of the parent directory.
+fn configuration_layers(
+    fs: &dyn FileSystem,
+    mut layers: Vec<ConfigurationLayer>,
+    loaded_configuration: &LoadedConfiguration,
+) -> Result<Vec<ConfigurationLayer>, CliDiagnostic> {
+    let inherits_from_parent = loaded_configuration.inherits_from_parent();
+    let mut extended_configuration = match layers.last() {
+        Some(parent) if inherits_from_parent => Some(parent.configuration.clone()),
+        _ => {
+            layers = vec![ConfigurationLayer {
+                source: "default".to_string(),
+                configuration: Configuration::default(),
+            }];
+            None
+        }
+    };
+    for extended_file in &loaded_configuration.extended_files {
+        let configuration = match extended_configuration {
+            Some(extended_configuration) if inherits_from_parent => {
+                extended_file.apply_to(extended_configuration)?
+            }
+            Some(mut extended_configuration) => {
+                extended_configuration.merge_with(extended_file.configuration.clone());
+                extended_configuration
+            }
+            None => extended_file.configuration.clone(),
+        };
+        layers.push(ConfigurationLayer {
+            source: display_path(fs, &extended_file.file_path),
+            configuration: without_overrides(&configuration),
+        });
+        extended_configuration = Some(configuration);
+    }
+
+    if let Some(file_path) = loaded_configuration.file_path.as_deref() {
+        layers.push(ConfigurationLayer {
+            source: display_path(fs, file_path),
+            configuration: without_overrides(&loaded_configuration.configuration),
+        });
+    }
+    Ok(layers)
+}
+
+/// Returns a layer for each override of `configuration`, applied in order
+fn override_layers(configuration: &Configuration) -> Vec<ConfigurationLayer> {
+    let Some(overrides) = configuration.overrides.as_ref() else {
+        return vec![];
+    };
+    (1..=overrides.0.len())
+        .map(|count| {
+            let mut configuration = configuration.clone();
+            if let Some(overrides) = configuration.overrides.as_mut() {
+                overrides.0.truncate(count);
+            }
+            ConfigurationLayer {
+                source: format!("overrides[{}]", count - 1),
+                configuration,
+            }
+        })
+        .collect()
+}
+
+fn without_overrides(configuration: &Configuration) -> Configuration {
+    Configuration {
+        overrides: None,
+        ..configuration.clone()
+    }
+}
+
+/// Returns the path of a configuration file relative to the working directory
+fn display_path(fs: &dyn FileSystem, path: &Path) -> String {
+    fs.working_directory()
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Maps the values of the configuration to their path, e.g. `formatter.enabled`.
+///
+/// The rules of the linter aren't split, their severity and their options form a single value.
+fn flatten(value: Value, key: &mut String, values: &mut BTreeMap<String, Value>) {
+    let is_rule = key.starts_with("linter.rules.") && key.matches('.').count() == 3;
+    match value {
+        Value::Object(map) if !is_rule => {
+            for (name, value) in map {
+                let length = key.len();
+                if !key.is_empty() {
+                    key.push('.');
+                }
+                key.push_str(&name);
+                flatten(value, key, values);
+                key.truncate(length);
+            }
+        }
+        value => {
+            values.insert(key.clone(), value);
+        }
+    }
+}
+
+/// The opposite of [flatten]
+fn unflatten(values: &BTreeMap<String, Value>) -> Value {
+    let mut configuration = Map::new();
+    for (key, value) in values {
+        let mut names = key.split('.').peekable();
+        let mut map = &mut configuration;
+        while let Some(name) = names.next() {
+            if names.peek().is_none() {
+                map.insert(name.to_string(), value.clone());
+            } else {
+                map = map
+                    .entry(name)
+                    .or_insert_with(|| Value::Object(Map::new()))
+                    .as_object_mut()
+                    .expect("the keys of the values are unique");
+            }
+        }
+    }
+    Value::Object(configuration)
+}
@@ -1,10 +1,11 @@
-use crate::changed::get_changed_files;
-use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
+use crate::changed::{get_changed_files, get_staged_files};
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
 use crate::diagnostics::DeprecatedArgument;
 use crate::execute::ReportMode;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, watch_mode, CliDiagnostic, CliSession,
+    ConfigurationArguments, Execution, TraversalMode,
 };
 use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::PrintDiagnostic;
@@ -14,10 +15,10 @@ use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
     load_configuration, FilesConfiguration, FormatterConfiguration, LoadedConfiguration,
 };
-use biome_service::workspace::UpdateSettingsParams;
-use biome_service::{ConfigurationBasePath, JavascriptFormatter, MergeWith};
+use biome_service::{Configuration, ConfigurationBasePath, JavascriptFormatter, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<JavascriptFormatter>,
@@ -28,10 +29,18 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) write: bool,
+    pub(crate) source_map: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) no_cache: bool,
+    pub(crate) cache_dir: Option<String>,
+    pub(crate) profile_rules: bool,
+    pub(crate) profile_rules_output: Option<String>,
+    pub(crate) profile_rules_format: ProfileFormat,
 }
 
 /// Handler for the "format" command of the Biome CLI
@@ -48,29 +57,99 @@ pub(crate) fn format(
         stdin_file_path,
         files_configuration,
         write,
+        source_map,
         json_formatter,
         css_formatter,
         since,
+        staged,
         changed,
+        watch,
+        no_cache,
+        cache_dir,
+        profile_rules,
+        profile_rules_output,
+        profile_rules_format,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
-    let base_path = match cli_options.config_path.as_ref() {
-        None => ConfigurationBasePath::default(),
-        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+    if staged && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--stdin-file-path",
+        ));
+    }
+
+    if staged && watch {
+        return Err(CliDiagnostic::incompatible_arguments("--staged", "--watch"));
+    }
+
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    if source_map {
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--source-map",
+                "--stdin-file-path",
+            ));
+        }
+        if staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--source-map",
+                "--staged",
+            ));
+        }
+        if !write {
+            return Err(CliDiagnostic::missing_argument("--write", "format"));
+        }
+    }
+
+    let arguments: ConfigurationArguments = Arc::new({
+        // the arguments are checked for deprecated options afterwards
+        let javascript_formatter = javascript_formatter.clone();
+        let json_formatter = json_formatter.clone();
+        let css_formatter = css_formatter.clone();
+        let formatter_configuration = formatter_configuration.clone();
+        move |configuration: &mut Configuration| {
+            configuration.merge_with(javascript_formatter.clone());
+            configuration.merge_with(json_formatter.clone());
+            configuration.merge_with(css_formatter.clone());
+            configuration.merge_with(formatter_configuration.clone());
+            configuration.merge_with(vcs_configuration.clone());
+            configuration.merge_with(files_configuration.clone());
+        }
+    });
+
+    let resolve_configuration = |session: &mut CliSession| {
+        let base_path = match cli_options.config_path.as_ref() {
+            None => ConfigurationBasePath::default(),
+            Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+        };
+
+        let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+        validate_configuration_diagnostics(
+            &loaded_configuration,
+            &mut *session.app.console,
+            cli_options.verbose,
+        )?;
+        let extended_files = loaded_configuration.extended_file_paths();
+        let LoadedConfiguration {
+            mut configuration,
+            directory_path: configuration_path,
+            ..
+        } = loaded_configuration;
+
+        arguments(&mut configuration);
+
+        Ok::<_, CliDiagnostic>((configuration, configuration_path, extended_files))
     };
 
-    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
-    validate_configuration_diagnostics(
-        &loaded_configuration,
-        session.app.console,
-        cli_options.verbose,
-    )?;
-    let LoadedConfiguration {
-        mut configuration,
-        directory_path: configuration_path,
-        ..
-    } = loaded_configuration;
+    let (configuration, configuration_path, extended_files) = resolve_configuration(&mut session)?;
+
     // TODO: remove in biome 2.0
     if formatter_configuration
         .as_ref()
@@ -124,35 +203,25 @@ pub(crate) fn format(
         })
     }
 
-    configuration.merge_with(javascript_formatter);
-    configuration.merge_with(json_formatter);
-    configuration.merge_with(css_formatter);
-    configuration.merge_with(formatter_configuration);
-    configuration.merge_with(vcs_configuration);
-    configuration.merge_with(files_configuration);
-
-    // check if support of git ignore files is enabled
-    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    let (vcs_base_path, gitignore_matches) =
-        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
-
     if since.is_some() && !changed {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if staged && changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--changed",
+        ));
+    }
+
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &configuration, since)?;
     }
 
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams {
-            configuration,
-            vcs_base_path,
-            gitignore_matches,
-        })?;
-
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
         let input_code = console.read();
@@ -172,6 +241,7 @@ pub(crate) fn format(
             TraversalMode::Format {
                 ignore_errors: cli_options.skip_errors,
                 write,
+                source_map,
                 stdin,
             },
             ReportMode::Json,
@@ -180,9 +250,33 @@ pub(crate) fn format(
         Execution::new(TraversalMode::Format {
             ignore_errors: cli_options.skip_errors,
             write,
+            source_map,
             stdin,
         })
-    };
+    }
+    .with_configuration(&*session.app.fs, &configuration)
+    .with_configuration_arguments(arguments.clone())
+    .with_staged_files(staged)
+    .with_cache(no_cache, cache_dir)
+    .with_profile_rules(profile_rules, profile_rules_output, profile_rules_format);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, configuration, configuration_path.clone())?;
+    let execution = execution
+        .with_ignore_files(ignore_files)
+        .with_nested_configurations(nested_configurations);
 
-    execute_mode(execution, session, &cli_options, paths)
+    if watch {
+        watch_mode(
+            execution,
+            session,
+            &cli_options,
+            paths,
+            configuration_path,
+            extended_files,
+            resolve_configuration,
+        )
+    } else {
+        execute_mode(execution, session, &cli_options, paths)
+    }
 }
@@ -1,17 +1,19 @@
-use crate::changed::get_changed_files;
-use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
+use crate::changed::{get_changed_files, get_staged_files};
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, setup_cli_subscriber, watch_mode, CliDiagnostic, CliSession,
+    ConfigurationArguments, Execution, TraversalMode,
 };
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
     load_configuration, FilesConfiguration, LinterConfiguration, LoadedConfiguration,
 };
-use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
-use biome_service::{ConfigurationBasePath, MergeWith};
+use biome_service::workspace::FixFileMode;
+use biome_service::{Configuration, ConfigurationBasePath, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
@@ -24,6 +26,16 @@ pub(crate) struct LintCommandPayload {
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) no_cache: bool,
+    pub(crate) cache_dir: Option<String>,
+    pub(crate) profile_rules: bool,
+    pub(crate) profile_rules_output: Option<String>,
+    pub(crate) profile_rules_format: ProfileFormat,
+    pub(crate) baseline: Option<String>,
+    pub(crate) prune_baseline: bool,
+    pub(crate) write_baseline: bool,
 }
 
 /// Handler for the "lint" command of the Biome CLI
@@ -42,6 +54,16 @@ pub(crate) fn lint(
         files_configuration,
         changed,
         since,
+        staged,
+        watch,
+        no_cache,
+        cache_dir,
+        profile_rules,
+        profile_rules_output,
+        profile_rules_format,
+        baseline,
+        prune_baseline,
+        write_baseline,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
@@ -58,36 +80,79 @@ pub(crate) fn lint(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
-    let base_path = match cli_options.config_path.as_ref() {
-        None => ConfigurationBasePath::default(),
-        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+    if staged && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--stdin-file-path",
+        ));
+    }
+
+    if staged && watch {
+        return Err(CliDiagnostic::incompatible_arguments("--staged", "--watch"));
+    }
+
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    if write_baseline && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--write-baseline",
+            "--stdin-file-path",
+        ));
+    }
+
+    let arguments: ConfigurationArguments = Arc::new(move |configuration: &mut Configuration| {
+        configuration.merge_with(linter_configuration.clone());
+        configuration.merge_with(files_configuration.clone());
+        configuration.merge_with(vcs_configuration.clone());
+    });
+
+    let resolve_configuration = |session: &mut CliSession| {
+        let base_path = match cli_options.config_path.as_ref() {
+            None => ConfigurationBasePath::default(),
+            Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+        };
+
+        let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+        validate_configuration_diagnostics(
+            &loaded_configuration,
+            &mut *session.app.console,
+            cli_options.verbose,
+        )?;
+
+        let extended_files = loaded_configuration.extended_file_paths();
+        let LoadedConfiguration {
+            configuration: mut fs_configuration,
+            directory_path: configuration_path,
+            ..
+        } = loaded_configuration;
+        arguments(&mut fs_configuration);
+
+        Ok::<_, CliDiagnostic>((fs_configuration, configuration_path, extended_files))
     };
 
-    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
-    validate_configuration_diagnostics(
-        &loaded_configuration,
-        session.app.console,
-        cli_options.verbose,
-    )?;
-
-    let LoadedConfiguration {
-        configuration: mut fs_configuration,
-        directory_path: configuration_path,
-        ..
-    } = loaded_configuration;
-    fs_configuration.merge_with(linter_configuration);
-    fs_configuration.merge_with(files_configuration);
-    fs_configuration.merge_with(vcs_configuration);
-
-    // check if support of git ignore files is enabled
-    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
-    let (vcs_base_path, gitignore_matches) =
-        fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+    let (fs_configuration, configuration_path, extended_files) =
+        resolve_configuration(&mut session)?;
 
     if since.is_some() && !changed {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if staged && changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--staged",
+            "--changed",
+        ));
+    }
+
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
@@ -106,22 +171,36 @@ pub(crate) fn lint(
         None
     };
 
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams {
-            configuration: fs_configuration,
-            vcs_base_path,
-            gitignore_matches,
-        })?;
-
-    execute_mode(
-        Execution::new(TraversalMode::Lint {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    let execution = Execution::new(TraversalMode::Lint {
+        fix_file_mode,
+        stdin,
+    })
+    .with_configuration(&*session.app.fs, &fs_configuration)
+    .with_configuration_arguments(arguments.clone())
+    .with_staged_files(staged)
+    .with_cache(no_cache, cache_dir)
+    .with_profile_rules(profile_rules, profile_rules_output, profile_rules_format)
+    .with_type_aware_rules(fs_configuration.has_type_aware_rules())
+    .with_baseline(baseline, prune_baseline)
+    .with_write_baseline(write_baseline);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, fs_configuration, configuration_path.clone())?;
+    let execution = execution
+        .with_ignore_files(ignore_files)
+        .with_nested_configurations(nested_configurations);
+
+    if watch {
+        watch_mode(
+            execution,
+            session,
+            &cli_options,
+            paths,
+            configuration_path,
+            extended_files,
+            resolve_configuration,
+        )
+    } else {
+        execute_mode(execution, session, &cli_options, paths)
+    }
 }
@@ -17,10 +17,9 @@ pub(crate) fn migrate(
         Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
     };
     let LoadedConfiguration {
-        configuration: _,
-        diagnostics: _,
         directory_path,
         file_path,
+        ..
     } = load_configuration(&session.app.fs, base_path)?;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
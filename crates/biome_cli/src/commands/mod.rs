@@ -1,7 +1,7 @@
-use crate::cli_options::{cli_options, CliOptions, ColorsArg};
+use crate::cli_options::{cli_options, CliOptions, ColorsArg, ProfileFormat};
 use crate::diagnostics::DeprecatedConfigurationFile;
 use crate::logging::LoggingKind;
-use crate::{CliDiagnostic, LoggingLevel, VERSION};
+use crate::{CliDiagnostic, CliSession, LoggingLevel, NestedConfigurations, VERSION};
 use biome_console::{markup, Console, ConsoleExt};
 use biome_diagnostics::PrintDiagnostic;
 use biome_service::configuration::css::CssFormatter;
@@ -9,18 +9,20 @@ use biome_service::configuration::json::JsonFormatter;
 use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
     configuration, css::css_formatter, files_configuration, formatter_configuration,
-    javascript::javascript_formatter, json::json_formatter, linter_configuration,
+    javascript::javascript_formatter, json::json_formatter, linter_configuration, load_plugins,
     vcs::vcs_configuration, FilesConfiguration, FormatterConfiguration, JavascriptFormatter,
     LinterConfiguration, LoadedConfiguration,
 };
 use biome_service::documentation::Doc;
-use biome_service::{Configuration, ConfigurationDiagnostic, WorkspaceError};
+use biome_service::workspace::UpdateSettingsParams;
+use biome_service::{Configuration, ConfigurationDiagnostic, IgnoreFiles, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
 use std::path::PathBuf;
 
 pub(crate) mod check;
 pub(crate) mod ci;
+pub(crate) mod config;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
@@ -28,6 +30,9 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod rewrite;
+pub(crate) mod search;
+pub(crate) mod transform;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -108,6 +113,50 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the files staged in the git index are processed, using their staged content.
+        /// The changes are written to the index, and to the working tree when they don't overlap with the unstaged changes.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keeps running after the first run, and checks the files again when they change.
+        /// The configuration is reloaded when the configuration file changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Process all the files again, instead of reusing the results of the files that didn't change since the last run.
+        #[bpaf(long("no-cache"), switch)]
+        no_cache: bool,
+
+        /// Set the directory where Biome stores the results of the files between two runs. Defaults to `node_modules/.cache/biome` when the working directory has a `node_modules` folder.
+        #[bpaf(long("cache-dir"), argument("PATH"), optional)]
+        cache_dir: Option<String>,
+
+        /// Print the time spent in each lint rule, in each phase of the analyzer and on each file, once the files are processed.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
+        /// Write the time spent in each lint rule, in each phase of the analyzer and on each file to a file.
+        #[bpaf(long("profile-rules-output"), argument("PATH"), optional)]
+        profile_rules_output: Option<String>,
+
+        /// The format of the file written by `--profile-rules-output`: "json" writes the timings as a JSON object, "chrome-trace" writes a trace that can be opened with `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-rules-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default()),
+            display_fallback
+        )]
+        profile_rules_format: ProfileFormat,
+
+        /// Set the path of the baseline file, which lists the lint diagnostics that aren't reported.
+        /// Defaults to `biome-baseline.json` in the directory of the configuration file.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+
+        /// Remove the diagnostics that no longer occur from the baseline file.
+        #[bpaf(long("prune-baseline"), switch)]
+        prune_baseline: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -147,6 +196,45 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// When set to true, only the files staged in the git index are processed, using their staged content.
+        /// The changes are written to the index, and to the working tree when they don't overlap with the unstaged changes.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// Keeps running after the first run, and lints the files again when they change.
+        /// The configuration is reloaded when the configuration file changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Process all the files again, instead of reusing the results of the files that didn't change since the last run.
+        #[bpaf(long("no-cache"), switch)]
+        no_cache: bool,
+        /// Set the directory where Biome stores the results of the files between two runs. Defaults to `node_modules/.cache/biome` when the working directory has a `node_modules` folder.
+        #[bpaf(long("cache-dir"), argument("PATH"), optional)]
+        cache_dir: Option<String>,
+        /// Print the time spent in each lint rule, in each phase of the analyzer and on each file, once the files are processed.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+        /// Write the time spent in each lint rule, in each phase of the analyzer and on each file to a file.
+        #[bpaf(long("profile-rules-output"), argument("PATH"), optional)]
+        profile_rules_output: Option<String>,
+        /// The format of the file written by `--profile-rules-output`: "json" writes the timings as a JSON object, "chrome-trace" writes a trace that can be opened with `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-rules-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default()),
+            display_fallback
+        )]
+        profile_rules_format: ProfileFormat,
+        /// Set the path of the baseline file, which lists the lint diagnostics that aren't reported.
+        /// Defaults to `biome-baseline.json` in the directory of the configuration file.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+        /// Remove the diagnostics that no longer occur from the baseline file.
+        #[bpaf(long("prune-baseline"), switch)]
+        prune_baseline: bool,
+        /// Writes the diagnostics of the files to the baseline file instead of reporting them.
+        /// The next runs of `lint`, `check` and `ci` only report the diagnostics that aren't in the baseline.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -186,6 +274,10 @@ pub enum BiomeCommand {
         #[bpaf(switch)]
         write: bool,
 
+        /// Writes a source map next to each formatted file, e.g. `file.js.map`, that maps the formatted code to the code before formatting. It requires `--write`.
+        #[bpaf(long("source-map"), switch)]
+        source_map: bool,
+
         /// When set to true, only the files that have been changed compared to your `defaultBranch`
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
@@ -196,6 +288,131 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the files staged in the git index are processed, using their staged content.
+        /// The changes are written to the index, and to the working tree when they don't overlap with the unstaged changes.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keeps running after the first run, and formats the files again when they change.
+        /// The configuration is reloaded when the configuration file changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Process all the files again, instead of reusing the results of the files that didn't change since the last run.
+        #[bpaf(long("no-cache"), switch)]
+        no_cache: bool,
+
+        /// Set the directory where Biome stores the results of the files between two runs. Defaults to `node_modules/.cache/biome` when the working directory has a `node_modules` folder.
+        #[bpaf(long("cache-dir"), argument("PATH"), optional)]
+        cache_dir: Option<String>,
+
+        /// Print the time spent in each lint rule, in each phase of the analyzer and on each file, once the files are processed.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
+        /// Write the time spent in each lint rule, in each phase of the analyzer and on each file to a file.
+        #[bpaf(long("profile-rules-output"), argument("PATH"), optional)]
+        profile_rules_output: Option<String>,
+
+        /// The format of the file written by `--profile-rules-output`: "json" writes the timings as a JSON object, "chrome-trace" writes a trace that can be opened with `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-rules-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default()),
+            display_fallback
+        )]
+        profile_rules_format: ProfileFormat,
+
+        /// Single file, single path or list of paths.
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+    /// Compiles the TypeScript and JSX syntax of a set of files, and prints the resulting JavaScript code.
+    #[bpaf(command)]
+    Transform {
+        #[bpaf(external, optional, hide_usage)]
+        vcs_configuration: Option<VcsConfiguration>,
+
+        #[bpaf(external, optional, hide_usage)]
+        files_configuration: Option<FilesConfiguration>,
+        /// Use this option when you want to transform code piped from `stdin`, and print the output to `stdout`.
+        ///
+        /// The file doesn't need to exist on disk, what matters is the extension of the file. Based on the extension, Biome knows how to transform the code.
+        ///
+        /// Example: `echo 'let a: string;' | biome transform --stdin-file-path=file.ts`
+        #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
+        stdin_file_path: Option<String>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Writes the transformed files next to the original ones, e.g. `file.ts` is written to `file.js`.
+        #[bpaf(switch)]
+        write: bool,
+
+        /// Writes a source map next to each transformed file, e.g. `file.js.map`. It requires `--write`.
+        #[bpaf(long("source-map"), switch)]
+        source_map: bool,
+
+        /// Single file, single path or list of paths.
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+    /// Searches the code that matches a structural pattern in a set of files, and prints the matches.
+    ///
+    /// The pattern is a JavaScript expression or statement, where the metavariables match any code:
+    /// `$name` matches a single node, e.g. an expression, and `$$$name` matches any number of nodes of a list,
+    /// e.g. the arguments of a call. A metavariable used several times matches the same code each time.
+    ///
+    /// Example: `biome search 'useEffect($$$, [])' ./src`
+    #[bpaf(command)]
+    Search {
+        #[bpaf(external, optional, hide_usage)]
+        vcs_configuration: Option<VcsConfiguration>,
+
+        #[bpaf(external, optional, hide_usage)]
+        files_configuration: Option<FilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The pattern to search, e.g. `$value as any`.
+        #[bpaf(positional("PATTERN"))]
+        pattern: String,
+
+        /// Single file, single path or list of paths.
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+    /// Replaces the code that matches a structural pattern with a replacement in a set of files.
+    ///
+    /// The pattern is written like the one of `biome search`. The metavariables of the replacement
+    /// are replaced by the code they captured in the pattern. By default, the changes are printed as diffs.
+    ///
+    /// Example: `biome rewrite '$list.concat($item)' '[...$list, $item]' ./src`
+    #[bpaf(command)]
+    Rewrite {
+        #[bpaf(external, optional, hide_usage)]
+        vcs_configuration: Option<VcsConfiguration>,
+
+        #[bpaf(external, optional, hide_usage)]
+        files_configuration: Option<FilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Writes the rewritten files to the file system.
+        #[bpaf(switch)]
+        write: bool,
+
+        /// The pattern to search, e.g. `$value as any`.
+        #[bpaf(positional("PATTERN"))]
+        pattern: String,
+
+        /// The code that replaces the matches, e.g. `$value as unknown`.
+        #[bpaf(positional("REPLACEMENT"))]
+        replacement: String,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -230,11 +447,49 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Process all the files again, instead of reusing the results of the files that didn't change since the last run.
+        #[bpaf(long("no-cache"), switch)]
+        no_cache: bool,
+
+        /// Set the directory where Biome stores the results of the files between two runs. Defaults to `node_modules/.cache/biome` when the working directory has a `node_modules` folder.
+        #[bpaf(long("cache-dir"), argument("PATH"), optional)]
+        cache_dir: Option<String>,
+
+        /// Print the time spent in each lint rule, in each phase of the analyzer and on each file, once the files are processed.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
+        /// Write the time spent in each lint rule, in each phase of the analyzer and on each file to a file.
+        #[bpaf(long("profile-rules-output"), argument("PATH"), optional)]
+        profile_rules_output: Option<String>,
+
+        /// The format of the file written by `--profile-rules-output`: "json" writes the timings as a JSON object, "chrome-trace" writes a trace that can be opened with `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-rules-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default()),
+            display_fallback
+        )]
+        profile_rules_format: ProfileFormat,
+
+        /// Set the path of the baseline file, which lists the lint diagnostics that aren't reported.
+        /// Defaults to `biome-baseline.json` in the directory of the configuration file.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+
+        /// Remove the diagnostics that no longer occur from the baseline file.
+        #[bpaf(long("prune-baseline"), switch)]
+        prune_baseline: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
     },
 
+    /// Commands to inspect the configuration of the project.
+    #[bpaf(command)]
+    Config(#[bpaf(external(config_command))] ConfigCommand),
+
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
     Init,
@@ -284,6 +539,32 @@ pub enum BiomeCommand {
     PrintSocket,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+pub enum ConfigCommand {
+    /// Prints the configuration that applies to a file: the options of its formatter and of its
+    /// parser, and the rules of the linter that are enabled, with their severity and their options.
+    ///
+    /// The values are resolved from the configuration files, the files they extend, and the
+    /// overrides that match the file. Each value is printed along with where it comes from.
+    ///
+    /// Use `--json` to print the configuration using the JSON format.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome config print src/index.js
+    /// ```
+    #[bpaf(command)]
+    Print {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The file to print the configuration of
+        #[bpaf(positional("PATH"))]
+        path: PathBuf,
+    },
+}
+
 impl BiomeCommand {
     pub const fn get_color(&self) -> Option<&ColorsArg> {
         match self {
@@ -293,6 +574,10 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Rewrite { cli_options, .. }
+            | BiomeCommand::Config(ConfigCommand::Print { cli_options, .. })
             | BiomeCommand::Migrate(cli_options, _) => cli_options.colors.as_ref(),
             BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
@@ -312,6 +597,10 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Rewrite { cli_options, .. }
+            | BiomeCommand::Config(ConfigCommand::Print { cli_options, .. })
             | BiomeCommand::Migrate(cli_options, _) => cli_options.use_server,
             BiomeCommand::Init
             | BiomeCommand::Start(_)
@@ -333,6 +622,10 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Rewrite { cli_options, .. }
+            | BiomeCommand::Config(ConfigCommand::Print { cli_options, .. })
             | BiomeCommand::Migrate(cli_options, _) => cli_options.verbose,
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
@@ -352,6 +645,10 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Rewrite { cli_options, .. }
+            | BiomeCommand::Config(ConfigCommand::Print { cli_options, .. })
             | BiomeCommand::Migrate(cli_options, _) => cli_options.log_level.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::LspProxy(_)
@@ -370,6 +667,10 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Rewrite { cli_options, .. }
+            | BiomeCommand::Config(ConfigCommand::Print { cli_options, .. })
             | BiomeCommand::Migrate(cli_options, _) => cli_options.log_kind.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
@@ -423,3 +724,40 @@ pub(crate) fn validate_configuration_diagnostics(
 
     Ok(())
 }
+
+/// Applies the configuration to the workspace, along with the ignore files of the VCS when
+/// their support is enabled. Returns the ignore files to read during the traversal, and the
+/// configuration files of its directories.
+pub(crate) fn update_workspace_settings(
+    session: &CliSession,
+    configuration: Configuration,
+    configuration_path: Option<PathBuf>,
+) -> Result<(Option<IgnoreFiles>, NestedConfigurations), CliDiagnostic> {
+    let vcs_base_path = configuration_path
+        .clone()
+        .or(session.app.fs.working_directory());
+    let ignore_files = configuration.vcs_ignore_files(vcs_base_path.as_deref())?;
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+    let plugins = load_plugins(&*session.app.fs, &configuration)?;
+    let nested_configurations = NestedConfigurations::new(
+        &*session.app.fs,
+        configuration_path,
+        configuration.clone(),
+        vcs_base_path.clone(),
+        gitignore_matches.clone(),
+    );
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_base_path,
+            gitignore_matches,
+            directory: None,
+            plugins,
+        })?;
+
+    Ok((ignore_files, nested_configurations))
+}
@@ -3,7 +3,9 @@ use biome_console::{fmt, markup, ConsoleExt, HorizontalLine, Markup};
 use biome_diagnostics::termcolor::{ColorChoice, WriteColor};
 use biome_diagnostics::{termcolor, PrintDescription};
 use biome_fs::FileSystem;
-use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_service::configuration::{
+    load_configuration, ExtendedConfigurationFile, LoadedConfiguration,
+};
 use biome_service::workspace::{client, RageEntry, RageParams};
 use biome_service::{ConfigurationBasePath, DynRef, Workspace};
 use std::{env, io, ops::Deref};
@@ -179,6 +181,7 @@ impl Display for RageConfiguration<'_, '_> {
                     let LoadedConfiguration {
                         configuration,
                         diagnostics,
+                        extended_files,
                         ..
                     } = loaded_configuration;
                     let status = if !diagnostics.is_empty() {
@@ -201,7 +204,12 @@ impl Display for RageConfiguration<'_, '_> {
                     {KeyValuePair("Linter disabled", markup!({DebugDisplay(configuration.is_linter_disabled())}))}
                     {KeyValuePair("Organize imports disabled", markup!({DebugDisplay(configuration.is_organize_imports_disabled())}))}
                     {KeyValuePair("VCS disabled", markup!({DebugDisplay(configuration.is_vcs_disabled())}))}
-                ).fmt(fmt)?
+                ).fmt(fmt)?;
+
+                    if !extended_files.is_empty() {
+                        Section("Extended Configuration Files").fmt(fmt)?;
+                        ExtendedFiles(&extended_files, 0).fmt(fmt)?;
+                    }
                 }
             }
             Err(err) => markup! (
@@ -215,6 +223,27 @@ impl Display for RageConfiguration<'_, '_> {
     }
 }
 
+/// Prints the configuration files extended by the configuration, and the path
+/// they resolve to. The files they extend are indented below them.
+struct ExtendedFiles<'a>(&'a [ExtendedConfigurationFile], usize);
+
+impl Display for ExtendedFiles<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let ExtendedFiles(extended_files, depth) = self;
+        for extended_file in extended_files.iter() {
+            let specifier = format!("{}{}", "  ".repeat(*depth), extended_file.specifier);
+            KeyValuePair(
+                &specifier,
+                markup!({ extended_file.file_path.display().to_string() }),
+            )
+            .fmt(fmt)?;
+            ExtendedFiles(&extended_file.extends, depth + 1).fmt(fmt)?;
+        }
+
+        Ok(())
+    }
+}
+
 struct DebugDisplay<T>(T);
 
 impl<T> Display for DebugDisplay<T>
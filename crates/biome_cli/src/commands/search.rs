@@ -0,0 +1,74 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, ConfigurationArguments,
+    Execution, TraversalMode,
+};
+use biome_pattern::parse_js_pattern;
+use biome_service::configuration::vcs::VcsConfiguration;
+use biome_service::configuration::{load_configuration, FilesConfiguration, LoadedConfiguration};
+use biome_service::{Configuration, ConfigurationBasePath, MergeWith, WorkspaceError};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub(crate) struct SearchCommandPayload {
+    pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) files_configuration: Option<FilesConfiguration>,
+    pub(crate) cli_options: CliOptions,
+    pub(crate) pattern: String,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the "search" command of the Biome CLI
+pub(crate) fn search(
+    session: CliSession,
+    payload: SearchCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let SearchCommandPayload {
+        vcs_configuration,
+        files_configuration,
+        cli_options,
+        pattern,
+        paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    // The pattern is validated once, instead of failing for each file
+    parse_js_pattern(&pattern).map_err(WorkspaceError::from)?;
+
+    let base_path = match cli_options.config_path.as_ref() {
+        None => ConfigurationBasePath::default(),
+        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+    };
+
+    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+    let LoadedConfiguration {
+        mut configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+    let arguments: ConfigurationArguments = Arc::new(move |configuration: &mut Configuration| {
+        configuration.merge_with(vcs_configuration.clone());
+        configuration.merge_with(files_configuration.clone());
+    });
+    arguments(&mut configuration);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, configuration, configuration_path)?;
+
+    execute_mode(
+        Execution::new(TraversalMode::Search { pattern })
+            .with_configuration_arguments(arguments)
+            .with_ignore_files(ignore_files)
+            .with_nested_configurations(nested_configurations),
+        session,
+        &cli_options,
+        paths,
+    )
+}
@@ -0,0 +1,104 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{update_workspace_settings, validate_configuration_diagnostics};
+use crate::{
+    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, ConfigurationArguments,
+    Execution, TraversalMode,
+};
+use biome_service::configuration::vcs::VcsConfiguration;
+use biome_service::configuration::{load_configuration, FilesConfiguration, LoadedConfiguration};
+use biome_service::{Configuration, ConfigurationBasePath, MergeWith};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub(crate) struct TransformCommandPayload {
+    pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) files_configuration: Option<FilesConfiguration>,
+    pub(crate) stdin_file_path: Option<String>,
+    pub(crate) write: bool,
+    pub(crate) source_map: bool,
+    pub(crate) cli_options: CliOptions,
+    pub(crate) paths: Vec<OsString>,
+}
+
+/// Handler for the "transform" command of the Biome CLI
+pub(crate) fn transform(
+    mut session: CliSession,
+    payload: TransformCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let TransformCommandPayload {
+        vcs_configuration,
+        files_configuration,
+        stdin_file_path,
+        write,
+        source_map,
+        cli_options,
+        paths,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let base_path = match cli_options.config_path.as_ref() {
+        None => ConfigurationBasePath::default(),
+        Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
+    };
+
+    let loaded_configuration = load_configuration(&session.app.fs, base_path)?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+    let LoadedConfiguration {
+        mut configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+    let arguments: ConfigurationArguments = Arc::new(move |configuration: &mut Configuration| {
+        configuration.merge_with(vcs_configuration.clone());
+        configuration.merge_with(files_configuration.clone());
+    });
+    arguments(&mut configuration);
+
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(&session, configuration, configuration_path)?;
+
+    if source_map {
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--source-map",
+                "--stdin-file-path",
+            ));
+        }
+        if !write {
+            return Err(CliDiagnostic::missing_argument("--write", "transform"));
+        }
+    }
+
+    let stdin = if let Some(stdin_file_path) = stdin_file_path {
+        let console = &mut session.app.console;
+        let input_code = console.read();
+        if let Some(input_code) = input_code {
+            let path = PathBuf::from(stdin_file_path);
+            Some((path, input_code))
+        } else {
+            // we provided the argument without a piped stdin, we bail
+            return Err(CliDiagnostic::missing_argument("stdin", "transform"));
+        }
+    } else {
+        None
+    };
+
+    execute_mode(
+        Execution::new(TraversalMode::Transform {
+            write,
+            source_map,
+            stdin,
+        })
+        .with_configuration_arguments(arguments)
+        .with_ignore_files(ignore_files)
+        .with_nested_configurations(nested_configurations),
+        session,
+        &cli_options,
+        paths,
+    )
+}
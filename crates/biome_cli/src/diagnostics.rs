@@ -2,7 +2,7 @@ use biome_console::fmt::{Display, Formatter};
 use biome_console::markup;
 use biome_diagnostics::adapters::{BpafError, IoError};
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
+    category, Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
     MessageAndDescription, Severity, Visit,
 };
 use biome_service::WorkspaceError;
@@ -439,6 +439,20 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the changes to a staged file conflict with its unstaged changes
+    pub fn unstaged_changes(file_path: impl Into<String>) -> Self {
+        Self::FileCheck(FileCheck {
+            file_path: file_path.into(),
+            category: category!("files/unstagedChanges"),
+            message: MessageAndDescription::from(
+                markup! {
+                    "The changes weren't written because they conflict with the unstaged changes of the file, stage or stash them and run the command again."
+                }
+                .to_owned(),
+            ),
+        })
+    }
+
     /// Emitted when the server is not running
     pub fn server_not_running() -> Self {
         Self::ServerNotRunning(ServerNotRunning)
@@ -0,0 +1,368 @@
+//! Baseline of the lint diagnostics, checked in with the code.
+//!
+//! The baseline is a JSON file that lists the lint diagnostics of each file
+//! when it was written with `biome lint --write-baseline`. The commands
+//! `lint`, `check` and `ci` don't report the diagnostics found in the
+//! baseline, so that a rule can be enabled before its existing violations are
+//! fixed.
+//!
+//! A diagnostic is identified by its rule and a fingerprint of the code it
+//! reports: the hash of the trimmed lines of its range. The fingerprint
+//! doesn't depend on the position of the code, so the baseline keeps matching
+//! when the lines around the diagnostic change.
+//!
+//! The entries of the processed files that no longer occur are stale: they're
+//! reported after the traversal, and `--prune-baseline` removes them.
+
+use crate::execute::nested_configurations::{absolute, configuration_root};
+use crate::execute::{Execution, TraversalMode, WrittenFiles};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, OpenOptions};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// The path of the baseline when the `--baseline` argument isn't passed,
+/// relative to the directory of the configuration
+const DEFAULT_BASELINE_PATH: &str = "biome-baseline.json";
+
+/// The baseline used by a command
+pub(crate) struct Baseline {
+    /// The path of the file where the baseline is stored
+    path: PathBuf,
+    /// Whether the command writes the diagnostics it finds to the baseline
+    write: bool,
+    /// Whether the command removes the stale entries from the baseline
+    prune: bool,
+    /// The directory of the configuration, the paths of the baseline are
+    /// relative to it
+    root: PathBuf,
+    /// The entries of the file, by path of the linted file
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+    /// The results of the files processed by the command
+    state: Mutex<BaselineState>,
+}
+
+#[derive(Default)]
+struct BaselineState {
+    /// The paths of the processed files
+    processed: FxHashSet<String>,
+    /// The entries of the processed files that didn't match a diagnostic
+    stale: FxHashMap<String, Vec<BaselineEntry>>,
+    /// The entries of the diagnostics found in the processed files, when the
+    /// baseline is written
+    recorded: FxHashMap<String, Vec<BaselineEntry>>,
+}
+
+/// The content of the file of the baseline
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BaselineFile {
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+/// The diagnostics of a rule that report the same code in a file
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct BaselineEntry {
+    /// The name of the rule, e.g. `style/useConst`
+    rule: String,
+    /// The hash of the code reported by the diagnostics
+    fingerprint: String,
+    /// The number of diagnostics
+    count: u32,
+}
+
+impl Baseline {
+    /// Loads the baseline of the command run by `execution`.
+    ///
+    /// It returns [None] if the command doesn't lint the files, or if there
+    /// isn't any baseline and the command doesn't write it.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        execution: &Execution,
+    ) -> Result<Option<Self>, io::Error> {
+        let lints_files = matches!(
+            execution.traversal_mode(),
+            TraversalMode::Check { stdin: None, .. }
+                | TraversalMode::Lint { stdin: None, .. }
+                | TraversalMode::CI { .. }
+        );
+        if !lints_files {
+            return Ok(None);
+        }
+        let write = execution.writes_baseline();
+        let root = baseline_root(fs, execution);
+        let path = match execution.baseline_path() {
+            Some(path) => path.to_path_buf(),
+            None => root.join(DEFAULT_BASELINE_PATH),
+        };
+        let files = if fs.path_exists(&path) {
+            read_baseline_file(fs, &path)?.files
+        } else if write || execution.baseline_path().is_some() {
+            BTreeMap::new()
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            path,
+            write,
+            prune: execution.prunes_baseline(),
+            root,
+            files,
+            state: Mutex::new(BaselineState::default()),
+        }))
+    }
+
+    /// Returns the diagnostics of the file at `path` that aren't in the
+    /// baseline. `content` is the code the diagnostics report.
+    ///
+    /// When the baseline is written, it records the lint diagnostics instead,
+    /// and only returns the other diagnostics.
+    pub(crate) fn filter(
+        &self,
+        fs: &dyn FileSystem,
+        path: &Path,
+        content: &str,
+        diagnostics: Vec<Error>,
+    ) -> Vec<Error> {
+        let path = absolute(fs, path);
+        let key = baseline_key(path.strip_prefix(&self.root).unwrap_or(&path));
+        let mut entries = if self.write {
+            Vec::new()
+        } else {
+            self.files.get(&key).cloned().unwrap_or_default()
+        };
+
+        let mut unmatched = Vec::new();
+        for diagnostic in diagnostics {
+            let Some(rule) = lint_rule(&diagnostic) else {
+                unmatched.push(diagnostic);
+                continue;
+            };
+            let fingerprint = fingerprint(rule, &diagnostic, content);
+            let position = entries
+                .iter()
+                .position(|entry| entry.rule == rule && entry.fingerprint == fingerprint);
+            match (position, self.write) {
+                (Some(index), true) => entries[index].count += 1,
+                (None, true) => entries.push(BaselineEntry {
+                    rule: rule.to_string(),
+                    fingerprint,
+                    count: 1,
+                }),
+                (Some(index), false) => {
+                    entries[index].count -= 1;
+                    if entries[index].count == 0 {
+                        entries.remove(index);
+                    }
+                }
+                (None, false) => unmatched.push(diagnostic),
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.processed.insert(key.clone());
+        if self.write {
+            state.recorded.insert(key, entries);
+        } else if !entries.is_empty() {
+            state.stale.insert(key, entries);
+        }
+        unmatched
+    }
+
+    /// Writes the baseline when the command updates it, or reports its stale
+    /// entries otherwise. The written baseline is recorded in `written_files`.
+    pub(crate) fn finish(
+        self,
+        fs: &dyn FileSystem,
+        console: &mut dyn Console,
+        written_files: Option<&WrittenFiles>,
+    ) -> io::Result<()> {
+        let BaselineState {
+            processed,
+            mut stale,
+            recorded,
+        } = self.state.into_inner().unwrap();
+
+        // The entries of the deleted files are stale too
+        for (path, entries) in &self.files {
+            if !processed.contains(path) && !fs.path_exists(&self.root.join(path)) {
+                stale.insert(path.clone(), entries.clone());
+            }
+        }
+
+        if self.write {
+            let mut files = self.files;
+            files.retain(|path, _| !processed.contains(path) && !stale.contains_key(path));
+            files.extend(
+                recorded
+                    .into_iter()
+                    .filter(|(_, entries)| !entries.is_empty()),
+            );
+            let count = count_diagnostics(files.values());
+            write_baseline_file(fs, &self.path, files, written_files)?;
+            console.log(markup! {
+                <Info>"Wrote "{count}" diagnostic(s) to the baseline "<Emphasis>{self.path.display().to_string()}</Emphasis></Info>
+            });
+        } else if self.prune && !stale.is_empty() {
+            let mut files = self.files;
+            for (path, stale_entries) in &stale {
+                let Some(entries) = files.get_mut(path) else {
+                    continue;
+                };
+                for stale_entry in stale_entries {
+                    if let Some(entry) = entries.iter_mut().find(|entry| {
+                        entry.rule == stale_entry.rule
+                            && entry.fingerprint == stale_entry.fingerprint
+                    }) {
+                        entry.count = entry.count.saturating_sub(stale_entry.count);
+                    }
+                }
+                entries.retain(|entry| entry.count > 0);
+            }
+            files.retain(|_, entries| !entries.is_empty());
+            let count = count_diagnostics(stale.values());
+            write_baseline_file(fs, &self.path, files, written_files)?;
+            console.log(markup! {
+                <Info>"Removed "{count}" stale diagnostic(s) from the baseline "<Emphasis>{self.path.display().to_string()}</Emphasis></Info>
+            });
+        } else if !stale.is_empty() {
+            let count = count_diagnostics(stale.values());
+            console.log(markup! {
+                <Warn>"The baseline contains "{count}" diagnostic(s) that no longer occur.\n"</Warn>
+                <Info>"Remove them with the argument "<Emphasis>"--prune-baseline"</Emphasis>"."</Info>
+            });
+            let stale: BTreeMap<_, _> = stale.into_iter().collect();
+            for (path, entries) in stale {
+                for entry in entries {
+                    console.log(markup! {
+                        "  "{path}" "<Emphasis>"lint/"{entry.rule}</Emphasis>" ("{entry.count}")"
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the absolute path of the baseline of the command run by
+/// `execution`, whether or not the command uses it
+pub(crate) fn baseline_path(fs: &dyn FileSystem, execution: &Execution) -> PathBuf {
+    match execution.baseline_path() {
+        Some(path) => absolute(fs, path),
+        None => baseline_root(fs, execution).join(DEFAULT_BASELINE_PATH),
+    }
+}
+
+/// Returns the directory of the configuration, the paths of the baseline are
+/// relative to it
+fn baseline_root(fs: &dyn FileSystem, execution: &Execution) -> PathBuf {
+    match execution.nested_configurations() {
+        Some(nested_configurations) => nested_configurations.root().to_path_buf(),
+        None => configuration_root(fs, None),
+    }
+}
+
+/// Returns the name of the rule of a lint diagnostic, e.g. `style/useConst`
+fn lint_rule(diagnostic: &Error) -> Option<&'static str> {
+    diagnostic.category()?.name().strip_prefix("lint/")
+}
+
+/// Returns the fingerprint of the code reported by a diagnostic of `rule`:
+/// the hash of the lines of its range, trimmed so that it doesn't depend on
+/// their indentation
+fn fingerprint(rule: &str, diagnostic: &Error, content: &str) -> String {
+    let mut hasher = Fnv1a::default();
+    hasher.write(rule.as_bytes());
+    if let Some(span) = diagnostic.location().span {
+        let start = usize::from(span.start()).min(content.len());
+        let end = usize::from(span.end()).clamp(start, content.len());
+        let line_start = content[..start].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = content[end..]
+            .find('\n')
+            .map_or(content.len(), |index| end + index);
+        for line in content[line_start..line_end].lines() {
+            hasher.write(b"\n");
+            hasher.write(line.trim().as_bytes());
+        }
+    }
+    format!("{:016x}", hasher.0)
+}
+
+/// The FNV-1a hash function, which is stable across the versions and the
+/// platforms of Biome, unlike the hashers of the standard library
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Returns the path of a file in the baseline, whose components are
+/// separated by `/` on all platforms
+fn baseline_key(path: &Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            Component::CurDir
+            | Component::ParentDir
+            | Component::RootDir
+            | Component::Prefix(_) => None,
+        })
+        .collect();
+    components.join("/")
+}
+
+fn count_diagnostics<'a>(entries: impl Iterator<Item = &'a Vec<BaselineEntry>>) -> u32 {
+    entries.flatten().map(|entry| entry.count).sum()
+}
+
+fn read_baseline_file(fs: &dyn FileSystem, path: &Path) -> io::Result<BaselineFile> {
+    let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+fn write_baseline_file(
+    fs: &dyn FileSystem,
+    path: &Path,
+    mut files: BTreeMap<String, Vec<BaselineEntry>>,
+    written_files: Option<&WrittenFiles>,
+) -> io::Result<()> {
+    for entries in files.values_mut() {
+        entries.sort();
+    }
+    let mut content = serde_json::to_string_pretty(&BaselineFile { files })?;
+    content.push('\n');
+
+    let mut file = fs.open_with_options(
+        path,
+        OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true),
+    )?;
+    file.set_content(content.as_bytes())?;
+    if let Some(written_files) = written_files {
+        written_files.record(path, &content);
+    }
+    Ok(())
+}
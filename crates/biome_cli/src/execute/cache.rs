@@ -0,0 +1,455 @@
+//! Cache of the results of the traversal, stored on disk between two runs.
+//!
+//! The cache of a command is a JSON file that maps the path of each processed
+//! file to the hash of its content and the messages that were emitted for it.
+//! When a file didn't change since the last run, the traversal replays these
+//! messages instead of processing the file again.
+//!
+//! The whole cache is discarded when its key changes: the key is computed from
+//! the version of Biome, the configuration applied to the files (including the
+//! files it extends and the plugins it lists), the TypeScript files of the
+//! project that the type-aware rules import from and the options of the
+//! command.
+
+use crate::execute::process_file::{DiffKind, FileStatus, Message};
+use crate::execute::{Execution, TraversalMode, WrittenFiles};
+use crate::CliDiagnostic;
+use biome_diagnostics::{serde::Diagnostic as SerdeDiagnostic, Category, Error};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_service::Configuration;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The results of the files processed by a command
+pub(crate) struct ResultCache {
+    /// The path of the file where the cache is stored
+    path: PathBuf,
+    /// The hash of everything that affects the results, other than the
+    /// content of the files
+    key: u64,
+    /// The results of the files, by path
+    files: Mutex<FxHashMap<String, CacheEntry>>,
+}
+
+/// The content of the file of the cache
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheFile {
+    key: u64,
+    files: FxHashMap<String, CacheEntry>,
+}
+
+/// The result of a file
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct CacheEntry {
+    /// The hash of the content of the file
+    hash: u64,
+    /// The messages emitted while processing the file
+    messages: Vec<CachedMessage>,
+    /// The status returned by the processing of the file
+    status: CachedStatus,
+}
+
+/// A [Message] that can be stored in the cache. The messages that report a
+/// failure aren't stored, and the file is processed again on the next run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CachedMessage {
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    /// The diagnostic that reports that a file contains errors
+    FileCheck {
+        category: &'static Category,
+    },
+    Diagnostics {
+        diagnostics: Vec<SerdeDiagnostic>,
+    },
+    Diff {
+        new: String,
+        diff_kind: DiffKind,
+    },
+}
+
+/// A [FileStatus] that can be stored in the cache
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CachedStatus {
+    Success,
+    Ignored,
+    Message(CachedMessage),
+}
+
+impl ResultCache {
+    /// Loads the cache of the command run by `execution`. `declarations_hash`
+    /// is the hash of the TypeScript files, that the type-aware rules use.
+    ///
+    /// It returns [None] if the cache is disabled, or if there isn't any
+    /// directory where to store it.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        execution: &Execution,
+        declarations_hash: u64,
+    ) -> Option<Self> {
+        if execution.is_cache_disabled() {
+            return None;
+        }
+        let name = cache_name(execution.traversal_mode())?;
+        let configuration_hash = execution.configuration_hash()?;
+        let directory = match execution.cache_dir() {
+            Some(directory) => directory.to_path_buf(),
+            None => default_cache_directory(fs)?,
+        };
+
+        let mut hasher = DefaultHasher::new();
+        biome_service::VERSION.hash(&mut hasher);
+        configuration_hash.hash(&mut hasher);
+        declarations_hash.hash(&mut hasher);
+        name.hash(&mut hasher);
+        if let TraversalMode::Format { ignore_errors, .. } = execution.traversal_mode() {
+            ignore_errors.hash(&mut hasher);
+        }
+        let key = hasher.finish();
+
+        let path = directory.join(format!("{name}.json"));
+        let files = read_cache_file(fs, &path)
+            .ok()
+            .filter(|cache_file| cache_file.key == key)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+
+        Some(Self {
+            path,
+            key,
+            files: Mutex::new(files),
+        })
+    }
+
+    /// Returns the result of the file at `path`, if its content didn't change
+    pub(crate) fn get(&self, path: &Path, hash: u64) -> Option<CacheEntry> {
+        let files = self.files.lock().unwrap();
+        files
+            .get(&path.display().to_string())
+            .filter(|entry| entry.hash == hash)
+            .cloned()
+    }
+
+    /// Stores the result of the file at `path`
+    pub(crate) fn insert(&self, path: &Path, entry: CacheEntry) {
+        let mut files = self.files.lock().unwrap();
+        files.insert(path.display().to_string(), entry);
+    }
+
+    /// Writes the cache to disk, and records it in `written_files` so that
+    /// the watch mode doesn't run again because of it
+    pub(crate) fn save(
+        self,
+        fs: &dyn FileSystem,
+        written_files: Option<&WrittenFiles>,
+    ) -> io::Result<()> {
+        let cache_file = CacheFile {
+            key: self.key,
+            files: self.files.into_inner().unwrap(),
+        };
+        let content = serde_json::to_string(&cache_file)?;
+
+        if let Some(directory) = self.path.parent() {
+            fs.create_dir_all(directory)?;
+        }
+        let mut file = fs.open_with_options(
+            &self.path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(content.as_bytes())?;
+        if let Some(written_files) = written_files {
+            written_files.record(&self.path, &content);
+        }
+        Ok(())
+    }
+}
+
+impl CacheEntry {
+    pub(crate) fn new(hash: u64, messages: Vec<CachedMessage>, status: CachedStatus) -> Self {
+        Self {
+            hash,
+            messages,
+            status,
+        }
+    }
+
+    /// Returns the messages emitted while processing the file, followed by its
+    /// status
+    pub(crate) fn replay(
+        self,
+        execution: &Execution,
+        path: &Path,
+        content: &str,
+    ) -> (Vec<Message>, FileStatus) {
+        let messages = self
+            .messages
+            .into_iter()
+            .map(|message| message.into_message(execution, path, content))
+            .collect();
+        let status = self.status.into_file_status(execution, path, content);
+        (messages, status)
+    }
+}
+
+impl CachedStatus {
+    /// Converts the status back to the [FileStatus] of the file at `path`
+    pub(crate) fn into_file_status(
+        self,
+        execution: &Execution,
+        path: &Path,
+        content: &str,
+    ) -> FileStatus {
+        match self {
+            CachedStatus::Success => FileStatus::Success,
+            CachedStatus::Ignored => FileStatus::Ignored,
+            CachedStatus::Message(message) => {
+                FileStatus::Message(message.into_message(execution, path, content))
+            }
+        }
+    }
+}
+
+impl CachedMessage {
+    /// Converts a [Message] emitted for the file with the given content. It
+    /// returns the original message if it can't be stored in the cache.
+    pub(crate) fn from_message(message: Message, content: &str) -> Result<Self, Message> {
+        match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Ok(Self::SkippedFixes {
+                skipped_suggested_fixes,
+            }),
+            Message::ApplyError(CliDiagnostic::FileCheck(file_check)) => Ok(Self::FileCheck {
+                category: file_check.category,
+            }),
+            // The diagnostics that didn't fit in the limit of the console
+            // would be lost
+            Message::Diagnostics {
+                content: diagnostics_content,
+                diagnostics,
+                skipped_diagnostics: 0,
+                ..
+            } if diagnostics_content == content => Ok(Self::Diagnostics {
+                diagnostics: diagnostics.into_iter().map(SerdeDiagnostic::new).collect(),
+            }),
+            Message::Diff {
+                old,
+                new,
+                diff_kind,
+                ..
+            } if old == content => Ok(Self::Diff { new, diff_kind }),
+            message => Err(message),
+        }
+    }
+
+    /// Converts the message back to the [Message] emitted for the file at
+    /// `path`
+    pub(crate) fn into_message(self, execution: &Execution, path: &Path, content: &str) -> Message {
+        let file_path = path.display().to_string();
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes,
+            },
+            Self::FileCheck { category } => {
+                if execution.is_check_apply() || execution.is_check_apply_unsafe() {
+                    Message::ApplyError(CliDiagnostic::file_check_apply_error(file_path, category))
+                } else {
+                    Message::ApplyError(CliDiagnostic::file_check_error(file_path, category))
+                }
+            }
+            Self::Diagnostics { diagnostics } => Message::Diagnostics {
+                name: file_path,
+                content: content.to_string(),
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics: 0,
+            },
+            Self::Diff { new, diff_kind } => Message::Diff {
+                file_name: file_path,
+                old: content.to_string(),
+                new,
+                diff_kind,
+            },
+        }
+    }
+}
+
+/// Returns the hash of the configuration applied to the files, along with the
+/// content of the plugin files it lists
+pub(crate) fn hash_configuration(fs: &dyn FileSystem, configuration: &Configuration) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    // SAFETY: the configuration is deserialized from JSON, so it can always
+    // be serialized back
+    serde_json::to_string(configuration)
+        .unwrap()
+        .hash(&mut hasher);
+    let plugins = configuration
+        .linter
+        .as_ref()
+        .and_then(|linter| linter.plugins.as_ref());
+    for path in plugins.into_iter().flat_map(|plugins| plugins.iter()) {
+        // A plugin that can't be read is reported when the workspace is updated
+        let mut content = Vec::new();
+        let _ = fs
+            .open_with_options(Path::new(path), OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_end(&mut content));
+        content.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Returns the hash of the content of a file, along with the hash of the
+/// configuration of its directory when it has its own configuration file
+pub(crate) fn hash_content(content: &str, configuration_hash: Option<u64>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    configuration_hash.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the name of the file of the cache of a command, or [None] if the
+/// results of the command can't be cached
+fn cache_name(traversal_mode: &TraversalMode) -> Option<&'static str> {
+    use biome_service::workspace::FixFileMode;
+
+    let name = match traversal_mode {
+        TraversalMode::Check { stdin: Some(_), .. }
+        | TraversalMode::Lint { stdin: Some(_), .. }
+        | TraversalMode::Format { stdin: Some(_), .. } => return None,
+        TraversalMode::Check { fix_file_mode, .. } => match fix_file_mode {
+            None => "check",
+            Some(FixFileMode::SafeFixes) => "check-apply",
+            Some(FixFileMode::SafeAndUnsafeFixes) => "check-apply-unsafe",
+        },
+        TraversalMode::Lint { fix_file_mode, .. } => match fix_file_mode {
+            None => "lint",
+            Some(FixFileMode::SafeFixes) => "lint-apply",
+            Some(FixFileMode::SafeAndUnsafeFixes) => "lint-apply-unsafe",
+        },
+        TraversalMode::CI { .. } => "ci",
+        // The source maps must be written for every file
+        TraversalMode::Format {
+            source_map: true, ..
+        } => return None,
+        TraversalMode::Format { write: false, .. } => "format",
+        TraversalMode::Format { write: true, .. } => "format-write",
+        TraversalMode::Transform { .. }
+        | TraversalMode::Search { .. }
+        | TraversalMode::Rewrite { .. }
+        | TraversalMode::Migrate { .. } => return None,
+    };
+    Some(name)
+}
+
+/// Returns the default directory of the cache, `node_modules/.cache/biome` in
+/// the working directory, if the working directory has a `node_modules` folder
+fn default_cache_directory(fs: &dyn FileSystem) -> Option<PathBuf> {
+    let node_modules = fs.working_directory()?.join("node_modules");
+    fs.path_exists(&node_modules)
+        .then(|| node_modules.join(".cache").join("biome"))
+}
+
+fn read_cache_file(fs: &dyn FileSystem, path: &Path) -> io::Result<CacheFile> {
+    let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheEntry, CachedMessage, CachedStatus, ResultCache};
+    use crate::execute::{Execution, TraversalMode};
+    use biome_fs::MemoryFileSystem;
+    use biome_service::configuration::LinterConfiguration;
+    use biome_service::Configuration;
+    use std::path::Path;
+
+    fn execution(linter_enabled: bool) -> Execution {
+        let configuration = Configuration {
+            linter: Some(LinterConfiguration {
+                enabled: Some(linter_enabled),
+                ..LinterConfiguration::default()
+            }),
+            ..Configuration::default()
+        };
+        Execution::new(TraversalMode::Lint {
+            fix_file_mode: None,
+            stdin: None,
+        })
+        .with_configuration(&MemoryFileSystem::default(), &configuration)
+        .with_cache(false, Some("cache".to_string()))
+    }
+
+    #[test]
+    fn keeps_the_results_between_runs() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("file.js");
+
+        let cache = ResultCache::load(&fs, &execution(true), 0).unwrap();
+        cache.insert(
+            path,
+            CacheEntry::new(
+                42,
+                vec![CachedMessage::SkippedFixes {
+                    skipped_suggested_fixes: 1,
+                }],
+                CachedStatus::Success,
+            ),
+        );
+        cache.save(&fs, None).unwrap();
+
+        let cache = ResultCache::load(&fs, &execution(true), 0).unwrap();
+        let entry = cache.get(path, 42).expect("the entry wasn't saved");
+        assert_eq!(entry.messages.len(), 1);
+        assert!(cache.get(path, 43).is_none());
+        assert!(cache.get(Path::new("other.js"), 42).is_none());
+    }
+
+    #[test]
+    fn discards_the_results_when_the_configuration_changes() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("file.js");
+
+        let cache = ResultCache::load(&fs, &execution(true), 0).unwrap();
+        cache.insert(path, CacheEntry::new(42, vec![], CachedStatus::Success));
+        cache.save(&fs, None).unwrap();
+
+        let cache = ResultCache::load(&fs, &execution(false), 0).unwrap();
+        assert!(cache.get(path, 42).is_none());
+    }
+
+    #[test]
+    fn discards_the_results_when_the_declarations_change() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("file.js");
+
+        let cache = ResultCache::load(&fs, &execution(true), 0).unwrap();
+        cache.insert(path, CacheEntry::new(42, vec![], CachedStatus::Success));
+        cache.save(&fs, None).unwrap();
+
+        let cache = ResultCache::load(&fs, &execution(true), 1).unwrap();
+        assert!(cache.get(path, 42).is_none());
+    }
+
+    #[test]
+    fn can_be_disabled() {
+        let fs = MemoryFileSystem::default();
+        let without_directory = execution(true).with_cache(false, None);
+        assert!(ResultCache::load(&fs, &without_directory, 0).is_none());
+        let disabled = execution(true).with_cache(true, Some("cache".to_string()));
+        assert!(ResultCache::load(&fs, &disabled, 0).is_none());
+    }
+}
@@ -0,0 +1,178 @@
+//! Registration of the declarations of the TypeScript files of the project.
+//!
+//! The type-aware lint rules resolve the declarations imported from the
+//! TypeScript files of the project, `.d.ts` files included. When one of these
+//! rules is enabled, the project is traversed before the files are linted, to
+//! open the TypeScript files in the workspace, which records their
+//! declarations. The whole project is traversed, from the directory of the
+//! configuration, so that the files can import the declarations that aren't
+//! in the inputs.
+
+use crate::execute::nested_configurations::configuration_root;
+use crate::execute::{Execution, TraversalMode};
+use biome_diagnostics::Error;
+use biome_fs::{
+    normalize_path, FileSystem, OpenOptions, PathInterner, RomePath, TraversalContext,
+    TraversalScope,
+};
+use biome_js_type_info::is_typescript_file;
+use biome_service::workspace::{CloseFileParams, IsPathIgnoredParams, OpenFileParams};
+use biome_service::Workspace;
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsString;
+use std::hash::{Hash, Hasher};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// Opens the TypeScript files of the project in the workspace, if a
+/// type-aware rule is enabled.
+///
+/// It returns a hash of the paths and the content of these files, which
+/// invalidates the cached results of the files when one of them changes.
+pub(crate) fn register_declarations(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    execution: &Execution,
+    inputs: &[OsString],
+) -> u64 {
+    if !execution.has_type_aware_rules()
+        || !matches!(
+            execution.traversal_mode(),
+            TraversalMode::Check { .. } | TraversalMode::Lint { .. } | TraversalMode::CI { .. }
+        )
+    {
+        return 0;
+    }
+
+    let root = match execution.nested_configurations() {
+        Some(nested_configurations) => nested_configurations.root().to_path_buf(),
+        None => configuration_root(fs, None),
+    };
+    // The imports are resolved from the paths of the linted files, so the
+    // project is traversed with paths of the same kind as the inputs
+    let mut roots = Vec::new();
+    if inputs.iter().any(|input| Path::new(input).is_absolute()) {
+        roots.push(root.clone());
+    }
+    if inputs.iter().any(|input| Path::new(input).is_relative()) {
+        roots.push(relative_to_working_directory(fs, &root));
+    }
+
+    let (interner, _) = PathInterner::new();
+    let ctx = DeclarationsContext {
+        fs,
+        workspace,
+        execution,
+        interner,
+        files: Mutex::default(),
+    };
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for root in roots {
+            scope.spawn(&ctx, root);
+        }
+    }));
+
+    // The files are visited in parallel, they're sorted to get a stable hash
+    let mut files = ctx.files.into_inner().unwrap();
+    files.sort_unstable();
+    let mut hasher = DefaultHasher::new();
+    files.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the path of the directory `root` relative to the working
+/// directory, e.g. `../..` when the configuration is two levels above it
+fn relative_to_working_directory(fs: &dyn FileSystem, root: &Path) -> PathBuf {
+    let Some(working_directory) = fs.working_directory() else {
+        return root.to_path_buf();
+    };
+    let working_directory = normalize_path(&working_directory);
+    let common = root
+        .components()
+        .zip(working_directory.components())
+        .take_while(|(root, working_directory)| root == working_directory)
+        .count();
+    if common == 0 {
+        return root.to_path_buf();
+    }
+
+    let mut path: PathBuf = working_directory
+        .components()
+        .skip(common)
+        .map(|_| Component::ParentDir)
+        .collect();
+    path.extend(root.components().skip(common));
+    if path.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        path
+    }
+}
+
+struct DeclarationsContext<'ctx> {
+    fs: &'ctx dyn FileSystem,
+    workspace: &'ctx dyn Workspace,
+    execution: &'ctx Execution,
+    interner: PathInterner,
+    /// The path and the content of the TypeScript files opened so far
+    files: Mutex<Vec<(PathBuf, String)>>,
+}
+
+impl<'ctx> TraversalContext for DeclarationsContext<'ctx> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The errors are reported by the traversal that processes the files
+    }
+
+    fn can_handle(&self, rome_path: &RomePath) -> bool {
+        let is_dir = rome_path.is_dir();
+        if !is_dir && !is_typescript_file(rome_path) {
+            return false;
+        }
+
+        if let Some(ignore_files) = self.execution.ignore_files() {
+            if ignore_files.is_ignored(self.fs, rome_path, is_dir) {
+                return false;
+            }
+        }
+
+        self.workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                rome_path: rome_path.clone(),
+                feature: self.execution.as_feature_name(),
+            })
+            .is_ok_and(|ignored| !ignored)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        let mut content = String::new();
+        let read = self
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content));
+        if read.is_err() {
+            return;
+        }
+
+        // The workspace keeps the declarations of a file once it's closed
+        let rome_path = RomePath::new(path);
+        let opened = self.workspace.open_file(OpenFileParams {
+            path: rome_path.clone(),
+            content: content.clone(),
+            version: 0,
+            language_hint: Default::default(),
+        });
+        if opened.is_ok() {
+            let _ = self
+                .workspace
+                .close_file(CloseFileParams { path: rome_path });
+            self.files
+                .lock()
+                .unwrap()
+                .push((PathBuf::from(path), content));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use biome_diagnostics::adapters::{IoError, StdError};
 use biome_diagnostics::{Advices, Category, Diagnostic, DiagnosticExt, Error, Severity, Visit};
 use biome_text_edit::TextEdit;
+use biome_text_size::TextRange;
 use std::io;
 
 #[derive(Debug, Diagnostic)]
@@ -66,6 +67,30 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "search",
+	severity = Information,
+	message = "The code matches the pattern."
+)]
+pub(crate) struct SearchMatchDiagnostic {
+    #[location(span)]
+    pub(crate) range: TextRange,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "rewrite",
+	severity = Information,
+	message = "The code that matches the pattern can be rewritten:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
@@ -1,18 +1,31 @@
+mod baseline;
+mod cache;
+mod declarations;
 mod diagnostics;
 mod migrate;
+mod nested_configurations;
 mod process_file;
+mod profile;
 mod std_in;
 mod traverse;
+mod watch;
 
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::execute::cache::hash_configuration;
+pub(crate) use crate::execute::nested_configurations::{
+    configuration_root, nested_directories, ConfigurationArguments, NestedConfigurations,
+};
+pub(crate) use crate::execute::profile::ProfileArguments;
 use crate::execute::traverse::traverse;
+pub(crate) use crate::execute::watch::{watch_mode, WrittenFiles};
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::{category, Category};
-use biome_fs::RomePath;
+use biome_fs::{FileSystem, RomePath};
 use biome_service::workspace::{FeatureName, FixFileMode};
+use biome_service::{Configuration, IgnoreFiles};
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Useful information during the traversal of files and virtual content
 pub(crate) struct Execution {
@@ -24,6 +37,55 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// The hash of the configuration applied to the files, it's [None] when
+    /// the results of the traversal can't be cached
+    configuration_hash: Option<u64>,
+
+    /// Whether the files are read from, and written to, the git index
+    staged: bool,
+
+    /// The path of the baseline file passed to the command, [None] for the
+    /// default file in the directory of the configuration
+    baseline_path: Option<PathBuf>,
+
+    /// Whether the lint diagnostics are written to the baseline, instead of
+    /// being reported
+    write_baseline: bool,
+
+    /// Whether the stale entries of the baseline are removed from it
+    prune_baseline: bool,
+
+    /// Whether the results of the files are computed again, instead of being
+    /// read from the cache of the previous run
+    no_cache: bool,
+
+    /// The directory of the cache passed to the command, [None] for the
+    /// default directory
+    cache_dir: Option<PathBuf>,
+
+    /// How the time spent in the rules is reported, [None] if the command
+    /// isn't profiled
+    profile: Option<ProfileArguments>,
+
+    /// Whether the configuration enables a type-aware lint rule, which needs
+    /// the declarations of the project
+    type_aware_rules: bool,
+
+    /// The ignore files read during the traversal, [None] if the ignore file of
+    /// the VCS isn't used
+    ignore_files: Option<IgnoreFiles>,
+
+    /// Applies the arguments of the command to the configuration files found
+    /// during the traversal
+    configuration_arguments: Option<ConfigurationArguments>,
+
+    /// The configuration files found in the directories of the traversal
+    nested_configurations: Option<NestedConfigurations>,
+
+    /// Records the files written by the traversal in watch mode, so that their
+    /// changes don't trigger another run
+    written_files: Option<WrittenFiles>,
 }
 
 impl Execution {
@@ -77,11 +139,38 @@ pub(crate) enum TraversalMode {
         ignore_errors: bool,
         /// It writes the new content on file
         write: bool,
+        /// It writes the source map of the formatted code next to it
+        source_map: bool,
+        /// An optional tuple.
+        /// 1. The virtual path to the file
+        /// 2. The content of the file
+        stdin: Option<(PathBuf, String)>,
+    },
+    /// This mode is enabled when running the command `biome transform`
+    Transform {
+        /// It writes the transformed code next to the original file
+        write: bool,
+        /// It writes the source map of the transformed code next to it
+        source_map: bool,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
     },
+    /// This mode is enabled when running the command `biome search`
+    Search {
+        /// The structural pattern to search
+        pattern: String,
+    },
+    /// This mode is enabled when running the command `biome rewrite`
+    Rewrite {
+        /// The structural pattern to search
+        pattern: String,
+        /// The code that replaces the matches of the pattern
+        replacement: String,
+        /// Whether the rewritten files should be written to disk
+        write: bool,
+    },
     /// This mode is enabled when running the command `biome migrate`
     Migrate {
         write: bool,
@@ -96,6 +185,9 @@ impl Display for TraversalMode {
             TraversalMode::Check { .. } => write!(f, "check"),
             TraversalMode::CI { .. } => write!(f, "ci"),
             TraversalMode::Format { .. } => write!(f, "format"),
+            TraversalMode::Transform { .. } => write!(f, "transform"),
+            TraversalMode::Search { .. } => write!(f, "search"),
+            TraversalMode::Rewrite { .. } => write!(f, "rewrite"),
             TraversalMode::Migrate { .. } => write!(f, "migrate"),
             TraversalMode::Lint { .. } => write!(f, "lint"),
         }
@@ -118,6 +210,19 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            configuration_hash: None,
+            staged: false,
+            baseline_path: None,
+            write_baseline: false,
+            prune_baseline: false,
+            no_cache: false,
+            cache_dir: None,
+            profile: None,
+            type_aware_rules: false,
+            ignore_files: None,
+            configuration_arguments: None,
+            nested_configurations: None,
+            written_files: None,
         }
     }
 
@@ -138,6 +243,19 @@ impl Execution {
                 },
             },
             max_diagnostics: 20,
+            configuration_hash: None,
+            staged: false,
+            baseline_path: None,
+            write_baseline: false,
+            prune_baseline: false,
+            no_cache: false,
+            cache_dir: None,
+            profile: None,
+            type_aware_rules: false,
+            ignore_files: None,
+            configuration_arguments: None,
+            nested_configurations: None,
+            written_files: None,
         }
     }
 
@@ -147,9 +265,164 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: 20,
+            configuration_hash: None,
+            staged: false,
+            baseline_path: None,
+            write_baseline: false,
+            prune_baseline: false,
+            no_cache: false,
+            cache_dir: None,
+            profile: None,
+            type_aware_rules: false,
+            ignore_files: None,
+            configuration_arguments: None,
+            nested_configurations: None,
+            written_files: None,
         }
     }
 
+    /// Allows the traversal to cache the results of the files, which depend on
+    /// the given configuration
+    pub(crate) fn with_configuration(
+        mut self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Self {
+        self.configuration_hash = Some(hash_configuration(fs, configuration));
+        self
+    }
+
+    /// Processes the content of the files staged in the git index, instead of
+    /// the content of the working tree
+    pub(crate) fn with_staged_files(mut self, staged: bool) -> Self {
+        self.staged = staged;
+        self
+    }
+
+    /// Matches the lint diagnostics against the baseline file at `path`, and
+    /// removes its stale entries when `prune` is set
+    pub(crate) fn with_baseline(mut self, path: Option<String>, prune: bool) -> Self {
+        self.baseline_path = path.map(PathBuf::from);
+        self.prune_baseline = prune;
+        self
+    }
+
+    /// Reuses the results of the files that didn't change since the previous
+    /// run, unless `no_cache` is set. The cache is stored in `cache_dir`.
+    pub(crate) fn with_cache(mut self, no_cache: bool, cache_dir: Option<String>) -> Self {
+        self.no_cache = no_cache;
+        self.cache_dir = cache_dir.map(PathBuf::from);
+        self
+    }
+
+    /// Measures the time spent in the rules, and prints it when `print` is set
+    /// or writes it to `output`
+    pub(crate) fn with_profile_rules(
+        mut self,
+        print: bool,
+        output: Option<String>,
+        format: ProfileFormat,
+    ) -> Self {
+        self.profile = (print || output.is_some()).then(|| ProfileArguments {
+            print,
+            output: output.map(PathBuf::from),
+            format,
+        });
+        self
+    }
+
+    /// Writes the lint diagnostics to the baseline, instead of reporting them
+    pub(crate) fn with_write_baseline(mut self, write_baseline: bool) -> Self {
+        self.write_baseline = write_baseline;
+        self
+    }
+
+    /// Registers the declarations of the project before the files are
+    /// processed, for the type-aware lint rules
+    pub(crate) fn with_type_aware_rules(mut self, type_aware_rules: bool) -> Self {
+        self.type_aware_rules = type_aware_rules;
+        self
+    }
+
+    /// Skips the files ignored by the ignore files found during the traversal
+    pub(crate) fn with_ignore_files(mut self, ignore_files: Option<IgnoreFiles>) -> Self {
+        self.ignore_files = ignore_files;
+        self
+    }
+
+    pub(crate) fn ignore_files(&self) -> Option<&IgnoreFiles> {
+        self.ignore_files.as_ref()
+    }
+
+    /// Applies the arguments of the command to the configuration files of the
+    /// directories too, so that they override these files like the one of the
+    /// workspace
+    pub(crate) fn with_configuration_arguments(
+        mut self,
+        arguments: ConfigurationArguments,
+    ) -> Self {
+        self.configuration_arguments = Some(arguments);
+        self
+    }
+
+    pub(crate) fn configuration_arguments(&self) -> Option<&ConfigurationArguments> {
+        self.configuration_arguments.as_ref()
+    }
+
+    /// Applies the configuration files found in the directories of the
+    /// traversal to their files
+    pub(crate) fn with_nested_configurations(
+        mut self,
+        nested_configurations: NestedConfigurations,
+    ) -> Self {
+        self.nested_configurations = Some(nested_configurations);
+        self
+    }
+
+    pub(crate) fn nested_configurations(&self) -> Option<&NestedConfigurations> {
+        self.nested_configurations.as_ref()
+    }
+
+    pub(crate) fn written_files(&self) -> Option<&WrittenFiles> {
+        self.written_files.as_ref()
+    }
+
+    pub(crate) const fn is_staged(&self) -> bool {
+        self.staged
+    }
+
+    pub(crate) fn baseline_path(&self) -> Option<&Path> {
+        self.baseline_path.as_deref()
+    }
+
+    pub(crate) const fn writes_baseline(&self) -> bool {
+        self.write_baseline
+    }
+
+    pub(crate) const fn prunes_baseline(&self) -> bool {
+        self.prune_baseline
+    }
+
+    pub(crate) const fn is_cache_disabled(&self) -> bool {
+        self.no_cache
+    }
+
+    pub(crate) fn cache_dir(&self) -> Option<&Path> {
+        self.cache_dir.as_deref()
+    }
+
+    pub(crate) fn profile_arguments(&self) -> Option<&ProfileArguments> {
+        self.profile.as_ref()
+    }
+
+    pub(crate) const fn has_type_aware_rules(&self) -> bool {
+        self.type_aware_rules
+    }
+
+    pub(crate) fn configuration_hash(&self) -> Option<u64> {
+        self.configuration_hash
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
@@ -170,6 +443,9 @@ impl Execution {
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.as_ref(),
             TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
+            | TraversalMode::Transform { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Rewrite { .. }
             | TraversalMode::Migrate { .. } => None,
         }
     }
@@ -180,6 +456,9 @@ impl Execution {
             TraversalMode::Lint { .. } => category!("lint"),
             TraversalMode::CI { .. } => category!("ci"),
             TraversalMode::Format { .. } => category!("format"),
+            TraversalMode::Transform { .. } => category!("transform"),
+            TraversalMode::Search { .. } => category!("search"),
+            TraversalMode::Rewrite { .. } => category!("rewrite"),
             TraversalMode::Migrate { .. } => category!("migrate"),
         }
     }
@@ -220,6 +499,10 @@ impl Execution {
         matches!(self.traversal_mode, TraversalMode::Format { .. })
     }
 
+    pub(crate) const fn is_transform(&self) -> bool {
+        matches!(self.traversal_mode, TraversalMode::Transform { .. })
+    }
+
     pub(crate) const fn is_format_write(&self) -> bool {
         if let TraversalMode::Format { write, .. } = self.traversal_mode {
             write
@@ -235,6 +518,10 @@ impl Execution {
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
+            // The transformed code is written to a new file
+            TraversalMode::Transform { .. } => false,
+            TraversalMode::Search { .. } => false,
+            TraversalMode::Rewrite { write, .. } => write,
             TraversalMode::Migrate { write: dry_run, .. } => dry_run,
         }
     }
@@ -243,8 +530,12 @@ impl Execution {
         match &self.traversal_mode {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
-            | TraversalMode::Check { stdin, .. } => stdin.as_ref(),
-            TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
+            | TraversalMode::Check { stdin, .. }
+            | TraversalMode::Transform { stdin, .. } => stdin.as_ref(),
+            TraversalMode::CI { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Rewrite { .. }
+            | TraversalMode::Migrate { .. } => None,
         }
     }
 }
@@ -253,7 +544,7 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
@@ -283,6 +574,6 @@ pub(crate) fn execute_mode(
             cli_options.verbose,
         )
     } else {
-        traverse(mode, session, cli_options, paths)
+        traverse(&mode, &mut session, cli_options, paths)
     }
 }
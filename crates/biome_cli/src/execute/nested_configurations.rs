@@ -0,0 +1,197 @@
+use crate::execute::cache::hash_configuration;
+use crate::execute::traverse::TraversalOptions;
+use biome_fs::{normalize_path, FileSystem, TraversalContext};
+use biome_service::configuration::{self, load_directory_configuration, load_plugins};
+use biome_service::workspace::UpdateSettingsParams;
+use biome_service::Configuration;
+use rustc_hash::FxHashMap;
+use std::panic::RefUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Applies the arguments of the command to a configuration loaded from the file system
+pub(crate) type ConfigurationArguments =
+    Arc<dyn Fn(&mut Configuration) + Send + Sync + RefUnwindSafe>;
+
+/// The configuration files found in the directories of the traversal, e.g. the
+/// ones of the packages of a monorepo. A configuration file applies to the files
+/// of its directory instead of the configuration of the workspace.
+///
+/// The configuration of a directory is loaded, and applied to the workspace, the
+/// first time one of its files is checked.
+pub(crate) struct NestedConfigurations {
+    /// The directory of the configuration of the workspace, where the lookup stops
+    root: PathBuf,
+    /// The configuration of the workspace
+    configuration: Arc<Configuration>,
+    /// The VCS settings of the workspace, shared by the directories
+    vcs_base_path: Option<PathBuf>,
+    gitignore_matches: Vec<String>,
+    /// The directories checked so far
+    directories: RwLock<FxHashMap<PathBuf, DirectoryConfiguration>>,
+    /// Held while a configuration file is loaded, so that it's loaded once
+    loading: Mutex<()>,
+}
+
+enum DirectoryConfiguration {
+    /// The directory doesn't have a configuration file
+    Missing,
+    /// The configuration applied to the files of the directory, and its hash
+    Loaded(Arc<Configuration>, u64),
+    /// The configuration file of the directory has errors, its files aren't processed
+    Invalid,
+}
+
+impl NestedConfigurations {
+    pub(crate) fn new(
+        fs: &dyn FileSystem,
+        configuration_path: Option<PathBuf>,
+        configuration: Configuration,
+        vcs_base_path: Option<PathBuf>,
+        gitignore_matches: Vec<String>,
+    ) -> Self {
+        Self {
+            root: configuration_root(fs, configuration_path),
+            configuration: Arc::new(configuration),
+            vcs_base_path,
+            gitignore_matches,
+            directories: RwLock::default(),
+            loading: Mutex::default(),
+        }
+    }
+
+    /// The directory of the configuration of the workspace
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Loads the configuration files of the directories that contain `path`,
+    /// from the outermost one, and applies them to the workspace.
+    ///
+    /// Returns `false` if one of them has errors, in which case the file
+    /// can't be processed.
+    pub(crate) fn load(&self, ctx: &TraversalOptions, path: &Path) -> bool {
+        for directory in nested_directories(ctx.fs, &self.root, path) {
+            if !self.directories.read().unwrap().contains_key(&directory) {
+                let _loading = self.loading.lock().unwrap();
+                if !self.directories.read().unwrap().contains_key(&directory) {
+                    let configuration = self.load_directory(ctx, &directory);
+                    self.directories
+                        .write()
+                        .unwrap()
+                        .insert(directory.clone(), configuration);
+                }
+            }
+
+            if matches!(
+                self.directories.read().unwrap().get(&directory),
+                Some(DirectoryConfiguration::Invalid)
+            ) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the hash of the configuration of the nearest directory that
+    /// contains `path`, or [None] if the configuration of the workspace applies
+    pub(crate) fn configuration_hash(&self, path: &Path) -> Option<u64> {
+        let directories = self.directories.read().unwrap();
+        normalize_path(path)
+            .ancestors()
+            .skip(1)
+            .find_map(|directory| match directories.get(directory) {
+                Some(DirectoryConfiguration::Loaded(_, hash)) => Some(*hash),
+                _ => None,
+            })
+    }
+
+    fn load_directory(&self, ctx: &TraversalOptions, directory: &Path) -> DirectoryConfiguration {
+        let parent = self.parent_configuration(directory);
+        let loaded_configuration = match load_directory_configuration(ctx.fs, directory, &parent) {
+            Ok(Some(loaded_configuration)) => loaded_configuration,
+            Ok(None) => return DirectoryConfiguration::Missing,
+            Err(error) => {
+                ctx.push_diagnostic(error.into());
+                return DirectoryConfiguration::Invalid;
+            }
+        };
+
+        let has_errors = loaded_configuration.has_errors();
+        for diagnostic in loaded_configuration.diagnostics {
+            ctx.push_diagnostic(diagnostic);
+        }
+        if has_errors {
+            return DirectoryConfiguration::Invalid;
+        }
+
+        let mut configuration = loaded_configuration.configuration;
+        if let Some(arguments) = ctx.execution.configuration_arguments() {
+            arguments(&mut configuration);
+        }
+        let result = load_plugins(ctx.fs, &configuration).and_then(|plugins| {
+            ctx.workspace.update_settings(UpdateSettingsParams {
+                configuration: configuration.clone(),
+                vcs_base_path: self.vcs_base_path.clone(),
+                gitignore_matches: self.gitignore_matches.clone(),
+                directory: Some(directory.to_path_buf()),
+                plugins,
+            })
+        });
+        match result {
+            Ok(()) => {
+                let hash = hash_configuration(ctx.fs, &configuration);
+                DirectoryConfiguration::Loaded(Arc::new(configuration), hash)
+            }
+            Err(error) => {
+                ctx.push_diagnostic(error.into());
+                DirectoryConfiguration::Invalid
+            }
+        }
+    }
+
+    /// Returns the configuration that applies to the parent of `directory`
+    fn parent_configuration(&self, directory: &Path) -> Arc<Configuration> {
+        let directories = self.directories.read().unwrap();
+        directory
+            .ancestors()
+            .skip(1)
+            .find_map(|directory| match directories.get(directory) {
+                Some(DirectoryConfiguration::Loaded(configuration, _)) => {
+                    Some(configuration.clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| self.configuration.clone())
+    }
+}
+
+/// Returns the directory of the configuration of the workspace, where the
+/// lookup of the configuration files of the directories stops
+pub(crate) fn configuration_root(
+    fs: &dyn FileSystem,
+    configuration_path: Option<PathBuf>,
+) -> PathBuf {
+    let root = configuration_path
+        .or(fs.working_directory())
+        .unwrap_or_default();
+    absolute(fs, &root)
+}
+
+/// Returns the directories that contain `path` below `root`, from the
+/// outermost one, see [biome_service::configuration::nested_directories]
+pub(crate) fn nested_directories(fs: &dyn FileSystem, root: &Path, path: &Path) -> Vec<PathBuf> {
+    configuration::nested_directories(root, path, |directory| absolute(fs, directory))
+}
+
+/// Returns the absolute path of a directory of the traversal, to compare it
+/// with the directory of the configuration of the workspace
+pub(crate) fn absolute(fs: &dyn FileSystem, directory: &Path) -> PathBuf {
+    match fs.working_directory() {
+        Some(working_directory) if directory.is_relative() => {
+            normalize_path(&working_directory.join(directory))
+        }
+        _ => normalize_path(directory),
+    }
+}
@@ -2,21 +2,31 @@ mod check;
 mod format;
 mod lint;
 mod organize_imports;
+mod rewrite;
+pub(crate) mod search;
+pub(crate) mod transform;
 mod workspace_file;
 
-use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
+use crate::execute::cache::{hash_content, CacheEntry, CachedMessage, CachedStatus, ResultCache};
+use crate::execute::diagnostics::{ResultExt, ResultIoExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+use crate::execute::process_file::rewrite::rewrite;
+use crate::execute::process_file::search::search;
+use crate::execute::process_file::transform::transform;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, DiagnosticExt, Error};
-use biome_fs::RomePath;
+use biome_diagnostics::{category, Category, DiagnosticExt, Error};
+use biome_formatter::SourceMapV3;
+use biome_fs::{FileSystemExt, OpenOptions, RomePath};
 use biome_service::workspace::{FeatureName, FeaturesBuilder, SupportKind, SupportsFeatureParams};
+use biome_service::WorkspaceError;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub(crate) enum FileStatus {
@@ -48,6 +58,11 @@ pub(crate) enum Message {
         new: String,
         diff_kind: DiffKind,
     },
+    /// The code emitted by the transformer, when it isn't written to disk
+    Transformed {
+        file_name: String,
+        code: String,
+    },
 }
 
 impl Message {
@@ -56,10 +71,12 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -87,14 +104,68 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// The messages emitted for the file, when its result can be cached
+    recorded: RefCell<Option<Recording>>,
     _p: PhantomData<&'app ()>,
 }
 
+/// The messages emitted for a file with the given content
+struct Recording {
+    path: PathBuf,
+    content: String,
+    messages: Vec<CachedMessage>,
+}
+
 impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
     fn new(t: &'app TraversalOptions<'ctx, 'app>) -> Self {
         Self {
             _p: PhantomData,
             inner: t,
+            recorded: RefCell::new(None),
+        }
+    }
+
+    /// Starts recording the messages emitted for the file with the given
+    /// content, so that they can be stored in the cache
+    fn record(&self, path: &Path, content: String) {
+        *self.recorded.borrow_mut() = Some(Recording {
+            path: PathBuf::from(path),
+            content,
+            messages: Vec::new(),
+        });
+    }
+
+    /// Returns the recorded messages, or [None] if one of them can't be
+    /// stored in the cache
+    fn take_recording(&self) -> Option<Recording> {
+        self.recorded.borrow_mut().take()
+    }
+
+    /// Send a message to the display thread, recording it if needed
+    ///
+    /// It shadows [TraversalOptions::push_message], so that all the messages
+    /// emitted while processing a file are recorded.
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        let mut recorded = self.recorded.borrow_mut();
+        let Some(recording) = recorded.as_mut() else {
+            self.inner.push_message(msg);
+            return;
+        };
+
+        match CachedMessage::from_message(msg, &recording.content) {
+            Ok(cached) => {
+                recording.messages.push(cached.clone());
+                self.inner.push_message(cached.into_message(
+                    self.execution,
+                    &recording.path,
+                    &recording.content,
+                ));
+            }
+            Err(msg) => {
+                *recorded = None;
+                self.inner.push_message(msg);
+            }
         }
     }
 }
@@ -205,7 +276,11 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                 ),
             TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureName::Lint),
-            TraversalMode::Migrate { .. } => None,
+            // the files that can't be transformed or searched are filtered during the traversal
+            TraversalMode::Transform { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Rewrite { .. }
+            | TraversalMode::Migrate { .. } => None,
         };
 
         if let Some(reason) = unsupported_reason {
@@ -228,7 +303,22 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
         let shared_context = &SharedTraversalOptions::new(ctx);
         ctx.increment_processed();
 
-        match ctx.execution.traversal_mode {
+        if let Some(cache) = ctx.cache {
+            if let Some(content) = read_content(ctx, path) {
+                if let Some(entry) =
+                    cache.get(path, hash_content(&content, ctx.configuration_hash(path)))
+                {
+                    let (messages, status) = entry.replay(ctx.execution, path, &content);
+                    for message in messages {
+                        ctx.push_message(message);
+                    }
+                    return Ok(status);
+                }
+                shared_context.record(path, content);
+            }
+        }
+
+        let result = match ctx.execution.traversal_mode {
             TraversalMode::Lint { .. } => {
                 // the unsupported case should be handled already at this point
                 lint(shared_context, path)
@@ -243,9 +333,104 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
             TraversalMode::CI { .. } => {
                 check_file(shared_context, path, &file_features, category!("ci"))
             }
+            TraversalMode::Transform { .. } => transform(shared_context, path),
+            TraversalMode::Search { .. } => search(shared_context, path),
+            TraversalMode::Rewrite { .. } => rewrite(shared_context, path),
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
+        };
+
+        match (ctx.cache, shared_context.take_recording()) {
+            (Some(cache), Some(recording)) => cache_result(ctx, cache, recording, result),
+            _ => result,
         }
     })
 }
+
+/// Stores the result of a file in the cache, unless it can't be replayed on
+/// the next run: the processing failed, or the file was written
+fn cache_result(
+    ctx: &TraversalOptions,
+    cache: &ResultCache,
+    recording: Recording,
+    result: FileResult,
+) -> FileResult {
+    let Recording {
+        path,
+        content,
+        messages,
+    } = recording;
+
+    let status = match result {
+        Ok(FileStatus::Success) => CachedStatus::Success,
+        Ok(FileStatus::Ignored) => CachedStatus::Ignored,
+        Ok(FileStatus::Message(msg)) => match CachedMessage::from_message(msg, &content) {
+            Ok(cached) => CachedStatus::Message(cached),
+            Err(msg) => return Ok(FileStatus::Message(msg)),
+        },
+        result => return result,
+    };
+
+    if !ctx.execution.requires_write_access() || read_content(ctx, &path).as_ref() == Some(&content)
+    {
+        let entry = CacheEntry::new(
+            hash_content(&content, ctx.configuration_hash(&path)),
+            messages,
+            status.clone(),
+        );
+        cache.insert(&path, entry);
+    }
+
+    Ok(status.into_file_status(ctx.execution, &path, &content))
+}
+
+/// Reads the content of the file at `path`, used to look up its result in
+/// the cache
+fn read_content(ctx: &TraversalOptions, path: &Path) -> Option<String> {
+    let mut file = ctx
+        .open_file(path, OpenOptions::default().read(true))
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+/// Writes `content` to the file at `path`
+pub(crate) fn write_file(
+    ctx: &SharedTraversalOptions,
+    path: &Path,
+    content: &str,
+) -> Result<(), Message> {
+    let mut file = ctx
+        .fs
+        .create(path)
+        .with_file_path(path.display().to_string())?;
+    file.set_content(content.as_bytes())
+        .with_file_path(path.display().to_string())?;
+    if let Some(written_files) = ctx.execution.written_files() {
+        written_files.record(path, content);
+    }
+    Ok(())
+}
+
+/// Writes the source map of the code written at `path` next to it, e.g. to
+/// `file.js.map`, and returns the name of the source map file
+pub(crate) fn write_source_map(
+    ctx: &SharedTraversalOptions,
+    path: &Path,
+    source_map: SourceMapV3,
+    category: &'static Category,
+) -> Result<String, Message> {
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let source_map_name = format!("{file_name}.map");
+    let source_map_path = path.with_file_name(&source_map_name);
+    let source_map = serde_json::to_string(&source_map.with_file(file_name))
+        .map_err(|error| WorkspaceError::report_not_serializable(error.to_string()))
+        .with_file_path_and_code(source_map_path.display().to_string(), category)?;
+    write_file(ctx, &source_map_path, &source_map)?;
+    Ok(source_map_name)
+}
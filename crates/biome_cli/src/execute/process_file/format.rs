@@ -1,8 +1,9 @@
 use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
-    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+    write_source_map, DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
 };
+use crate::execute::profile::{FileStep, Profiler};
 use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::RuleCategories;
@@ -19,6 +20,7 @@ pub(crate) fn format_with_guard<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
 ) -> FileResult {
+    let _timer = Profiler::start(ctx.profiler, &workspace_file.path, FileStep::Format);
     tracing::info_span!("Processes formatting", path =? workspace_file.path.display()).in_scope(
         move || {
             let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
@@ -32,18 +34,21 @@ pub(crate) fn format_with_guard<'ctx>(
                 )?;
 
             let input = workspace_file.input()?;
-            let (should_write, ignore_errors) = match ctx.execution.traversal_mode {
-                TraversalMode::Format {
-                    write,
-                    ignore_errors,
-                    ..
-                } => (write, ignore_errors),
+            let (should_write, should_write_source_map, ignore_errors) =
+                match ctx.execution.traversal_mode {
+                    TraversalMode::Format {
+                        write,
+                        source_map,
+                        ignore_errors,
+                        ..
+                    } => (write, write && source_map, ignore_errors),
 
-                _ => (
-                    ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
-                    false,
-                ),
-            };
+                    _ => (
+                        ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
+                        false,
+                        false,
+                    ),
+                };
             debug!("Should write the file to disk? {}", should_write);
             debug!("Should ignore errors? {}", ignore_errors);
 
@@ -53,21 +58,24 @@ pub(crate) fn format_with_guard<'ctx>(
                 ));
             }
 
-            let printed = workspace_file
+            let mut printed = workspace_file
                 .guard()
-                .format_file()
+                .format_file(should_write_source_map)
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("format"),
                 )?;
 
-            let output = printed.into_code();
-
             // NOTE: ignoring the
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
             }
 
+            if let Some(source_map) = printed.take_source_map_v3() {
+                write_source_map(ctx, &workspace_file.path, source_map, category!("format"))?;
+            }
+            let output = printed.into_code();
+
             if output != input {
                 if should_write {
                     workspace_file.update_file(output)?;
@@ -1,8 +1,9 @@
 use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::profile::{FileStep, Profiler};
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, Error};
+use biome_diagnostics::{category, Error, Severity};
 use biome_service::workspace::RuleCategories;
 use std::path::Path;
 use std::sync::atomic::Ordering;
@@ -17,6 +18,7 @@ pub(crate) fn lint_with_guard<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
 ) -> FileResult {
+    let _timer = Profiler::start(ctx.profiler, &workspace_file.path, FileStep::Lint);
     tracing::info_span!("Processes linting", path =? workspace_file.path.display()).in_scope(
         move || {
             let mut errors = 0;
@@ -42,28 +44,43 @@ pub(crate) fn lint_with_guard<'ctx>(
                 errors = fix_result.errors;
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            // All the diagnostics are matched against the baseline
+            let max_diagnostics = match ctx.baseline {
+                Some(_) => u64::MAX,
+                None => ctx.remaining_diagnostics.load(Ordering::Relaxed).into(),
+            };
             let pull_diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::LINT, max_diagnostics.into())
+                .pull_diagnostics(RuleCategories::LINT, max_diagnostics)
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
-            let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
-                && pull_diagnostics_result.skipped_diagnostics == 0;
-            errors += pull_diagnostics_result.errors;
+            let mut diagnostics: Vec<_> = pull_diagnostics_result
+                .diagnostics
+                .into_iter()
+                .map(Error::from)
+                .collect();
+            if let Some(baseline) = ctx.baseline {
+                diagnostics = baseline.filter(ctx.fs, &workspace_file.path, &input, diagnostics);
+                // The errors that remain after the fixes are reported again
+                errors = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+                    .count();
+            } else {
+                errors += pull_diagnostics_result.errors;
+            }
+
+            let no_diagnostics =
+                diagnostics.is_empty() && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
-                    diagnostics: pull_diagnostics_result
-                        .diagnostics
-                        .into_iter()
-                        .map(Error::from)
-                        .collect(),
+                    diagnostics,
                     skipped_diagnostics: pull_diagnostics_result.skipped_diagnostics,
                 });
             }
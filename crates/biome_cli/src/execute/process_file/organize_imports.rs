@@ -3,6 +3,7 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
 };
+use crate::execute::profile::{FileStep, Profiler};
 use biome_diagnostics::category;
 
 /// Lints a single file and returns a [FileResult]
@@ -10,6 +11,11 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
 ) -> FileResult {
+    let _timer = Profiler::start(
+        ctx.profiler,
+        &workspace_file.path,
+        FileStep::OrganizeImports,
+    );
     tracing::info_span!("Processes import sorting", path =? workspace_file.path.display()).in_scope(
         move || {
             let sorted = workspace_file
@@ -0,0 +1,52 @@
+use crate::execute::diagnostics::ResultExt;
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use crate::execute::TraversalMode;
+use biome_diagnostics::category;
+use std::path::Path;
+
+/// Replaces the code of a single file that matches the pattern and returns a [FileResult]
+pub(crate) fn rewrite<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+) -> FileResult {
+    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
+    tracing::info_span!("Processes rewrite", path =? workspace_file.path.display()).in_scope(
+        move || {
+            let TraversalMode::Rewrite {
+                pattern,
+                replacement,
+                write,
+            } = &ctx.execution.traversal_mode
+            else {
+                return Ok(FileStatus::Success);
+            };
+
+            let result = workspace_file
+                .guard()
+                .rewrite_file(pattern, replacement)
+                .with_file_path_and_code(
+                    workspace_file.path.display().to_string(),
+                    category!("rewrite"),
+                )?;
+
+            if result.rewritten == 0 {
+                return Ok(FileStatus::Success);
+            }
+
+            if *write {
+                workspace_file.update_file(result.code)?;
+                return Ok(FileStatus::Success);
+            }
+
+            Ok(FileStatus::Message(Message::Diff {
+                file_name: workspace_file.path.display().to_string(),
+                old: workspace_file.input()?,
+                new: result.code,
+                diff_kind: DiffKind::Rewrite,
+            }))
+        },
+    )
+}
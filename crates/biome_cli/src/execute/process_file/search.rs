@@ -0,0 +1,47 @@
+use crate::execute::diagnostics::{ResultExt, SearchMatchDiagnostic};
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::TraversalMode;
+use biome_diagnostics::{category, Error};
+use biome_service::file_handlers::Language;
+use std::path::Path;
+
+/// Searches the code of a single file that matches the pattern and returns a [FileResult]
+pub(crate) fn search<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
+    let workspace_file = WorkspaceFile::new(ctx, path)?;
+    tracing::info_span!("Processes search", path =? workspace_file.path.display()).in_scope(
+        move || {
+            let TraversalMode::Search { pattern } = &ctx.execution.traversal_mode else {
+                return Ok(FileStatus::Success);
+            };
+
+            let result = workspace_file
+                .guard()
+                .search_pattern(pattern)
+                .with_file_path_and_code(
+                    workspace_file.path.display().to_string(),
+                    category!("search"),
+                )?;
+
+            if result.matches.is_empty() {
+                return Ok(FileStatus::Success);
+            }
+
+            Ok(FileStatus::Message(Message::Diagnostics {
+                name: workspace_file.path.display().to_string(),
+                content: workspace_file.input()?,
+                diagnostics: result
+                    .matches
+                    .into_iter()
+                    .map(|range| Error::from(SearchMatchDiagnostic { range }))
+                    .collect(),
+                skipped_diagnostics: 0,
+            }))
+        },
+    )
+}
+
+/// Whether the file contains JavaScript code that can be searched
+pub(crate) fn can_search(path: &Path) -> bool {
+    Language::from_path(path).is_javascript_like()
+}
@@ -0,0 +1,79 @@
+use crate::execute::diagnostics::ResultExt;
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{
+    write_file, write_source_map, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use crate::execute::TraversalMode;
+use biome_diagnostics::category;
+use biome_service::file_handlers::Language;
+use std::path::{Path, PathBuf};
+
+/// Compiles the TypeScript and JSX syntax of a single file and returns a [FileResult]
+pub(crate) fn transform<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+) -> FileResult {
+    let workspace_file = WorkspaceFile::new(ctx, path)?;
+    tracing::info_span!("Processes transformation", path =? workspace_file.path.display()).in_scope(
+        move || {
+            let (should_write, should_write_source_map) = match ctx.execution.traversal_mode {
+                TraversalMode::Transform {
+                    write, source_map, ..
+                } => (write, source_map),
+                _ => (false, false),
+            };
+
+            let result = workspace_file
+                .guard()
+                .transform_file(should_write_source_map)
+                .with_file_path_and_code(
+                    workspace_file.path.display().to_string(),
+                    category!("transform"),
+                )?;
+
+            if !should_write {
+                return Ok(FileStatus::Message(Message::Transformed {
+                    file_name: workspace_file.path.display().to_string(),
+                    code: result.code,
+                }));
+            }
+
+            let output_path = output_path(&workspace_file.path);
+            let mut code = result.code;
+
+            if let Some(source_map) = result.source_map {
+                let source_map_name =
+                    write_source_map(ctx, &output_path, source_map, category!("transform"))?;
+                code.push_str(&format!("//# sourceMappingURL={source_map_name}\n"));
+            }
+
+            write_file(ctx, &output_path, &code)?;
+
+            Ok(FileStatus::Success)
+        },
+    )
+}
+
+/// Whether the file contains TypeScript or JSX code that can be emitted as JavaScript.
+/// Declaration files, e.g. `file.d.ts`, are skipped because they only contain types.
+pub(crate) fn can_transform(path: &Path) -> bool {
+    let is_declaration_file = path
+        .file_stem()
+        .and_then(|file_stem| file_stem.to_str())
+        .is_some_and(|file_stem| file_stem.ends_with(".d"));
+    matches!(
+        Language::from_path(path),
+        Language::TypeScript | Language::TypeScriptReact | Language::JavaScriptReact
+    ) && !is_declaration_file
+}
+
+/// Returns the path of the JavaScript file emitted for `path`,
+/// e.g. `file.mts` is emitted to `file.mjs`
+pub(crate) fn output_path(path: &Path) -> PathBuf {
+    let extension = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("mts") => "mjs",
+        Some("cts") => "cjs",
+        _ => "js",
+    };
+    path.with_extension(extension)
+}
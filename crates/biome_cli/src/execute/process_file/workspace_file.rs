@@ -1,7 +1,10 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{Message, SharedTraversalOptions};
+use crate::execute::profile::{FileStep, Profiler};
+use crate::execute::WrittenFiles;
+use crate::CliDiagnostic;
 use biome_diagnostics::{category, Error};
-use biome_fs::{File, OpenOptions, RomePath};
+use biome_fs::{File, OpenOptions, RomePath, UnstagedChangesConflict};
 use biome_service::file_handlers::Language;
 use biome_service::workspace::{FileGuard, OpenFileParams};
 use biome_service::{Workspace, WorkspaceError};
@@ -12,6 +15,8 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// Records the content written to the file in watch mode
+    written_files: Option<WrittenFiles>,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -26,14 +31,14 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .read(true)
             .write(ctx.execution.requires_write_access());
         let mut file = ctx
-            .fs
-            .open_with_options(path, open_options)
+            .open_file(path, open_options)
             .with_file_path(path.display().to_string())?;
 
         let mut input = String::new();
         file.read_to_string(&mut input)
             .with_file_path(path.display().to_string())?;
 
+        let timer = Profiler::start(ctx.profiler, path, FileStep::Parse);
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
@@ -44,11 +49,13 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             },
         )
         .with_file_path_and_code(path.display().to_string(), category!("internalError/fs"))?;
+        drop(timer);
 
         Ok(Self {
             file,
             guard,
             path: PathBuf::from(path),
+            written_files: ctx.execution.written_files().cloned(),
         })
     }
 
@@ -61,11 +68,22 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
     }
 
     /// It updates the workspace file with `new_content`
-    pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
+    ///
+    /// The conflicts with the unstaged changes of a staged file are reported as errors
+    pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Message> {
         let new_content = new_content.into();
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
+        let file_name = self.path.display().to_string();
+        match self.file.set_content(new_content.as_bytes()) {
+            Err(error) if UnstagedChangesConflict::is_conflict(&error) => {
+                return Err(Message::ApplyError(CliDiagnostic::unstaged_changes(
+                    file_name,
+                )));
+            }
+            result => result.with_file_path(file_name)?,
+        }
+        if let Some(written_files) = &self.written_files {
+            written_files.record(&self.path, &new_content);
+        }
         self.guard
             .change_file(self.file.file_version(), new_content)?;
         Ok(())
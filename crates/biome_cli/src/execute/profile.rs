@@ -0,0 +1,525 @@
+//! Profiling of the commands that process files, enabled by `--profile-rules`.
+//!
+//! The CLI measures how long each file takes to be parsed, linted, formatted
+//! and to have its imports sorted, while the analyzer measures the time spent
+//! in each rule, phase and visitor (see [biome_analyze::enable_profiling]). Once
+//! the files are processed, the timings are printed as tables sorted from the
+//! slowest to the fastest, and they can be written to a file as JSON or in
+//! the Trace Event Format of Chrome.
+//!
+//! The timings of the analyzer are collected in the process of the CLI, so
+//! profiling isn't available when the files are processed by the daemon.
+
+use crate::cli_options::{CliOptions, ProfileFormat};
+use crate::execute::{Execution, WrittenFiles};
+use crate::CliDiagnostic;
+use biome_analyze::{AnalyzerProfile, Phases, ProfilingSession, RuleProfile};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_fs::{FileSystem, OpenOptions};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+/// The number of files listed in the table, from the slowest one
+const MAX_PRINTED_FILES: usize = 10;
+
+/// A step of the processing of a file
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum FileStep {
+    Parse,
+    Lint,
+    Format,
+    OrganizeImports,
+}
+
+impl FileStep {
+    const fn name(self) -> &'static str {
+        match self {
+            FileStep::Parse => "parse",
+            FileStep::Lint => "lint",
+            FileStep::Format => "format",
+            FileStep::OrganizeImports => "organizeImports",
+        }
+    }
+}
+
+/// The time spent on a step of the processing of a file
+struct FileSpan {
+    path: PathBuf,
+    step: FileStep,
+    thread: ThreadId,
+    start: Instant,
+    duration: Duration,
+}
+
+/// Collects the timings of the files processed by a command
+/// The profiling arguments of a command
+#[derive(Debug, Clone)]
+pub(crate) struct ProfileArguments {
+    /// Whether the timings are printed once the files are processed
+    pub(crate) print: bool,
+    /// The file where the timings are written
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) format: ProfileFormat,
+}
+
+pub(crate) struct Profiler {
+    /// Whether the timings are printed once the files are processed
+    print: bool,
+    /// The file where the timings are written
+    output: Option<PathBuf>,
+    format: ProfileFormat,
+    /// The start of the traversal, which is the origin of the trace
+    start: Instant,
+    spans: Mutex<Vec<FileSpan>>,
+    /// The profiling of the analyzer, enabled until the profiler finishes
+    session: ProfilingSession,
+}
+
+impl Profiler {
+    /// Creates the profiler requested by the arguments of the command, and
+    /// enables the profiling of the analyzer.
+    ///
+    /// It returns [None] if the command isn't profiled.
+    pub(crate) fn new(
+        execution: &Execution,
+        cli_options: &CliOptions,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        let Some(arguments) = execution.profile_arguments() else {
+            return Ok(None);
+        };
+        if cli_options.use_server {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "use-server",
+                "profile-rules",
+            ));
+        }
+
+        let session = biome_analyze::enable_profiling();
+
+        Ok(Some(Self {
+            print: arguments.print,
+            output: arguments.output.clone(),
+            format: arguments.format,
+            start: Instant::now(),
+            spans: Mutex::new(Vec::new()),
+            session,
+        }))
+    }
+
+    /// Starts measuring the `step` of the file at `path`, until the returned
+    /// timer is dropped. It returns [None] if the command isn't profiled.
+    pub(crate) fn start<'a>(
+        profiler: Option<&'a Self>,
+        path: &Path,
+        step: FileStep,
+    ) -> Option<StepTimer<'a>> {
+        profiler.map(|profiler| StepTimer {
+            profiler,
+            path: path.to_path_buf(),
+            step,
+            start: Instant::now(),
+        })
+    }
+
+    /// Prints the timings and writes them to the output file, which is
+    /// recorded in `written_files`
+    pub(crate) fn finish(
+        self,
+        fs: &dyn FileSystem,
+        console: &mut dyn Console,
+        print: bool,
+        written_files: Option<&WrittenFiles>,
+    ) -> io::Result<()> {
+        let analyzer = biome_analyze::disable_profiling(self.session);
+        let spans = self.spans.into_inner().unwrap();
+        let files = aggregate_files(&spans);
+
+        if self.print && print {
+            print_profile(console, &analyzer, &files);
+        }
+
+        if let Some(output) = &self.output {
+            let content = match self.format {
+                ProfileFormat::Json => {
+                    serde_json::to_string_pretty(&JsonProfile::new(&analyzer, &files))?
+                }
+                ProfileFormat::ChromeTrace => {
+                    serde_json::to_string(&ChromeTrace::new(self.start, &analyzer, &spans))?
+                }
+            };
+
+            let mut file = fs.open_with_options(
+                output,
+                OpenOptions::default()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )?;
+            file.set_content(content.as_bytes())?;
+            if let Some(written_files) = written_files {
+                written_files.record(output, &content);
+            }
+            console.log(markup! {
+                <Info>"Wrote the profile to "<Emphasis>{output.display().to_string()}</Emphasis></Info>
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Measures a step of the processing of a file, which is recorded when the
+/// timer is dropped
+pub(crate) struct StepTimer<'a> {
+    profiler: &'a Profiler,
+    path: PathBuf,
+    step: FileStep,
+    start: Instant,
+}
+
+impl Drop for StepTimer<'_> {
+    fn drop(&mut self) {
+        let span = FileSpan {
+            path: std::mem::take(&mut self.path),
+            step: self.step,
+            thread: thread::current().id(),
+            start: self.start,
+            duration: self.start.elapsed(),
+        };
+        self.profiler.spans.lock().unwrap().push(span);
+    }
+}
+
+/// The time spent on each step of the processing of a file
+#[derive(Default)]
+struct FileTimings {
+    parse: Option<Duration>,
+    lint: Option<Duration>,
+    format: Option<Duration>,
+    organize_imports: Option<Duration>,
+}
+
+impl FileTimings {
+    fn total(&self) -> Duration {
+        [self.parse, self.lint, self.format, self.organize_imports]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+/// Returns the timings of each file, from the slowest to the fastest
+fn aggregate_files(spans: &[FileSpan]) -> Vec<(&Path, FileTimings)> {
+    let mut files: BTreeMap<&Path, FileTimings> = BTreeMap::new();
+    for span in spans {
+        let timings = files.entry(&span.path).or_default();
+        let duration = match span.step {
+            FileStep::Parse => &mut timings.parse,
+            FileStep::Lint => &mut timings.lint,
+            FileStep::Format => &mut timings.format,
+            FileStep::OrganizeImports => &mut timings.organize_imports,
+        };
+        *duration = Some(duration.unwrap_or_default() + span.duration);
+    }
+
+    let mut files: Vec<_> = files.into_iter().collect();
+    files.sort_by(|(_, a), (_, b)| b.total().cmp(&a.total()));
+    files
+}
+
+fn print_profile(
+    console: &mut dyn Console,
+    analyzer: &AnalyzerProfile,
+    files: &[(&Path, FileTimings)],
+) {
+    if !analyzer.rules.is_empty() {
+        let mut table = format!(
+            "{:<48} {:>10} {:>10} {:>10} {:>8}",
+            "Rule", "Total", "Query", "Signals", "Matches"
+        );
+        for rule in &analyzer.rules {
+            table.push_str(&format!(
+                "\n{:<48} {:>10} {:>10} {:>10} {:>8}",
+                rule_name(rule),
+                format_duration(rule.total()),
+                format_duration(rule.query),
+                format_duration(rule.signals),
+                rule.matches
+            ));
+        }
+        console.log(markup! {
+            <Emphasis>"Time spent in the lint rules"</Emphasis>"\n"{table}"\n"
+        });
+    }
+
+    if !analyzer.phases.is_empty() {
+        let mut table = format!("{:<48} {:>10} {:>10}", "Phase", "Total", "Runs");
+        for phase in &analyzer.phases {
+            table.push_str(&format!(
+                "\n{:<48} {:>10} {:>10}",
+                phase_name(phase.phase),
+                format_duration(phase.duration),
+                phase.runs
+            ));
+        }
+        for visitor in &analyzer.visitors {
+            table.push_str(&format!(
+                "\n{:<48} {:>10}",
+                format!("  {} ({})", visitor.name, phase_name(visitor.phase)),
+                format_duration(visitor.duration),
+            ));
+        }
+        console.log(markup! {
+            <Emphasis>"Time spent in the phases of the analyzer"</Emphasis>"\n"{table}"\n"
+        });
+    }
+
+    if !files.is_empty() {
+        let mut table = format!(
+            "{:<48} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "File", "Total", "Parse", "Lint", "Format", "Imports"
+        );
+        for (path, timings) in files.iter().take(MAX_PRINTED_FILES) {
+            let optional = |duration: Option<Duration>| {
+                duration.map_or_else(|| String::from("-"), format_duration)
+            };
+            table.push_str(&format!(
+                "\n{:<48} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                path.display(),
+                format_duration(timings.total()),
+                optional(timings.parse),
+                optional(timings.lint),
+                optional(timings.format),
+                optional(timings.organize_imports),
+            ));
+        }
+        if files.len() > MAX_PRINTED_FILES {
+            table.push_str(&format!(
+                "\n... and {} more file(s)",
+                files.len() - MAX_PRINTED_FILES
+            ));
+        }
+        console.log(markup! {
+            <Emphasis>"Time spent on the files"</Emphasis>"\n"{table}"\n"
+        });
+    }
+}
+
+/// Returns the name of a rule, e.g. `suspicious/noDebugger`
+fn rule_name(rule: &RuleProfile) -> String {
+    format!("{}/{}", rule.rule.group(), rule.rule.rule_name())
+}
+
+const fn phase_name(phase: Phases) -> &'static str {
+    match phase {
+        Phases::Syntax => "syntax",
+        Phases::Semantic => "semantic",
+        Phases::Type => "type",
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+/// Returns a duration in milliseconds, the unit of the JSON profile, with a
+/// precision of a microsecond
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+/// Returns a duration in microseconds, the unit of the Chrome trace, with a
+/// precision of a nanosecond
+fn as_micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+/// The profile written with the `json` format, whose durations are in
+/// milliseconds
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonProfile<'a> {
+    rules: Vec<JsonRule>,
+    phases: Vec<JsonPhase>,
+    visitors: Vec<JsonVisitor>,
+    files: Vec<JsonFile<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonRule {
+    rule: String,
+    total: f64,
+    query: f64,
+    signals: f64,
+    matches: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonPhase {
+    phase: &'static str,
+    total: f64,
+    runs: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonVisitor {
+    name: &'static str,
+    phase: &'static str,
+    total: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFile<'a> {
+    path: &'a Path,
+    total: f64,
+    parse: Option<f64>,
+    lint: Option<f64>,
+    format: Option<f64>,
+    organize_imports: Option<f64>,
+}
+
+impl<'a> JsonProfile<'a> {
+    fn new(analyzer: &AnalyzerProfile, files: &[(&'a Path, FileTimings)]) -> Self {
+        Self {
+            rules: analyzer
+                .rules
+                .iter()
+                .map(|rule| JsonRule {
+                    rule: rule_name(rule),
+                    total: as_millis(rule.total()),
+                    query: as_millis(rule.query),
+                    signals: as_millis(rule.signals),
+                    matches: rule.matches,
+                })
+                .collect(),
+            phases: analyzer
+                .phases
+                .iter()
+                .map(|phase| JsonPhase {
+                    phase: phase_name(phase.phase),
+                    total: as_millis(phase.duration),
+                    runs: phase.runs,
+                })
+                .collect(),
+            visitors: analyzer
+                .visitors
+                .iter()
+                .map(|visitor| JsonVisitor {
+                    name: visitor.name,
+                    phase: phase_name(visitor.phase),
+                    total: as_millis(visitor.duration),
+                })
+                .collect(),
+            files: files
+                .iter()
+                .map(|(path, timings)| JsonFile {
+                    path,
+                    total: as_millis(timings.total()),
+                    parse: timings.parse.map(as_millis),
+                    lint: timings.lint.map(as_millis),
+                    format: timings.format.map(as_millis),
+                    organize_imports: timings.organize_imports.map(as_millis),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The profile written with the `chrome-trace` format: a complete event for
+/// each step of the processing of a file and for each phase of the analyzer,
+/// whose timestamps are in microseconds since the start of the traversal
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<TraceEvent>,
+}
+
+#[derive(Serialize)]
+struct TraceEvent {
+    name: String,
+    cat: &'static str,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u32,
+    tid: usize,
+    args: TraceEventArgs,
+}
+
+#[derive(Serialize)]
+struct TraceEventArgs {
+    path: String,
+}
+
+impl ChromeTrace {
+    fn new(origin: Instant, analyzer: &AnalyzerProfile, spans: &[FileSpan]) -> Self {
+        // The trace identifies the threads with small numbers
+        let mut threads = FxHashMap::default();
+        let mut thread_id = |thread: ThreadId| {
+            let next = threads.len() + 1;
+            *threads.entry(thread).or_insert(next)
+        };
+        let mut event = |name: String,
+                         cat: &'static str,
+                         path: &Path,
+                         thread: ThreadId,
+                         start: Instant,
+                         duration: Duration| TraceEvent {
+            name,
+            cat,
+            ph: "X",
+            ts: as_micros(start.saturating_duration_since(origin)),
+            dur: as_micros(duration),
+            pid: 1,
+            tid: thread_id(thread),
+            args: TraceEventArgs {
+                path: path.display().to_string(),
+            },
+        };
+
+        let mut trace_events: Vec<_> = spans
+            .iter()
+            .map(|span| {
+                event(
+                    span.step.name().to_string(),
+                    "file",
+                    &span.path,
+                    span.thread,
+                    span.start,
+                    span.duration,
+                )
+            })
+            .collect();
+        trace_events.extend(
+            analyzer
+                .spans
+                .iter()
+                // The analyzer may have run before the traversal started,
+                // e.g. in another command running in the same process
+                .filter(|span| span.start >= origin)
+                .map(|span| {
+                    event(
+                        format!("{} phase", phase_name(span.phase)),
+                        "analyzer",
+                        &span.path,
+                        span.thread,
+                        span.start,
+                        span.duration,
+                    )
+                }),
+        );
+        trace_events.sort_by(|a, b| a.ts.total_cmp(&b.ts));
+
+        Self { trace_events }
+    }
+}
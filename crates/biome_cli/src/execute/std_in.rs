@@ -9,7 +9,7 @@ use biome_fs::RomePath;
 use biome_service::workspace::{
     ChangeFileParams, FeatureName, FeaturesBuilder, FixFileParams, FormatFileParams, Language,
     OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, RuleCategories,
-    SupportsFeatureParams,
+    SupportsFeatureParams, TransformFileParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -45,7 +45,10 @@ pub(crate) fn run<'a>(
                 content: content.into(),
                 language_hint: Language::default(),
             })?;
-            let printed = workspace.format_file(FormatFileParams { path: rome_path })?;
+            let printed = workspace.format_file(FormatFileParams {
+                path: rome_path,
+                source_map: false,
+            })?;
 
             console.append(markup! {
                 {printed.as_code()}
@@ -134,6 +137,7 @@ pub(crate) fn run<'a>(
         if file_features.supports_for(&FeatureName::Format) && mode.is_check() {
             let printed = workspace.format_file(FormatFileParams {
                 path: rome_path.clone(),
+                source_map: false,
             })?;
             if mode.is_check_apply() || mode.is_check_apply_unsafe() {
                 if printed.as_code() != new_content {
@@ -170,6 +174,21 @@ pub(crate) fn run<'a>(
                 })
             }
         }
+    } else if mode.is_transform() {
+        workspace.open_file(OpenFileParams {
+            path: rome_path.clone(),
+            version: 0,
+            content: content.into(),
+            language_hint: Language::default(),
+        })?;
+        let result = workspace.transform_file(TransformFileParams {
+            path: rome_path,
+            source_map: false,
+        })?;
+
+        console.append(markup! {
+            {result.code}
+        });
     } else {
         console.append(markup! {{content}});
     }
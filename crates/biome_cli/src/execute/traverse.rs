@@ -1,22 +1,29 @@
+use super::baseline::{baseline_path, Baseline};
+use super::cache::ResultCache;
+use super::declarations::register_declarations;
+use super::nested_configurations::absolute;
+use super::process_file::search::can_search;
+use super::process_file::transform::can_transform;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
+use super::profile::Profiler;
 use super::ExecutionEnvironment;
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
-    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic, RewriteDiffDiagnostic,
 };
 use crate::{CliDiagnostic, CliSession, Execution, FormatterReportSummary, Report, TraversalMode};
 use biome_console::{fmt, markup, Console, ConsoleExt};
 use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintGitHubDiagnostic;
 use biome_diagnostics::{category, DiagnosticExt, Error, PrintDiagnostic, Resource, Severity};
-use biome_fs::{FileSystem, PathInterner, RomePath};
+use biome_fs::{File, FileSystem, OpenOptions, PathInterner, RomePath};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
 use biome_service::{
     extension_error,
     workspace::{FeatureName, SupportsFeatureParams},
-    Workspace, WorkspaceError,
+    IgnoreFiles, Workspace, WorkspaceError,
 };
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use rustc_hash::FxHashSet;
@@ -51,8 +58,8 @@ impl fmt::Display for CheckResult {
 
 ///
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
@@ -79,7 +86,19 @@ pub(crate) fn traverse(
 
     let mut report = Report::default();
 
-    let printer = DiagnosticsPrinter::new(&execution)
+    let declarations_hash = register_declarations(fs, workspace, execution, &inputs);
+    let baseline = Baseline::load(fs, execution)?;
+    let profiler = Profiler::new(execution, cli_options)?;
+    // The results of the files can't be replayed from the cache when there's
+    // a baseline, because their diagnostics must be matched against it, nor
+    // when the command is profiled, because the files must be processed again
+    let cache = if baseline.is_some() || profiler.is_some() {
+        None
+    } else {
+        ResultCache::load(fs, execution, declarations_hash)
+    };
+
+    let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
@@ -100,12 +119,17 @@ pub(crate) fn traverse(
             &TraversalOptions {
                 fs,
                 workspace,
-                execution: &execution,
+                execution,
                 interner,
                 processed: &processed,
                 skipped: &skipped,
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
+                cache: cache.as_ref(),
+                baseline: baseline.as_ref(),
+                baseline_path: baseline_path(fs, execution),
+                profiler: profiler.as_ref(),
+                ignore_files: execution.ignore_files().cloned(),
             },
         );
         // wait for the main thread to finish
@@ -114,6 +138,25 @@ pub(crate) fn traverse(
         elapsed
     });
 
+    if let Some(cache) = cache {
+        if let Err(error) = cache.save(fs, execution.written_files()) {
+            tracing::warn!("Failed to save the cache: {error}");
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        baseline.finish(fs, console, execution.written_files())?;
+    }
+
+    if let Some(profiler) = profiler {
+        profiler.finish(
+            fs,
+            console,
+            execution.should_report_to_terminal(),
+            execution.written_files(),
+        )?;
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let count = processed.load(Ordering::Relaxed);
@@ -156,6 +199,34 @@ pub(crate) fn traverse(
                 });
             }
 
+            TraversalMode::Transform { write: false, .. } => {
+                console.log(markup! {
+                    <Info>"Transformed "{count}" file(s) in "{duration}</Info>
+                });
+            }
+            TraversalMode::Transform { write: true, .. } => {
+                console.log(markup! {
+                    <Info>"Transformed and wrote "{count}" file(s) in "{duration}</Info>
+                });
+            }
+
+            TraversalMode::Search { .. } => {
+                console.log(markup! {
+                    <Info>"Searched "{count}" file(s) in "{duration}</Info>
+                });
+            }
+
+            TraversalMode::Rewrite { write: false, .. } => {
+                console.log(markup! {
+                    <Info>"Compared "{count}" file(s) in "{duration}</Info>
+                });
+            }
+            TraversalMode::Rewrite { write: true, .. } => {
+                console.log(markup! {
+                    <Info>"Rewrote "{count}" file(s) in "{duration}</Info>
+                });
+            }
+
             TraversalMode::Migrate { write: false, .. } => {
                 console.log(markup! {
                     <Info>"Checked your configuration file in "{duration}</Info>
@@ -233,7 +304,13 @@ fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOp
     let start = Instant::now();
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
         for input in inputs {
-            scope.spawn(ctx, PathBuf::from(input));
+            // The staged files are read from the index, they may not exist in
+            // the working tree anymore
+            if ctx.execution.is_staged() {
+                scope.handle(ctx, PathBuf::from(input));
+            } else {
+                scope.spawn(ctx, PathBuf::from(input));
+            }
         }
     }));
 
@@ -453,20 +530,32 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         }
                     }
                 }
+                Message::Transformed { file_name, code } => {
+                    if self.execution.should_report_to_terminal() {
+                        console.log(markup! {
+                            <Emphasis>{file_name}</Emphasis>"\n"{code}
+                        });
+                    }
+                }
                 Message::Diff {
                     file_name,
                     old,
                     new,
                     diff_kind,
                 } => {
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
-                    // A diff is an error in CI mode and in format check mode
-                    if self.execution.is_ci() || !self.execution.is_format_write() {
+                    let is_rewrite = matches!(diff_kind, DiffKind::Rewrite);
+                    let is_error = !is_rewrite
+                        && (self.execution.is_ci() || !self.execution.is_format_write());
+                    // A diff is an error in CI mode and in format check mode,
+                    // a rewrite is only previewed
+                    if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if is_rewrite {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -510,6 +599,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -533,6 +632,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         }
                     }
@@ -594,6 +703,19 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// The results of the previous run, [None] if the cache is disabled
+    pub(crate) cache: Option<&'ctx ResultCache>,
+    /// The lint diagnostics that aren't reported, [None] if there isn't any
+    /// baseline
+    pub(crate) baseline: Option<&'ctx Baseline>,
+    /// The path of the baseline file, which is written by Biome and isn't
+    /// processed like the other files
+    baseline_path: PathBuf,
+    /// Records the time spent on the files, [None] if the command isn't
+    /// profiled
+    pub(crate) profiler: Option<&'ctx Profiler>,
+    /// The ignore files found by the traversal, [None] if they aren't used
+    ignore_files: Option<IgnoreFiles>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -613,6 +735,24 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         );
     }
 
+    /// Opens a file of the traversal, from the git index when the execution
+    /// processes the staged files
+    pub(crate) fn open_file(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>> {
+        if self.execution.is_staged() {
+            self.fs.open_staged(path)
+        } else {
+            self.fs.open_with_options(path, options)
+        }
+    }
+
+    /// Returns the hash of the configuration file of the directory of `path`,
+    /// [None] if the configuration of the workspace applies to it
+    pub(crate) fn configuration_hash(&self, path: &Path) -> Option<u64> {
+        self.execution
+            .nested_configurations()
+            .and_then(|nested_configurations| nested_configurations.configuration_hash(path))
+    }
+
     pub(crate) fn protected_file(&self, rome_path: &RomePath) {
         self.push_diagnostic(WorkspaceError::protected_file(rome_path.display().to_string()).into())
     }
@@ -628,6 +768,25 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
+        if let Some(ignore_files) = &self.ignore_files {
+            // The ignore files are consumed by the traversal
+            if ignore_files.is_ignore_file(rome_path)
+                || ignore_files.is_ignored(self.fs, rome_path, rome_path.is_dir())
+            {
+                return false;
+            }
+        }
+
+        if let Some(nested_configurations) = self.execution.nested_configurations() {
+            if !nested_configurations.load(self, rome_path) {
+                return false;
+            }
+        }
+
+        if absolute(self.fs, rome_path) == self.baseline_path {
+            return false;
+        }
+
         if rome_path.is_dir() {
             let can_handle = !self
                 .workspace
@@ -679,6 +838,12 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             }
             TraversalMode::Format { .. } => file_features.supports_for(&FeatureName::Format),
             TraversalMode::Lint { .. } => file_features.supports_for(&FeatureName::Lint),
+            TraversalMode::Transform { .. } => {
+                !file_features.is_ignored() && can_transform(rome_path.as_path())
+            }
+            TraversalMode::Search { .. } | TraversalMode::Rewrite { .. } => {
+                !file_features.is_ignored() && can_search(rome_path.as_path())
+            }
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
         }
@@ -703,7 +868,10 @@ fn handle_file(ctx: &TraversalOptions, path: &Path) {
         }
         Ok(Ok(FileStatus::Ignored)) => {}
         Ok(Err(err)) => {
-            ctx.skipped.fetch_add(1, Ordering::Relaxed);
+            // The file was processed, but its changes couldn't be written
+            if !matches!(err, Message::ApplyError(_)) {
+                ctx.skipped.fetch_add(1, Ordering::Relaxed);
+            }
             ctx.push_message(err);
         }
         Err(err) => {
@@ -0,0 +1,573 @@
+use crate::cli_options::CliOptions;
+use crate::commands::update_workspace_settings;
+use crate::execute::cache::hash_configuration;
+use crate::execute::traverse::traverse;
+use crate::{CliDiagnostic, CliSession, Execution};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::{normalize_path, FileSystem, OpenOptions, RomePath, BIOME_JSON, ROME_JSON};
+use biome_service::workspace::{FeaturesBuilder, SupportsFeatureParams};
+use biome_service::Configuration;
+use crossbeam::channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::collections::BTreeSet;
+use std::ffi::{OsStr, OsString};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The configuration resolved by a command: the configuration, the directory of
+/// its file, and the paths of the files it extends
+type ResolvedConfiguration = (Configuration, Option<PathBuf>, Vec<PathBuf>);
+
+/// How long the file system must stay quiet before a batch of changes is processed.
+///
+/// Editors and formatters usually touch a file several times when saving it, the
+/// delay gathers these events into a single run.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Runs the traversal of the given paths, then keeps the session alive and runs
+/// it again on the files that change, until the process is stopped.
+///
+/// The configuration is resolved and applied to the workspace again when the
+/// configuration file, or one of the files it extends, changes, in which case
+/// all the paths are traversed again.
+pub(crate) fn watch_mode<F>(
+    mut execution: Execution,
+    session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+    configuration_path: Option<PathBuf>,
+    extended_files_paths: Vec<PathBuf>,
+    resolve_configuration: F,
+) -> Result<(), CliDiagnostic>
+where
+    F: FnMut(&mut CliSession) -> Result<ResolvedConfiguration, CliDiagnostic>,
+{
+    execution.max_diagnostics = cli_options.max_diagnostics;
+    execution.written_files = Some(WrittenFiles::new(session.app.fs.working_directory()));
+    if paths.is_empty() {
+        return Err(CliDiagnostic::missing_argument(
+            "<INPUT>",
+            format!("{}", execution.traversal_mode),
+        ));
+    }
+
+    let (sender, receiver) = unbounded();
+    let mut watcher = create_watcher(sender.clone(), |_| true)?;
+    for path in &paths {
+        watcher
+            .watch(Path::new(path), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+
+    // The other files of the directory may be outside of the given paths
+    let mut configuration_watcher = create_watcher(sender.clone(), is_configuration_file)?;
+    if let Some(directory) = configuration_path.or(session.app.fs.working_directory()) {
+        configuration_watcher
+            .watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+    }
+
+    let mut extended_files = ExtendedFiles::new(Some(sender), session.app.fs.working_directory())?;
+    extended_files.update(extended_files_paths);
+
+    run(
+        execution,
+        session,
+        cli_options,
+        paths,
+        resolve_configuration,
+        &mut extended_files,
+        &receiver,
+    );
+
+    Ok(())
+}
+
+/// Creates a watcher that sends the paths of the changes accepted by `filter`
+fn create_watcher(
+    sender: Sender<PathBuf>,
+    filter: impl Fn(&Path) -> bool + Send + 'static,
+) -> Result<RecommendedWatcher, CliDiagnostic> {
+    notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+        Ok(event) if !event.kind.is_access() => {
+            for path in event.paths.into_iter().filter(|path| filter(path)) {
+                // the receiver is gone once the session ends
+                let _ = sender.send(path);
+            }
+        }
+        Ok(_) => {}
+        Err(error) => tracing::warn!("Failed to watch the file system: {error}"),
+    })
+    .map_err(watch_error)
+}
+
+/// The watch loop: it returns when the channel of file changes is closed
+fn run<F>(
+    mut execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+    mut resolve_configuration: F,
+    extended_files: &mut ExtendedFiles,
+    changes: &Receiver<PathBuf>,
+) where
+    F: FnMut(&mut CliSession) -> Result<ResolvedConfiguration, CliDiagnostic>,
+{
+    let mut inputs = paths.clone();
+    loop {
+        if let Some(written_files) = &execution.written_files {
+            written_files.clear();
+        }
+        let result = traverse(&execution, &mut session, cli_options, inputs);
+        print_result(&mut session, cli_options, result);
+
+        session.app.console.log(markup! {
+            <Info>"Watching for file changes..."</Info>
+        });
+
+        inputs = loop {
+            let Some(mut changed) = next_changes(changes) else {
+                return;
+            };
+
+            // The files written by the traversal would trigger another run
+            if let Some(written_files) = &execution.written_files {
+                changed.retain(|path| !written_files.is_unchanged(&*session.app.fs, path));
+            }
+
+            if changed
+                .iter()
+                .any(|path| is_configuration_file(path) || extended_files.contains(path))
+            {
+                session.app.console.log(markup! {
+                    <Info>"The configuration file changed, reloading the configuration."</Info>
+                });
+                match reload_configuration(
+                    &mut execution,
+                    &mut session,
+                    &mut resolve_configuration,
+                    extended_files,
+                ) {
+                    Ok(()) => break paths.clone(),
+                    Err(error) => print_result(&mut session, cli_options, Err(error)),
+                }
+                continue;
+            }
+
+            let files: Vec<_> = changed
+                .into_iter()
+                .filter(|path| is_supported(&session, path))
+                .map(PathBuf::into_os_string)
+                .collect();
+            if !files.is_empty() {
+                let count = files.len();
+                session.app.console.log(markup! {
+                    <Info>"Detected changes in "{count}" file(s)."</Info>
+                });
+                break files;
+            }
+        };
+    }
+}
+
+/// Resolves the configuration again, and applies it to the workspace
+fn reload_configuration<F>(
+    execution: &mut Execution,
+    session: &mut CliSession,
+    resolve_configuration: &mut F,
+    extended_files: &mut ExtendedFiles,
+) -> Result<(), CliDiagnostic>
+where
+    F: FnMut(&mut CliSession) -> Result<ResolvedConfiguration, CliDiagnostic>,
+{
+    let (configuration, configuration_path, extended_files_paths) = resolve_configuration(session)?;
+    extended_files.update(extended_files_paths);
+    execution.configuration_hash = Some(hash_configuration(&*session.app.fs, &configuration));
+    let (ignore_files, nested_configurations) =
+        update_workspace_settings(session, configuration, configuration_path)?;
+    execution.ignore_files = ignore_files;
+    execution.nested_configurations = Some(nested_configurations);
+    Ok(())
+}
+
+/// The files written by the runs, e.g. the formatted files, along with the hash
+/// of the content that was written. Their changes are caused by the run, and
+/// don't trigger another run unless their content changed since.
+#[derive(Clone, Debug)]
+pub(crate) struct WrittenFiles {
+    working_directory: Option<PathBuf>,
+    hashes: Arc<Mutex<FxHashMap<PathBuf, u64>>>,
+}
+
+impl WrittenFiles {
+    fn new(working_directory: Option<PathBuf>) -> Self {
+        Self {
+            working_directory,
+            hashes: Arc::default(),
+        }
+    }
+
+    /// Records that `content` was written to the file at `path`
+    pub(crate) fn record(&self, path: &Path, content: &str) {
+        let path = absolute(self.working_directory.as_deref(), path);
+        self.hashes
+            .lock()
+            .unwrap()
+            .insert(path, hash_content(content));
+    }
+
+    /// Tells if `path` was written by the last run, and still has the content
+    /// that was written
+    fn is_unchanged(&self, fs: &dyn FileSystem, path: &Path) -> bool {
+        let path = absolute(self.working_directory.as_deref(), path);
+        let Some(hash) = self.hashes.lock().unwrap().get(&path).copied() else {
+            return false;
+        };
+
+        let mut content = String::new();
+        fs.open_with_options(&path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .is_ok_and(|()| hash_content(&content) == hash)
+    }
+
+    fn clear(&self) {
+        self.hashes.lock().unwrap().clear();
+    }
+}
+
+fn hash_content(content: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The files extended by the configuration, they aren't necessarily in the
+/// directory of the configuration file, e.g. when they come from a package
+struct ExtendedFiles {
+    /// Watches the directories of the files, [None] in the tests
+    watcher: Option<RecommendedWatcher>,
+    working_directory: Option<PathBuf>,
+    /// The absolute paths of the files, shared with the filter of the watcher
+    paths: Arc<Mutex<FxHashSet<PathBuf>>>,
+    /// The directories watched so far
+    directories: FxHashSet<PathBuf>,
+}
+
+impl ExtendedFiles {
+    fn new(
+        sender: Option<Sender<PathBuf>>,
+        working_directory: Option<PathBuf>,
+    ) -> Result<Self, CliDiagnostic> {
+        let paths: Arc<Mutex<FxHashSet<PathBuf>>> = Arc::default();
+        let watcher = match sender {
+            Some(sender) => {
+                let paths = paths.clone();
+                Some(create_watcher(sender, move |path| {
+                    paths.lock().unwrap().contains(path)
+                })?)
+            }
+            None => None,
+        };
+        Ok(Self {
+            watcher,
+            working_directory,
+            paths,
+            directories: FxHashSet::default(),
+        })
+    }
+
+    /// Replaces the files extended by the configuration, and watches their
+    /// directories. The whole directory is watched because editors usually
+    /// save a file by replacing it.
+    fn update(&mut self, files: Vec<PathBuf>) {
+        let files: FxHashSet<_> = files
+            .iter()
+            .map(|path| absolute(self.working_directory.as_deref(), path))
+            .collect();
+        if let Some(watcher) = &mut self.watcher {
+            for directory in files.iter().filter_map(|path| path.parent()) {
+                if self.directories.insert(directory.to_path_buf()) {
+                    if let Err(error) = watcher.watch(directory, RecursiveMode::NonRecursive) {
+                        tracing::warn!("Failed to watch {}: {error}", directory.display());
+                    }
+                }
+            }
+        }
+        *self.paths.lock().unwrap() = files;
+    }
+
+    fn contains(&self, path: &Path) -> bool {
+        let path = absolute(self.working_directory.as_deref(), path);
+        self.paths.lock().unwrap().contains(&path)
+    }
+}
+
+/// Returns the absolute path of a file, so that the paths of the traversal,
+/// the configuration and the watchers can be compared with each other
+fn absolute(working_directory: Option<&Path>, path: &Path) -> PathBuf {
+    match working_directory {
+        Some(working_directory) if path.is_relative() => {
+            normalize_path(&working_directory.join(path))
+        }
+        _ => normalize_path(path),
+    }
+}
+
+/// Blocks until some paths change, and collects them until the file system is
+/// quiet for [DEBOUNCE]. Returns `None` when the channel is closed.
+fn next_changes(changes: &Receiver<PathBuf>) -> Option<BTreeSet<PathBuf>> {
+    let mut changed = BTreeSet::new();
+    changed.insert(changes.recv().ok()?);
+    loop {
+        match changes.recv_timeout(DEBOUNCE) {
+            Ok(path) => {
+                changed.insert(path);
+            }
+            Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {
+                return Some(changed)
+            }
+        }
+    }
+}
+
+/// Prints the error of a run, the watch mode doesn't stop because of it
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(error) = result {
+        let verbose = cli_options.verbose;
+        session.app.console.error(markup! {
+            {if verbose { PrintDiagnostic::verbose(&error) } else { PrintDiagnostic::simple(&error) }}
+        });
+    }
+}
+
+/// Tells if a changed path is a file that the traversal can process. The other
+/// paths, e.g. removed files or files without a handler, aren't passed to the
+/// traversal because they would be reported as errors.
+fn is_supported(session: &CliSession, path: &Path) -> bool {
+    if is_excluded(path) || !session.app.fs.path_exists(path) {
+        return false;
+    }
+
+    let file_features = session.app.workspace.file_features(SupportsFeatureParams {
+        path: RomePath::new(path),
+        feature: FeaturesBuilder::new()
+            .with_linter()
+            .with_formatter()
+            .with_organize_imports()
+            .build(),
+    });
+    file_features.is_ok_and(|features| {
+        !features.is_not_supported() && !features.is_ignored() && !features.is_protected()
+    })
+}
+
+fn is_configuration_file(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(OsStr::to_str),
+        Some(BIOME_JSON | ROME_JSON)
+    )
+}
+
+/// The directories that change all the time, e.g. because of the cache, and are
+/// never checked
+fn is_excluded(path: &Path) -> bool {
+    path.components().any(|component| {
+        matches!(
+            component,
+            Component::Normal(name) if name == "node_modules" || name == ".git"
+        )
+    })
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::new(io::ErrorKind::Other, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, ExtendedFiles};
+    use crate::cli_options::{cli_options, CliOptions};
+    use crate::execute::{Execution, TraversalMode, WrittenFiles};
+    use crate::CliSession;
+    use biome_console::BufferConsole;
+    use biome_fs::MemoryFileSystem;
+    use biome_service::{workspace, App, Configuration, DynRef, WorkspaceRef};
+    use bpaf::{Args, Parser};
+    use crossbeam::channel::unbounded;
+    use std::path::{Path, PathBuf};
+
+    fn lint_mode() -> TraversalMode {
+        TraversalMode::Lint {
+            fix_file_mode: None,
+            stdin: None,
+        }
+    }
+
+    /// Runs the watch loop on `a.js` and `b.js` with the given changes, and
+    /// returns the messages printed to the console along with the number of
+    /// times the configuration was reloaded. The configuration extends
+    /// `shared.json`.
+    fn run_with_changes(mode: TraversalMode, changes: &[&str]) -> (Vec<String>, usize) {
+        run_execution_with_changes(Execution::new(mode), changes)
+    }
+
+    fn run_execution_with_changes(
+        mut execution: Execution,
+        changes: &[&str],
+    ) -> (Vec<String>, usize) {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("a.js").into(), "debugger;\n".as_bytes());
+        fs.insert(Path::new("b.js").into(), "a( );\n".as_bytes());
+        fs.insert(Path::new("notes.txt").into(), "a();\n".as_bytes());
+        fs.insert(
+            Path::new("node_modules/dep/index.js").into(),
+            "debugger;\n".as_bytes(),
+        );
+        let mut console = BufferConsole::default();
+
+        let (sender, receiver) = unbounded();
+        for path in changes {
+            sender.send(PathBuf::from(path)).unwrap();
+        }
+        drop(sender);
+
+        let cli_options: CliOptions = cli_options()
+            .to_options()
+            .run_inner(Args::from(&[] as &[&str]))
+            .unwrap();
+        let session = CliSession {
+            app: App::new(
+                DynRef::Borrowed(&mut fs),
+                &mut console,
+                WorkspaceRef::Owned(workspace::server()),
+            ),
+        };
+        execution.written_files = Some(WrittenFiles::new(None));
+        let mut extended_files = ExtendedFiles::new(None, None).unwrap();
+        extended_files.update(vec![PathBuf::from("shared.json")]);
+        let mut reloads = 0;
+        run(
+            execution,
+            session,
+            &cli_options,
+            vec!["a.js".into(), "b.js".into()],
+            |_| {
+                reloads += 1;
+                Ok((
+                    Configuration::default(),
+                    None,
+                    vec![PathBuf::from("shared.json")],
+                ))
+            },
+            &mut extended_files,
+            &receiver,
+        );
+
+        let messages = console
+            .out_buffer
+            .iter()
+            .map(|message| {
+                message
+                    .content
+                    .0
+                    .iter()
+                    .map(|node| node.content.as_str())
+                    .collect()
+            })
+            .collect();
+        (messages, reloads)
+    }
+
+    #[test]
+    fn runs_the_changed_files_again() {
+        let (messages, reloads) =
+            run_with_changes(lint_mode(), &["b.js", "missing.js", "notes.txt"]);
+
+        assert_eq!(reloads, 0);
+        assert!(messages.contains(&"Detected changes in 1 file(s).".to_string()));
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked "))
+            .collect();
+        assert_eq!(summaries.len(), 2);
+        assert!(summaries[0].starts_with("Checked 2 file(s)"));
+        assert!(summaries[1].starts_with("Checked 1 file(s)"));
+    }
+
+    #[test]
+    fn reloads_the_configuration() {
+        let (messages, reloads) = run_with_changes(lint_mode(), &["a.js", "biome.json"]);
+
+        assert_eq!(reloads, 1);
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked "))
+            .collect();
+        assert_eq!(summaries.len(), 2);
+        assert!(summaries[1].starts_with("Checked 2 file(s)"));
+    }
+
+    #[test]
+    fn ignores_the_dependencies() {
+        let (messages, _) = run_with_changes(lint_mode(), &["node_modules/dep/index.js"]);
+
+        let summaries = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked "))
+            .count();
+        assert_eq!(summaries, 1);
+    }
+
+    #[test]
+    fn reloads_the_configuration_when_an_extended_file_changes() {
+        let (_, reloads) = run_with_changes(lint_mode(), &["./shared.json"]);
+
+        assert_eq!(reloads, 1);
+    }
+
+    #[test]
+    fn ignores_the_files_written_by_the_run() {
+        let mode = TraversalMode::Format {
+            ignore_errors: false,
+            write: true,
+            source_map: false,
+            stdin: None,
+        };
+        let (messages, _) = run_with_changes(mode, &["a.js", "b.js"]);
+
+        assert!(messages.contains(&"Detected changes in 1 file(s).".to_string()));
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Formatted "))
+            .collect();
+        assert_eq!(summaries.len(), 2);
+        assert!(summaries[0].starts_with("Formatted 2 file(s)"));
+        assert!(summaries[1].starts_with("Formatted 1 file(s)"));
+    }
+
+    #[test]
+    fn ignores_the_cache_written_by_the_run() {
+        let execution = Execution::new(lint_mode())
+            .with_configuration(&MemoryFileSystem::default(), &Configuration::default())
+            .with_cache(false, Some("mycache".to_string()));
+        let (messages, _) = run_execution_with_changes(execution, &["mycache/lint.json"]);
+
+        assert!(!messages
+            .iter()
+            .any(|message| message.starts_with("Detected changes")));
+        let summaries = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked "))
+            .count();
+        assert_eq!(summaries, 1);
+    }
+}
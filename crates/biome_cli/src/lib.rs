@@ -27,10 +27,16 @@ use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
-pub use crate::commands::{biome_command, BiomeCommand};
+use crate::commands::rewrite::RewriteCommandPayload;
+use crate::commands::search::SearchCommandPayload;
+use crate::commands::transform::TransformCommandPayload;
+pub use crate::commands::{biome_command, BiomeCommand, ConfigCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
-pub(crate) use execute::{execute_mode, Execution, TraversalMode};
+pub(crate) use execute::{
+    execute_mode, watch_mode, ConfigurationArguments, Execution, NestedConfigurations,
+    TraversalMode,
+};
 pub use panic::setup_panic_handler;
 pub use reports::{
     formatter::{FormatterReport, FormatterReportFileDetail, FormatterReportSummary},
@@ -90,6 +96,15 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 changed,
                 since,
+                staged,
+                watch,
+                no_cache,
+                cache_dir,
+                profile_rules,
+                profile_rules_output,
+                profile_rules_format,
+                baseline,
+                prune_baseline,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
@@ -104,6 +119,15 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     changed,
                     since,
+                    staged,
+                    watch,
+                    no_cache,
+                    cache_dir,
+                    profile_rules,
+                    profile_rules_output,
+                    profile_rules_format,
+                    baseline,
+                    prune_baseline,
                 },
             ),
             BiomeCommand::Lint {
@@ -117,6 +141,16 @@ impl<'app> CliSession<'app> {
                 files_configuration,
                 changed,
                 since,
+                staged,
+                watch,
+                no_cache,
+                cache_dir,
+                profile_rules,
+                profile_rules_output,
+                profile_rules_format,
+                baseline,
+                prune_baseline,
+                write_baseline,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
@@ -130,6 +164,16 @@ impl<'app> CliSession<'app> {
                     files_configuration,
                     changed,
                     since,
+                    staged,
+                    watch,
+                    no_cache,
+                    cache_dir,
+                    profile_rules,
+                    profile_rules_output,
+                    profile_rules_format,
+                    baseline,
+                    prune_baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Ci {
@@ -141,6 +185,13 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                no_cache,
+                cache_dir,
+                profile_rules,
+                profile_rules_output,
+                profile_rules_format,
+                baseline,
+                prune_baseline,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -152,6 +203,13 @@ impl<'app> CliSession<'app> {
                     cli_options,
                     changed,
                     since,
+                    no_cache,
+                    cache_dir,
+                    profile_rules,
+                    profile_rules_output,
+                    profile_rules_format,
+                    baseline,
+                    prune_baseline,
                 },
             ),
             BiomeCommand::Format {
@@ -159,6 +217,7 @@ impl<'app> CliSession<'app> {
                 formatter_configuration,
                 stdin_file_path,
                 write,
+                source_map,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -167,6 +226,13 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 changed,
                 since,
+                staged,
+                watch,
+                no_cache,
+                cache_dir,
+                profile_rules,
+                profile_rules_output,
+                profile_rules_format,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -174,6 +240,7 @@ impl<'app> CliSession<'app> {
                     formatter_configuration,
                     stdin_file_path,
                     write,
+                    source_map,
                     cli_options,
                     paths,
                     vcs_configuration,
@@ -182,9 +249,75 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     changed,
                     since,
+                    staged,
+                    watch,
+                    no_cache,
+                    cache_dir,
+                    profile_rules,
+                    profile_rules_output,
+                    profile_rules_format,
+                },
+            ),
+            BiomeCommand::Transform {
+                vcs_configuration,
+                files_configuration,
+                stdin_file_path,
+                cli_options,
+                write,
+                source_map,
+                paths,
+            } => commands::transform::transform(
+                self,
+                TransformCommandPayload {
+                    vcs_configuration,
+                    files_configuration,
+                    stdin_file_path,
+                    write,
+                    source_map,
+                    cli_options,
+                    paths,
+                },
+            ),
+            BiomeCommand::Search {
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                pattern,
+                paths,
+            } => commands::search::search(
+                self,
+                SearchCommandPayload {
+                    vcs_configuration,
+                    files_configuration,
+                    cli_options,
+                    pattern,
+                    paths,
+                },
+            ),
+            BiomeCommand::Rewrite {
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                write,
+                pattern,
+                replacement,
+                paths,
+            } => commands::rewrite::rewrite(
+                self,
+                RewriteCommandPayload {
+                    vcs_configuration,
+                    files_configuration,
+                    cli_options,
+                    pattern,
+                    replacement,
+                    write,
+                    paths,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
+            BiomeCommand::Config(ConfigCommand::Print { cli_options, path }) => {
+                commands::config::print(self, cli_options, path)
+            }
             BiomeCommand::Init => commands::init::init(self),
             BiomeCommand::LspProxy(config_path) => commands::daemon::lsp_proxy(config_path),
             BiomeCommand::Migrate(cli_options, write) => {
@@ -0,0 +1,300 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const DEBUGGER: &str = "debugger;\n";
+
+const BASELINE_PATH: &str = "biome-baseline.json";
+
+fn read_baseline(fs: &MemoryFileSystem) -> serde_json::Value {
+    let mut file = fs
+        .open(Path::new(BASELINE_PATH))
+        .expect("the baseline wasn't written");
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn write_baseline(fs: &mut MemoryFileSystem) {
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        &mut BufferConsole::default(),
+        Args::from([("lint"), ("--write-baseline"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn lint_write_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--write-baseline"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let baseline = read_baseline(&fs);
+    let entries = baseline["files"]["file.js"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["rule"], "suspicious/noDebugger");
+    assert_eq!(entries[0]["count"], 1);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_write_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_ignores_the_diagnostics_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    // The lines before the diagnostic change, and a new diagnostic is added
+    fs.insert(
+        Path::new("file.js").into(),
+        "const a = 1;\n\n  debugger;\nif (a) {\n    debugger;\n}\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_ignores_the_diagnostics_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_ignores_the_diagnostics_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn check_reports_the_stale_entries_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    fs.insert(Path::new("file.js").into(), "statement();\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!read_baseline(&fs)["files"]["file.js"].is_null());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_reports_the_stale_entries_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_prune_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    fs.insert(Path::new("file.js").into(), "statement();\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--prune-baseline"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(read_baseline(&fs)["files"]["file.js"].is_null());
+}
+
+#[test]
+fn lint_write_baseline_keeps_the_other_files() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    fs.insert(Path::new("other.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("lint"), ("--write-baseline"), ("other.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    let baseline = read_baseline(&fs);
+    assert!(!baseline["files"]["file.js"].is_null());
+    assert!(!baseline["files"]["other.js"].is_null());
+}
+
+#[test]
+fn lint_write_baseline_next_to_the_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("packages/app/biome.json").into(), "{}".as_bytes());
+    fs.insert(
+        Path::new("packages/app/file.js").into(),
+        DEBUGGER.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(
+            [
+                ("lint"),
+                ("--config-path=packages/app"),
+                ("--write-baseline"),
+                ("packages/app/file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!fs.path_exists(Path::new(BASELINE_PATH)));
+    let mut file = fs
+        .open(Path::new("packages/app/biome-baseline.json"))
+        .expect("the baseline wasn't written next to the configuration");
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    let baseline: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert!(!baseline["files"]["file.js"].is_null());
+}
+
+#[test]
+fn ci_accepts_the_path_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+    let baseline = read_baseline(&fs).to_string();
+    fs.insert(Path::new("other-baseline.json").into(), baseline.as_bytes());
+    fs.remove(Path::new(BASELINE_PATH));
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("ci"), ("--baseline=other-baseline.json"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn check_and_ci_skip_the_baseline_file() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+    write_baseline(&mut fs);
+
+    for command in ["check", "format"] {
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut BufferConsole::default(),
+            Args::from([(command), (".")].as_slice()),
+        );
+        assert!(result.is_ok(), "{command} returned {result:?}");
+    }
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), (".")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_and_ci_skip_the_baseline_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_write_baseline_with_plugin_rules() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-console-log.json"] } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("no-console-log.json").into(),
+        r#"{
+    "rules": [
+        {
+            "name": "noConsoleLog",
+            "pattern": "console.log($$$args)",
+            "message": "Use the logger instead of the console."
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+    fs.insert(Path::new("file.js").into(), "console.log(a);\n".as_bytes());
+    write_baseline(&mut fs);
+
+    let baseline = read_baseline(&fs);
+    let entries = baseline["files"]["file.js"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["rule"], "plugin/noConsoleLog");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_write_baseline_with_plugin_rules",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,183 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const DEBUGGER: &str = "debugger;\n";
+
+const CACHE_PATH: &str = "cache/lint.json";
+
+fn read_cache(fs: &MemoryFileSystem) -> serde_json::Value {
+    let mut file = fs
+        .open(Path::new(CACHE_PATH))
+        .expect("the cache wasn't saved");
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+/// Replaces the stored result of `file.js` with a success, to tell when the
+/// cache is used
+fn forge_cache(fs: &mut MemoryFileSystem) {
+    let mut cache = read_cache(fs);
+    let entry = &mut cache["files"]["file.js"];
+    assert!(entry.is_object(), "the result of the file wasn't stored");
+    entry["messages"] = serde_json::json!([]);
+    entry["status"] = serde_json::json!("success");
+    fs.insert(CACHE_PATH.into(), cache.to_string());
+}
+
+fn run_lint(fs: &mut MemoryFileSystem, console: &mut BufferConsole, args: &[&str]) -> bool {
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        console,
+        Args::from([&["lint"], args, &["file.js"]].concat().as_slice()),
+    );
+    result.is_ok()
+}
+
+#[test]
+fn lint_replays_the_cached_results() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    assert!(!run_lint(&mut fs, &mut console, &["--cache-dir=cache"]));
+    forge_cache(&mut fs);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache-dir=cache"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Path::new(CACHE_PATH));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_replays_the_cached_results",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_replays_the_cached_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    assert!(!run_lint(&mut fs, &mut console, &["--cache-dir=cache"]));
+    let first_run = console.out_buffer.len();
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache-dir=cache"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(console.out_buffer.len(), first_run);
+
+    fs.remove(Path::new(CACHE_PATH));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_replays_the_cached_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_processes_the_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    assert!(!run_lint(&mut fs, &mut console, &["--cache-dir=cache"]));
+    forge_cache(&mut fs);
+    fs.insert(
+        Path::new("file.js").into(),
+        "debugger;\ndebugger;\n".as_bytes(),
+    );
+
+    assert!(!run_lint(
+        &mut fs,
+        &mut BufferConsole::default(),
+        &["--cache-dir=cache"]
+    ));
+}
+
+#[test]
+fn lint_discards_the_cache_when_the_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    assert!(!run_lint(&mut fs, &mut console, &["--cache-dir=cache"]));
+    forge_cache(&mut fs);
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+    );
+
+    assert!(!run_lint(
+        &mut fs,
+        &mut BufferConsole::default(),
+        &["--cache-dir=cache"]
+    ));
+}
+
+#[test]
+fn lint_no_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    assert!(!run_lint(&mut fs, &mut console, &["--cache-dir=cache"]));
+    forge_cache(&mut fs);
+
+    assert!(!run_lint(
+        &mut fs,
+        &mut BufferConsole::default(),
+        &["--cache-dir=cache", "--no-cache"]
+    ));
+}
+
+#[test]
+fn format_write_doesnt_cache_the_written_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement( )".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--cache-dir=cache"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(Path::new("cache/format-write.json")).unwrap();
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    let cache: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert!(cache["files"]["file.js"].is_null());
+}
@@ -266,3 +266,115 @@ fn applies_extended_values_in_current_config() {
         result,
     ));
 }
+
+#[test]
+fn extends_resolves_the_configuration_of_a_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["@company/biome-config/strict"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@company/biome-config/package.json").into(),
+        r#"{
+    "name": "@company/biome-config",
+    "exports": {
+        ".": "./biome.json",
+        "./strict": { "biome": "./configs/strict.json" }
+    }
+}"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@company/biome-config/biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@company/biome-config/configs/strict.json").into(),
+        r#"{ "extends": ["../biome.json"], "javascript": { "formatter": { "semicolons": "asNeeded" } } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_resolves_the_configuration_of_a_package",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_a_missing_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["@company/biome-config"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_a_missing_package",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_an_entry_point_that_isnt_exported() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["biome-config/strict"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/biome-config/package.json").into(),
+        r#"{ "name": "biome-config", "exports": { ".": "./biome.json" } }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_an_entry_point_that_isnt_exported",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,108 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const ASTRO_UNFORMATTED: &str = r#"---
+import Layout from "../layouts/Layout.astro"
+const {title}:{title:string} = Astro.props
+---
+
+<Layout title={title}>
+  <h1>{title}</h1>
+</Layout>
+"#;
+
+const ASTRO_FORMATTED: &str = r#"---
+import Layout from "../layouts/Layout.astro";
+const { title }: { title: string } = Astro.props;
+---
+
+<Layout title={title}>
+  <h1>{title}</h1>
+</Layout>
+"#;
+
+const ASTRO_UNDECLARED: &str = r#"---
+const { title } = Astro.props;
+console.log(title, undeclared);
+---
+
+<h1>{title}</h1>
+"#;
+
+const CONFIG_UNDECLARED_VARIABLES: &str = r#"{
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noUndeclaredVariables": "error"
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn format_astro_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.astro");
+    fs.insert(file_path.into(), ASTRO_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, ASTRO_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_astro_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_astro_files_with_astro_global() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.astro");
+    fs.insert(file_path.into(), ASTRO_UNDECLARED.as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_UNDECLARED_VARIABLES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_astro_files_with_astro_global",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,113 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const SVELTE_UNFORMATTED: &str = r#"<script lang="ts">
+let {name}:{name:string} = $props( )
+let count = $state(0)
+</script>
+
+<button on:click={() => count++}>Hello {name}</button>
+
+<style>
+button   { color: red; }
+</style>
+"#;
+
+const SVELTE_FORMATTED: &str = r#"<script lang="ts">
+let { name }: { name: string } = $props();
+let count = $state(0);
+</script>
+
+<button on:click={() => count++}>Hello {name}</button>
+
+<style>
+button   { color: red; }
+</style>
+"#;
+
+const SVELTE_UNDECLARED: &str = r#"<script>
+const { name } = $props();
+const greeting = $derived(`Hello ${name}`);
+console.log(greeting, undeclared);
+</script>
+
+<h1>{greeting}</h1>
+"#;
+
+const CONFIG_UNDECLARED_VARIABLES: &str = r#"{
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noUndeclaredVariables": "error"
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn format_svelte_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.svelte");
+    fs.insert(file_path.into(), SVELTE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, SVELTE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_svelte_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_files_with_runes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.svelte");
+    fs.insert(file_path.into(), SVELTE_UNDECLARED.as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_UNDECLARED_VARIABLES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_files_with_runes",
+        fs,
+        console,
+        result,
+    ));
+}
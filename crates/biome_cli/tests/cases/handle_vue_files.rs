@@ -0,0 +1,184 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const VUE_UNFORMATTED: &str = r#"<template>
+  <div>{{ message }}</div>
+</template>
+
+<script>
+export default { name : "Greeting" }
+</script>
+
+<script setup lang="ts">
+import {ref} from "vue";
+const message = ref<string>(  "Hello" )
+</script>
+
+<style>
+div   { color: red; }
+</style>
+"#;
+
+const VUE_FORMATTED: &str = r#"<template>
+  <div>{{ message }}</div>
+</template>
+
+<script>
+export default { name: "Greeting" };
+</script>
+
+<script setup lang="ts">
+import { ref } from "vue";
+const message = ref<string>("Hello");
+</script>
+
+<style>
+div   { color: red; }
+</style>
+"#;
+
+const VUE_DEBUGGER: &str = r#"<template>
+  <button @click="onClick">Click</button>
+</template>
+
+<script setup>
+debugger;
+function onClick() {}
+</script>
+"#;
+
+#[test]
+fn format_vue_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.vue");
+    fs.insert(file_path.into(), VUE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, VUE_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_vue_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.vue");
+    fs.insert(file_path.into(), VUE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, VUE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.vue");
+    fs.insert(file_path.into(), VUE_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_apply_unsafe_vue_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.vue");
+    fs.insert(file_path.into(), VUE_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply-unsafe"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"<template>
+  <button @click="onClick">Click</button>
+</template>
+
+<script setup>
+function onClick() {}
+</script>
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_apply_unsafe_vue_files",
+        fs,
+        console,
+        result,
+    ));
+}
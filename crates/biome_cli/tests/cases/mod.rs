@@ -1,12 +1,20 @@
 //! Add here test cases that are not related directly to a command, but to specific
 //! case that affects many commands
 
+mod baseline;
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod diagnostics;
+mod handle_astro_files;
+mod handle_svelte_files;
+mod handle_vue_files;
 mod included_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod plugins;
+mod profile_rules;
 mod protected_files;
+mod staged;
 mod unknown_files;
@@ -0,0 +1,408 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const NO_CONSOLE_LOG: &str = r#"{
+    "rules": [
+        {
+            "name": "noConsoleLog",
+            "pattern": "console.log($$$args)",
+            "message": "Use the logger instead of the console.",
+            "fix": "logger.info($$$args)"
+        }
+    ]
+}"#;
+
+const NO_RED: &str = r#"{
+    "rules": [
+        {
+            "name": "noRed",
+            "language": "css",
+            "pattern": "color: $color",
+            "where": { "$color": { "matches": "^red$" } },
+            "message": "Use the colors of the theme.",
+            "fix": "color: var(--$color)"
+        }
+    ]
+}"#;
+
+#[test]
+fn lint_reports_plugin_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["plugins/no-console-log.json"] } }"#,
+    );
+    fs.insert(
+        Path::new("plugins/no-console-log.json").into(),
+        NO_CONSOLE_LOG,
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(
+        test_file.into(),
+        "console.log(a);\n// biome-ignore lint/plugin/noConsoleLog: test\nconsole.log(b);\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_plugin_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_rules_level() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "plugins": ["no-console-log.json"],
+        "rules": { "plugin": { "noConsoleLog": "warn" } }
+    }
+}"#,
+    );
+    fs.insert(Path::new("no-console-log.json").into(), NO_CONSOLE_LOG);
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(a);\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_rules_level",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_rules_off() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "plugins": ["no-console-log.json"],
+        "rules": { "plugin": { "noConsoleLog": "off" } }
+    }
+}"#,
+    );
+    fs.insert(Path::new("no-console-log.json").into(), NO_CONSOLE_LOG);
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(a);\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_rules_off",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_apply_unsafe_plugin_fix() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-console-log.json"] } }"#,
+    );
+    fs.insert(Path::new("no-console-log.json").into(), NO_CONSOLE_LOG);
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(a, b);\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, test_file, "logger.info(a, b);\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_apply_unsafe_plugin_fix",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reports_css_plugin_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-red.json"] } }"#,
+    );
+    fs.insert(Path::new("no-red.json").into(), NO_RED);
+    let test_file = Path::new("test.css");
+    fs.insert(
+        test_file.into(),
+        "a {\n  color: red;\n  /* biome-ignore lint/plugin/noRed: test */\n  color: red;\n  color: blue;\n}\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_css_plugin_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_apply_unsafe_css_plugin_fix() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-red.json"] } }"#,
+    );
+    fs.insert(Path::new("no-red.json").into(), NO_RED);
+    let test_file = Path::new("test.css");
+    fs.insert(test_file.into(), "a {\n  color: red;\n}\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, test_file, "a {\n  color: var(--red);\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_apply_unsafe_css_plugin_fix",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugins_of_extended_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["config/base.json"] }"#,
+    );
+    fs.insert(
+        Path::new("config/base.json").into(),
+        r#"{ "linter": { "plugins": ["./no-console-log.json"] } }"#,
+    );
+    fs.insert(
+        Path::new("config/no-console-log.json").into(),
+        NO_CONSOLE_LOG,
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(a);\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugins_of_extended_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn invalid_plugin_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-foo.json"] } }"#,
+    );
+    fs.insert(
+        Path::new("no-foo.json").into(),
+        r#"{ "rules": [{ "name": "noFoo", "pattern": "foo(", "message": "Don't call foo." }] }"#,
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "foo();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "invalid_plugin_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn missing_plugin_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "plugins": ["no-foo.json"] } }"#,
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "foo();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "missing_plugin_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+/// A WebAssembly plugin whose rule `noDebugger` reports the debugger
+/// statements, with the message set in its options
+const NO_DEBUGGER_WASM: &str = r#"(module
+    (import "biome_v1" "rule_options" (func $rule_options (param i32 i32) (result i32)))
+    (import "biome_v1" "report" (func $report (param i32 i32)))
+    (memory (export "memory") 1)
+    (data (i32.const 0) "{ \"rules\": [{ \"name\": \"noDebugger\", \"query\": [\"JS_DEBUGGER_STATEMENT\"] }] }")
+    (data (i32.const 1024) "{ \"node\": 0, \"message\": ")
+    (func (export "biome_rules") (result i64) (i64.const 75))
+    (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+        (local $len i32)
+        (local.set $len
+            (i32.add (i32.const 24) (call $rule_options (i32.const 1048) (i32.const 256))))
+        (i32.store8 (i32.add (i32.const 1024) (local.get $len)) (i32.const 125))
+        (call $report (i32.const 1024) (i32.add (local.get $len) (i32.const 1))))
+)"#;
+
+#[test]
+fn lint_reports_wasm_plugin_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "plugin": {
+                "noDebugger": { "level": "warn", "options": "Remove the debugger." }
+            }
+        },
+        "plugins": ["no-debugger.wasm"]
+    }
+}"#,
+    );
+    let plugin_file = Path::new("no-debugger.wasm");
+    fs.insert(
+        plugin_file.into(),
+        wat::parse_str(NO_DEBUGGER_WASM).unwrap(),
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "debugger;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The snapshot only shows the text files
+    fs.remove(plugin_file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_wasm_plugin_rules",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,124 @@
+use crate::run_cli;
+use biome_console::{BufferConsole, LogLevel};
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const DEBUGGER: &str = "debugger;\n";
+
+fn read_json(fs: &MemoryFileSystem, path: &str) -> serde_json::Value {
+    let mut file = fs
+        .open(Path::new(path))
+        .expect("the profile wasn't written");
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn lint_profile_rules_prints_the_timings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--profile-rules"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| format!("{:?}", message.content))
+        .collect();
+    for expected in [
+        "Time spent in the lint rules",
+        "suspicious/noDebugger",
+        "Time spent in the phases of the analyzer",
+        "Time spent on the files",
+    ] {
+        assert!(
+            messages.iter().any(|message| message.contains(expected)),
+            "{expected:?} wasn't printed"
+        );
+    }
+}
+
+#[test]
+fn check_profile_rules_writes_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--profile-rules-output=profile.json"),
+                ("file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let profile = read_json(&fs, "profile.json");
+    let rules = profile["rules"].as_array().unwrap();
+    let debugger = rules
+        .iter()
+        .find(|rule| rule["rule"] == "suspicious/noDebugger")
+        .expect("the rule wasn't profiled");
+    assert!(debugger["matches"].as_u64().unwrap() >= 1);
+
+    let phases = profile["phases"].as_array().unwrap();
+    assert!(phases.iter().any(|phase| phase["phase"] == "syntax"));
+
+    let files = profile["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["path"], "file.js");
+    for step in ["parse", "lint", "format", "organizeImports"] {
+        assert!(files[0][step].is_f64(), "the {step} step wasn't profiled");
+    }
+}
+
+#[test]
+fn format_profile_rules_writes_chrome_trace() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(Path::new("file.js").into(), DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--profile-rules-output=trace.json"),
+                ("--profile-rules-format=chrome-trace"),
+                ("file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let trace = read_json(&fs, "trace.json");
+    let events = trace["traceEvents"].as_array().unwrap();
+    for step in ["parse", "format"] {
+        let event = events
+            .iter()
+            .find(|event| event["name"] == step)
+            .unwrap_or_else(|| panic!("the {step} step wasn't traced"));
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["args"]["path"], "file.js");
+    }
+}
@@ -0,0 +1,184 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+fn assert_staged_contents(fs: &MemoryFileSystem, path: &Path, expected_content: &str) {
+    let content = fs.staged_content(path).expect("the file isn't staged");
+    assert_eq!(
+        String::from_utf8(content).unwrap(),
+        expected_content,
+        "staged file {} doesn't match the expected content (right)",
+        path.display()
+    );
+}
+
+#[test]
+fn lint_staged_reads_the_index() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement();\n".as_bytes());
+    fs.insert_staged(file_path.into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("other.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_staged_reads_the_index",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_staged_file_deleted_from_the_working_tree() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert_staged(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_staged_file_deleted_from_the_working_tree",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_write_staged_keeps_the_unstaged_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement( )\nunstaged( )\n".as_bytes());
+    fs.insert_staged(file_path.into(), "statement( )\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_staged_contents(&fs, file_path, "statement();\n");
+    assert_file_contents(&fs, file_path, "statement();\nunstaged( )\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_write_staged_keeps_the_unstaged_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_write_staged_reports_the_conflicts() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement( ) // unstaged\n".as_bytes());
+    fs.insert_staged(file_path.into(), "statement( )\n".as_bytes());
+    let other_path = Path::new("other.js");
+    fs.insert(other_path.into(), "other( )\n".as_bytes());
+    fs.insert_staged(other_path.into(), "other( )\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_staged_contents(&fs, file_path, "statement( )\n");
+    assert_file_contents(&fs, file_path, "statement( ) // unstaged\n");
+    assert_staged_contents(&fs, other_path, "other();\n");
+    assert_file_contents(&fs, other_path, "other();\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_write_staged_reports_the_conflicts",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_apply_staged_doesnt_write_overlapping_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement( ) // unstaged\n".as_bytes());
+    fs.insert_staged(file_path.into(), "statement( )\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--apply"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_staged_contents(&fs, file_path, "statement( )\n");
+    assert_file_contents(&fs, file_path, "statement( ) // unstaged\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_apply_staged_doesnt_write_overlapping_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn staged_and_changed_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert_staged(Path::new("file.js").into(), "statement();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--staged"), ("--changed"), ("--since=main")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "staged_and_changed_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2729,3 +2729,115 @@ fn use_literal_keys_should_emit_correct_ast_issue_266() {
         result,
     ));
 }
+
+#[test]
+fn apply_unsafe_fix_configured_as_safe() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "style": {
+            "useTemplate": {
+                "level": "error",
+                "fix": "safe"
+            }
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("fix.js");
+    fs.insert(
+        file_path.into(),
+        "const name = \"world\";\nconsole.log(\"Hello \" + name);\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "const name = \"world\";\nconsole.log(`Hello ${name}`);\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_unsafe_fix_configured_as_safe",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_safe_fix_configured_as_none() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "style": {
+            "useConst": {
+                "level": "error",
+                "fix": "none"
+            }
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("fix.js");
+    let source = "let a = 4;\nconsole.log(a);\n";
+    fs.insert(file_path.into(), source.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply-unsafe"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, source);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_safe_fix_configured_as_none",
+        fs,
+        console,
+        result,
+    ));
+}
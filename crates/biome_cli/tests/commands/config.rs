@@ -0,0 +1,226 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn config_print_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_default_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "style.css"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_default_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_sources_of_the_values() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "formatter": { "indentStyle": "space" },
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "suspicious": { "noDebugger": "warn" },
+            "complexity": {
+                "noExcessiveCognitiveComplexity": {
+                    "level": "error",
+                    "options": { "maxAllowedComplexity": 10 }
+                }
+            }
+        }
+    },
+    "overrides": [
+        {
+            "include": ["src/legacy/**"],
+            "javascript": { "formatter": { "quoteStyle": "single" } },
+            "linter": {
+                "rules": { "recommended": false, "suspicious": { "noDoubleEquals": "error" } }
+            }
+        },
+        {
+            "include": ["src/**"],
+            "formatter": { "lineWidth": 100 }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "src/legacy/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_sources_of_the_values",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_values_of_the_extended_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "extends": ["./shared.json"],
+    "javascript": { "formatter": { "quoteStyle": "single" } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("shared.json").into(),
+        r#"{
+    "linter": { "enabled": false },
+    "javascript": { "formatter": { "semicolons": "asNeeded", "quoteStyle": "double" } }
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "index.ts"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_values_of_the_extended_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_configuration_of_a_nested_directory_as_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "formatter": { "lineWidth": 120 },
+    "linter": { "enabled": false },
+    "json": { "parser": { "allowComments": true } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/biome.json").into(),
+        r#"{
+    "root": false,
+    "formatter": { "indentWidth": 4 },
+    "json": { "parser": { "allowTrailingCommas": true } }
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--json", "packages/app/data.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_configuration_of_a_nested_directory_as_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_extended_files_of_an_inheriting_nested_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "lineWidth": 120 } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/biome.json").into(),
+        r#"{
+    "root": false,
+    "extends": ["./shared.json"],
+    "formatter": { "indentWidth": 4 }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/shared.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "packages/app/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_extended_files_of_an_inheriting_nested_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
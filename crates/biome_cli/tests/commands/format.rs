@@ -2444,3 +2444,170 @@ const a = {
         result,
     ));
 }
+
+#[test]
+fn applies_the_configuration_of_nested_directories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#.as_bytes(),
+    );
+    // inherits the configuration of the workspace
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "root": false, "formatter": { "indentWidth": 4 } }"#.as_bytes(),
+    );
+    // replaces the configuration of the workspace
+    fs.insert(
+        Path::new("packages/b/biome.json").into(),
+        r#"{ "formatter": { "lineWidth": 120 } }"#.as_bytes(),
+    );
+
+    let code = "if (a) {\nb;\n}\n";
+    let files = [
+        "index.js",
+        "packages/a/index.js",
+        "packages/a/src/index.js",
+        "packages/b/index.js",
+        "packages/c/index.js",
+    ];
+    for file in files {
+        fs.insert(Path::new(file).into(), code.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--write", "index.js", "packages"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, Path::new("index.js"), "if (a) {\n  b;\n}\n");
+    assert_file_contents(
+        &fs,
+        Path::new("packages/a/index.js"),
+        "if (a) {\n    b;\n}\n",
+    );
+    assert_file_contents(
+        &fs,
+        Path::new("packages/a/src/index.js"),
+        "if (a) {\n    b;\n}\n",
+    );
+    assert_file_contents(&fs, Path::new("packages/b/index.js"), "if (a) {\n\tb;\n}\n");
+    assert_file_contents(&fs, Path::new("packages/c/index.js"), "if (a) {\n  b;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_the_configuration_of_nested_directories",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn the_overrides_of_the_command_apply_to_nested_directories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4 } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/a/index.js").into(),
+        "if (a) {\nb;\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--write", "--indent-width=8", "packages"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("packages/a/index.js"),
+        "if (a) {\n        b;\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "the_overrides_of_the_command_apply_to_nested_directories",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_write_source_map() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "function f(a,b){return a+b}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--source-map"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "function f(a, b) {\n\treturn a + b;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_write_source_map",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_source_map_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "function f(a,b){return a+b}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--source-map"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_source_map_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
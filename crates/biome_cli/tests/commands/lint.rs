@@ -1795,6 +1795,62 @@ fn ignore_vcs_os_independent_parse() {
     ));
 }
 
+#[test]
+fn ignore_vcs_nested_ignore_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), rome_json.as_bytes());
+
+    fs.insert(Path::new(".gitignore").into(), "dist\n".as_bytes());
+    fs.insert(
+        Path::new("packages/a/.gitignore").into(),
+        "generated.js\n!dist\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/b/.ignore").into(),
+        "*.test.js\n".as_bytes(),
+    );
+
+    let code = r#"debugger;"#;
+    // ignored files
+    fs.insert(Path::new("dist/index.js").into(), code.as_bytes());
+    fs.insert(Path::new("packages/a/generated.js").into(), code.as_bytes());
+    fs.insert(
+        Path::new("packages/b/index.test.js").into(),
+        code.as_bytes(),
+    );
+    // linted files
+    fs.insert(
+        Path::new("packages/a/dist/index.js").into(),
+        code.as_bytes(),
+    );
+    fs.insert(Path::new("packages/b/generated.js").into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("dist"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_nested_ignore_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn ignore_vcs_ignored_file_via_cli() {
     let mut fs = MemoryFileSystem::default();
@@ -2607,3 +2663,271 @@ fn should_not_process_ignored_file_even_if_its_changed() {
         result,
     ));
 }
+
+#[test]
+fn should_error_if_watch_is_used_with_stdin_file_path() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    console.in_buffer.push("debugger;".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--watch", "--stdin-file-path=file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_watch_is_used_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_nested_configurations() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+        "root": false,
+        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+    }"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/b/biome.json").into(),
+        r#"{ "linter": { "unknownKey": true } }"#.as_bytes(),
+    );
+
+    let code = "debugger;\n";
+    fs.insert(Path::new("index.js").into(), code.as_bytes());
+    fs.insert(Path::new("packages/a/index.js").into(), code.as_bytes());
+    fs.insert(Path::new("packages/b/index.js").into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "index.js", "packages"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_nested_configurations",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_types_imported_from_declaration_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noFloatingPromises": "error" } } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/api.d.ts").into(),
+        r#"export declare function save(): Promise<void>;
+export declare function count(): number;
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/main.ts").into(),
+        r#"import { count, save } from "./api";
+save();
+count();
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_types_imported_from_declaration_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_types_reexported_from_declaration_files_outside_the_inputs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noFloatingPromises": "error" } } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("types/index.d.ts").into(),
+        r#"export { save } from "./api";
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("types/api.d.ts").into(),
+        r#"export declare function save(): Promise<void>;
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/main.ts").into(),
+        r#"import { save } from "../types";
+save();
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src/main.ts"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_types_reexported_from_declaration_files_outside_the_inputs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_switch_over_enum_imported_from_declaration_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "useExhaustiveSwitchCases": "error" } } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/types.d.ts").into(),
+        r#"export declare enum Direction {
+    Up,
+    Down,
+}
+export type Status = "active" | "disabled";
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/main.ts").into(),
+        r#"import { Direction, type Status } from "./types";
+function move(direction: Direction) {
+    switch (direction) {
+        case Direction.Up:
+            break;
+    }
+}
+function label(status: Status) {
+    switch (status) {
+        case "active":
+        case "disabled":
+            break;
+    }
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_switch_over_enum_imported_from_declaration_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_switch_over_enum_imported_from_typescript_modules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "useExhaustiveSwitchCases": "error" } } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/status.ts").into(),
+        r#"export enum Status {
+    Active,
+    Inactive,
+}
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/main.ts").into(),
+        r#"import { Status } from "./status";
+function label(status: Status) {
+    switch (status) {
+        case Status.Active:
+            return "Active";
+    }
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src/main.ts"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_switch_over_enum_imported_from_typescript_modules",
+        fs,
+        console,
+        result,
+    ));
+}
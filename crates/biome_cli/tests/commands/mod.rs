@@ -1,5 +1,6 @@
 mod check;
 mod ci;
+mod config;
 mod explain;
 mod format;
 mod init;
@@ -7,4 +8,7 @@ mod lint;
 mod lsp_proxy;
 mod migrate;
 mod rage;
+mod rewrite;
+mod search;
+mod transform;
 mod version;
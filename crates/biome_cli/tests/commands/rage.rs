@@ -83,6 +83,48 @@ fn with_configuration() {
     ));
 }
 
+#[test]
+fn with_extended_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").to_path_buf(),
+        r#"{ "extends": ["shared.json", "biome-config"] }"#,
+    );
+    fs.insert(
+        Path::new("shared.json").to_path_buf(),
+        r#"{ "formatter": { "enabled": false } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/biome-config/package.json").to_path_buf(),
+        r#"{ "name": "biome-config" }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/biome-config/biome.json").to_path_buf(),
+        r#"{ "extends": ["./base.json"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/biome-config/base.json").to_path_buf(),
+        r#"{ "linter": { "enabled": false } }"#,
+    );
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rage")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_rage_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_extended_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn with_malformed_configuration() {
     let mut fs = MemoryFileSystem::default();
@@ -0,0 +1,240 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CODE: &str = r#"import { Button, Kind } from "./button";
+
+export function App({ onClick }) {
+    return (
+        <div>
+            <Button kind={Kind.Primary} onClick={onClick} />
+            <Button kind={Kind.Link} disabled />
+        </div>
+    );
+}
+"#;
+
+const REWRITTEN_CODE: &str = r#"import { Button, Kind } from "./button";
+
+export function App({ onClick }) {
+    return (
+        <div>
+            <Button variant={Kind.Primary} onClick={onClick} />
+            <Button variant={Kind.Link} disabled />
+        </div>
+    );
+}
+"#;
+
+#[test]
+fn rewrite_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rewrite"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrite_prints_the_diffs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("app.jsx");
+    fs.insert(file_path.into(), CODE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rewrite"),
+                "<Button kind={$kind} $$$props />",
+                "<Button variant={$kind} $$$props />",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, CODE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_prints_the_diffs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrite_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("app.jsx");
+    fs.insert(file_path.into(), CODE.as_bytes());
+    let helpers_path = Path::new("helpers.js");
+    fs.insert(
+        helpers_path.into(),
+        "const items = list.concat(item);\nconst other = list.concat(a, b);\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rewrite"),
+                ("--write"),
+                "$list.concat($item)",
+                "[...$list, $item]",
+                file_path.as_os_str().to_str().unwrap(),
+                helpers_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, CODE);
+    assert_file_contents(
+        &fs,
+        helpers_path,
+        "const items = [...list, item];\nconst other = list.concat(a, b);\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrite_write_jsx_attributes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("app.jsx");
+    fs.insert(file_path.into(), CODE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rewrite"),
+                ("--write"),
+                "<Button kind={$kind} $$$props />",
+                "<Button variant={$kind} $$$props />",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITTEN_CODE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_write_jsx_attributes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrite_write_parenthesizes_the_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "x * f(y);\nf(a + b);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rewrite"),
+                ("--write"),
+                "f($a)",
+                "$a * 2",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "x * (y * 2);\n(a + b) * 2;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_write_parenthesizes_the_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrite_reports_an_unknown_metavariable() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "foo(a);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rewrite"),
+                "foo($a)",
+                "bar($b)",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrite_reports_an_unknown_metavariable",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,139 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn search_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_prints_the_matches() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("src/app.jsx").into(),
+        r#"function App() {
+    useEffect(() => {
+        subscribe();
+    }, []);
+    useEffect(() => {}, [props.id]);
+    return <div />;
+}
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/hooks.ts").into(),
+        "useEffect(load, [ ]);\nuseLayoutEffect(load, []);\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/style.css").into(),
+        "a { color: red; }\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), "useEffect($$$, [])", "src"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_matches",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_matches_the_same_code_for_a_metavariable() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        "if (a.b === a.b) {}\nif (a.b === a.c) {}\nconst x = y as any;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                "$value === $value",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_matches_the_same_code_for_a_metavariable",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_reports_an_invalid_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "foo();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                "foo(; bar()",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_reports_an_invalid_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
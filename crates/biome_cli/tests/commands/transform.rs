@@ -0,0 +1,277 @@
+use crate::run_cli;
+use crate::snap_test::{
+    assert_cli_snapshot, assert_file_contents, markup_to_string, SnapshotPayload,
+};
+use biome_console::{markup, BufferConsole};
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const TS_CODE: &str = r#"import type { A } from "./a";
+interface B {}
+enum C {
+	D,
+}
+export function e(a: A, b?: B): C {
+	return C.D;
+}
+"#;
+
+const JS_CODE: &str = r#"var C;
+(function (C) {
+	C[(C["D"] = 0)] = "D";
+})(C || (C = {}));
+export function e(a, b) {
+	return C.D;
+}
+"#;
+
+#[test]
+fn transform_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_prints_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), TS_CODE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, TS_CODE);
+    assert!(fs.open(Path::new("file.js")).is_err());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_prints_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), TS_CODE.as_bytes());
+    let declaration_path = Path::new("file.d.ts");
+    fs.insert(
+        declaration_path.into(),
+        "declare const a: string;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+                declaration_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, TS_CODE);
+    assert_file_contents(&fs, Path::new("file.js"), JS_CODE);
+    assert!(fs.open(Path::new("file.d.js")).is_err());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_write_jsx() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("component.tsx");
+    fs.insert(
+        file_path.into(),
+        "export const A = (props: { a: string }) => <div>{props.a}</div>;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("component.js"),
+        r#"import { jsx as _jsx } from "react/jsx-runtime";
+export const A = (props) => _jsx("div", { children: props.a });
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_write_jsx",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_write_source_map() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        "const a: number = 1;\nexport function f(b: string): string { return b; }\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--write"),
+                ("--source-map"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("file.js"),
+        r#"const a = 1;
+export function f(b) {
+	return b;
+}
+//# sourceMappingURL=file.js.map
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_write_source_map",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_source_map_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), TS_CODE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--source-map"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_source_map_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_stdin_successfully() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(TS_CODE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), ("--stdin-file-path"), ("mock.ts")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, JS_CODE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_stdin_successfully",
+        fs,
+        console,
+        result,
+    ));
+}
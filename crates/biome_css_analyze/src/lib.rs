@@ -0,0 +1,112 @@
+//! The CSS linter. There are no built-in CSS rules yet: it runs the rules of
+//! the plugins that match CSS code.
+
+mod suppression_action;
+
+use crate::suppression_action::apply_suppression_comment;
+use biome_analyze::{
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerPlugin, AnalyzerSignal,
+    ControlFlow, InspectMatcher, LanguageRoot, MetadataRegistry, RuleRegistry, SuppressionKind,
+    PLUGIN_GROUP,
+};
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::{category, Error as DiagnosticError};
+// The suppression comments of CSS have the same syntax as the JavaScript ones
+use biome_js_syntax::suppression::{parse_suppression_comment, SuppressionDiagnostic};
+use std::sync::Arc;
+
+/// Run the rules defined by `plugins` on the provided `root`: this process will
+/// use the given `filter` to selectively restrict analysis to specific rules /
+/// a specific source range, then call `emit_signal` when a rule emits a
+/// diagnostic or action
+pub fn analyze_with_plugins<'a, F, B>(
+    root: &LanguageRoot<CssLanguage>,
+    filter: AnalysisFilter,
+    options: &'a AnalyzerOptions,
+    plugins: &[Arc<dyn AnalyzerPlugin<CssLanguage>>],
+    mut emit_signal: F,
+) -> (Option<B>, Vec<DiagnosticError>)
+where
+    F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    fn parse_linter_suppression_comment(
+        text: &str,
+    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+        let mut result = Vec::new();
+
+        for comment in parse_suppression_comment(text) {
+            let categories = match comment {
+                Ok(comment) => {
+                    if comment.is_legacy {
+                        result.push(Ok(SuppressionKind::Deprecated));
+                    }
+                    comment.categories
+                }
+                Err(err) => {
+                    result.push(Err(err));
+                    continue;
+                }
+            };
+
+            for (key, value) in categories {
+                if key == category!("lint") {
+                    if let Some(value) = value {
+                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                    } else {
+                        result.push(Ok(SuppressionKind::Everything));
+                    }
+                } else if let Some(rule) = value.filter(|_| key == category!("lint/plugin")) {
+                    result.push(Ok(SuppressionKind::Plugin(rule)));
+                } else {
+                    let category = key.name();
+                    if let Some(rule) = category.strip_prefix("lint/") {
+                        result.push(Ok(SuppressionKind::Rule(rule)));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    let mut registry = RuleRegistry::builder(&filter, root);
+    for plugin in plugins {
+        registry.record_plugin(plugin.clone());
+    }
+
+    let (registry, services, diagnostics, visitors) = registry.build();
+
+    // Bail if we can't parse a rule option
+    if !diagnostics.is_empty() {
+        return (None, diagnostics);
+    }
+
+    // The rules of the plugins must be known to resolve their suppression comments
+    let mut metadata = MetadataRegistry::default();
+    for plugin in plugins {
+        metadata.insert_rule(PLUGIN_GROUP, plugin.name());
+    }
+
+    let mut analyzer = Analyzer::new(
+        &metadata,
+        InspectMatcher::new(registry, |_| {}),
+        parse_linter_suppression_comment,
+        apply_suppression_comment,
+        &mut emit_signal,
+    );
+
+    for ((phase, _), visitor) in visitors {
+        analyzer.add_visitor(phase, visitor);
+    }
+
+    (
+        analyzer.run(AnalyzerContext {
+            root: root.clone(),
+            range: filter.range,
+            services,
+            options,
+        }),
+        diagnostics,
+    )
+}
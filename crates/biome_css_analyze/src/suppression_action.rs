@@ -0,0 +1,57 @@
+use biome_analyze::SuppressionCommentEmitterPayload;
+use biome_css_syntax::{CssLanguage, CssSyntaxToken};
+use biome_rowan::{TokenAtOffset, TriviaPieceKind};
+
+/// Adds the suppression comment on the line before the diagnostic. The
+/// suppression comments are line based, so the comment is attached to the
+/// first token of the line of the diagnostic, with the same indentation.
+pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayload<CssLanguage>) {
+    let SuppressionCommentEmitterPayload {
+        token_offset,
+        mutation,
+        suppression_text,
+        ..
+    } = payload;
+
+    let token = match token_offset {
+        TokenAtOffset::None => return,
+        TokenAtOffset::Single(token) | TokenAtOffset::Between(_, token) => token,
+    };
+    let token = first_token_of_line(token);
+
+    // The whitespace after the last newline of the leading trivia
+    let mut indentation = String::new();
+    for piece in token.leading_trivia().pieces() {
+        if piece.is_newline() {
+            indentation.clear();
+        } else if piece.is_whitespace() {
+            indentation.push_str(piece.text());
+        }
+    }
+
+    let comment = format!("/* {suppression_text}: <explanation> */");
+    let new_token = token.with_leading_trivia([
+        (TriviaPieceKind::SingleLineComment, comment.as_str()),
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation.as_str()),
+    ]);
+    mutation.replace_token_transfer_trivia(token, new_token);
+}
+
+/// Returns the first token of the line of `token`: the previous tokens are
+/// visited until one has a newline in its leading trivia
+fn first_token_of_line(mut token: CssSyntaxToken) -> CssSyntaxToken {
+    loop {
+        if token
+            .leading_trivia()
+            .pieces()
+            .any(|piece| piece.is_newline())
+        {
+            return token;
+        }
+        match token.prev_token() {
+            Some(prev_token) => token = prev_token,
+            None => return token,
+        }
+    }
+}
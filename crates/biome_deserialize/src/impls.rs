@@ -678,3 +678,93 @@ impl<K: Hash + Eq + Deserializable, V: Deserializable, S: Default + BuildHasher>
         value.deserialize(Visitor(PhantomData), name, diagnostics)
     }
 }
+
+impl Deserializable for serde_json::Value {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = serde_json::Value;
+            const EXPECTED_TYPE: VisitableType = VisitableType::all();
+            fn visit_null(
+                self,
+                _range: TextRange,
+                _name: &str,
+                _diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                Some(serde_json::Value::Null)
+            }
+            fn visit_bool(
+                self,
+                value: bool,
+                _range: TextRange,
+                _name: &str,
+                _diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                Some(serde_json::Value::Bool(value))
+            }
+            fn visit_number(
+                self,
+                value: TextNumber,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                if let Ok(value) = value.parse::<serde_json::Number>() {
+                    return Some(serde_json::Value::Number(value));
+                }
+                let diagnostic =
+                    DeserializationDiagnostic::new("The number should be a valid JSON number")
+                        .with_range(range);
+                diagnostics.push(diagnostic);
+                None
+            }
+            fn visit_str(
+                self,
+                value: Text,
+                _range: TextRange,
+                _name: &str,
+                _diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                Some(serde_json::Value::String(value.text().to_string()))
+            }
+            fn visit_array(
+                self,
+                items: impl Iterator<Item = Option<impl DeserializableValue>>,
+                _range: TextRange,
+                name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                Some(serde_json::Value::Array(
+                    items
+                        .flatten()
+                        .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+                        .collect(),
+                ))
+            }
+            fn visit_map(
+                self,
+                members: impl Iterator<
+                    Item = Option<(impl DeserializableValue, impl DeserializableValue)>,
+                >,
+                _range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let mut result = serde_json::Map::new();
+                for (key, value) in members.flatten() {
+                    let key = String::deserialize(&key, "", diagnostics);
+                    let value = Deserializable::deserialize(&value, "", diagnostics);
+                    if let (Some(key), Some(value)) = (key, value) {
+                        result.insert(key, value);
+                    }
+                }
+                Some(serde_json::Value::Object(result))
+            }
+        }
+        value.deserialize(Visitor, name, diagnostics)
+    }
+}
@@ -689,4 +689,18 @@ mod tests {
         assert!(!diagnostics.is_empty());
         assert!(deserialized.is_none());
     }
+
+    #[test]
+    fn test_json_value() {
+        let source = r#"{ "a": [null, true, 1.5, "b"], "c": { "d": -2 } }"#;
+        let Deserialized {
+            deserialized,
+            diagnostics,
+        } = deserialize_from_json_str::<serde_json::Value>(source, JsonParserOptions::default());
+        assert!(diagnostics.is_empty());
+        assert_eq!(
+            deserialized.unwrap(),
+            serde_json::json!({ "a": [null, true, 1.5, "b"], "c": { "d": -2 } })
+        );
+    }
 }
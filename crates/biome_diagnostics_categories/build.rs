@@ -59,7 +59,7 @@ pub fn main() -> io::Result<()> {
             fn from_str(name: &str) -> Result<Self, ()> {
                 match name {
                     #( #parse_arms )*
-                    _ => Err(()),
+                    _ => parse_plugin_rule(name),
                 }
             }
         }
@@ -103,6 +103,7 @@ define_categories! {
     "lint/nursery/noDefaultExport": "https://biomejs.dev/linter/rules/no-default-export",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
     "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/linter/rules/no-empty-block-statements",
+    "lint/nursery/noFloatingPromises": "https://biomejs.dev/linter/rules/no-floating-promises",
     "lint/nursery/noGlobalEval": "https://biomejs.dev/linter/rules/no-global-eval",
     "lint/nursery/noGlobalAssign": "https://biomejs.dev/linter/rules/no-global-assign",
     "lint/nursery/noImplicitAnyLet": "https://biomejs.dev/linter/rules/no-implicit-any-let",
@@ -117,6 +118,7 @@ define_categories! {
     "lint/nursery/noUselessTernary": "https://biomejs.dev/linter/rules/no-useless-ternary",
     "lint/nursery/useAwait": "https://biomejs.dev/linter/rules/use-await",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useExhaustiveSwitchCases": "https://biomejs.dev/linter/rules/use-exhaustive-switch-cases",
     "lint/nursery/useExportType": "https://biomejs.dev/linter/rules/use-export-type",
     "lint/nursery/useFilenamingConvention": "https://biomejs.dev/linter/rules/use-filenaming-convention",
     "lint/nursery/useForOf": "https://biomejs.dev/linter/rules/use-for-of",
@@ -212,12 +214,17 @@ define_categories! {
     ;
     // General categories
     "files/missingHandler",
+    "files/unstagedChanges",
     "format",
     "check",
     "ci",
     "configuration",
     "organizeImports",
     "migrate",
+    "transform",
+    "search",
+    "rewrite",
+    "plugin",
     "deserialize",
     "project",
     "internalError/io",
@@ -236,6 +243,7 @@ define_categories! {
     "lint/correctness",
     "lint/nursery",
     "lint/performance",
+    "lint/plugin",
     "lint/security",
     "lint/style",
     "lint/suspicious",
@@ -1,8 +1,12 @@
 use std::{
     hash::{Hash, Hasher},
     str::FromStr,
+    sync::Mutex,
 };
 
+/// Prefix of the categories of the rules defined by plugins
+const PLUGIN_RULE_PREFIX: &str = "lint/plugin/";
+
 /// Metadata for a diagnostic category
 ///
 /// This type cannot be instantiated outside of the `biome_diagnostics_categories`
@@ -27,6 +31,52 @@ impl Category {
     pub fn link(&self) -> Option<&'static str> {
         self.link
     }
+
+    /// Return whether this is the category of a rule defined by a plugin
+    pub fn is_plugin_rule(&self) -> bool {
+        self.name.starts_with(PLUGIN_RULE_PREFIX)
+    }
+
+    /// Return the `lint/plugin/<name>` category of the plugin rule `name`
+    ///
+    /// The plugin rules are only known at runtime, so their categories are
+    /// registered the first time they're requested and live until the end
+    /// of the process
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biome_diagnostics_categories::Category;
+    /// let category = Category::plugin_rule("noFoo");
+    /// assert_eq!(category.name(), "lint/plugin/noFoo");
+    /// assert_eq!("lint/plugin/noFoo".parse::<&'static Category>(), Ok(category));
+    /// ```
+    pub fn plugin_rule(name: &str) -> &'static Category {
+        static PLUGIN_RULES: Mutex<Vec<&'static Category>> = Mutex::new(Vec::new());
+
+        let name = format!("{PLUGIN_RULE_PREFIX}{name}");
+        let mut categories = PLUGIN_RULES
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        if let Some(category) = categories.iter().find(|category| category.name == name) {
+            return category;
+        }
+
+        let category: &'static Category = Box::leak(Box::new(Category {
+            name: Box::leak(name.into_boxed_str()),
+            link: None,
+        }));
+        categories.push(category);
+        category
+    }
+}
+
+/// Parses the category of a plugin rule, which isn't in the static registry
+fn parse_plugin_rule(name: &str) -> Result<&'static Category, ()> {
+    match name.strip_prefix(PLUGIN_RULE_PREFIX) {
+        Some(rule) if !rule.is_empty() && !rule.contains('/') => Ok(Category::plugin_rule(rule)),
+        _ => Err(()),
+    }
 }
 
 impl Eq for Category {}
@@ -37,6 +37,7 @@ pub mod printed_tokens;
 pub mod printer;
 pub mod separated;
 mod source_map;
+mod source_map_v3;
 pub mod token;
 pub mod trivia;
 mod verbatim;
@@ -72,6 +73,7 @@ use biome_rowan::{
 pub use format_element::{normalize_newlines, FormatElement, LINE_TERMINATORS};
 pub use group_id::GroupId;
 pub use source_map::{TransformSourceMap, TransformSourceMapBuilder};
+pub use source_map_v3::SourceMapV3;
 use std::marker::PhantomData;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -652,6 +654,11 @@ pub struct Printed {
     range: Option<TextRange>,
     sourcemap: Vec<SourceMarker>,
     verbatim_ranges: Vec<TextRange>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    source_map_v3: Option<SourceMapV3>,
 }
 
 impl Printed {
@@ -666,6 +673,7 @@ impl Printed {
             range,
             sourcemap,
             verbatim_ranges: verbatim_source,
+            source_map_v3: None,
         }
     }
 
@@ -676,6 +684,7 @@ impl Printed {
             range: None,
             sourcemap: Vec::new(),
             verbatim_ranges: Vec::new(),
+            source_map_v3: None,
         }
     }
 
@@ -704,6 +713,28 @@ impl Printed {
         std::mem::take(&mut self.sourcemap)
     }
 
+    /// Computes the [SourceMapV3] between `source`, the code of the formatted
+    /// tree, and the printed code, and attaches it to the result.
+    pub fn with_source_map_v3(mut self, source: &str, source_name: &str) -> Self {
+        self.source_map_v3 = Some(SourceMapV3::from_markers(
+            self.sourcemap.iter().copied(),
+            source,
+            source_name,
+            &self.code,
+        ));
+        self
+    }
+
+    /// Returns the [SourceMapV3] attached with [Printed::with_source_map_v3]
+    pub fn source_map_v3(&self) -> Option<&SourceMapV3> {
+        self.source_map_v3.as_ref()
+    }
+
+    /// Takes the [SourceMapV3] attached with [Printed::with_source_map_v3]
+    pub fn take_source_map_v3(&mut self) -> Option<SourceMapV3> {
+        self.source_map_v3.take()
+    }
+
     /// Access the resulting code, borrowing the result
     pub fn as_code(&self) -> &str {
         &self.code
@@ -0,0 +1,222 @@
+use crate::{SourceMarker, TextSize};
+
+/// A source map in the [revision 3 format](https://sourcemaps.info/spec.html),
+/// mapping the positions of the printed code back to the positions in the source code.
+///
+/// The columns are counted in UTF-16 code units, like the runtimes that consume the source maps do.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct SourceMapV3 {
+    /// The version of the format, always `3`
+    pub version: u8,
+    /// The name of the generated file
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub file: Option<String>,
+    /// The names of the original files. The printed code always has a single source.
+    pub sources: Vec<String>,
+    /// The content of the original files
+    pub sources_content: Vec<String>,
+    /// The symbol names used by the mappings, which are never emitted
+    pub names: Vec<String>,
+    /// The mappings between the printed code and the source code, encoded as
+    /// Base64 VLQ segments
+    pub mappings: String,
+}
+
+impl SourceMapV3 {
+    /// Creates a source map from a list of markers between `source`, the code
+    /// that was printed, and `code`, the printed code.
+    ///
+    /// The markers must be sorted by their position in the printed code.
+    pub fn from_markers(
+        markers: impl IntoIterator<Item = SourceMarker>,
+        source: &str,
+        source_name: &str,
+        code: &str,
+    ) -> Self {
+        let source_lines = LineIndex::new(source);
+        let code_lines = LineIndex::new(code);
+
+        let mut mappings = String::new();
+        let mut line = 0;
+        // The columns are relative to the previous segment of the line, the
+        // other fields to the previous segment of the file
+        let mut previous_column = 0;
+        let mut previous_source_line = 0;
+        let mut previous_source_column = 0;
+
+        let markers: Vec<_> = markers.into_iter().collect();
+        for (index, marker) in markers.iter().enumerate() {
+            // When a token ends where the next token starts, only the start of the
+            // next token is mapped
+            if markers
+                .get(index + 1)
+                .is_some_and(|next| next.dest == marker.dest)
+            {
+                continue;
+            }
+
+            let (dest_line, dest_column) = code_lines.line_column(code, marker.dest);
+            let (source_line, source_column) = source_lines.line_column(source, marker.source);
+
+            if dest_line > line {
+                for _ in line..dest_line {
+                    mappings.push(';');
+                }
+                line = dest_line;
+                previous_column = 0;
+            } else if !mappings.is_empty() && !mappings.ends_with(';') {
+                mappings.push(',');
+            }
+
+            encode_vlq(&mut mappings, dest_column as i64 - previous_column as i64);
+            // Index of the source file
+            encode_vlq(&mut mappings, 0);
+            encode_vlq(
+                &mut mappings,
+                source_line as i64 - previous_source_line as i64,
+            );
+            encode_vlq(
+                &mut mappings,
+                source_column as i64 - previous_source_column as i64,
+            );
+
+            previous_column = dest_column;
+            previous_source_line = source_line;
+            previous_source_column = source_column;
+        }
+
+        Self {
+            version: 3,
+            file: None,
+            sources: vec![source_name.to_string()],
+            sources_content: vec![source.to_string()],
+            names: Vec::new(),
+            mappings,
+        }
+    }
+
+    /// Sets the name of the generated file
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+/// The byte offsets of the start of the lines of a text
+struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { line_starts }
+    }
+
+    /// Returns the zero-based line and UTF-16 column of `offset` in `text`
+    fn line_column(&self, text: &str, offset: TextSize) -> (usize, usize) {
+        let offset = usize::from(offset).min(text.len());
+        let line = self
+            .line_starts
+            .partition_point(|line_start| *line_start <= offset)
+            .saturating_sub(1);
+        let line_start = self.line_starts[line];
+        let column = text
+            .get(line_start..offset)
+            .map_or(0, |line| line.encode_utf16().count());
+        (line, column)
+    }
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Appends `value` encoded as a Base64 VLQ to `output`
+fn encode_vlq(output: &mut String, value: i64) {
+    // The sign is stored in the least significant bit
+    let mut value = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+
+    loop {
+        let mut digit = (value & 0b1_1111) as usize;
+        value >>= 5;
+        if value > 0 {
+            // The continuation bit
+            digit |= 0b10_0000;
+        }
+        output.push(BASE64_CHARS[digit] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_vlq, SourceMapV3};
+    use crate::{SourceMarker, TextSize};
+
+    fn vlq(value: i64) -> String {
+        let mut output = String::new();
+        encode_vlq(&mut output, value);
+        output
+    }
+
+    #[test]
+    fn encodes_vlq() {
+        assert_eq!(vlq(0), "A");
+        assert_eq!(vlq(1), "C");
+        assert_eq!(vlq(-1), "D");
+        assert_eq!(vlq(15), "e");
+        assert_eq!(vlq(16), "gB");
+        assert_eq!(vlq(-17), "jB");
+        assert_eq!(vlq(1000), "w+B");
+    }
+
+    #[test]
+    fn maps_printed_positions() {
+        let source = "let  a=1;\nlet b = \"é\";";
+        let code = "let a = 1;\n\nlet b = \"é\";\n";
+        let marker = |source: u32, dest: u32| SourceMarker {
+            source: TextSize::from(source),
+            dest: TextSize::from(dest),
+        };
+        let markers = [
+            marker(0, 0),
+            marker(3, 3),
+            marker(5, 4),
+            marker(6, 5),
+            marker(6, 6),
+            marker(7, 7),
+            // The end of `1` and the start of `;`
+            marker(8, 8),
+            marker(8, 8),
+            marker(9, 9),
+            marker(10, 12),
+            marker(14, 16),
+            // `"é"` is 4 bytes long, but 3 UTF-16 code units
+            marker(18, 20),
+            marker(22, 24),
+        ];
+
+        let source_map = SourceMapV3::from_markers(markers, source, "a.js", code).with_file("b.js");
+
+        assert_eq!(source_map.version, 3);
+        assert_eq!(source_map.file.as_deref(), Some("b.js"));
+        assert_eq!(source_map.sources, vec!["a.js".to_string()]);
+        assert_eq!(source_map.sources_content, vec![source.to_string()]);
+        assert_eq!(
+            source_map.mappings,
+            "AAAA,GAAG,CAAE,CAAC,CAAA,CAAC,CAAC,CAAC;;AACT,IAAI,IAAI,GAAG"
+        );
+    }
+}
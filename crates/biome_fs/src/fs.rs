@@ -2,6 +2,7 @@ use crate::{PathInterner, RomePath};
 use biome_diagnostics::{console, Advices, Diagnostic, LogCategory, Visit};
 use biome_diagnostics::{Error, Severity};
 pub use memory::{ErrorEntry, MemoryFileSystem};
+pub use merge::UnstagedChangesConflict;
 pub use os::OsFileSystem;
 use serde::{Deserialize, Serialize};
 use std::io;
@@ -10,7 +11,10 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{error, info};
 
+#[cfg(feature = "git")]
+mod git;
 mod memory;
+mod merge;
 mod os;
 
 pub const ROME_JSON: &str = "rome.json";
@@ -43,6 +47,9 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path exists in the file system
     fn path_exists(&self, path: &Path) -> bool;
 
+    /// Creates a directory and all its missing parents
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -133,7 +140,52 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
         }
     }
 
-    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
+    /// Returns the paths of the files changed between `HEAD` and its merge base
+    /// with the `base` git reference, relative to the working directory. The
+    /// deleted files aren't returned.
+    fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
+        Err(git_unsupported())
+    }
+
+    /// Returns the paths of the files staged in the git index, relative to the
+    /// working directory. The deleted files aren't returned.
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        Err(git_unsupported())
+    }
+
+    /// Opens the content of a file staged in the git index.
+    ///
+    /// Writing to the file updates the index, and applies the same changes to
+    /// the file in the working tree without overriding its unstaged changes.
+    /// The working tree is left untouched when the changes overlap.
+    fn open_staged(&self, _path: &Path) -> io::Result<Box<dyn File>> {
+        Err(git_unsupported())
+    }
+
+    /// Returns the ignore patterns of the git repository that contains `path`,
+    /// or `None` if `path` isn't in a git repository or the file system can't
+    /// read it.
+    fn get_git_ignore_matches(&self, _path: &Path) -> io::Result<Option<GitIgnoreMatches>> {
+        Ok(None)
+    }
+}
+
+fn git_unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "this file system doesn't support git",
+    )
+}
+
+/// The ignore patterns of a git repository
+#[derive(Debug)]
+pub struct GitIgnoreMatches {
+    /// The root of the working tree of the repository, the patterns are
+    /// relative to it
+    pub directory_path: PathBuf,
+    /// The patterns of the global ignore file, `.git/info/exclude` and the
+    /// `.gitignore` files, ordered by increasing precedence
+    pub patterns: Vec<String>,
 }
 
 /// Result of the auto search
@@ -151,6 +203,9 @@ pub trait File {
     /// Read the content of the file into `buffer`
     fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()>;
 
+    /// Read the bytes of the file into `buffer`, e.g. when it isn't a text file
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()>;
+
     /// Overwrite the content of the file with the provided bytes
     ///
     /// This will write to the associated memory buffer, as well as flush the
@@ -253,6 +308,13 @@ pub trait TraversalScope<'scope> {
     /// [`can_handle`](TraversalContext::can_handle) method of the context
     /// returns true for will be handled as well
     fn spawn(&self, context: &'scope dyn TraversalContext, path: PathBuf);
+
+    /// Handles the file at the provided path, without checking its type in
+    /// the file system first
+    ///
+    /// This is used for the files that may not exist in the working tree,
+    /// such as the files staged in the git index
+    fn handle(&self, context: &'scope dyn TraversalContext, path: PathBuf);
 }
 
 pub trait TraversalContext: Sync {
@@ -294,9 +356,25 @@ where
         T::path_exists(self, path)
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        T::get_staged_files(self)
+    }
+
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        T::open_staged(self, path)
+    }
+
+    fn get_git_ignore_matches(&self, path: &Path) -> io::Result<Option<GitIgnoreMatches>> {
+        T::get_git_ignore_matches(self, path)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
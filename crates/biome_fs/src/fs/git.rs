@@ -0,0 +1,440 @@
+//! Reads and updates the git repository of the working directory in-process,
+//! instead of spawning the `git` binary
+use git2::{ErrorCode, IndexTime, Repository};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use std::{env, fs, io};
+
+/// The files of the index are processed in parallel, the writes to the index
+/// are serialized so that they don't overwrite each other
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+/// A git repository, opened from a directory of its working tree
+pub(crate) struct GitRepository {
+    repository: Repository,
+    /// The root of the working tree
+    workdir: PathBuf,
+    /// The path of the directory the repository was opened from, relative to
+    /// the root of the working tree
+    directory: PathBuf,
+}
+
+impl GitRepository {
+    /// Opens the repository that contains `directory`
+    pub(crate) fn discover(directory: &Path) -> io::Result<Self> {
+        let repository = Repository::discover(directory).map_err(to_io_error)?;
+        let canonical_workdir = repository
+            .workdir()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "the git repository doesn't have a working tree",
+                )
+            })?
+            .canonicalize()?;
+        let relative_directory = directory
+            .canonicalize()?
+            .strip_prefix(&canonical_workdir)
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        // The form of the given path is kept, e.g. its symbolic links, so that
+        // the root can be compared with the paths of the traversal
+        let workdir = if directory.is_absolute() {
+            let mut workdir = directory.to_path_buf();
+            for _ in relative_directory.components() {
+                workdir.pop();
+            }
+            workdir
+        } else {
+            canonical_workdir
+        };
+
+        Ok(Self {
+            repository,
+            workdir,
+            directory: relative_directory,
+        })
+    }
+
+    /// The root of the working tree
+    pub(crate) fn workdir(&self) -> &Path {
+        &self.workdir
+    }
+
+    /// Returns the files changed between the merge base of `base` and `HEAD`,
+    /// and `HEAD`. The deleted files aren't returned.
+    pub(crate) fn changed_files(&self, base: &str) -> io::Result<Vec<String>> {
+        let repository = &self.repository;
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(to_io_error)?;
+        let base = repository
+            .revparse_single(base)
+            .and_then(|base| base.peel_to_commit())
+            .map_err(to_io_error)?;
+        let merge_base = repository
+            .merge_base(base.id(), head.id())
+            .and_then(|merge_base| repository.find_commit(merge_base))
+            .and_then(|merge_base| merge_base.tree())
+            .map_err(to_io_error)?;
+        let head_tree = head.tree().map_err(to_io_error)?;
+
+        let diff = repository
+            .diff_tree_to_tree(Some(&merge_base), Some(&head_tree), None)
+            .map_err(to_io_error)?;
+        Ok(self.new_files(&diff))
+    }
+
+    /// Returns the files whose content in the index differs from `HEAD`. The
+    /// deleted files aren't returned.
+    pub(crate) fn staged_files(&self) -> io::Result<Vec<String>> {
+        let repository = &self.repository;
+        let head_tree = match repository.head() {
+            Ok(head) => Some(head.peel_to_tree().map_err(to_io_error)?),
+            // the repository doesn't have any commit yet
+            Err(error) if error.code() == ErrorCode::UnbornBranch => None,
+            Err(error) => return Err(to_io_error(error)),
+        };
+        let index = repository.index().map_err(to_io_error)?;
+
+        let diff = repository
+            .diff_tree_to_index(head_tree.as_ref(), Some(&index), None)
+            .map_err(to_io_error)?;
+        Ok(self.new_files(&diff))
+    }
+
+    /// Returns the content of `path` in the index
+    pub(crate) fn read_staged(&self, path: &Path) -> io::Result<String> {
+        let index = self.repository.index().map_err(to_io_error)?;
+        let entry = index
+            .get_path(&self.index_path(path), 0)
+            .ok_or_else(|| not_staged(path))?;
+        let blob = self.repository.find_blob(entry.id).map_err(to_io_error)?;
+
+        String::from_utf8(blob.content().to_vec())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Replaces the content of `path` in the index, its mode is preserved
+    pub(crate) fn write_staged(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        let _guard = INDEX_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+        let mut index = self.repository.index().map_err(to_io_error)?;
+        // another process may have updated the index in the meantime
+        index.read(false).map_err(to_io_error)?;
+        let mut entry = index
+            .get_path(&self.index_path(path), 0)
+            .ok_or_else(|| not_staged(path))?;
+        entry.id = self.repository.blob(content).map_err(to_io_error)?;
+        entry.file_size = content.len() as u32;
+        // The entry doesn't describe the file of the working tree anymore, git
+        // compares their content when their metadata don't match
+        entry.ctime = IndexTime::new(0, 0);
+        entry.mtime = IndexTime::new(0, 0);
+
+        index.add(&entry).map_err(to_io_error)?;
+        index.write().map_err(to_io_error)
+    }
+
+    /// Returns the ignore patterns of the repository, ordered by increasing
+    /// precedence: when several patterns match a path, the last one wins.
+    ///
+    /// They come from `core.excludesFile`, `.git/info/exclude`, and the
+    /// `.gitignore` file at the root of the working tree. The nested `.gitignore`
+    /// files are read by the traversal, see `IgnoreFiles` in `biome_service`.
+    pub(crate) fn ignore_patterns(&self) -> io::Result<Vec<String>> {
+        let mut patterns = Vec::new();
+        let mut read_patterns = |path: &Path| -> io::Result<()> {
+            match fs::read_to_string(path) {
+                Ok(content) => {
+                    patterns.extend(
+                        content
+                            .lines()
+                            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+                            .map(String::from),
+                    );
+                    Ok(())
+                }
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+                Err(error) => Err(error),
+            }
+        };
+
+        if let Some(excludes_file) = self.excludes_file() {
+            read_patterns(&excludes_file)?;
+        }
+        read_patterns(&self.repository.path().join("info").join("exclude"))?;
+        read_patterns(&self.workdir.join(".gitignore"))?;
+
+        Ok(patterns)
+    }
+
+    /// The global ignore file, `$XDG_CONFIG_HOME/git/ignore` when the option
+    /// isn't set
+    fn excludes_file(&self) -> Option<PathBuf> {
+        let configured = self
+            .repository
+            .config()
+            .and_then(|config| config.get_path("core.excludesFile"));
+        match configured {
+            Ok(path) => Some(path),
+            Err(_) => env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+                .map(|config| config.join("git").join("ignore")),
+        }
+    }
+
+    /// Returns the new paths of the files of `diff` that weren't deleted,
+    /// relative to the directory the repository was opened from
+    fn new_files(&self, diff: &git2::Diff) -> Vec<String> {
+        let mut files: Vec<_> = diff
+            .deltas()
+            .filter(|delta| delta.status() != git2::Delta::Deleted)
+            .filter_map(|delta| delta.new_file().path())
+            .filter_map(|path| path.strip_prefix(&self.directory).ok())
+            .map(|path| path.display().to_string())
+            .collect();
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// The path of the index entry of `path`, which is relative to the
+    /// directory the repository was opened from, or absolute
+    fn index_path(&self, path: &Path) -> PathBuf {
+        let path = if path.is_absolute() {
+            path.strip_prefix(&self.workdir)
+                .map(Path::to_path_buf)
+                .or_else(|_| {
+                    let workdir = self.workdir.canonicalize()?;
+                    let path = path.canonicalize()?;
+                    path.strip_prefix(workdir)
+                        .map(Path::to_path_buf)
+                        .map_err(|error| io::Error::new(io::ErrorKind::NotFound, error))
+                })
+                .unwrap_or_else(|_| path.to_path_buf())
+        } else {
+            self.directory.join(path)
+        };
+
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect()
+    }
+}
+
+fn not_staged(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} isn't staged", path.display()),
+    )
+}
+
+fn to_io_error(error: git2::Error) -> io::Error {
+    let kind = match error.code() {
+        ErrorCode::NotFound => io::ErrorKind::NotFound,
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GitRepository;
+    use git2::{Commit, Oid, Repository, Signature};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// A repository created in a temporary directory, which is removed when
+    /// the fixture is dropped
+    struct Fixture {
+        root: PathBuf,
+        repository: Repository,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let root =
+                std::env::temp_dir().join(format!("biome_fs_git_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            let repository = Repository::init(&root).unwrap();
+            // the global ignore file of the machine must not leak into the tests
+            let excludes_file = root.join(".git").join("global_ignore");
+            fs::write(&excludes_file, "").unwrap();
+            repository
+                .config()
+                .unwrap()
+                .set_str("core.excludesFile", excludes_file.to_str().unwrap())
+                .unwrap();
+
+            Self { root, repository }
+        }
+
+        fn write(&self, path: &str, content: &str) {
+            let path = self.root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        fn remove(&self, path: &str) {
+            fs::remove_file(self.root.join(path)).unwrap();
+        }
+
+        /// Stages all the changes of the working tree
+        fn stage(&self) {
+            let mut index = self.repository.index().unwrap();
+            index
+                .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+                .unwrap();
+            index.update_all(["*"], None).unwrap();
+            index.write().unwrap();
+        }
+
+        /// Stages and commits all the changes of the working tree
+        fn commit(&self, message: &str) -> Oid {
+            self.stage();
+            let repository = &self.repository;
+            let tree_id = repository.index().unwrap().write_tree().unwrap();
+            let tree = repository.find_tree(tree_id).unwrap();
+            let signature = Signature::now("Biome", "biome@example.com").unwrap();
+            let parent = repository
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<&Commit> = parent.iter().collect();
+            repository
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    message,
+                    &tree,
+                    &parents,
+                )
+                .unwrap()
+        }
+
+        fn checkout_new_branch(&self, name: &str) {
+            let head = self.repository.head().unwrap().peel_to_commit().unwrap();
+            self.repository.branch(name, &head, false).unwrap();
+            self.repository
+                .set_head(&format!("refs/heads/{name}"))
+                .unwrap();
+        }
+
+        fn open(&self, directory: &str) -> GitRepository {
+            GitRepository::discover(&self.root.join(directory)).unwrap()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn returns_the_files_changed_since_the_merge_base() {
+        let fixture = Fixture::new("changed");
+        fixture.write("a.js", "a();\n");
+        fixture.write("b.js", "b();\n");
+        fixture.commit("initial");
+        fixture.checkout_new_branch("main");
+        fixture.checkout_new_branch("feature");
+
+        fixture.write("a.js", "a(1);\n");
+        fixture.write("src/c.js", "c();\n");
+        fixture.remove("b.js");
+        fixture.commit("feature");
+
+        let repository = fixture.open("");
+        assert_eq!(
+            repository.changed_files("main").unwrap(),
+            vec!["a.js".to_string(), "src/c.js".to_string()]
+        );
+        assert_eq!(
+            fixture.open("src").changed_files("main").unwrap(),
+            vec!["c.js".to_string()]
+        );
+        assert!(repository.changed_files("missing").is_err());
+    }
+
+    #[test]
+    fn returns_the_staged_files() {
+        let fixture = Fixture::new("staged");
+        fixture.write("a.js", "a();\n");
+        fixture.write("packages/a/b.js", "b();\n");
+        fixture.write("packages/a/c.js", "c();\n");
+        fixture.commit("initial");
+
+        fixture.write("a.js", "a(1);\n");
+        fixture.write("packages/a/b.js", "b(1);\n");
+        fixture.write("packages/a/d.js", "d();\n");
+        fixture.remove("packages/a/c.js");
+        fixture.stage();
+        fixture.write("packages/a/e.js", "e();\n");
+
+        assert_eq!(
+            fixture.open("").staged_files().unwrap(),
+            vec![
+                "a.js".to_string(),
+                "packages/a/b.js".to_string(),
+                "packages/a/d.js".to_string()
+            ]
+        );
+        assert_eq!(
+            fixture.open("packages/a").staged_files().unwrap(),
+            vec!["b.js".to_string(), "d.js".to_string()]
+        );
+    }
+
+    #[test]
+    fn reads_and_writes_the_staged_content() {
+        let fixture = Fixture::new("index");
+        fixture.write("src/a.js", "a();\n");
+        fixture.stage();
+        fixture.write("src/a.js", "a();\nb();\n");
+
+        let repository = fixture.open("src");
+        assert_eq!(
+            repository.read_staged(Path::new("./a.js")).unwrap(),
+            "a();\n"
+        );
+
+        repository
+            .write_staged(Path::new("a.js"), b"a(1);\n")
+            .unwrap();
+        assert_eq!(
+            repository.read_staged(Path::new("a.js")).unwrap(),
+            "a(1);\n"
+        );
+        assert_eq!(
+            fs::read_to_string(fixture.root.join("src/a.js")).unwrap(),
+            "a();\nb();\n"
+        );
+        let index = fixture.repository.index().unwrap();
+        let entry = index.get_path(Path::new("src/a.js"), 0).unwrap();
+        assert_eq!(entry.mode, 0o100_644);
+
+        assert!(repository.read_staged(Path::new("b.js")).is_err());
+        assert!(repository.write_staged(Path::new("b.js"), b"").is_err());
+    }
+
+    #[test]
+    fn returns_the_ignore_patterns_by_precedence() {
+        let fixture = Fixture::new("ignore");
+        fixture.write(".git/global_ignore", "*.log\n");
+        fixture.write(".git/info/exclude", "# local\ntmp/\n");
+        fixture.write(".gitignore", "dist\n\n!keep.log\n");
+        fixture.write("packages/a/.gitignore", "/build\n");
+
+        assert_eq!(
+            fixture.open("packages").ignore_patterns().unwrap(),
+            vec!["*.log", "tmp/", "dist", "!keep.log"]
+        );
+    }
+}
@@ -12,6 +12,7 @@ use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 use crate::fs::OpenOptions;
 use crate::{FileSystem, RomePath, TraversalContext, TraversalScope};
 
+use super::merge::{conflict_error, merge};
 use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
 
 type OnGetChangedFiles = Option<
@@ -28,6 +29,8 @@ pub struct MemoryFileSystem {
     errors: FxHashMap<PathBuf, ErrorEntry>,
     allow_write: bool,
     on_get_changed_files: OnGetChangedFiles,
+    /// The content of the files in the git index
+    staged: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, FileEntry>>>,
 }
 
 impl Default for MemoryFileSystem {
@@ -37,6 +40,7 @@ impl Default for MemoryFileSystem {
             errors: Default::default(),
             allow_write: true,
             on_get_changed_files: None,
+            staged: Default::default(),
         }
     }
 }
@@ -86,6 +90,18 @@ impl MemoryFileSystem {
         files.insert(path, Arc::new(Mutex::new(content.into())));
     }
 
+    /// Stage the content of a file in the git index
+    pub fn insert_staged(&mut self, path: PathBuf, content: impl Into<Vec<u8>>) {
+        let staged = self.staged.0.get_mut();
+        staged.insert(path, Arc::new(Mutex::new(content.into())));
+    }
+
+    /// Returns the content of a file staged in the git index
+    pub fn staged_content(&self, path: &Path) -> Option<Vec<u8>> {
+        let staged = self.staged.0.read();
+        staged.get(path).map(|entry| entry.lock().clone())
+    }
+
     /// Create or update an error in the filesystem
     pub fn insert_error(&mut self, path: PathBuf, kind: ErrorEntry) {
         self.errors.insert(path, kind);
@@ -184,6 +200,11 @@ impl FileSystem for MemoryFileSystem {
         files.get(path).is_some()
     }
 
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // The directories are implied by the paths of the files
+        Ok(())
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
@@ -193,6 +214,33 @@ impl FileSystem for MemoryFileSystem {
 
         Ok(cb())
     }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let staged = self.staged.0.read();
+        let mut paths: Vec<_> = staged
+            .keys()
+            .map(|path| path.display().to_string())
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        let index = self.staged.0.read().get(path).cloned().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("path {path:?} isn't staged in memory filesystem"),
+            )
+        })?;
+        let working = self.files.0.read().get(path).cloned();
+
+        Ok(Box::new(MemoryStagedFile {
+            index,
+            working,
+            can_write: self.allow_write,
+            version: 0,
+        }))
+    }
 }
 
 struct MemoryFile {
@@ -219,6 +267,18 @@ impl File for MemoryFile {
         Ok(())
     }
 
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        if !self.can_read {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "this file wasn't open with read access",
+            ));
+        }
+
+        buffer.extend_from_slice(&self.inner);
+        Ok(())
+    }
+
     fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
         if !self.can_write {
             return Err(io::Error::new(
@@ -241,6 +301,62 @@ impl File for MemoryFile {
     }
 }
 
+/// A file staged in the git index, along with its entry in the working tree
+struct MemoryStagedFile {
+    index: FileEntry,
+    working: Option<FileEntry>,
+    can_write: bool,
+    version: i32,
+}
+
+impl File for MemoryStagedFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        let index = self.index.lock();
+        let content = str::from_utf8(&index)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        buffer.push_str(content);
+        Ok(())
+    }
+
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        buffer.extend_from_slice(&self.index.lock());
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        if !self.can_write {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot acquire write access to file in read-only filesystem",
+            ));
+        }
+
+        let mut index = self.index.lock();
+        if let Some(working) = &self.working {
+            let mut working = working.lock();
+            let merged = match (
+                str::from_utf8(&index),
+                str::from_utf8(&working),
+                str::from_utf8(content),
+            ) {
+                (Ok(base), Ok(ours), Ok(theirs)) => merge(base, ours, theirs),
+                _ => None,
+            };
+            match merged {
+                Some(merged) => *working = merged.into_bytes(),
+                None => return Err(conflict_error()),
+            }
+        }
+        *index = content.to_vec();
+        self.version += 1;
+        Ok(())
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
+    }
+}
+
 pub struct MemoryTraversalScope<'scope> {
     fs: &'scope MemoryFileSystem,
 }
@@ -248,28 +364,33 @@ pub struct MemoryTraversalScope<'scope> {
 impl<'scope> TraversalScope<'scope> for MemoryTraversalScope<'scope> {
     fn spawn(&self, ctx: &'scope dyn TraversalContext, base: PathBuf) {
         // Traversal is implemented by iterating on all keys, and matching on
-        // those that are prefixed with the provided `base` path
-        {
+        // those that are prefixed with the provided `base` path. The paths are
+        // collected first, so the files can be created or removed while they're handled
+        let paths = {
             let files = &self.fs.files.0.read();
-            for path in files.keys() {
-                let should_process_file = if base.starts_with(".") || base.starts_with("./") {
-                    // we simulate absolute paths, so we can correctly strips out the base path from the path
-                    let absolute_base = PathBuf::from("/").join(&base);
-                    let absolute_path = Path::new("/").join(path);
-                    absolute_path.strip_prefix(&absolute_base).is_ok()
-                } else {
-                    path.strip_prefix(&base).is_ok()
-                };
-
-                if should_process_file {
-                    let _ = ctx.interner().intern_path(path.into());
-                    let rome_path = RomePath::new(path);
-                    if !ctx.can_handle(&rome_path) {
-                        continue;
+            files
+                .keys()
+                .filter(|path| {
+                    if base.starts_with(".") || base.starts_with("./") {
+                        // we simulate absolute paths, so we can correctly strips out the base path from the path
+                        let absolute_base = PathBuf::from("/").join(&base);
+                        let absolute_path = Path::new("/").join(path);
+                        absolute_path.strip_prefix(&absolute_base).is_ok()
+                    } else {
+                        path.strip_prefix(&base).is_ok()
                     }
-                    ctx.handle_file(path);
-                }
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        for path in paths {
+            let _ = ctx.interner().intern_path(path.clone());
+            let rome_path = RomePath::new(&path);
+            if !ctx.can_handle(&rome_path) {
+                continue;
             }
+            ctx.handle_file(&path);
         }
 
         for (path, entry) in &self.fs.errors {
@@ -292,6 +413,14 @@ impl<'scope> TraversalScope<'scope> for MemoryTraversalScope<'scope> {
             }
         }
     }
+
+    fn handle(&self, ctx: &'scope dyn TraversalContext, path: PathBuf) {
+        let _ = ctx.interner().intern_path(path.clone());
+        let rome_path = RomePath::new(&path);
+        if ctx.can_handle(&rome_path) {
+            ctx.handle_file(&path);
+        }
+    }
 }
 
 #[cfg(test)]
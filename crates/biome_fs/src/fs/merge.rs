@@ -0,0 +1,171 @@
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::ops::Range;
+
+/// A change to a range of lines of the base text
+#[derive(Debug, PartialEq)]
+struct Hunk<'a> {
+    base: Range<usize>,
+    lines: &'a [&'a str],
+}
+
+/// Applies to `ours` the changes that turned `base` into `theirs`, line by line.
+///
+/// It's used to bring the changes written to the git index to the working tree,
+/// where `ours` holds the unstaged changes. Returns `None` when the changes
+/// overlap.
+pub(crate) fn merge(base: &str, ours: &str, theirs: &str) -> Option<String> {
+    if ours == base {
+        return Some(theirs.to_string());
+    }
+    if theirs == base {
+        return Some(ours.to_string());
+    }
+
+    let base_lines: Vec<_> = base.split_inclusive('\n').collect();
+    let our_lines: Vec<_> = ours.split_inclusive('\n').collect();
+    let their_lines: Vec<_> = theirs.split_inclusive('\n').collect();
+
+    let our_hunks = hunks(&base_lines, &our_lines);
+    let their_hunks = hunks(&base_lines, &their_lines);
+
+    let mut all_hunks: Vec<&Hunk> = Vec::with_capacity(our_hunks.len() + their_hunks.len());
+    for hunk in &their_hunks {
+        match our_hunks.iter().find(|our_hunk| conflicts(our_hunk, hunk)) {
+            // both sides made the same change
+            Some(our_hunk) if our_hunk == hunk => {}
+            Some(_) => return None,
+            None => all_hunks.push(hunk),
+        }
+    }
+    all_hunks.extend(&our_hunks);
+    all_hunks.sort_by_key(|hunk| (hunk.base.start, hunk.base.end));
+
+    let mut merged = String::with_capacity(ours.len().max(theirs.len()));
+    let mut position = 0;
+    for hunk in all_hunks {
+        merged.extend(base_lines[position..hunk.base.start].iter().copied());
+        merged.extend(hunk.lines.iter().copied());
+        position = hunk.base.end;
+    }
+    merged.extend(base_lines[position..].iter().copied());
+
+    Some(merged)
+}
+
+/// The error of a staged file whose new content can't be merged with the
+/// unstaged changes of the working tree. Neither the index nor the working tree
+/// are written, otherwise the working tree would silently lose the new content.
+#[derive(Debug)]
+pub struct UnstagedChangesConflict;
+
+impl Display for UnstagedChangesConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the new content conflicts with the unstaged changes of the file")
+    }
+}
+
+impl std::error::Error for UnstagedChangesConflict {}
+
+impl UnstagedChangesConflict {
+    /// Tells if `error` was returned because of a conflict with the unstaged changes
+    pub fn is_conflict(error: &io::Error) -> bool {
+        error.get_ref().is_some_and(|error| error.is::<Self>())
+    }
+}
+
+pub(crate) fn conflict_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, UnstagedChangesConflict)
+}
+
+/// Returns the changes between `base` and `other`, the adjacent changes are
+/// grouped in a single hunk
+fn hunks<'a>(base: &[&str], other: &'a [&'a str]) -> Vec<Hunk<'a>> {
+    let mut hunks: Vec<(Range<usize>, Range<usize>)> = Vec::new();
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        let (tag, base_range, other_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some((last_base, last_other)) if last_base.end == base_range.start => {
+                last_base.end = base_range.end;
+                last_other.end = other_range.end;
+            }
+            _ => hunks.push((base_range, other_range)),
+        }
+    }
+
+    hunks
+        .into_iter()
+        .map(|(base, other_range)| Hunk {
+            base,
+            lines: &other[other_range],
+        })
+        .collect()
+}
+
+/// Two hunks conflict when they overlap, or when they start at the same line,
+/// in which case the order of their lines can't be decided
+fn conflicts(first: &Hunk, second: &Hunk) -> bool {
+    first.base.start == second.base.start
+        || (first.base.start < second.base.end && second.base.start < first.base.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge;
+
+    const BASE: &str = "a\nb\nc\nd\ne\n";
+
+    #[test]
+    fn applies_the_changes_without_unstaged_changes() {
+        assert_eq!(
+            merge(BASE, BASE, "a\nB\nc\nd\ne\n"),
+            Some("a\nB\nc\nd\ne\n".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_the_unstaged_changes() {
+        assert_eq!(
+            merge(BASE, "a\nb\nc\nd\nE\nf\n", "A\nb\nc\nd\ne\n"),
+            Some("A\nb\nc\nd\nE\nf\n".to_string())
+        );
+        assert_eq!(
+            merge(BASE, "a\nc\nd\ne\n", "a\nb\nc\nd\n"),
+            Some("a\nc\nd\n".to_string())
+        );
+    }
+
+    #[test]
+    fn applies_the_same_change_once() {
+        assert_eq!(
+            merge(BASE, "a\nB\nc\nd\nE\n", "a\nB\nc\nd\ne\n"),
+            Some("a\nB\nc\nd\nE\n".to_string())
+        );
+    }
+
+    #[test]
+    fn merges_the_adjacent_changes() {
+        assert_eq!(
+            merge(BASE, "a\nb\nC\nd\ne\n", "a\nb\nc\nD\ne\n"),
+            Some("a\nb\nC\nD\ne\n".to_string())
+        );
+        assert_eq!(
+            merge(BASE, "a\nb\nc\nd\ne\nf\n", "a\nb\nc\nd\nE\n"),
+            Some("a\nb\nc\nd\nE\nf\n".to_string())
+        );
+    }
+
+    #[test]
+    fn fails_when_the_changes_overlap() {
+        assert_eq!(merge(BASE, "a\nb\nC\nd\ne\n", "a\nb\nc3\nd\ne\n"), None);
+        assert_eq!(merge(BASE, "a\nb\nC\nd\ne\n", "a\nb\nC2\nD\ne\n"), None);
+        assert_eq!(
+            merge(BASE, "a\nb\nx\nc\nd\ne\n", "a\nb\ny\nc\nd\ne\n"),
+            None
+        );
+    }
+}
@@ -1,4 +1,8 @@
 //! Implementation of the [FileSystem] and related traits for the underlying OS filesystem
+#[cfg(feature = "git")]
+use super::merge::{conflict_error, merge};
+#[cfg(feature = "git")]
+use super::{git::GitRepository, GitIgnoreMatches};
 use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
 use crate::fs::OpenOptions;
 use crate::{
@@ -9,7 +13,6 @@ use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
 use rayon::{scope, Scope};
 use std::ffi::OsStr;
 use std::fs::{DirEntry, FileType};
-use std::process::Command;
 use std::{
     env, fs,
     io::{self, ErrorKind as IoErrorKind, Read, Seek, Write},
@@ -48,17 +51,105 @@ impl FileSystem for OsFileSystem {
         path.exists()
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
+    #[cfg(feature = "git")]
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg(format!("{}...HEAD", base))
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        open_repository()?.changed_files(base)
+    }
+
+    #[cfg(feature = "git")]
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        open_repository()?.staged_files()
+    }
+
+    #[cfg(feature = "git")]
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        let repository = open_repository()?;
+        let content = repository.read_staged(path)?;
+
+        Ok(Box::new(OsStagedFile {
+            repository,
+            path: PathBuf::from(path),
+            content,
+            version: 0,
+        }))
+    }
+
+    #[cfg(feature = "git")]
+    fn get_git_ignore_matches(&self, path: &Path) -> io::Result<Option<GitIgnoreMatches>> {
+        let repository = match GitRepository::discover(path) {
+            Ok(repository) => repository,
+            Err(error) if error.kind() == IoErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        Ok(Some(GitIgnoreMatches {
+            patterns: repository.ignore_patterns()?,
+            directory_path: repository.workdir().to_path_buf(),
+        }))
+    }
+}
+
+/// Opens the git repository that contains the working directory
+#[cfg(feature = "git")]
+fn open_repository() -> io::Result<GitRepository> {
+    GitRepository::discover(&env::current_dir()?)
+}
+
+/// A file staged in the git index
+#[cfg(feature = "git")]
+struct OsStagedFile {
+    repository: GitRepository,
+    path: PathBuf,
+    /// The content of the file in the index
+    content: String,
+    version: i32,
+}
+
+#[cfg(feature = "git")]
+impl File for OsStagedFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        buffer.push_str(&self.content);
+        Ok(())
+    }
+
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        buffer.extend_from_slice(self.content.as_bytes());
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        tracing::debug_span!("OsStagedFile::set_content").in_scope(move || {
+            let new_content = std::str::from_utf8(content)
+                .map_err(|err| io::Error::new(IoErrorKind::InvalidData, err))?;
+
+            // The unstaged changes are kept, the file isn't written when they
+            // conflict with the new content
+            let merged = match fs::read_to_string(&self.path) {
+                Ok(working_content) => match merge(&self.content, &working_content, new_content) {
+                    Some(merged) if merged == working_content => None,
+                    Some(merged) => Some(merged),
+                    None => return Err(conflict_error()),
+                },
+                Err(_) => None,
+            };
+
+            self.repository.write_staged(&self.path, content)?;
+            if let Some(merged) = merged {
+                fs::write(&self.path, merged)?;
+            }
+
+            self.content = new_content.to_string();
+            self.version += 1;
+            Ok(())
+        })
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
     }
 }
 
@@ -78,6 +169,16 @@ impl File for OsFile {
         })
     }
 
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        tracing::debug_span!("OsFile::read_to_end").in_scope(move || {
+            // Reset the cursor to the starting position
+            self.inner.rewind()?;
+            // Read the file content
+            self.inner.read_to_end(buffer)?;
+            Ok(())
+        })
+    }
+
     fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
         tracing::debug_span!("OsFile::set_content").in_scope(move || {
             // Truncate the file
@@ -161,6 +262,13 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
             severity: Severity::Warning,
         }));
     }
+
+    fn handle(&self, ctx: &'scope dyn TraversalContext, path: PathBuf) {
+        let _ = ctx.interner().intern_path(path.clone());
+        self.scope.spawn(move |_| {
+            ctx.handle_file(&path);
+        });
+    }
 }
 
 // TODO: remove in Biome 2.0, and directly use `.gitignore`
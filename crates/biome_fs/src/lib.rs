@@ -6,8 +6,8 @@ mod path;
 pub use dir::ensure_cache_dir;
 pub use fs::{
     AutoSearchResult, ErrorEntry, File, FileSystem, FileSystemDiagnostic, FileSystemExt,
-    MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope, BIOME_JSON,
-    ROME_JSON,
+    GitIgnoreMatches, MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext,
+    TraversalScope, UnstagedChangesConflict, BIOME_JSON, ROME_JSON,
 };
 pub use interner::PathInterner;
-pub use path::RomePath;
+pub use path::{normalize_path, RomePath};
@@ -5,6 +5,7 @@
 //! - shortcuts to open/write to the file
 use std::fs::read_to_string;
 use std::io::Read;
+use std::path::{Component, Path};
 use std::{fs::File, io, io::Write, ops::Deref, path::PathBuf};
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
@@ -68,3 +69,51 @@ impl RomePath {
             .expect("Can't read the file")
     }
 }
+
+/// Resolves the `.` and `..` components of a path, without accessing the file
+/// system and without following the symbolic links, like Node.js does.
+///
+/// It allows to compare the paths given by the users, the traversal and the
+/// configuration files with each other. The `..` components that go above the
+/// start of a relative path are kept.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::RootDir | Component::Prefix(_))
+                ) => {}
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_path;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn normalizes_the_paths() {
+        assert_eq!(normalize_path(Path::new("./a/./b")), PathBuf::from("a/b"));
+        assert_eq!(normalize_path(Path::new("a/../b/c/..")), PathBuf::from("b"));
+        assert_eq!(
+            normalize_path(Path::new("../a/../../b")),
+            PathBuf::from("../../b")
+        );
+        assert_eq!(normalize_path(Path::new("/a/../../b")), PathBuf::from("/b"));
+        assert_eq!(normalize_path(Path::new(".")), PathBuf::new());
+    }
+}
@@ -0,0 +1,34 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub(crate) mod add_braces_to_arrow_function;
+pub(crate) mod convert_require_to_import;
+pub(crate) mod convert_to_arrow_function;
+pub(crate) mod convert_to_function_declaration;
+pub(crate) mod convert_to_string_literal;
+pub(crate) mod convert_to_template_literal;
+pub(crate) mod extract_function;
+pub(crate) mod extract_variable;
+pub(crate) mod flip_if_else;
+pub(crate) mod inline_variable;
+pub(crate) mod remove_braces_from_arrow_function;
+
+declare_group! {
+    pub (crate) Refactor {
+        name : "refactor" ,
+        rules : [
+            self :: add_braces_to_arrow_function :: AddBracesToArrowFunction ,
+            self :: convert_require_to_import :: ConvertRequireToImport ,
+            self :: convert_to_arrow_function :: ConvertToArrowFunction ,
+            self :: convert_to_function_declaration :: ConvertToFunctionDeclaration ,
+            self :: convert_to_string_literal :: ConvertToStringLiteral ,
+            self :: convert_to_template_literal :: ConvertToTemplateLiteral ,
+            self :: extract_function :: ExtractFunction ,
+            self :: extract_variable :: ExtractVariable ,
+            self :: flip_if_else :: FlipIfElse ,
+            self :: inline_variable :: InlineVariable ,
+            self :: remove_braces_from_arrow_function :: RemoveBracesFromArrowFunction ,
+        ]
+     }
+}
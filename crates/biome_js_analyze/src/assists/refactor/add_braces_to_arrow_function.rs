@@ -0,0 +1,80 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, AnyJsFunctionBody, JsArrowFunctionExpression};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::utils::refactor::{
+    function_body_returning, indentation_unit, is_selected_in_function, line_indentation,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to wrap the expression returned by the arrow
+    /// function under the cursor in a block.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor in the arrow function:
+    ///
+    /// ```js
+    /// const double = (value) => value * 2;
+    /// ```
+    ///
+    /// Adds braces around its body:
+    ///
+    /// ```js
+    /// const double = (value) => {
+    ///     return value * 2;
+    /// };
+    /// ```
+    pub(crate) AddBracesToArrowFunction {
+        version: "next",
+        name: "addBracesToArrowFunction",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for AddBracesToArrowFunction {
+    type Query = Ast<JsArrowFunctionExpression>;
+    /// The expression returned by the arrow function
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let arrow = ctx.query();
+        if !is_selected_in_function(ctx.root().syntax(), ctx.selection()?, arrow.syntax()) {
+            return None;
+        }
+        match arrow.body().ok()? {
+            AnyJsFunctionBody::AnyJsExpression(expression) => Some(expression),
+            AnyJsFunctionBody::JsFunctionBody(_) => None,
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, expression: &Self::State) -> Option<JsRuleAction> {
+        let arrow = ctx.query();
+        let body = function_body_returning(
+            expression.clone(),
+            &line_indentation(&arrow.syntax().first_token()?),
+            &indentation_unit(ctx.root().syntax()),
+        )?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsFunctionBody::AnyJsExpression(expression.clone()),
+            AnyJsFunctionBody::JsFunctionBody(body),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::Always,
+            message: markup! { "Add braces to the arrow function" }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,275 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression, AnyJsImportClause,
+    AnyJsLiteralExpression, AnyJsNamedImportSpecifier, AnyJsObjectBindingPatternMember,
+    AnyJsObjectMemberName, JsCallExpression, JsExpressionStatement, JsModuleItemList, JsSyntaxKind,
+    JsSyntaxToken, JsVariableKind, JsVariableStatement, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::semantic_services::Semantic;
+use crate::utils::refactor::{is_selected, token_with_leading_space, token_with_trailing_space};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert the `require` call under the cursor
+    /// to an `import` declaration.
+    ///
+    /// The call must be a statement at the top level of a module, or
+    /// initialize a variable. The variable can be destructured, but its
+    /// properties can't be nested, have default values nor be assigned.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor in:
+    ///
+    /// ```js
+    /// const { readFile, writeFile: write } = require("node:fs");
+    /// ```
+    ///
+    /// Converts the statement to an `import` declaration:
+    ///
+    /// ```js
+    /// import { readFile, writeFile as write } from "node:fs";
+    /// ```
+    pub(crate) ConvertRequireToImport {
+        version: "next",
+        name: "convertRequireToImport",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsRequireStatement = JsVariableStatement | JsExpressionStatement
+}
+
+/// The names imported by a `require` call
+pub(crate) enum RequiredNames {
+    /// The module is only imported for its side effects, e.g. `require("a")`
+    None,
+    /// The module is imported as a whole, e.g. `const a = require("a")`
+    Default(AnyJsBinding),
+    /// The module is destructured, e.g. `const { a, b: c } = require("a")`:
+    /// the name of each property and its local name, if it's renamed
+    Named(Vec<(JsSyntaxToken, Option<AnyJsBinding>)>),
+}
+
+impl Rule for ConvertRequireToImport {
+    type Query = Semantic<AnyJsRequireStatement>;
+    /// The imported names, and the string of the module
+    type State = (RequiredNames, JsSyntaxToken);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        let model = ctx.model();
+        if statement.parent::<JsModuleItemList>().is_none()
+            || !is_selected(ctx.root().syntax(), ctx.selection()?, statement.range())
+        {
+            return None;
+        }
+        match statement {
+            AnyJsRequireStatement::JsExpressionStatement(statement) => {
+                let source = required_module(model, &statement.expression().ok()?)?;
+                Some((RequiredNames::None, source))
+            }
+            AnyJsRequireStatement::JsVariableStatement(statement) => {
+                let declaration = statement.declaration().ok()?;
+                let declarators = declaration.declarators();
+                if declarators.len() != 1
+                    || declaration.variable_kind().ok()? == JsVariableKind::Using
+                {
+                    return None;
+                }
+                let declarator = declarators.first()?.ok()?;
+                if declarator.variable_annotation().is_some() {
+                    return None;
+                }
+                let source = required_module(model, &declarator.initializer()?.expression().ok()?)?;
+                let names = match declarator.id().ok()? {
+                    AnyJsBindingPattern::AnyJsBinding(binding) => {
+                        if !is_never_assigned(model, &binding) {
+                            return None;
+                        }
+                        RequiredNames::Default(binding)
+                    }
+                    AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
+                        let mut names = Vec::new();
+                        for property in pattern.properties() {
+                            names.push(required_name(model, property.ok()?)?);
+                        }
+                        RequiredNames::Named(names)
+                    }
+                    AnyJsBindingPattern::JsArrayBindingPattern(_) => return None,
+                };
+                Some((names, source))
+            }
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, (names, source): &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        let source = make::js_module_source(source.clone());
+        let clause: AnyJsImportClause = match names {
+            RequiredNames::None => make::js_import_bare_clause(source).build().into(),
+            RequiredNames::Default(binding) => make::js_import_default_clause(
+                make::js_default_import_specifier(binding.clone().trim_trivia()?),
+                make::token_decorated_with_space(T![from]),
+                source,
+            )
+            .build()
+            .into(),
+            RequiredNames::Named(names) => {
+                let mut specifiers = Vec::with_capacity(names.len());
+                for (name, local_name) in names {
+                    let specifier: AnyJsNamedImportSpecifier = match local_name {
+                        Some(local_name) => make::js_named_import_specifier(
+                            make::js_literal_export_name(name.clone()),
+                            make::token_decorated_with_space(T![as]),
+                            local_name.clone().trim_trivia()?,
+                        )
+                        .build()
+                        .into(),
+                        None => make::js_shorthand_named_import_specifier(
+                            make::js_identifier_binding(name.clone()).into(),
+                        )
+                        .build()
+                        .into(),
+                    };
+                    specifiers.push(specifier);
+                }
+                let separators = (1..names.len()).map(|_| token_with_trailing_space(T![,]));
+                make::js_import_named_clause(
+                    make::js_named_import_specifiers(
+                        token_with_trailing_space(T!['{']),
+                        make::js_named_import_specifier_list(specifiers, separators),
+                        token_with_leading_space(T!['}']),
+                    ),
+                    make::token_decorated_with_space(T![from]),
+                    source,
+                )
+                .build()
+                .into()
+            }
+        };
+        let import = make::js_import(token_with_trailing_space(T![import]), clause)
+            .with_semicolon_token(make::token(T![;]))
+            .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(
+            statement.syntax().clone().into(),
+            import.into_syntax().into(),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to an "<Emphasis>"import"</Emphasis>" declaration" }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the string of the module if `expression` calls the global
+/// `require` function with a string
+fn required_module(model: &SemanticModel, expression: &AnyJsExpression) -> Option<JsSyntaxToken> {
+    let AnyJsExpression::JsCallExpression(call) = expression else {
+        return None;
+    };
+    if !is_global_require(model, call) {
+        return None;
+    }
+    let arguments = call.arguments().ok()?.args();
+    if arguments.len() != 1 {
+        return None;
+    }
+    let Some(Ok(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+        AnyJsLiteralExpression::JsStringLiteralExpression(string),
+    )))) = arguments.first()
+    else {
+        return None;
+    };
+    let token = string.value_token().ok()?;
+    Some(token.with_leading_trivia([]).with_trailing_trivia([]))
+}
+
+/// Returns `true` if `call` calls the global `require` function
+fn is_global_require(model: &SemanticModel, call: &JsCallExpression) -> bool {
+    if call.is_optional_chain() || call.type_arguments().is_some() {
+        return false;
+    }
+    let Ok(AnyJsExpression::JsIdentifierExpression(callee)) = call.callee() else {
+        return false;
+    };
+    callee.name().is_ok_and(|name| {
+        name.value_token()
+            .is_ok_and(|token| token.text_trimmed() == "require")
+            && model.binding(&name).is_none()
+    })
+}
+
+/// Returns the name of the property declared by `member`, and its local name
+/// if it's renamed
+fn required_name(
+    model: &SemanticModel,
+    member: AnyJsObjectBindingPatternMember,
+) -> Option<(JsSyntaxToken, Option<AnyJsBinding>)> {
+    match member {
+        AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(property) => {
+            if property.init().is_some() {
+                return None;
+            }
+            let binding = property.identifier().ok()?;
+            if !is_never_assigned(model, &binding) {
+                return None;
+            }
+            let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+            Some((name.with_leading_trivia([]).with_trailing_trivia([]), None))
+        }
+        AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(property) => {
+            if property.init().is_some() {
+                return None;
+            }
+            let AnyJsObjectMemberName::JsLiteralMemberName(name) = property.member().ok()? else {
+                return None;
+            };
+            let name = name.value().ok()?;
+            if !matches!(
+                name.kind(),
+                JsSyntaxKind::IDENT | JsSyntaxKind::JS_STRING_LITERAL
+            ) {
+                return None;
+            }
+            let AnyJsBindingPattern::AnyJsBinding(binding) = property.pattern().ok()? else {
+                return None;
+            };
+            if !is_never_assigned(model, &binding) {
+                return None;
+            }
+            Some((
+                name.with_leading_trivia([]).with_trailing_trivia([]),
+                Some(binding),
+            ))
+        }
+        AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(_)
+        | AnyJsObjectBindingPatternMember::JsBogusBinding(_) => None,
+    }
+}
+
+/// Returns `true` if the variable declared by `binding` is never assigned,
+/// because an import can't be assigned
+fn is_never_assigned(model: &SemanticModel, binding: &AnyJsBinding) -> bool {
+    binding
+        .as_js_identifier_binding()
+        .is_some_and(|binding| model.as_binding(binding).all_writes().next().is_none())
+}
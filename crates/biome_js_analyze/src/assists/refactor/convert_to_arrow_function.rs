@@ -0,0 +1,165 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsBindingPattern, AnyJsFunctionBody, JsExport, JsFunctionDeclaration, JsSyntaxKind,
+    TextRange, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::semantic_services::Semantic;
+use crate::utils::refactor::{is_selected, token_with_trailing_space, uses_outer_this};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert the function declaration under the
+    /// cursor to a constant initialized with an arrow function.
+    ///
+    /// The action is available when the cursor is before the body of the
+    /// function. The function must not be a generator, use `this` or
+    /// `arguments`, nor be used before its declaration.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor on `double` in:
+    ///
+    /// ```js
+    /// function double(value) {
+    ///     return value * 2;
+    /// }
+    /// ```
+    ///
+    /// Converts the function to an arrow function:
+    ///
+    /// ```js
+    /// const double = (value) => {
+    ///     return value * 2;
+    /// };
+    /// ```
+    pub(crate) ConvertToArrowFunction {
+        version: "next",
+        name: "convertToArrowFunction",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ConvertToArrowFunction {
+    type Query = Semantic<JsFunctionDeclaration>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let head_end = match function.return_type_annotation() {
+            Some(annotation) => annotation.range().end(),
+            None => function.parameters().ok()?.range().end(),
+        };
+        let head = TextRange::new(function.range().start(), head_end);
+        if !is_selected(ctx.root().syntax(), ctx.selection()?, head)
+            || function.star_token().is_some()
+            || uses_outer_this(function.syntax())
+        {
+            return None;
+        }
+
+        // The declaration must be a statement, possibly exported, which isn't
+        // the implementation of overloads
+        let statement = match function.parent::<JsExport>() {
+            Some(export) => export.into_syntax(),
+            None => function.syntax().clone(),
+        };
+        if !matches!(
+            statement.parent()?.kind(),
+            JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+        ) {
+            return None;
+        }
+        let is_overloaded = statement.prev_sibling().is_some_and(|sibling| {
+            sibling.kind() == JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+                || JsExport::cast(sibling)
+                    .and_then(|export| export.export_clause().ok())
+                    .is_some_and(|clause| {
+                        clause.syntax().kind() == JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+                    })
+        });
+        if is_overloaded {
+            return None;
+        }
+
+        // The constant can't be used before its declaration, nor assigned
+        let id = function.id().ok()?;
+        let id = id.as_js_identifier_binding()?;
+        if model.as_binding(id).all_writes().next().is_some() {
+            return None;
+        }
+        let start = function.range().start();
+        let is_used_before = id
+            .all_references(model)
+            .any(|reference| reference.syntax().text_trimmed_range().start() < start);
+        (!is_used_before).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let function = ctx.query();
+
+        let mut arrow = make::js_arrow_function_expression(
+            function.parameters().ok()?.trim_trivia()?.into(),
+            make::token_decorated_with_space(T![=>]),
+            AnyJsFunctionBody::JsFunctionBody(function.body().ok()?.trim_trivia()?),
+        );
+        if let Some(async_token) = function.async_token() {
+            arrow = arrow.with_async_token(token_with_trailing_space(async_token.kind()));
+        }
+        if let Some(type_parameters) = function.type_parameters() {
+            arrow = arrow.with_type_parameters(type_parameters.trim_trivia()?);
+        }
+        if let Some(return_type_annotation) = function.return_type_annotation() {
+            arrow = arrow.with_return_type_annotation(return_type_annotation.trim_trivia()?);
+        }
+        let declaration = make::js_variable_declaration(
+            token_with_trailing_space(T![const]),
+            make::js_variable_declarator_list(
+                [
+                    make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                        function.id().ok()?.trim_trivia()?,
+                    ))
+                    .with_initializer(make::js_initializer_clause(
+                        make::token_decorated_with_space(T![=]),
+                        arrow.build().into(),
+                    ))
+                    .build(),
+                ],
+                [],
+            ),
+        )
+        .build();
+        let declaration = if function.parent::<JsExport>().is_some() {
+            make::js_variable_declaration_clause(declaration)
+                .with_semicolon_token(make::token(T![;]))
+                .build()
+                .into_syntax()
+        } else {
+            make::js_variable_statement(declaration)
+                .with_semicolon_token(make::token(T![;]))
+                .build()
+                .into_syntax()
+        };
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(function.syntax().clone().into(), declaration.into());
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to an arrow function" }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,174 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBindingPattern, AnyJsExpression, AnyJsFunctionBody,
+    AnyJsParameter, JsArrowFunctionExpression, JsSyntaxKind, JsVariableDeclaration,
+    JsVariableDeclarator, JsVariableDeclaratorList, JsVariableKind, TextRange, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::semantic_services::Semantic;
+use crate::utils::refactor::{
+    function_body_returning, indentation_unit, is_selected, line_indentation,
+    token_with_trailing_space, uses_outer_this,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert the variable initialized with an
+    /// arrow function under the cursor to a function declaration.
+    ///
+    /// The action is available when the cursor is before the body of the
+    /// arrow function. The arrow function must not use `this` or `arguments`,
+    /// and the variable must not be assigned.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor on `double` in:
+    ///
+    /// ```js
+    /// const double = (value) => value * 2;
+    /// ```
+    ///
+    /// Converts the arrow function to a function declaration:
+    ///
+    /// ```js
+    /// function double(value) {
+    ///     return value * 2;
+    /// }
+    /// ```
+    pub(crate) ConvertToFunctionDeclaration {
+        version: "next",
+        name: "convertToFunctionDeclaration",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ConvertToFunctionDeclaration {
+    type Query = Semantic<JsVariableDeclarator>;
+    /// The arrow function initializing the variable
+    type State = JsArrowFunctionExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declarator = ctx.query();
+        let id = declarator.id().ok()?;
+        let id = id.as_any_js_binding()?.as_js_identifier_binding()?;
+        let Ok(AnyJsExpression::JsArrowFunctionExpression(arrow)) =
+            declarator.initializer()?.expression()
+        else {
+            return None;
+        };
+        let list = declarator.parent::<JsVariableDeclaratorList>()?;
+        let declaration = list.parent::<JsVariableDeclaration>()?;
+        let head = TextRange::new(
+            declaration.range().start(),
+            arrow.fat_arrow_token().ok()?.text_trimmed_range().end(),
+        );
+        if !is_selected(ctx.root().syntax(), ctx.selection()?, head) {
+            return None;
+        }
+
+        // The declaration must be a statement, possibly exported, only
+        // declaring the function
+        let is_statement = declaration.syntax().parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_VARIABLE_STATEMENT | JsSyntaxKind::JS_VARIABLE_DECLARATION_CLAUSE
+            )
+        });
+        if !is_statement
+            || list.len() != 1
+            || declarator.variable_annotation().is_some()
+            || declaration.variable_kind().ok()? == JsVariableKind::Using
+            || uses_outer_this(arrow.syntax())
+        {
+            return None;
+        }
+        let binding = ctx.model().as_binding(id);
+        if binding.all_writes().next().is_some() {
+            return None;
+        }
+        Some(arrow)
+    }
+
+    fn action(ctx: &RuleContext<Self>, arrow: &Self::State) -> Option<JsRuleAction> {
+        let declarator = ctx.query();
+        let statement = declarator
+            .parent::<JsVariableDeclaratorList>()?
+            .parent::<JsVariableDeclaration>()?
+            .syntax()
+            .parent()?;
+
+        let parameters = match arrow.parameters().ok()? {
+            AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters.trim_trivia()?,
+            AnyJsArrowFunctionParameters::AnyJsBinding(binding) => make::js_parameters(
+                make::token(T!['(']),
+                make::js_parameter_list(
+                    [AnyJsParameter::AnyJsFormalParameter(
+                        make::js_formal_parameter(
+                            make::js_decorator_list([]),
+                            AnyJsBindingPattern::AnyJsBinding(binding.trim_trivia()?),
+                        )
+                        .build()
+                        .into(),
+                    )],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        };
+        let body = match arrow.body().ok()? {
+            AnyJsFunctionBody::JsFunctionBody(body) => body,
+            AnyJsFunctionBody::AnyJsExpression(expression) => function_body_returning(
+                expression,
+                &line_indentation(&statement.first_token()?),
+                &indentation_unit(ctx.root().syntax()),
+            )?,
+        };
+        let body = body.trim_trivia()?;
+        let l_curly_token = body.l_curly_token().ok()?;
+        let body = body.with_l_curly_token(
+            l_curly_token.with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        );
+
+        let mut function = make::js_function_declaration(
+            token_with_trailing_space(T![function]),
+            declarator
+                .id()
+                .ok()?
+                .as_any_js_binding()?
+                .clone()
+                .trim_trivia()?,
+            parameters,
+            body,
+        );
+        if let Some(async_token) = arrow.async_token() {
+            function = function.with_async_token(token_with_trailing_space(async_token.kind()));
+        }
+        if let Some(type_parameters) = arrow.type_parameters() {
+            function = function.with_type_parameters(type_parameters.trim_trivia()?);
+        }
+        if let Some(return_type_annotation) = arrow.return_type_annotation() {
+            function = function.with_return_type_annotation(return_type_annotation.trim_trivia()?);
+        }
+        let function = function.build();
+
+        // The function replaces the statement, or the clause of an export
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(statement.into(), function.into_syntax().into());
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert to a function declaration" }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,124 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsTemplateElement, JsExpressionStatement,
+    JsTemplateExpression,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+use crate::utils::refactor::is_selected;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert the template literal under the
+    /// cursor to a string.
+    ///
+    /// The template literal must not have a tag nor substitutions.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor in the template literal:
+    ///
+    /// ```js
+    /// const message = `Hello, world!`;
+    /// ```
+    ///
+    /// Converts it to a string:
+    ///
+    /// ```js
+    /// const message = "Hello, world!";
+    /// ```
+    pub(crate) ConvertToStringLiteral {
+        version: "next",
+        name: "convertToStringLiteral",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for ConvertToStringLiteral {
+    type Query = Ast<JsTemplateExpression>;
+    /// The text of the template literal
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let template = ctx.query();
+        if !is_selected(ctx.root().syntax(), ctx.selection()?, template.range())
+            || template.tag().is_some()
+            || template.type_arguments().is_some()
+        {
+            return None;
+        }
+        // A string at the start of a body could become a directive
+        if template.parent::<JsExpressionStatement>().is_some() {
+            return None;
+        }
+        let mut text = String::new();
+        for element in template.elements().iter() {
+            let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element else {
+                return None;
+            };
+            text.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+        }
+        Some(text)
+    }
+
+    fn action(ctx: &RuleContext<Self>, text: &Self::State) -> Option<JsRuleAction> {
+        let template = ctx.query();
+        let string = make::js_string_literal_expression(make::js_string_literal(&escape(text)));
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::from(template.clone()),
+            AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::from(string)),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::Always,
+            message: markup! { "Convert to a string" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the text of a template literal escaped for a string delimited with
+/// double quotes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // These characters are only escaped in template literals
+                Some(c @ ('`' | '$')) => escaped.push(c),
+                Some(c) => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                    // A line continuation may end with `\r\n`
+                    if c == '\r' && chars.peek() == Some(&'\n') {
+                        escaped.push('\n');
+                        chars.next();
+                    }
+                }
+                None => escaped.push('\\'),
+            },
+            '"' => escaped.push_str("\\\""),
+            // The line breaks of a template literal are normalized to `\n`
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                escaped.push_str("\\n");
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
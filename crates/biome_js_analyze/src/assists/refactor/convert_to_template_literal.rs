@@ -0,0 +1,107 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, JsInitializerClause, JsStringLiteralExpression, TsEnumMember, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::utils::refactor::is_selected;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to convert the string under the cursor to a
+    /// template literal.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor in the string:
+    ///
+    /// ```js
+    /// const message = "Hello, world!";
+    /// ```
+    ///
+    /// Converts it to a template literal:
+    ///
+    /// ```js
+    /// const message = `Hello, world!`;
+    /// ```
+    pub(crate) ConvertToTemplateLiteral {
+        version: "next",
+        name: "convertToTemplateLiteral",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for ConvertToTemplateLiteral {
+    type Query = Ast<JsStringLiteralExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let string = ctx.query();
+        if !is_selected(ctx.root().syntax(), ctx.selection()?, string.range()) {
+            return None;
+        }
+        // The value of an enum member must be a string literal
+        let is_enum_member = string
+            .parent::<JsInitializerClause>()
+            .and_then(|initializer| initializer.parent::<TsEnumMember>())
+            .is_some();
+        (!is_enum_member).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let string = ctx.query();
+        let text = string.inner_string_text().ok()?;
+        let template = make::js_template_expression(
+            make::token(T!['`']),
+            make::js_template_element_list([make::js_template_chunk_element(
+                make::js_template_chunk(&unescape_quotes(text.text())),
+            )
+            .into()]),
+            make::token(T!['`']),
+        )
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::AnyJsLiteralExpression(string.clone().into()),
+            AnyJsExpression::from(template),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::Always,
+            message: markup! { "Convert to a template literal" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `text` without the backslashes escaping quotes, which don't need
+/// to be escaped in a template literal
+fn unescape_quotes(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(quote @ ('\'' | '"')) => unescaped.push(quote),
+            Some(escaped) => {
+                unescaped.push('\\');
+                unescaped.push(escaped);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
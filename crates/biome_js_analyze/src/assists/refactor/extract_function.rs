@@ -0,0 +1,717 @@
+use std::ops::Range;
+
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_control_flow::InstructionKind;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsAssignmentPattern, AnyJsBindingPattern,
+    AnyJsCallArgument, AnyJsExpression, AnyJsIdentifierUsage, AnyJsParameter, AnyJsRoot,
+    AnyJsStatement, AnyTsType, JsBreakStatement, JsContinueStatement, JsForOfStatement,
+    JsFunctionBody, JsIdentifierBinding, JsLabeledStatement, JsReferenceIdentifier, JsSyntaxKind,
+    JsSyntaxNode, JsVariableKind, JsVariableStatement, TextRange, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::control_flow::{AnyJsControlFlowRoot, SemanticControlFlowGraph};
+use crate::utils::refactor::{
+    indentation, indentation_unit, is_function_boundary, is_this_boundary, on_new_line, reindent,
+    token_with_leading_space, token_with_trailing_space, trim_selection, unused_name,
+    AnyJsStatementList,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to extract the selected statements to a function.
+    ///
+    /// The function is declared at the top level of the file, after the
+    /// declaration containing the statements. The local variables the
+    /// statements read become its parameters, and the variables they declare
+    /// or assign for the code after them become its return value.
+    ///
+    /// The action isn't available when the statements can't run in another
+    /// function, e.g. when they use `this`, `yield`, or `break` out of a loop
+    /// that isn't selected. The statements can only `return` when they end
+    /// the body of their function.
+    ///
+    /// ## Examples
+    ///
+    /// Selecting the first two statements of the body in:
+    ///
+    /// ```js
+    /// function checkout(cart, discount) {
+    ///     const subtotal = cart.total();
+    ///     const total = subtotal - discount;
+    ///     pay(total);
+    /// }
+    /// ```
+    ///
+    /// Extracts them to the function `newFunction`:
+    ///
+    /// ```js
+    /// function checkout(cart, discount) {
+    ///     const total = newFunction(cart, discount);
+    ///     pay(total);
+    /// }
+    ///
+    /// function newFunction(cart, discount) {
+    ///     const subtotal = cart.total();
+    ///     const total = subtotal - discount;
+    ///     return total;
+    /// }
+    /// ```
+    pub(crate) ExtractFunction {
+        version: "next",
+        name: "extractFunction",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ExtractFunction {
+    type Query = SemanticControlFlowGraph;
+    type State = ExtractedStatements;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let graph = ctx.query();
+        let model = ctx.model();
+        let root = ctx.root();
+        let selection = trim_selection(root.syntax(), ctx.selection()?)?;
+        let (list, items) = selected_statements(&root, selection)?;
+
+        // Each function is visited once, with the graph of its body
+        let function = list
+            .syntax()
+            .ancestors()
+            .find(|node| AnyJsControlFlowRoot::can_cast(node.kind()))?;
+        if function != graph.node {
+            return None;
+        }
+
+        let statements: Vec<_> = list.syntax().children().collect();
+        let statements = &statements[items.clone()];
+        let range = statements
+            .first()?
+            .text_trimmed_range()
+            .cover(statements.last()?.text_trimmed_range());
+
+        let mut is_async = false;
+        for statement in statements {
+            is_async |= check_function_context(statement, range)?;
+        }
+
+        let has_return = graph
+            .blocks
+            .iter()
+            .flat_map(|block| &block.instructions)
+            .filter(|instruction| matches!(instruction.kind, InstructionKind::Return))
+            .filter_map(|instruction| instruction.node.as_ref()?.as_node())
+            .any(|node| {
+                node.kind() == JsSyntaxKind::JS_RETURN_STATEMENT
+                    && range.contains_range(node.text_trimmed_range())
+            });
+        // The statements can return only if nothing runs after them
+        if has_return
+            && !(items.end == list.syntax().children().count()
+                && list
+                    .syntax()
+                    .parent()
+                    .is_some_and(|parent| JsFunctionBody::can_cast(parent.kind())))
+        {
+            return None;
+        }
+
+        let Variables {
+            parameters,
+            outputs,
+        } = Variables::of_statements(model, statements, range)?;
+        if has_return && !matches!(outputs, Outputs::None) {
+            return None;
+        }
+
+        Some(ExtractedStatements {
+            list,
+            items,
+            parameters,
+            outputs,
+            is_async,
+            has_return,
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let root = ctx.root();
+        let root_list = match &root {
+            AnyJsRoot::JsModule(module) => AnyJsStatementList::from(module.items()),
+            AnyJsRoot::JsScript(script) => AnyJsStatementList::from(script.statements()),
+            _ => return None,
+        };
+        let name = unused_name(root.syntax(), "newFunction");
+
+        let statements: Vec<_> = state.list.syntax().children().collect();
+        let statements = &statements[state.items.clone()];
+        let first_token = statements.first()?.first_token()?;
+        let call = state
+            .call_statement(&name)?
+            .with_leading_trivia_pieces(first_token.leading_trivia().pieces())?;
+
+        // The statements keep their indentation relative to the function
+        let unit = indentation_unit(root.syntax());
+        let statement_indentation = indentation(&first_token);
+        let mut body: Vec<_> = statements
+            .iter()
+            .map(|statement| {
+                AnyJsStatement::cast(reindent(statement.clone(), &statement_indentation, &unit))
+            })
+            .collect::<Option<_>>()?;
+        let first_statement = body.first()?.clone();
+        let first_token = first_statement.syntax().first_token()?;
+        body[0] = AnyJsStatement::cast(first_statement.into_syntax().replace_child(
+            first_token.clone().into(),
+            on_new_line(&first_token, &unit).into(),
+        )?)?;
+        if let Some(statement) = state.outputs.return_statement() {
+            body.push(
+                statement.with_leading_trivia_pieces(
+                    on_new_line(&make::token(T![return]), &unit)
+                        .leading_trivia()
+                        .pieces(),
+                )?,
+            );
+        }
+        let function = state.function_declaration(&name, body)?;
+
+        let new_root_list = if state.list == root_list {
+            root_list.splice(
+                state.items.clone(),
+                [call.into_syntax(), function.into_syntax()],
+            )?
+        } else {
+            let new_list = state
+                .list
+                .splice(state.items.clone(), [call.into_syntax()])?;
+            let top_item = state
+                .list
+                .syntax()
+                .ancestors()
+                .find(|node| node.parent().as_ref() == Some(root_list.syntax()))?;
+            let new_top_item = top_item.clone().replace_child(
+                state.list.syntax().clone().into(),
+                new_list.into_syntax().into(),
+            )?;
+            let index = top_item.index();
+            root_list.splice(index..index + 1, [new_top_item, function.into_syntax()])?
+        };
+
+        let mut mutation = root.begin();
+        mutation.replace_node_discard_trivia(root_list, new_root_list);
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Extract),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Extract to a function" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+pub(crate) struct ExtractedStatements {
+    /// The list containing the statements
+    list: AnyJsStatementList,
+    /// The indices of the statements in `list`
+    items: Range<usize>,
+    /// The names of the parameters of the function
+    parameters: Vec<String>,
+    outputs: Outputs,
+    /// Whether the statements use `await`
+    is_async: bool,
+    /// Whether the statements return from their function
+    has_return: bool,
+}
+
+/// The variables that the statements set for the code after them
+enum Outputs {
+    None,
+    /// The variables declared by the statements, with the kind of their
+    /// declaration
+    Declared(JsVariableKind, Vec<String>),
+    /// The variables declared before the statements, and assigned by them
+    Assigned(Vec<String>),
+}
+
+impl ExtractedStatements {
+    /// Returns the statement calling the function `name`
+    fn call_statement(&self, name: &str) -> Option<AnyJsStatement> {
+        let arguments = self.parameters.iter().map(|parameter| {
+            AnyJsCallArgument::AnyJsExpression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    parameter,
+                )))
+                .into(),
+            )
+        });
+        let separators = (1..self.parameters.len()).map(|_| token_with_trailing_space(T![,]));
+        let mut call: AnyJsExpression = make::js_call_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(name))).into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(arguments, separators),
+                make::token(T![')']),
+            ),
+        )
+        .build()
+        .into();
+        if self.is_async {
+            call = make::js_await_expression(token_with_trailing_space(T![await]), call).into();
+        }
+
+        let statement = match &self.outputs {
+            Outputs::None if self.has_return => make::js_return_statement(
+                make::token(T![return]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )
+            .with_argument(call)
+            .with_semicolon_token(make::token(T![;]))
+            .build()
+            .into(),
+            Outputs::None => make::js_expression_statement(call)
+                .with_semicolon_token(make::token(T![;]))
+                .build()
+                .into(),
+            Outputs::Declared(kind, names) => {
+                let kind = match kind {
+                    JsVariableKind::Const => T![const],
+                    JsVariableKind::Let => T![let],
+                    JsVariableKind::Var => T![var],
+                    JsVariableKind::Using => T![using],
+                };
+                let id: AnyJsBindingPattern = match names.as_slice() {
+                    [name] => AnyJsBindingPattern::AnyJsBinding(
+                        make::js_identifier_binding(make::ident(name)).into(),
+                    ),
+                    names => object_binding_pattern(names).into(),
+                };
+                make::js_variable_statement(
+                    make::js_variable_declaration(
+                        make::token(kind)
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                        make::js_variable_declarator_list(
+                            [make::js_variable_declarator(id)
+                                .with_initializer(make::js_initializer_clause(
+                                    make::token_decorated_with_space(T![=]),
+                                    call,
+                                ))
+                                .build()],
+                            [],
+                        ),
+                    )
+                    .build(),
+                )
+                .with_semicolon_token(make::token(T![;]))
+                .build()
+                .into()
+            }
+            Outputs::Assigned(names) => {
+                let (left, is_pattern): (AnyJsAssignmentPattern, _) = match names.as_slice() {
+                    [name] => (
+                        AnyJsAssignmentPattern::AnyJsAssignment(
+                            make::js_identifier_assignment(make::ident(name)).into(),
+                        ),
+                        false,
+                    ),
+                    names => (object_assignment_pattern(names).into(), true),
+                };
+                let mut assignment: AnyJsExpression = make::js_assignment_expression(
+                    left,
+                    make::token_decorated_with_space(T![=]),
+                    call,
+                )
+                .into();
+                // An object pattern at the start of a statement would be a block
+                if is_pattern {
+                    assignment = make::js_parenthesized_expression(
+                        make::token(T!['(']),
+                        assignment,
+                        make::token(T![')']),
+                    )
+                    .into();
+                }
+                make::js_expression_statement(assignment)
+                    .with_semicolon_token(make::token(T![;]))
+                    .build()
+                    .into()
+            }
+        };
+        Some(statement)
+    }
+
+    /// Returns the declaration of the function `name`, with the statements
+    /// `body`
+    fn function_declaration(
+        &self,
+        name: &str,
+        body: Vec<AnyJsStatement>,
+    ) -> Option<AnyJsStatement> {
+        let parameters = self.parameters.iter().map(|parameter| {
+            AnyJsParameter::AnyJsFormalParameter(
+                make::js_formal_parameter(
+                    make::js_decorator_list([]),
+                    AnyJsBindingPattern::AnyJsBinding(
+                        make::js_identifier_binding(make::ident(parameter)).into(),
+                    ),
+                )
+                .build()
+                .into(),
+            )
+        });
+        let separators = (1..self.parameters.len()).map(|_| token_with_trailing_space(T![,]));
+
+        let function_token =
+            make::token(T![function]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let empty_line = [
+            (TriviaPieceKind::Newline, "\n"),
+            (TriviaPieceKind::Newline, "\n"),
+        ];
+        let mut function = make::js_function_declaration(
+            if self.is_async {
+                function_token
+            } else {
+                function_token.with_leading_trivia(empty_line)
+            },
+            make::js_identifier_binding(make::ident(name)).into(),
+            make::js_parameters(
+                make::token(T!['(']),
+                make::js_parameter_list(parameters, separators),
+                make::token(T![')']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ),
+            make::js_function_body(
+                make::token(T!['{']),
+                make::js_directive_list([]),
+                make::js_statement_list(body),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
+            ),
+        );
+        if self.is_async {
+            function = function.with_async_token(
+                make::token(T![async])
+                    .with_leading_trivia(empty_line)
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+        }
+        Some(function.build().into())
+    }
+}
+
+impl Outputs {
+    /// Returns the statement returning the outputs from the function
+    fn return_statement(&self) -> Option<AnyJsStatement> {
+        let names = match self {
+            Outputs::None => return None,
+            Outputs::Declared(_, names) | Outputs::Assigned(names) => names,
+        };
+        let argument: AnyJsExpression = match names.as_slice() {
+            [name] => {
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(name)))
+                    .into()
+            }
+            names => {
+                let members = names.iter().map(|name| {
+                    make::js_shorthand_property_object_member(make::js_reference_identifier(
+                        make::ident(name),
+                    ))
+                    .into()
+                });
+                let separators = (1..names.len()).map(|_| token_with_trailing_space(T![,]));
+                make::js_object_expression(
+                    token_with_trailing_space(T!['{']),
+                    make::js_object_member_list(members, separators),
+                    token_with_leading_space(T!['}']),
+                )
+                .into()
+            }
+        };
+        Some(
+            make::js_return_statement(
+                make::token(T![return]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )
+            .with_argument(argument)
+            .with_semicolon_token(make::token(T![;]))
+            .build()
+            .into(),
+        )
+    }
+}
+
+/// Returns the pattern `{ a, b }` declaring `names`
+fn object_binding_pattern(names: &[String]) -> biome_js_syntax::JsObjectBindingPattern {
+    let properties = names.iter().map(|name| {
+        make::js_object_binding_pattern_shorthand_property(
+            make::js_identifier_binding(make::ident(name)).into(),
+        )
+        .build()
+        .into()
+    });
+    let separators = (1..names.len()).map(|_| token_with_trailing_space(T![,]));
+    make::js_object_binding_pattern(
+        token_with_trailing_space(T!['{']),
+        make::js_object_binding_pattern_property_list(properties, separators),
+        token_with_leading_space(T!['}']),
+    )
+}
+
+/// Returns the pattern `{ a, b }` assigning `names`
+fn object_assignment_pattern(names: &[String]) -> biome_js_syntax::JsObjectAssignmentPattern {
+    let properties = names.iter().map(|name| {
+        make::js_object_assignment_pattern_shorthand_property(make::js_identifier_assignment(
+            make::ident(name),
+        ))
+        .build()
+        .into()
+    });
+    let separators = (1..names.len()).map(|_| token_with_trailing_space(T![,]));
+    make::js_object_assignment_pattern(
+        token_with_trailing_space(T!['{']),
+        make::js_object_assignment_pattern_property_list(properties, separators),
+        token_with_leading_space(T!['}']),
+    )
+}
+
+/// Returns the list of statements containing the selection, and the indices
+/// of its selected statements
+fn selected_statements(
+    root: &AnyJsRoot,
+    selection: TextRange,
+) -> Option<(AnyJsStatementList, Range<usize>)> {
+    let list = root
+        .syntax()
+        .covering_element(selection)
+        .ancestors()
+        .find_map(AnyJsStatementList::cast)?;
+
+    let mut start = None;
+    let mut end = None;
+    for (index, item) in list.syntax().children().enumerate() {
+        let range = item.text_trimmed_range();
+        if selection.contains_range(range) {
+            // Only statements can be moved to the body of a function
+            if end.is_some() || !AnyJsStatement::can_cast(item.kind()) {
+                return None;
+            }
+            start.get_or_insert(index);
+        } else if range
+            .intersect(selection)
+            .is_some_and(|range| !range.is_empty())
+        {
+            return None;
+        } else if start.is_some() {
+            end.get_or_insert(index);
+        }
+    }
+    let start = start?;
+    let end = end.unwrap_or_else(|| list.syntax().children().count());
+    Some((list, start..end))
+}
+
+/// Checks that `statement`, which is in the selected `range`, can run in
+/// another function. Returns whether it uses `await`.
+fn check_function_context(statement: &JsSyntaxNode, range: TextRange) -> Option<bool> {
+    let mut is_async = false;
+    for node in statement.descendants() {
+        match node.kind() {
+            JsSyntaxKind::JS_THIS_EXPRESSION
+            | JsSyntaxKind::JS_SUPER_EXPRESSION
+            | JsSyntaxKind::JS_NEW_TARGET_EXPRESSION => {
+                if !is_inside(&node, range, is_this_boundary) {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_REFERENCE_IDENTIFIER => {
+                let is_arguments = JsReferenceIdentifier::cast_ref(&node)
+                    .and_then(|reference| reference.value_token().ok())
+                    .is_some_and(|token| token.text_trimmed() == "arguments");
+                if is_arguments && !is_inside(&node, range, is_this_boundary) {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_YIELD_EXPRESSION => {
+                if !is_inside(&node, range, is_function_boundary) {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_AWAIT_EXPRESSION => {
+                is_async |= !is_inside(&node, range, is_function_boundary);
+            }
+            JsSyntaxKind::JS_FOR_OF_STATEMENT => {
+                let is_await = JsForOfStatement::cast_ref(&node)
+                    .is_some_and(|statement| statement.await_token().is_some());
+                is_async |= is_await && !is_inside(&node, range, is_function_boundary);
+            }
+            JsSyntaxKind::JS_BREAK_STATEMENT | JsSyntaxKind::JS_CONTINUE_STATEMENT => {
+                if !is_inside(&node, range, is_function_boundary) && !jumps_inside(&node, range) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(is_async)
+}
+
+/// Returns `true` if `node` is inside a node matching `is_boundary`, which is
+/// inside `range`
+fn is_inside(
+    node: &JsSyntaxNode,
+    range: TextRange,
+    is_boundary: fn(&JsSyntaxNode) -> bool,
+) -> bool {
+    node.ancestors()
+        .skip(1)
+        .take_while(|ancestor| range.contains_range(ancestor.text_trimmed_range()))
+        .any(|ancestor| is_boundary(&ancestor))
+}
+
+/// Returns `true` if the `break` or `continue` statement `node` jumps to a
+/// statement inside `range`
+fn jumps_inside(node: &JsSyntaxNode, range: TextRange) -> bool {
+    let is_continue = node.kind() == JsSyntaxKind::JS_CONTINUE_STATEMENT;
+    let label = JsBreakStatement::cast_ref(node)
+        .and_then(|statement| statement.label())
+        .or_else(|| JsContinueStatement::cast_ref(node).and_then(|statement| statement.label()))
+        .and_then(|label| label.value_token().ok());
+    node.ancestors()
+        .skip(1)
+        .take_while(|ancestor| range.contains_range(ancestor.text_trimmed_range()))
+        .any(|ancestor| match &label {
+            Some(label) => JsLabeledStatement::cast(ancestor)
+                .and_then(|statement| statement.label().ok()?.value_token().ok())
+                .is_some_and(|token| token.text_trimmed() == label.text_trimmed()),
+            None => match ancestor.kind() {
+                JsSyntaxKind::JS_FOR_STATEMENT
+                | JsSyntaxKind::JS_FOR_IN_STATEMENT
+                | JsSyntaxKind::JS_FOR_OF_STATEMENT
+                | JsSyntaxKind::JS_WHILE_STATEMENT
+                | JsSyntaxKind::JS_DO_WHILE_STATEMENT => true,
+                JsSyntaxKind::JS_SWITCH_STATEMENT => !is_continue,
+                _ => false,
+            },
+        })
+}
+
+/// The variables flowing in and out of the selected statements
+struct Variables {
+    parameters: Vec<String>,
+    outputs: Outputs,
+}
+
+impl Variables {
+    /// Returns the variables of the `statements` covering `range`, or `None`
+    /// if some of them can't be passed to or returned from a function
+    fn of_statements(
+        model: &SemanticModel,
+        statements: &[JsSyntaxNode],
+        range: TextRange,
+    ) -> Option<Self> {
+        let global_scope = model.global_scope();
+        let name = |binding: &Binding| {
+            binding
+                .tree()
+                .name_token()
+                .ok()
+                .map(|token| token.text_trimmed().to_string())
+        };
+        let is_outside = |node: &JsSyntaxNode| !range.contains_range(node.text_trimmed_range());
+
+        // The declarations and names of the variables
+        let mut parameters: Vec<(JsSyntaxNode, String)> = Vec::new();
+        let mut assigned: Vec<(JsSyntaxNode, String)> = Vec::new();
+        let usages = statements
+            .iter()
+            .flat_map(|statement| statement.descendants())
+            .filter_map(AnyJsIdentifierUsage::cast);
+        for usage in usages {
+            let binding = match &usage {
+                AnyJsIdentifierUsage::JsReferenceIdentifier(node) => model.binding(node),
+                AnyJsIdentifierUsage::JsIdentifierAssignment(node) => model.binding(node),
+                AnyJsIdentifierUsage::JsxReferenceIdentifier(node) => model.binding(node),
+            };
+            // The function can read the global variables
+            let Some(binding) = binding else { continue };
+            if !is_outside(binding.syntax()) || binding.scope() == global_scope {
+                continue;
+            }
+            // A type can't be passed as a parameter
+            if usage
+                .syntax()
+                .ancestors()
+                .any(|node| AnyTsType::can_cast(node.kind()))
+            {
+                return None;
+            }
+            let variable = (binding.syntax().clone(), name(&binding)?);
+            let is_read_after = binding
+                .all_references()
+                .any(|reference| is_outside(reference.syntax()));
+            let is_write = matches!(usage, AnyJsIdentifierUsage::JsIdentifierAssignment(_));
+            if is_write && is_read_after && !assigned.contains(&variable) {
+                assigned.push(variable.clone());
+            }
+            if !parameters.contains(&variable) {
+                parameters.push(variable);
+            }
+        }
+
+        let mut declared = Vec::new();
+        let mut declared_kind = None;
+        let bindings = statements
+            .iter()
+            .flat_map(|statement| statement.descendants())
+            .filter_map(JsIdentifierBinding::cast);
+        for identifier in bindings {
+            let binding = model.as_binding(&identifier);
+            if !binding
+                .all_references()
+                .any(|reference| is_outside(reference.syntax()))
+            {
+                continue;
+            }
+            // Only the variables declared by the selected statements
+            // themselves can be declared again around the call
+            let Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) =
+                identifier.declaration()
+            else {
+                return None;
+            };
+            let statement = declarator
+                .syntax()
+                .ancestors()
+                .find_map(JsVariableStatement::cast)?;
+            if !statements.contains(statement.syntax()) {
+                return None;
+            }
+            let kind = statement.declaration().ok()?.variable_kind().ok()?;
+            if kind == JsVariableKind::Using || declared_kind.is_some_and(|other| other != kind) {
+                return None;
+            }
+            declared_kind = Some(kind);
+            declared.push(name(&binding)?);
+        }
+
+        let outputs = match (declared_kind, assigned.is_empty()) {
+            (None, true) => Outputs::None,
+            (Some(kind), true) => Outputs::Declared(kind, declared),
+            (None, false) => {
+                Outputs::Assigned(assigned.into_iter().map(|(_, name)| name).collect())
+            }
+            (Some(_), false) => return None,
+        };
+        Some(Self {
+            parameters: parameters.into_iter().map(|(_, name)| name).collect(),
+            outputs,
+        })
+    }
+}
@@ -0,0 +1,276 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsBindingPattern, AnyJsExpression, AnyJsIdentifierUsage, AnyJsOptionalChainExpression,
+    JsAssignmentExpression, JsCallExpression, JsConditionalExpression, JsDoWhileStatement,
+    JsExpressionStatement, JsForInStatement, JsForOfStatement, JsForStatement, JsIfStatement,
+    JsInitializerClause, JsLogicalExpression, JsSwitchStatement, JsSyntaxNode,
+    JsTemplateExpression, JsUnaryExpression, JsUnaryOperator, JsVariableDeclarator,
+    JsWhileStatement, JsWithStatement, TsEnumMember, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+
+use crate::semantic_services::Semantic;
+use crate::utils::refactor::{
+    indentation, is_function_boundary, on_new_line, replace_descendant, trim_selection,
+    unused_name, AnyJsStatementList,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to extract the selected expression to a constant.
+    ///
+    /// The constant is declared right before the statement containing the
+    /// expression. The action isn't available when this statement may
+    /// evaluate the expression several times or not at all, e.g. for the
+    /// right operand of a logical expression or the condition of a loop.
+    ///
+    /// ## Examples
+    ///
+    /// Selecting `price * quantity` in:
+    ///
+    /// ```js
+    /// function total(price, quantity) {
+    ///     return price * quantity + shipping;
+    /// }
+    /// ```
+    ///
+    /// Extracts the expression to the constant `newVariable`:
+    ///
+    /// ```js
+    /// function total(price, quantity) {
+    ///     const newVariable = price * quantity;
+    ///     return newVariable + shipping;
+    /// }
+    /// ```
+    pub(crate) ExtractVariable {
+        version: "next",
+        name: "extractVariable",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for ExtractVariable {
+    type Query = Semantic<AnyJsExpression>;
+    /// The statement before which the constant is declared
+    type State = JsSyntaxNode;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let expression = ctx.query();
+        let selection = trim_selection(ctx.root().syntax(), ctx.selection()?)?;
+        if expression.range() != selection || !is_extractable(expression) {
+            return None;
+        }
+        // Only the outermost expression matching the selection is extracted,
+        // e.g. the parenthesized expression rather than its content
+        let parent = expression.syntax().parent()?;
+        if AnyJsExpression::cast(parent).is_some_and(|parent| parent.range() == selection) {
+            return None;
+        }
+
+        let statement = enclosing_statement(expression)?;
+        if reads_bindings_declared_by(ctx.model(), expression, &statement) {
+            return None;
+        }
+        Some(statement)
+    }
+
+    fn action(ctx: &RuleContext<Self>, statement: &Self::State) -> Option<JsRuleAction> {
+        let expression = ctx.query();
+        let name = unused_name(ctx.root().syntax(), "newVariable");
+
+        let reference =
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(&name)));
+        let new_statement =
+            replace_descendant(statement, expression.syntax(), reference.into_syntax())?;
+
+        // The declaration takes the place of the statement, which moves to
+        // the next line
+        let first_token = new_statement.first_token()?;
+        let indentation = indentation(&first_token);
+        let new_statement = new_statement.replace_child(
+            first_token.clone().into(),
+            on_new_line(&first_token, &indentation).into(),
+        )?;
+
+        let declaration = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![const])
+                    .with_leading_trivia_pieces(first_token.leading_trivia().pieces())
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_variable_declarator_list(
+                    [
+                        make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                            make::js_identifier_binding(make::ident(&name)).into(),
+                        ))
+                        .with_initializer(make::js_initializer_clause(
+                            make::token_decorated_with_space(T![=]),
+                            expression.clone().trim_trivia()?,
+                        ))
+                        .build(),
+                    ],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+
+        let list = AnyJsStatementList::of_item(statement)?;
+        let index = statement.index();
+        let new_list = list.splice(index..index + 1, [declaration.into_syntax(), new_statement])?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(list, new_list);
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Extract),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Extract to a constant" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if the value of `expression` can be stored in a constant
+/// without changing the meaning of the code using it
+fn is_extractable(expression: &AnyJsExpression) -> bool {
+    if matches!(expression, AnyJsExpression::JsSuperExpression(_)) {
+        return false;
+    }
+    let Some(parent) = expression.syntax().parent() else {
+        return false;
+    };
+    if JsExpressionStatement::can_cast(parent.kind()) {
+        return false;
+    }
+    if matches!(
+        expression,
+        AnyJsExpression::JsStaticMemberExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_)
+    ) {
+        // A method called through a constant loses its `this`
+        let is_callee = JsCallExpression::cast_ref(&parent)
+            .and_then(|call| call.callee().ok())
+            .is_some_and(|callee| &callee == expression)
+            || JsTemplateExpression::cast_ref(&parent)
+                .and_then(|template| template.tag())
+                .is_some_and(|tag| &tag == expression);
+        if is_callee {
+            return false;
+        }
+    }
+    !JsUnaryExpression::cast(parent)
+        .and_then(|unary| unary.operator().ok())
+        .is_some_and(|operator| operator == JsUnaryOperator::Delete)
+}
+
+/// Returns the item of a statement list which evaluates `expression` exactly
+/// once each time it runs
+fn enclosing_statement(expression: &AnyJsExpression) -> Option<JsSyntaxNode> {
+    let mut child = expression.syntax().clone();
+    for node in expression.syntax().ancestors().skip(1) {
+        if is_function_boundary(&node)
+            || TsEnumMember::can_cast(node.kind())
+            || !evaluates_child_once(&node, &child)
+        {
+            return None;
+        }
+        if AnyJsStatementList::of_item(&node).is_some() {
+            return Some(node);
+        }
+        child = node;
+    }
+    None
+}
+
+/// Returns `true` if evaluating `node` always evaluates its child `child`
+/// exactly once
+fn evaluates_child_once(node: &JsSyntaxNode, child: &JsSyntaxNode) -> bool {
+    let is_child = |node: Option<JsSyntaxNode>| node.as_ref() == Some(child);
+
+    if let Some(logical) = JsLogicalExpression::cast_ref(node) {
+        return !is_child(logical.right().ok().map(AstNode::into_syntax));
+    }
+    if let Some(conditional) = JsConditionalExpression::cast_ref(node) {
+        return is_child(conditional.test().ok().map(AstNode::into_syntax));
+    }
+    if let Some(assignment) = JsAssignmentExpression::cast_ref(node) {
+        let is_logical = assignment
+            .operator_token()
+            .is_ok_and(|operator| matches!(operator.kind(), T![&&=] | T![||=] | T![??=]));
+        return !is_logical || !is_child(assignment.right().ok().map(AstNode::into_syntax));
+    }
+    if let Some(chain) = AnyJsOptionalChainExpression::cast_ref(node) {
+        // The rest of an optional chain isn't evaluated when its object is
+        // nullish
+        let is_optional_chain = |node: &JsSyntaxNode| {
+            AnyJsOptionalChainExpression::cast_ref(node)
+                .is_some_and(|chain| chain.is_optional_chain())
+        };
+        return !is_optional_chain(node)
+            || is_child(chain.object().ok().map(AstNode::into_syntax))
+                && !is_optional_chain(child);
+    }
+    if JsInitializerClause::can_cast(node.kind()) {
+        // The default values of the parameters and of the patterns are only
+        // evaluated when the value is `undefined`
+        return node
+            .parent()
+            .is_some_and(|parent| JsVariableDeclarator::can_cast(parent.kind()));
+    }
+    // Only the head of the statements with a body is evaluated once
+    if let Some(statement) = JsIfStatement::cast_ref(node) {
+        return is_child(statement.test().ok().map(AstNode::into_syntax));
+    }
+    if let Some(statement) = JsForStatement::cast_ref(node) {
+        return is_child(statement.initializer().map(AstNode::into_syntax));
+    }
+    if let Some(statement) = JsForInStatement::cast_ref(node) {
+        return is_child(statement.expression().ok().map(AstNode::into_syntax));
+    }
+    if let Some(statement) = JsForOfStatement::cast_ref(node) {
+        return is_child(statement.expression().ok().map(AstNode::into_syntax));
+    }
+    if let Some(statement) = JsSwitchStatement::cast_ref(node) {
+        return is_child(statement.discriminant().ok().map(AstNode::into_syntax));
+    }
+    if let Some(statement) = JsWithStatement::cast_ref(node) {
+        return is_child(statement.object().ok().map(AstNode::into_syntax));
+    }
+    !JsWhileStatement::can_cast(node.kind()) && !JsDoWhileStatement::can_cast(node.kind())
+}
+
+/// Returns `true` if `expression` reads a binding declared by `statement`:
+/// the binding can't be read before the statement
+fn reads_bindings_declared_by(
+    model: &SemanticModel,
+    expression: &AnyJsExpression,
+    statement: &JsSyntaxNode,
+) -> bool {
+    let statement_range = statement.text_trimmed_range();
+    let expression_range = expression.range();
+    expression
+        .syntax()
+        .descendants()
+        .filter_map(AnyJsIdentifierUsage::cast)
+        .filter_map(|usage| match usage {
+            AnyJsIdentifierUsage::JsReferenceIdentifier(node) => model.binding(&node),
+            AnyJsIdentifierUsage::JsIdentifierAssignment(node) => model.binding(&node),
+            AnyJsIdentifierUsage::JsxReferenceIdentifier(node) => model.binding(&node),
+        })
+        .any(|binding| {
+            let declaration_range = binding.syntax().text_trimmed_range();
+            statement_range.contains_range(declaration_range)
+                && !expression_range.contains_range(declaration_range)
+        })
+}
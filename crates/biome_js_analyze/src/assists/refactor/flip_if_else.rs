@@ -0,0 +1,138 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsStatement, JsBinaryOperator, JsIfStatement, JsSyntaxKind,
+    JsUnaryOperator, OperatorPrecedence, TextRange, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::utils::refactor::is_selected;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to negate the condition of the `if` statement
+    /// under the cursor, and swap its branches.
+    ///
+    /// The action is available when the cursor is in the head of the
+    /// statement, and the statement has an `else` branch which isn't another
+    /// `if` statement.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor on `if` in:
+    ///
+    /// ```js
+    /// if (user.isAdmin) {
+    ///     showDashboard();
+    /// } else {
+    ///     showHome();
+    /// }
+    /// ```
+    ///
+    /// Flips the statement:
+    ///
+    /// ```js
+    /// if (!user.isAdmin) {
+    ///     showHome();
+    /// } else {
+    ///     showDashboard();
+    /// }
+    /// ```
+    pub(crate) FlipIfElse {
+        version: "next",
+        name: "flipIfElse",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for FlipIfElse {
+    type Query = Ast<JsIfStatement>;
+    /// The statement of the `else` branch
+    type State = AnyJsStatement;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        let head = TextRange::new(
+            statement.if_token().ok()?.text_trimmed_range().start(),
+            statement.r_paren_token().ok()?.text_trimmed_range().end(),
+        );
+        if !is_selected(ctx.root().syntax(), ctx.selection()?, head) {
+            return None;
+        }
+        let alternate = statement.else_clause()?.alternate().ok()?;
+        if matches!(alternate, AnyJsStatement::JsIfStatement(_)) {
+            return None;
+        }
+        // An `else` following an `if` without braces would belong to it
+        let has_dangling_if = !matches!(alternate, AnyJsStatement::JsBlockStatement(_))
+            && alternate
+                .syntax()
+                .descendants()
+                .any(|node| node.kind() == JsSyntaxKind::JS_IF_STATEMENT);
+        (!has_dangling_if).then_some(alternate)
+    }
+
+    fn action(ctx: &RuleContext<Self>, alternate: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        let test = statement.test().ok()?;
+        let consequent = statement.consequent().ok()?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(test.clone(), negate(test.trim_trivia()?)?);
+        mutation.replace_node(consequent.clone(), alternate.clone().trim_trivia()?);
+        mutation.replace_node(alternate.clone(), consequent.trim_trivia()?);
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::Always,
+            message: markup! { "Flip the "<Emphasis>"if"</Emphasis>" and "<Emphasis>"else"</Emphasis>" branches" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the negation of `test`
+fn negate(test: AnyJsExpression) -> Option<AnyJsExpression> {
+    match test {
+        AnyJsExpression::JsUnaryExpression(unary)
+            if unary.operator().ok()? == JsUnaryOperator::LogicalNot =>
+        {
+            Some(unary.argument().ok()?.omit_parentheses())
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            let operator = match binary.operator().ok()? {
+                JsBinaryOperator::Equality => T![!=],
+                JsBinaryOperator::Inequality => T![==],
+                JsBinaryOperator::StrictEquality => T![!==],
+                JsBinaryOperator::StrictInequality => T![===],
+                _ => return Some(negate_with_operator(binary.into())),
+            };
+            let operator_token = binary.operator_token().ok()?;
+            let new_operator_token = make::token(operator)
+                .with_leading_trivia_pieces(operator_token.leading_trivia().pieces())
+                .with_trailing_trivia_pieces(operator_token.trailing_trivia().pieces());
+            Some(binary.with_operator_token_token(new_operator_token).into())
+        }
+        test => Some(negate_with_operator(test)),
+    }
+}
+
+/// Returns `!test`
+fn negate_with_operator(test: AnyJsExpression) -> AnyJsExpression {
+    let argument = if test
+        .precedence()
+        .is_ok_and(|precedence| precedence >= OperatorPrecedence::Unary)
+    {
+        test
+    } else {
+        make::parenthesized(test).into()
+    };
+    make::js_unary_expression(make::token(T![!]), argument).into()
+}
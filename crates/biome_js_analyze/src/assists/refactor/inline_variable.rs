@@ -0,0 +1,359 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::{Reference, ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsIdentifierUsage, AnyJsObjectMemberName, AnyJsOptionalChainExpression,
+    JsBinaryExpression, JsComputedMemberExpression, JsIdentifierExpression,
+    JsShorthandPropertyObjectMember, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator,
+    JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList, JsVariableKind, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+
+use crate::semantic_services::Semantic;
+use crate::utils::batch::JsBatchMutation;
+use crate::utils::refactor::{is_function_boundary, is_this_boundary};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to replace the variable under the cursor with
+    /// its value.
+    ///
+    /// The variable must be initialized and never assigned. When it's used
+    /// several times, or in a function or a loop, its value must be cheap to
+    /// evaluate and without side effects, e.g. a literal or another variable.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor on `shipping` in:
+    ///
+    /// ```js
+    /// function total(price) {
+    ///     const shipping = price * 0.1;
+    ///     return price + shipping;
+    /// }
+    /// ```
+    ///
+    /// Inlines the variable:
+    ///
+    /// ```js
+    /// function total(price) {
+    ///     return price + price * 0.1;
+    /// }
+    /// ```
+    pub(crate) InlineVariable {
+        version: "next",
+        name: "inlineVariable",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for InlineVariable {
+    type Query = Semantic<JsVariableDeclarator>;
+    /// The references to the variable
+    type State = Vec<Reference>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declarator = ctx.query();
+        let model = ctx.model();
+        let id = declarator.id().ok()?;
+        let id = id.as_any_js_binding()?.as_js_identifier_binding()?;
+        if !id.range().contains_range(ctx.selection()?) {
+            return None;
+        }
+        let value = declarator.initializer()?.expression().ok()?;
+
+        // The variable must keep its value from its declaration, which can't
+        // be a loop head
+        let declaration = declarator
+            .parent::<JsVariableDeclaratorList>()?
+            .parent::<JsVariableDeclaration>()?;
+        let is_statement = declaration
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == JsSyntaxKind::JS_VARIABLE_STATEMENT);
+        if !is_statement
+            || declaration.variable_kind().ok()? == JsVariableKind::Using
+            || model.is_exported(id)
+        {
+            return None;
+        }
+        let binding = model.as_binding(id);
+        if binding.all_writes().next().is_some() {
+            return None;
+        }
+
+        let references: Vec<_> = id.all_references(model).collect();
+        let declarator_range = declarator.range();
+        let mut is_evaluated_once = references.len() == 1;
+        for reference in &references {
+            let node = reference.syntax();
+            if reference.is_using_hoisted_declaration()
+                || declarator_range.contains_range(node.text_trimmed_range())
+            {
+                return None;
+            }
+            let parent = node.parent()?;
+            if !JsIdentifierExpression::can_cast(parent.kind())
+                && !JsShorthandPropertyObjectMember::can_cast(parent.kind())
+            {
+                return None;
+            }
+            is_evaluated_once &= !runs_several_times(node, declarator.syntax());
+            if !reads_same_bindings(model, &value, node)
+                || has_other_this(&value, node, declarator.syntax())
+            {
+                return None;
+            }
+        }
+        if references.is_empty() || !is_evaluated_once && !is_simple(&value) {
+            return None;
+        }
+
+        Some(references)
+    }
+
+    fn action(ctx: &RuleContext<Self>, references: &Self::State) -> Option<JsRuleAction> {
+        let declarator = ctx.query();
+        let value = declarator.initializer()?.expression().ok()?.trim_trivia()?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.remove_js_variable_declarator(declarator);
+        for reference in references {
+            let parent = reference.syntax().parent()?;
+            if let Some(expression) = JsIdentifierExpression::cast_ref(&parent) {
+                let value = if needs_parentheses(&value, &expression) {
+                    make::js_parenthesized_expression(
+                        make::token(T!['(']),
+                        value.clone(),
+                        make::token(T![')']),
+                    )
+                    .into()
+                } else {
+                    value.clone()
+                };
+                mutation.replace_node(AnyJsExpression::from(expression), value);
+            } else if let Some(member) = JsShorthandPropertyObjectMember::cast(parent) {
+                let name = member.name().ok()?.value_token().ok()?;
+                let value = if matches!(value, AnyJsExpression::JsSequenceExpression(_)) {
+                    make::js_parenthesized_expression(
+                        make::token(T!['(']),
+                        value.clone(),
+                        make::token(T![')']),
+                    )
+                    .into()
+                } else {
+                    value.clone()
+                };
+                let property = make::js_property_object_member(
+                    AnyJsObjectMemberName::JsLiteralMemberName(make::js_literal_member_name(
+                        make::ident(name.text_trimmed()),
+                    )),
+                    make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    value,
+                );
+                mutation
+                    .replace_element(member.into_syntax().into(), property.into_syntax().into());
+            }
+        }
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Inline),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Inline the variable" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `node` may run several times each time `declarator`
+/// runs, because it's in a loop or a function
+fn runs_several_times(node: &JsSyntaxNode, declarator: &JsSyntaxNode) -> bool {
+    let declarator_range = declarator.text_trimmed_range();
+    node.ancestors()
+        .take_while(|ancestor| {
+            !ancestor
+                .text_trimmed_range()
+                .contains_range(declarator_range)
+        })
+        .any(|ancestor| {
+            is_function_boundary(&ancestor)
+                || matches!(
+                    ancestor.kind(),
+                    JsSyntaxKind::JS_FOR_STATEMENT
+                        | JsSyntaxKind::JS_FOR_IN_STATEMENT
+                        | JsSyntaxKind::JS_FOR_OF_STATEMENT
+                        | JsSyntaxKind::JS_WHILE_STATEMENT
+                        | JsSyntaxKind::JS_DO_WHILE_STATEMENT
+                )
+        })
+}
+
+/// Returns `true` if the variables read by `value` are the same at the
+/// position of `reference`, and keep their values
+fn reads_same_bindings(
+    model: &SemanticModel,
+    value: &AnyJsExpression,
+    reference: &JsSyntaxNode,
+) -> bool {
+    let scope = model.scope(reference);
+    value
+        .syntax()
+        .descendants()
+        .filter_map(AnyJsIdentifierUsage::cast)
+        .all(|usage| {
+            let (name, binding) = match &usage {
+                AnyJsIdentifierUsage::JsReferenceIdentifier(node) => {
+                    (node.value_token(), model.binding(node))
+                }
+                AnyJsIdentifierUsage::JsxReferenceIdentifier(node) => {
+                    (node.value_token(), model.binding(node))
+                }
+                AnyJsIdentifierUsage::JsIdentifierAssignment(_) => return false,
+            };
+            let Ok(name) = name else { return false };
+            if binding
+                .as_ref()
+                .is_some_and(|binding| binding.all_writes().next().is_some())
+            {
+                return false;
+            }
+            let shadowing = scope
+                .ancestors()
+                .find_map(|scope| scope.get_binding(name.text_trimmed()));
+            match (binding, shadowing) {
+                (Some(binding), Some(shadowing)) => binding.syntax() == shadowing.syntax(),
+                (None, None) => true,
+                _ => false,
+            }
+        })
+}
+
+/// Returns `true` if `value` uses `this`, `arguments` or `new.target`, which
+/// are different at the position of `reference`
+fn has_other_this(
+    value: &AnyJsExpression,
+    reference: &JsSyntaxNode,
+    declarator: &JsSyntaxNode,
+) -> bool {
+    let declarator_range = declarator.text_trimmed_range();
+    let crosses_boundary = reference
+        .ancestors()
+        .take_while(|ancestor| {
+            !ancestor
+                .text_trimmed_range()
+                .contains_range(declarator_range)
+        })
+        .any(|ancestor| is_this_boundary(&ancestor));
+    crosses_boundary
+        && value.syntax().descendants().any(|node| {
+            matches!(
+                node.kind(),
+                JsSyntaxKind::JS_THIS_EXPRESSION
+                    | JsSyntaxKind::JS_SUPER_EXPRESSION
+                    | JsSyntaxKind::JS_NEW_TARGET_EXPRESSION
+            ) || node.kind() == JsSyntaxKind::JS_REFERENCE_IDENTIFIER
+                && node.text_trimmed() == "arguments"
+        })
+}
+
+/// Returns `true` if evaluating `value` several times is cheap and has no side
+/// effects
+fn is_simple(value: &AnyJsExpression) -> bool {
+    match value {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => {
+            literal.syntax().kind() != JsSyntaxKind::JS_REGEX_LITERAL_EXPRESSION
+        }
+        AnyJsExpression::JsIdentifierExpression(_) | AnyJsExpression::JsThisExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            !member.is_optional_chain() && member.object().is_ok_and(|object| is_simple(&object))
+        }
+        AnyJsExpression::JsUnaryExpression(unary) => {
+            unary
+                .operator()
+                .is_ok_and(|operator| operator != JsUnaryOperator::Delete)
+                && unary.argument().is_ok_and(|argument| is_simple(&argument))
+        }
+        AnyJsExpression::JsParenthesizedExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_simple(&expression)),
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none()
+                && template.elements().into_iter().all(|element| {
+                    element
+                        .as_js_template_element()
+                        .and_then(|element| element.expression().ok())
+                        .map_or(true, |expression| is_simple(&expression))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `value` must be wrapped in parentheses to replace
+/// `reference`
+fn needs_parentheses(value: &AnyJsExpression, reference: &JsIdentifierExpression) -> bool {
+    if let AnyJsExpression::JsSequenceExpression(_) = value {
+        return true;
+    }
+    let is_primary = match value {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsThisExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::JsTemplateExpression(_)
+        | AnyJsExpression::JsxTagExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(_)
+        | AnyJsExpression::JsComputedMemberExpression(_)
+        | AnyJsExpression::JsCallExpression(_) => {
+            AnyJsOptionalChainExpression::cast_ref(value.syntax())
+                .is_some_and(|chain| !chain.is_optional_chain())
+        }
+        _ => false,
+    };
+    if is_primary {
+        return false;
+    }
+
+    let Some(parent) = reference.syntax().parent() else {
+        return true;
+    };
+    let reference = AnyJsExpression::from(reference.clone());
+    if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+        return member.member().ok().as_ref() != Some(&reference);
+    }
+    if let (AnyJsExpression::JsBinaryExpression(value), Some(binary)) =
+        (value, JsBinaryExpression::cast_ref(&parent))
+    {
+        // The operator of the value must bind tighter than the operator using it
+        let (Ok(operator), Ok(parent_operator)) = (value.operator(), binary.operator()) else {
+            return true;
+        };
+        let (precedence, parent_precedence) = (operator.precedence(), parent_operator.precedence());
+        let is_left = binary.left().ok().as_ref() == Some(&reference);
+        return precedence < parent_precedence
+            || precedence == parent_precedence && (!is_left || precedence.is_right_to_left());
+    }
+    // These positions accept any expression, except a sequence
+    !matches!(
+        parent.kind(),
+        JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+            | JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+            | JsSyntaxKind::JS_INITIALIZER_CLAUSE
+            | JsSyntaxKind::JS_RETURN_STATEMENT
+            | JsSyntaxKind::JS_THROW_STATEMENT
+            | JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+            | JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+            | JsSyntaxKind::JS_TEMPLATE_ELEMENT
+            | JsSyntaxKind::JS_SPREAD
+            | JsSyntaxKind::JSX_EXPRESSION_CHILD
+            | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+    )
+}
@@ -0,0 +1,114 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsFunctionBody, AnyJsStatement, JsArrowFunctionExpression, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+use crate::utils::refactor::{
+    indentation_unit, is_selected_in_function, line_indentation, reindent,
+};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a code action to replace the body of the arrow function under
+    /// the cursor with the expression it returns.
+    ///
+    /// The body must only contain a `return` statement, without comments.
+    ///
+    /// ## Examples
+    ///
+    /// With the cursor in the arrow function:
+    ///
+    /// ```js
+    /// const double = (value) => {
+    ///     return value * 2;
+    /// };
+    /// ```
+    ///
+    /// Removes the braces around its body:
+    ///
+    /// ```js
+    /// const double = (value) => value * 2;
+    /// ```
+    pub(crate) RemoveBracesFromArrowFunction {
+        version: "next",
+        name: "removeBracesFromArrowFunction",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for RemoveBracesFromArrowFunction {
+    type Query = Ast<JsArrowFunctionExpression>;
+    /// The expression returned by the arrow function
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let arrow = ctx.query();
+        if !is_selected_in_function(ctx.root().syntax(), ctx.selection()?, arrow.syntax()) {
+            return None;
+        }
+        let AnyJsFunctionBody::JsFunctionBody(body) = arrow.body().ok()? else {
+            return None;
+        };
+        let statements = body.statements();
+        if !body.directives().is_empty() || statements.len() != 1 {
+            return None;
+        }
+        let Some(AnyJsStatement::JsReturnStatement(statement)) = statements.first() else {
+            return None;
+        };
+        let argument = statement.argument()?;
+        // The comments around the statement would be lost
+        if body.syntax().has_comments_direct()
+            || statements.syntax().has_comments_direct()
+            || statement.syntax().has_comments_direct()
+            || argument.syntax().has_comments_direct()
+        {
+            return None;
+        }
+        Some(argument)
+    }
+
+    fn action(ctx: &RuleContext<Self>, argument: &Self::State) -> Option<JsRuleAction> {
+        let arrow = ctx.query();
+        let body = arrow.body().ok()?;
+        let indentation = line_indentation(&arrow.syntax().first_token()?);
+        let body_indentation = format!("{indentation}{}", indentation_unit(ctx.root().syntax()));
+
+        let expression = AnyJsExpression::cast(reindent(
+            argument.clone().trim_trivia()?.into_syntax(),
+            &body_indentation,
+            &indentation,
+        ))?;
+        // An object would be parsed as a block, and a sequence as several
+        // arguments
+        let needs_parentheses = matches!(expression, AnyJsExpression::JsSequenceExpression(_))
+            || expression
+                .syntax()
+                .first_token()
+                .is_some_and(|token| token.kind() == T!['{']);
+        let expression = if needs_parentheses {
+            make::parenthesized(expression).into()
+        } else {
+            expression
+        };
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(body, AnyJsFunctionBody::AnyJsExpression(expression));
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor(RefactorKind::Rewrite),
+            applicability: Applicability::Always,
+            message: markup! { "Remove the braces from the arrow function" }.to_owned(),
+            mutation,
+        })
+    }
+}
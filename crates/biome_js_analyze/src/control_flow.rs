@@ -1,3 +1,4 @@
+use crate::semantic_services::{SemanticModelBuilderVisitor, SemanticServices};
 use biome_analyze::QueryMatch;
 use biome_analyze::{AddVisitor, Phases, Queryable, ServiceBag};
 use biome_js_syntax::AnyJsRoot;
@@ -38,3 +39,24 @@ impl Queryable for ControlFlowGraph {
         query.graph.clone()
     }
 }
+
+/// Query type usable by the rules that use both the control flow graph of a
+/// function and the semantic model
+pub struct SemanticControlFlowGraph;
+
+impl Queryable for SemanticControlFlowGraph {
+    type Input = ControlFlowGraph;
+    type Output = JsControlFlowGraph;
+
+    type Language = JsLanguage;
+    type Services = SemanticServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, make_visitor);
+    }
+
+    fn unwrap_match(_: &ServiceBag, query: &ControlFlowGraph) -> Self::Output {
+        query.graph.clone()
+    }
+}
@@ -1,15 +1,19 @@
 #![warn(clippy::needless_pass_by_value)]
 
+use crate::semantic_services::Semantic;
 use crate::suppression_action::apply_suppression_comment;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerPlugin, AnalyzerSignal,
+    ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases,
+    RuleAction, RuleRegistry, SuppressionKind, PLUGIN_GROUP,
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
 use biome_js_syntax::suppression::SuppressionDiagnostic;
-use biome_js_syntax::{suppression::parse_suppression_comment, JsFileSource, JsLanguage};
+use biome_js_syntax::{
+    suppression::parse_suppression_comment, AnyJsRoot, JsFileSource, JsLanguage,
+};
+use biome_js_type_info::{ImportResolver, ProjectDeclarations};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::{borrow::Cow, error::Error};
@@ -28,10 +32,13 @@ mod semantic_analyzers;
 mod semantic_services;
 mod suppression_action;
 mod syntax;
+mod type_analyzers;
+mod type_services;
 pub mod utils;
 
 pub use crate::control_flow::ControlFlowGraph;
 pub use crate::registry::visit_registry;
+pub use crate::type_services::{TypeServices, Typed};
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -53,12 +60,17 @@ pub fn metadata() -> &'static MetadataRegistry {
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
-/// processed by the lint rules registry
+/// processed by the lint rules registry, and the rules defined by `plugins`
+/// that run along the built-in rules. The rules that use types resolve the
+/// imported declarations from the `declarations` of the project.
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    plugins: &[Arc<dyn AnalyzerPlugin<JsLanguage>>],
+    declarations: &Arc<ProjectDeclarations>,
     source_type: JsFileSource,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
@@ -93,6 +105,8 @@ where
                     } else {
                         result.push(Ok(SuppressionKind::Everything));
                     }
+                } else if let Some(rule) = value.filter(|_| key == category!("lint/plugin")) {
+                    result.push(Ok(SuppressionKind::Plugin(rule)));
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
@@ -107,6 +121,15 @@ where
 
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
+    for plugin in plugins {
+        registry.record_plugin(plugin.clone());
+    }
+    if plugins
+        .iter()
+        .any(|plugin| plugin.phase() == Phases::Semantic)
+    {
+        registry.record_query::<Semantic<AnyJsRoot>>();
+    }
 
     let (registry, mut services, diagnostics, visitors) = registry.build();
 
@@ -115,8 +138,21 @@ where
         return (None, diagnostics);
     }
 
+    // The rules of the plugins must be known to resolve their suppression comments
+    let plugin_metadata;
+    let metadata = if plugins.is_empty() {
+        metadata()
+    } else {
+        let mut metadata = metadata().clone();
+        for plugin in plugins {
+            metadata.insert_rule(PLUGIN_GROUP, plugin.name());
+        }
+        plugin_metadata = metadata;
+        &plugin_metadata
+    };
+
     let mut analyzer = Analyzer::new(
-        metadata(),
+        metadata,
         InspectMatcher::new(registry, inspect_matcher),
         parse_linter_suppression_comment,
         apply_suppression_comment,
@@ -130,6 +166,10 @@ where
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
     services.insert_service(source_type);
+    services.insert_service(ImportResolver::new(
+        declarations.clone(),
+        options.file_path.clone(),
+    ));
     (
         analyzer.run(AnalyzerContext {
             root: root.clone(),
@@ -155,7 +195,43 @@ where
     F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, source_type, emit_signal)
+    analyze_with_plugins(
+        root,
+        filter,
+        options,
+        &[],
+        &Arc::default(),
+        source_type,
+        emit_signal,
+    )
+}
+
+/// Run the analyzer on the provided `root` like [analyze], along with the
+/// rules defined by `plugins`, resolving the imported types from the
+/// `declarations` of the project
+pub fn analyze_with_plugins<'a, F, B>(
+    root: &LanguageRoot<JsLanguage>,
+    filter: AnalysisFilter,
+    options: &'a AnalyzerOptions,
+    plugins: &[Arc<dyn AnalyzerPlugin<JsLanguage>>],
+    declarations: &Arc<ProjectDeclarations>,
+    source_type: JsFileSource,
+    emit_signal: F,
+) -> (Option<B>, Vec<DiagnosticError>)
+where
+    F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        plugins,
+        declarations,
+        source_type,
+        emit_signal,
+    )
 }
 
 /// Series of errors encountered when running rules on a file
@@ -165,6 +241,9 @@ pub enum RuleError {
     ReplacedRootWithNonRootError {
         rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
     },
+    /// The passes of the transformations kept changing the file without
+    /// converging, e.g. because two transformations undo the changes of each other.
+    NonConvergingTransformationsError,
 }
 
 impl Diagnostic for RuleError {}
@@ -186,6 +265,12 @@ impl std::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::NonConvergingTransformationsError => {
+                std::write!(
+                    fmt,
+                    "the transformations kept changing the file without converging."
+                )
+            }
         }
     }
 }
@@ -207,6 +292,12 @@ impl biome_console::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::NonConvergingTransformationsError => {
+                std::write!(
+                    fmt,
+                    "the transformations kept changing the file without converging."
+                )
+            }
         }
     }
 }
@@ -6,6 +6,7 @@ pub fn visit_registry<V: RegistryVisitor<JsLanguage>>(registry: &mut V) {
     registry.record_category::<crate::analyzers::Analyzers>();
     registry.record_category::<crate::semantic_analyzers::SemanticAnalyzers>();
     registry.record_category::<crate::aria_analyzers::AriaAnalyzers>();
+    registry.record_category::<crate::type_analyzers::TypeAnalyzers>();
     registry.record_category::<crate::assists::Assists>();
     registry.record_category::<crate::syntax::Syntax>();
 }
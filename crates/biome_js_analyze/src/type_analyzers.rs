@@ -0,0 +1,4 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod nursery;
+::biome_analyze::declare_category! { pub (crate) TypeAnalyzers { kind : Lint , groups : [self :: nursery :: Nursery ,] } }
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub(crate) mod no_floating_promises;
+pub(crate) mod use_exhaustive_switch_cases;
+
+declare_group! {
+    pub (crate) Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_floating_promises :: NoFloatingPromises ,
+            self :: use_exhaustive_switch_cases :: UseExhaustiveSwitchCases ,
+        ]
+     }
+}
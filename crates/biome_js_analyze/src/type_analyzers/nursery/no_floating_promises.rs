@@ -0,0 +1,226 @@
+use crate::{JsRuleAction, Typed};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsExpression, JsCallExpression, JsExpressionStatement, JsSyntaxKind,
+    OperatorPrecedence, TriviaPieceKind, T,
+};
+use biome_js_type_info::TypeModel;
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Require the promises to be handled.
+    ///
+    /// A promise that is created and then forgotten hides its rejection: the
+    /// error is never reported to the caller, and the code that follows runs
+    /// before the promise is settled. This rule reports the expression
+    /// statements that produce a promise that isn't awaited, returned,
+    /// explicitly ignored with the `void` operator, or handled with `.catch()`
+    /// or with the second argument of `.then()`.
+    ///
+    /// The rule relies on the types inferred from the file: the return types
+    /// of the functions it declares, the `Promise` type annotations, and the
+    /// declarations imported from the TypeScript files of the project.
+    ///
+    /// Source: [@typescript-eslint/no-floating-promises](https://typescript-eslint.io/rules/no-floating-promises)
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// async function save(): Promise<void> {}
+    /// save();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// declare const request: Promise<string>;
+    /// request.then((response) => console.log(response));
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// async function save(): Promise<void> {}
+    /// async function submit() {
+    ///     save();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// async function save(): Promise<void> {}
+    /// async function submit() {
+    ///     await save();
+    ///     save().catch((error) => console.error(error));
+    ///     save().then(
+    ///         () => console.log("saved"),
+    ///         (error) => console.error(error),
+    ///     );
+    ///     void save();
+    ///     return save();
+    /// }
+    /// ```
+    pub(crate) NoFloatingPromises {
+        version: "next",
+        name: "noFloatingPromises",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoFloatingPromises {
+    type Query = Typed<JsExpressionStatement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let expression = ctx.query().expression().ok()?;
+        is_floating(ctx.model(), &expression).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let expression = ctx.query().expression().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                expression.range(),
+                markup! {
+                    "This promise is not handled."
+                },
+            )
+            .note(markup! {
+                "The rejection of a promise that isn't handled is silently ignored, and the code that follows runs before the promise is settled."
+            })
+            .note(markup! {
+                "Await or return the promise, handle its rejection with "<Emphasis>".catch()"</Emphasis>", or ignore it explicitly with the "<Emphasis>"void"</Emphasis>" operator."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+        if !is_in_async_function(statement) {
+            return None;
+        }
+
+        let expression = statement.expression().ok()?;
+        let leading_trivia = expression.syntax().first_leading_trivia()?;
+        let trailing_trivia = expression.syntax().last_trailing_trivia()?;
+        // The trivia of the expression is moved around the `await` expression
+        let argument = expression
+            .clone()
+            .with_leading_trivia_pieces([])?
+            .with_trailing_trivia_pieces([])?;
+        let argument = if argument.precedence().ok()? < OperatorPrecedence::Unary {
+            make::parenthesized(argument).into()
+        } else {
+            argument
+        };
+        let await_token = make::token(T![await])
+            .with_leading_trivia_pieces(leading_trivia.pieces())
+            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let await_expression = make::js_await_expression(await_token, argument)
+            .with_trailing_trivia_pieces(trailing_trivia.pieces())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(expression, AnyJsExpression::from(await_expression));
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the "<Emphasis>"await"</Emphasis>" operator." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `expression` produces a promise that isn't handled
+fn is_floating(model: &TypeModel, expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsParenthesizedExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_floating(model, &expression)),
+        AnyJsExpression::JsConditionalExpression(expression) => {
+            expression
+                .consequent()
+                .is_ok_and(|expression| is_floating(model, &expression))
+                || expression
+                    .alternate()
+                    .is_ok_and(|expression| is_floating(model, &expression))
+        }
+        AnyJsExpression::JsLogicalExpression(expression) => {
+            expression
+                .left()
+                .is_ok_and(|expression| is_floating(model, &expression))
+                || expression
+                    .right()
+                    .is_ok_and(|expression| is_floating(model, &expression))
+        }
+        AnyJsExpression::JsSequenceExpression(expression) => {
+            expression
+                .left()
+                .is_ok_and(|expression| is_floating(model, &expression))
+                || expression
+                    .right()
+                    .is_ok_and(|expression| is_floating(model, &expression))
+        }
+        // The promise is stored, and can be handled later
+        AnyJsExpression::JsAssignmentExpression(_) => false,
+        AnyJsExpression::JsCallExpression(call) => match handler_call(call) {
+            Some(Handler::Handled) => false,
+            // `.finally()` doesn't handle the rejection of the promise it's
+            // called on
+            Some(Handler::Finally(object)) => is_floating(model, &object),
+            None => model.type_of_expression(expression).is_promise(),
+        },
+        _ => model.type_of_expression(expression).is_promise(),
+    }
+}
+
+enum Handler {
+    /// `.catch()` or `.then()` with two arguments
+    Handled,
+    /// `.finally()` called on the given object
+    Finally(AnyJsExpression),
+}
+
+/// Returns how `call` handles a promise if it's a call of one of the methods
+/// of the promises
+fn handler_call(call: &JsCallExpression) -> Option<Handler> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    let member = callee.as_js_static_member_expression()?;
+    let name = member.member().ok()?.value_token().ok()?;
+    let arguments = call.arguments().ok()?.args().len();
+    match name.text_trimmed() {
+        "catch" if arguments >= 1 => Some(Handler::Handled),
+        "then" if arguments >= 2 => Some(Handler::Handled),
+        "finally" => Some(Handler::Finally(member.object().ok()?)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `statement` belongs to an `async` function, where the
+/// `await` operator is allowed
+fn is_in_async_function(statement: &JsExpressionStatement) -> bool {
+    for ancestor in statement.syntax().ancestors() {
+        if let Some(function) = AnyFunctionLike::cast_ref(&ancestor) {
+            return function.is_async();
+        }
+        if matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+                | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+        ) {
+            return false;
+        }
+    }
+    false
+}
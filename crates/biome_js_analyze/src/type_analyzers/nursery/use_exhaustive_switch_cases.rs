@@ -0,0 +1,517 @@
+use crate::{JsRuleAction, Typed};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, AnyJsName, AnyJsStatement,
+    AnyJsSwitchClause, JsStatementList, JsSwitchStatement, JsSyntaxKind, JsSyntaxToken, TextRange,
+    TriviaPieceKind, T,
+};
+use biome_js_type_info::{Literal, Type};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+use biome_unicode_table::is_js_ident;
+use std::fmt::{self, Display, Formatter};
+
+declare_rule! {
+    /// Require the `switch` statements over a union of literals or an enum to
+    /// handle all the cases.
+    ///
+    /// When the value of a `switch` statement is a union of string literals,
+    /// e.g. the tag of a discriminated union, or a member of an enum, a case
+    /// that is added to the type and forgotten in the `switch` silently falls
+    /// through it. This rule reports the `switch` statements that don't have a
+    /// `default` clause and miss some of the values of the type.
+    ///
+    /// The rule relies on the types inferred from the file: the unions and the
+    /// enums it declares, and the ones imported from the TypeScript files of
+    /// the project.
+    ///
+    /// Source: [@typescript-eslint/switch-exhaustiveness-check](https://typescript-eslint.io/rules/switch-exhaustiveness-check)
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Shape = { kind: "circle"; radius: number } | { kind: "square"; size: number };
+    /// function area(shape: Shape): number {
+    ///     switch (shape.kind) {
+    ///         case "circle":
+    ///             return Math.PI * shape.radius ** 2;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Direction { Up, Down }
+    /// function move(direction: Direction) {
+    ///     switch (direction) {
+    ///         case Direction.Up:
+    ///             break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// type Status = "active" | "disabled";
+    /// function label(status: Status): string {
+    ///     switch (status) {
+    ///         case "active":
+    ///             return "Active";
+    ///         case "disabled":
+    ///             return "Disabled";
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// type Status = "active" | "disabled" | "deleted";
+    /// function isVisible(status: Status): boolean {
+    ///     switch (status) {
+    ///         case "active":
+    ///             return true;
+    ///         default:
+    ///             return false;
+    ///     }
+    /// }
+    /// ```
+    pub(crate) UseExhaustiveSwitchCases {
+        version: "next",
+        name: "useExhaustiveSwitchCases",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseExhaustiveSwitchCases {
+    type Query = Typed<JsSwitchStatement>;
+    type State = Vec<CaseValue>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let switch = ctx.query();
+        let model = ctx.model();
+
+        let mut covered = Vec::new();
+        // The expressions used to refer to the enums in the cases, by name
+        let mut enum_objects = Vec::new();
+        for clause in switch.cases() {
+            let AnyJsSwitchClause::JsCaseClause(clause) = clause else {
+                // A `default` clause handles the missing cases
+                return None;
+            };
+            let test = clause.test().ok()?;
+            let ty = model.type_of_expression(&test);
+            if !ty.variants().iter().all(is_case_value) {
+                // The case can match values that aren't known
+                return None;
+            }
+            if let (Type::EnumMember(enum_type, _), Some(object)) = (&ty, enum_object(&test)) {
+                enum_objects.push((enum_type.name.clone(), object));
+            }
+            covered.extend(ty.variants().iter().cloned());
+        }
+
+        let ty = model.type_of_expression(&switch.discriminant().ok()?);
+        let expected = expand(&ty)?;
+        // Only the unions of string literals and the enums are checked
+        let is_checked = expected.iter().any(|ty| {
+            matches!(
+                ty,
+                Type::Literal(Literal::String(_)) | Type::EnumMember(_, _)
+            )
+        });
+        if !is_checked {
+            return None;
+        }
+
+        let missing: Vec<_> = expected
+            .into_iter()
+            .filter(|ty| !covered.contains(ty))
+            .filter_map(|ty| CaseValue::from_type(ty, &enum_objects))
+            .collect();
+        (!missing.is_empty()).then_some(missing)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, missing: &Self::State) -> Option<RuleDiagnostic> {
+        let switch = ctx.query();
+        let range = TextRange::new(
+            switch.switch_token().ok()?.text_trimmed_range().start(),
+            switch.r_paren_token().ok()?.text_trimmed_range().end(),
+        );
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The "<Emphasis>"switch"</Emphasis>" statement doesn't handle all the cases."
+                },
+            )
+            .footer_list(
+                markup! {
+                    "The following cases are missing:"
+                },
+                missing,
+            )
+            .note(markup! {
+                "Add the missing cases, or a "<Emphasis>"default"</Emphasis>" clause to handle them."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, missing: &Self::State) -> Option<JsRuleAction> {
+        let switch = ctx.query();
+        let cases = switch.cases();
+
+        // The new clauses are indented like the last clause when it starts a
+        // line, one level deeper than the `switch` otherwise, and their
+        // statement one level deeper than them
+        let switch_indentation = indentation(&switch.switch_token().ok()?);
+        let case_indentation = match cases.last() {
+            Some(clause) if clause.clause_token().ok()?.has_leading_newline() => {
+                indentation(&clause.clause_token().ok()?)
+            }
+            _ => format!("{switch_indentation}\t"),
+        };
+        let statement_indentation = cases
+            .iter()
+            .filter_map(|clause| clause.consequent().first()?.syntax().first_token())
+            .find(|token| token.has_leading_newline())
+            .map_or_else(
+                || format!("{case_indentation}\t"),
+                |token| indentation(&token),
+            );
+
+        let new_cases = missing.iter().map(|value| {
+            let message = format!("Not implemented yet: {value} case");
+            let error = make::js_new_expression(
+                make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_identifier_expression(make::js_reference_identifier(make::ident("Error")))
+                    .into(),
+            )
+            .with_arguments(make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(literal(
+                        make::js_string_literal_expression(string_literal(&message)),
+                    ))],
+                    [],
+                ),
+                make::token(T![')']),
+            ))
+            .build();
+            let throw_statement = make::js_throw_statement(
+                make::token(T![throw])
+                    .with_leading_trivia([
+                        (TriviaPieceKind::Newline, "\n"),
+                        (TriviaPieceKind::Whitespace, statement_indentation.as_str()),
+                    ])
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                error.into(),
+            )
+            .with_semicolon_token(make::token(T![;]))
+            .build();
+
+            AnyJsSwitchClause::from(make::js_case_clause(
+                make::token(T![case])
+                    .with_leading_trivia([
+                        (TriviaPieceKind::Newline, "\n"),
+                        (TriviaPieceKind::Whitespace, case_indentation.as_str()),
+                    ])
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                value.to_expression(),
+                make::token(T![:]),
+                make::js_statement_list([AnyJsStatement::from(throw_statement)]),
+            ))
+        });
+        // The last clause mustn't fall through the new ones
+        let last_clause = cases.last().map(|clause| match clause {
+            AnyJsSwitchClause::JsCaseClause(clause) if falls_through(&clause.consequent()) => {
+                let break_statement =
+                    make::js_break_statement(make::token(T![break]).with_leading_trivia([
+                        (TriviaPieceKind::Newline, "\n"),
+                        (TriviaPieceKind::Whitespace, statement_indentation.as_str()),
+                    ]))
+                    .with_semicolon_token(make::token(T![;]))
+                    .build();
+                let consequent = make::js_statement_list(
+                    clause
+                        .consequent()
+                        .iter()
+                        .chain([AnyJsStatement::from(break_statement)])
+                        .collect::<Vec<_>>(),
+                );
+                clause.with_consequent(consequent).into()
+            }
+            clause => clause,
+        });
+        // The new clauses start a line, after the whitespace of the last clause
+        let last_clause =
+            last_clause.map(|clause| clause.clone().trim_trailing_trivia().unwrap_or(clause));
+        let new_list = make::js_switch_case_list(
+            cases
+                .iter()
+                .take(cases.len().saturating_sub(1))
+                .chain(last_clause)
+                .chain(new_cases)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(cases, new_list);
+        // The `}` of the `switch` goes on its own line, after the new clauses
+        let r_curly_token = switch.r_curly_token().ok()?;
+        if !r_curly_token.has_leading_newline()
+            && r_curly_token
+                .leading_trivia()
+                .pieces()
+                .all(|piece| piece.is_whitespace())
+        {
+            mutation.replace_token_discard_trivia(
+                r_curly_token,
+                make::token(T!['}']).with_leading_trivia([
+                    (TriviaPieceKind::Newline, "\n"),
+                    (TriviaPieceKind::Whitespace, switch_indentation.as_str()),
+                ]),
+            );
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the missing cases." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// A value of the type of a `switch` statement that isn't handled by a case
+#[derive(Debug)]
+pub(crate) enum CaseValue {
+    /// A string, with the content of the literal as written in the source
+    String(String),
+    Number(String),
+    BigInt(String),
+    Boolean(bool),
+    Null,
+    Undefined,
+    /// The member of an enum, accessed on `object`
+    EnumMember {
+        object: String,
+        member: String,
+    },
+}
+
+impl CaseValue {
+    fn from_type(ty: Type, enum_objects: &[(String, String)]) -> Option<Self> {
+        let value = match ty {
+            Type::Literal(Literal::String(value)) => Self::String(value),
+            Type::Literal(Literal::Number(value)) => Self::Number(value),
+            Type::Literal(Literal::BigInt(value)) => Self::BigInt(value),
+            Type::Literal(Literal::Boolean(value)) => Self::Boolean(value),
+            Type::Null => Self::Null,
+            Type::Undefined => Self::Undefined,
+            Type::EnumMember(enum_type, member) => {
+                let object = enum_objects
+                    .iter()
+                    .find(|(name, _)| *name == enum_type.name)
+                    .map_or_else(|| enum_type.name.clone(), |(_, object)| object.clone());
+                Self::EnumMember { object, member }
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn to_expression(&self) -> AnyJsExpression {
+        match self {
+            Self::String(value) => {
+                literal(make::js_string_literal_expression(string_literal(value)))
+            }
+            Self::Number(value) => match value.strip_prefix('-') {
+                Some(value) => make::js_unary_expression(
+                    make::token(T![-]),
+                    literal(make::js_number_literal_expression(number_literal(value))),
+                )
+                .into(),
+                None => literal(make::js_number_literal_expression(number_literal(value))),
+            },
+            Self::BigInt(value) => match value.strip_prefix('-') {
+                Some(value) => make::js_unary_expression(
+                    make::token(T![-]),
+                    literal(make::js_bigint_literal_expression(bigint_literal(value))),
+                )
+                .into(),
+                None => literal(make::js_bigint_literal_expression(bigint_literal(value))),
+            },
+            Self::Boolean(true) => {
+                literal(make::js_boolean_literal_expression(make::token(T![true])))
+            }
+            Self::Boolean(false) => {
+                literal(make::js_boolean_literal_expression(make::token(T![false])))
+            }
+            Self::Null => literal(make::js_null_literal_expression(make::token(T![null]))),
+            Self::Undefined => reference("undefined"),
+            Self::EnumMember { object, member } => {
+                if is_js_ident(member) {
+                    make::js_static_member_expression(
+                        reference(object),
+                        make::token(T![.]),
+                        AnyJsName::JsName(make::js_name(make::ident(member))),
+                    )
+                    .into()
+                } else {
+                    make::js_computed_member_expression(
+                        reference(object),
+                        make::token(T!['[']),
+                        literal(make::js_string_literal_expression(string_literal(member))),
+                        make::token(T![']']),
+                    )
+                    .build()
+                    .into()
+                }
+            }
+        }
+    }
+}
+
+impl Display for CaseValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => f.write_str(string_literal(value).text()),
+            Self::Number(value) | Self::BigInt(value) => f.write_str(value),
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Null => f.write_str("null"),
+            Self::Undefined => f.write_str("undefined"),
+            Self::EnumMember { object, member } => {
+                if is_js_ident(member) {
+                    write!(f, "{object}.{member}")
+                } else {
+                    write!(f, "{object}[{}]", string_literal(member).text())
+                }
+            }
+        }
+    }
+}
+
+impl biome_console::fmt::Display for CaseValue {
+    fn fmt(&self, f: &mut biome_console::fmt::Formatter) -> std::io::Result<()> {
+        write!(f, "{self}")
+    }
+}
+
+/// Returns the values of `ty`, or [None] if it has values that can't be
+/// listed in the cases of a `switch`
+fn expand(ty: &Type) -> Option<Vec<Type>> {
+    let mut values = Vec::new();
+    for ty in ty.variants() {
+        match ty {
+            Type::Boolean => values.extend([
+                Type::Literal(Literal::Boolean(true)),
+                Type::Literal(Literal::Boolean(false)),
+            ]),
+            Type::Enum(enum_type) => values.extend(
+                enum_type
+                    .members
+                    .iter()
+                    .map(|member| Type::EnumMember(enum_type.clone(), member.clone())),
+            ),
+            ty if is_case_value(ty) => values.push(ty.clone()),
+            _ => return None,
+        }
+    }
+    Some(values)
+}
+
+/// Returns `true` if `ty` has a single value
+fn is_case_value(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Literal(_) | Type::Null | Type::Undefined | Type::EnumMember(_, _)
+    )
+}
+
+/// Returns the text of the object of `test` if it accesses a member of an enum
+fn enum_object(test: &AnyJsExpression) -> Option<String> {
+    let object = match test.clone().omit_parentheses() {
+        AnyJsExpression::JsStaticMemberExpression(member) => member.object().ok()?,
+        AnyJsExpression::JsComputedMemberExpression(member) => member.object().ok()?,
+        _ => return None,
+    };
+    Some(object.syntax().text_trimmed().to_string())
+}
+
+/// Returns `true` if the execution can reach the end of the statements of a
+/// clause, and continue with the next clause
+fn falls_through(statements: &JsStatementList) -> bool {
+    match statements.last() {
+        Some(
+            AnyJsStatement::JsBreakStatement(_)
+            | AnyJsStatement::JsContinueStatement(_)
+            | AnyJsStatement::JsReturnStatement(_)
+            | AnyJsStatement::JsThrowStatement(_),
+        ) => false,
+        Some(AnyJsStatement::JsBlockStatement(block)) => falls_through(&block.statements()),
+        _ => true,
+    }
+}
+
+/// Returns the whitespace that indents the line of `token`
+fn indentation(token: &JsSyntaxToken) -> String {
+    // The first token of the line has the indentation in its leading trivia
+    let mut token = token.clone();
+    while !token.has_leading_newline() {
+        match token.prev_token() {
+            Some(prev_token) => token = prev_token,
+            None => break,
+        }
+    }
+    token
+        .indentation_trivia_pieces()
+        .filter(|piece| piece.kind() == TriviaPieceKind::Whitespace)
+        .map(|piece| piece.text().to_string())
+        .collect()
+}
+
+fn reference(name: &str) -> AnyJsExpression {
+    make::js_identifier_expression(make::js_reference_identifier(make::ident(name))).into()
+}
+
+/// Returns a string literal token with `content`, using the double quotes
+/// unless the content contains some
+fn string_literal(content: &str) -> JsSyntaxToken {
+    let quote = if content.contains('"') && !content.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+    let mut text = String::with_capacity(content.len() + 2);
+    text.push(quote);
+    let mut escaped = false;
+    for char in content.chars() {
+        if char == quote && !escaped {
+            text.push('\\');
+        }
+        escaped = char == '\\' && !escaped;
+        text.push(char);
+    }
+    text.push(quote);
+    JsSyntaxToken::new_detached(JsSyntaxKind::JS_STRING_LITERAL, &text, [], [])
+}
+
+fn number_literal(text: &str) -> JsSyntaxToken {
+    JsSyntaxToken::new_detached(JsSyntaxKind::JS_NUMBER_LITERAL, text, [], [])
+}
+
+fn bigint_literal(text: &str) -> JsSyntaxToken {
+    JsSyntaxToken::new_detached(JsSyntaxKind::JS_BIGINT_LITERAL, text, [], [])
+}
+
+fn literal(expression: impl Into<AnyJsLiteralExpression>) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(expression.into())
+}
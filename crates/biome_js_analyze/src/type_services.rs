@@ -0,0 +1,91 @@
+use crate::semantic_services::SemanticModelBuilderVisitor;
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
+};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode, WalkEvent};
+use biome_js_type_info::{ImportResolver, TypeModel};
+use biome_rowan::{AstNode, SyntaxNode};
+
+pub struct TypeServices {
+    model: TypeModel,
+}
+
+impl TypeServices {
+    pub fn model(&self) -> &TypeModel {
+        &self.model
+    }
+}
+
+impl FromServices for TypeServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let model: &TypeModel = services
+            .get_service()
+            .ok_or_else(|| MissingServicesDiagnostic::new(rule_key.rule_name(), &["TypeModel"]))?;
+        Ok(Self {
+            model: model.clone(),
+        })
+    }
+}
+
+impl Phase for TypeServices {
+    fn phase() -> Phases {
+        Phases::Type
+    }
+}
+
+/// Query type usable by lint rules **that uses the type model** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Typed<N>(pub N);
+
+impl<N> Queryable for Typed<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = TypeServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, || TypeModelBuilderVisitor);
+        analyzer.add_visitor(Phases::Type, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
+
+/// Builds the [TypeModel] once the [SemanticModel] is available, the types
+/// being inferred lazily when the rules request them
+pub(crate) struct TypeModelBuilderVisitor;
+
+impl Visitor for TypeModelBuilderVisitor {
+    type Language = JsLanguage;
+
+    fn visit(&mut self, _: &WalkEvent<SyntaxNode<JsLanguage>>, _: VisitorContext<JsLanguage>) {}
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        let Some(semantic) = ctx.services.get_service::<SemanticModel>() else {
+            return;
+        };
+        let imports = ctx
+            .services
+            .get_service::<ImportResolver>()
+            .cloned()
+            .unwrap_or_default();
+        let model = TypeModel::new(semantic.clone(), imports);
+        ctx.services.insert_service(model);
+    }
+}
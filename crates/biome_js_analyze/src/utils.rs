@@ -4,6 +4,7 @@ use std::iter;
 
 pub mod batch;
 pub mod case;
+pub mod refactor;
 pub mod rename;
 #[cfg(test)]
 pub mod tests;
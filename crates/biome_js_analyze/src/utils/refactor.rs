@@ -0,0 +1,366 @@
+//! Utilities shared by the refactor assists, which rewrite the code selected
+//! in the editor
+
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsClassMember, AnyJsExpression, AnyJsFunction, AnyJsModuleItem, AnyJsStatement,
+    JsArrowFunctionExpression, JsFunctionBody, JsLanguage, JsModuleItemList, JsStatementList,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TextRange, TextSize, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutation, Direction, SyntaxKindSet, WalkEvent};
+use rustc_hash::FxHashSet;
+use std::ops::Range;
+
+/// A list of statements, e.g. the body of a block, of a function or of a module
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum AnyJsStatementList {
+    JsStatementList(JsStatementList),
+    JsModuleItemList(JsModuleItemList),
+}
+
+impl AstNode for AnyJsStatementList {
+    type Language = JsLanguage;
+
+    const KIND_SET: SyntaxKindSet<JsLanguage> =
+        JsStatementList::KIND_SET.union(JsModuleItemList::KIND_SET);
+
+    fn can_cast(kind: JsSyntaxKind) -> bool {
+        JsStatementList::can_cast(kind) || JsModuleItemList::can_cast(kind)
+    }
+
+    fn cast(syntax: JsSyntaxNode) -> Option<Self> {
+        match syntax.kind() {
+            JsSyntaxKind::JS_STATEMENT_LIST => JsStatementList::cast(syntax).map(Self::from),
+            JsSyntaxKind::JS_MODULE_ITEM_LIST => JsModuleItemList::cast(syntax).map(Self::from),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &JsSyntaxNode {
+        match self {
+            Self::JsStatementList(list) => list.syntax(),
+            Self::JsModuleItemList(list) => list.syntax(),
+        }
+    }
+
+    fn into_syntax(self) -> JsSyntaxNode {
+        match self {
+            Self::JsStatementList(list) => list.into_syntax(),
+            Self::JsModuleItemList(list) => list.into_syntax(),
+        }
+    }
+}
+
+impl From<JsStatementList> for AnyJsStatementList {
+    fn from(list: JsStatementList) -> Self {
+        Self::JsStatementList(list)
+    }
+}
+
+impl From<JsModuleItemList> for AnyJsStatementList {
+    fn from(list: JsModuleItemList) -> Self {
+        Self::JsModuleItemList(list)
+    }
+}
+
+impl AnyJsStatementList {
+    /// Returns the list of statements containing `node`, which is one of its
+    /// items
+    pub(crate) fn of_item(node: &JsSyntaxNode) -> Option<Self> {
+        node.parent().and_then(Self::cast)
+    }
+
+    /// Returns a new list, where the items in `range` are replaced by `items`
+    pub(crate) fn splice(
+        &self,
+        range: Range<usize>,
+        items: impl IntoIterator<Item = JsSyntaxNode>,
+    ) -> Option<Self> {
+        let list = match self {
+            Self::JsStatementList(list) => {
+                let mut statements: Vec<_> = list.iter().collect();
+                let items = items
+                    .into_iter()
+                    .map(AnyJsStatement::cast)
+                    .collect::<Option<Vec<_>>>()?;
+                statements.splice(range, items);
+                Self::JsStatementList(make::js_statement_list(statements))
+            }
+            Self::JsModuleItemList(list) => {
+                let mut module_items: Vec<_> = list.iter().collect();
+                let items = items
+                    .into_iter()
+                    .map(AnyJsModuleItem::cast)
+                    .collect::<Option<Vec<_>>>()?;
+                module_items.splice(range, items);
+                Self::JsModuleItemList(make::js_module_item_list(module_items))
+            }
+        };
+        Some(list)
+    }
+}
+
+/// Returns the range of `selection` in `root`, without its leading and
+/// trailing whitespaces
+///
+/// Returns `None` if the selection is empty or only contains whitespaces.
+pub(crate) fn trim_selection(root: &JsSyntaxNode, selection: TextRange) -> Option<TextRange> {
+    let selection = root.text_range().intersect(selection)?;
+    let text = root.text().slice(selection).to_string();
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    (start < end).then(|| {
+        TextRange::new(
+            selection.start() + TextSize::from(start as u32),
+            selection.start() + TextSize::from(end as u32),
+        )
+    })
+}
+
+/// Returns `true` if the code selected in the editor, or the cursor, is
+/// within `range`
+pub(crate) fn is_selected(root: &JsSyntaxNode, selection: TextRange, range: TextRange) -> bool {
+    let selection = trim_selection(root, selection).unwrap_or(selection);
+    range.contains_range(selection)
+}
+
+/// Returns `true` if the code selected in the editor, or the cursor, is
+/// within `function` but not within a function nested in it
+pub(crate) fn is_selected_in_function(
+    root: &JsSyntaxNode,
+    selection: TextRange,
+    function: &JsSyntaxNode,
+) -> bool {
+    let selection = trim_selection(root, selection).unwrap_or(selection);
+    function.text_trimmed_range().contains_range(selection)
+        && !function.descendants().skip(1).any(|descendant| {
+            is_function_boundary(&descendant)
+                && descendant.text_trimmed_range().contains_range(selection)
+        })
+}
+
+/// Returns `base`, or `base` followed by a number, such that no identifier of
+/// `root` has this name
+pub(crate) fn unused_name(root: &JsSyntaxNode, base: &str) -> String {
+    let names: FxHashSet<_> = root
+        .descendants_tokens(Direction::Next)
+        .filter(|token| token.kind() == JsSyntaxKind::IDENT)
+        .map(|token| token.text_trimmed().to_string())
+        .collect();
+    if !names.contains(base) {
+        return base.to_string();
+    }
+    (1..)
+        .map(|index| format!("{base}{index}"))
+        .find(|name| !names.contains(name))
+        .expect("the names of the identifiers are finite")
+}
+
+/// Returns the whitespaces written before `token` on its line
+pub(crate) fn indentation(token: &JsSyntaxToken) -> String {
+    let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+    let line_start = pieces
+        .iter()
+        .rposition(|piece| piece.is_newline())
+        .map_or(0, |index| index + 1);
+    pieces[line_start..]
+        .iter()
+        .filter(|piece| piece.is_whitespace())
+        .map(|piece| piece.text().to_string())
+        .collect()
+}
+
+/// Returns the whitespaces written at the start of the line of `token`
+pub(crate) fn line_indentation(token: &JsSyntaxToken) -> String {
+    let mut token = token.clone();
+    while !token.has_leading_newline() {
+        match token.prev_token() {
+            Some(prev_token) => token = prev_token,
+            None => break,
+        }
+    }
+    indentation(&token)
+}
+
+/// Returns the string used to indent the code of `root`: a tab, or the
+/// smallest indentation with spaces
+pub(crate) fn indentation_unit(root: &JsSyntaxNode) -> String {
+    let mut unit: Option<String> = None;
+    for token in root.descendants_tokens(Direction::Next) {
+        let indentation = indentation(&token);
+        if indentation.is_empty() || !token.has_leading_newline() {
+            continue;
+        }
+        if indentation.starts_with('\t') {
+            return "\t".to_string();
+        }
+        if unit
+            .as_ref()
+            .map_or(true, |unit| indentation.len() < unit.len())
+        {
+            unit = Some(indentation);
+        }
+    }
+    unit.unwrap_or_else(|| "\t".to_string())
+}
+
+/// Returns `token` at the start of a new line, indented with `indentation`
+pub(crate) fn on_new_line(token: &JsSyntaxToken, indentation: &str) -> JsSyntaxToken {
+    token.with_leading_trivia([
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation),
+    ])
+}
+
+/// Returns a token of the given kind followed by a space, e.g. `,` in a list
+pub(crate) fn token_with_trailing_space(kind: JsSyntaxKind) -> JsSyntaxToken {
+    make::token(kind).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
+
+/// Returns a token of the given kind preceded by a space, e.g. the closing
+/// brace of an object
+pub(crate) fn token_with_leading_space(kind: JsSyntaxKind) -> JsSyntaxToken {
+    make::token(kind).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
+
+/// Returns a function body returning `expression`, where `indentation` is the
+/// indentation of the function
+pub(crate) fn function_body_returning(
+    expression: AnyJsExpression,
+    indentation: &str,
+    unit: &str,
+) -> Option<JsFunctionBody> {
+    let body_indentation = format!("{indentation}{unit}");
+    // The parentheses around an object are only needed in an expression body
+    let expression = match expression {
+        AnyJsExpression::JsParenthesizedExpression(parenthesized)
+            if matches!(
+                parenthesized.expression(),
+                Ok(AnyJsExpression::JsObjectExpression(_))
+            ) =>
+        {
+            parenthesized.expression().ok()?
+        }
+        expression => expression,
+    };
+    let argument = AnyJsExpression::cast(reindent(
+        expression.trim_trivia()?.into_syntax(),
+        indentation,
+        &body_indentation,
+    ))?;
+    let statement = make::js_return_statement(on_new_line(
+        &token_with_trailing_space(T![return]),
+        &body_indentation,
+    ))
+    .with_argument(argument)
+    .with_semicolon_token(make::token(T![;]))
+    .build();
+    Some(make::js_function_body(
+        make::token(T!['{']),
+        make::js_directive_list([]),
+        make::js_statement_list([statement.into()]),
+        on_new_line(&make::token(T!['}']), indentation),
+    ))
+}
+
+/// Returns `node`, where the indentation `from` at the start of its lines is
+/// replaced with `to`
+pub(crate) fn reindent(node: JsSyntaxNode, from: &str, to: &str) -> JsSyntaxNode {
+    let node = node.detach();
+    let mut mutation = BatchMutation::<JsLanguage>::new(node.clone());
+    for token in node.descendants_tokens(Direction::Next) {
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        if !pieces.iter().any(|piece| piece.is_newline()) {
+            continue;
+        }
+        let mut trivia = Vec::with_capacity(pieces.len());
+        let mut is_line_start = false;
+        for piece in &pieces {
+            let text = piece.text();
+            if is_line_start && piece.is_whitespace() {
+                let text = text.strip_prefix(from).unwrap_or(text);
+                trivia.push((piece.kind(), format!("{to}{text}")));
+            } else {
+                // The line isn't indented yet, e.g. at the top level
+                if is_line_start && !piece.is_newline() {
+                    trivia.push((TriviaPieceKind::Whitespace, to.to_string()));
+                }
+                trivia.push((piece.kind(), text.to_string()));
+            }
+            is_line_start = piece.is_newline();
+        }
+        if is_line_start {
+            trivia.push((TriviaPieceKind::Whitespace, to.to_string()));
+        }
+        let new_token = token.with_leading_trivia(
+            trivia
+                .iter()
+                .map(|(kind, text)| (*kind, text.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        mutation.replace_token_discard_trivia(token, new_token);
+    }
+    mutation.commit()
+}
+
+/// Returns a detached copy of `node`, where its descendant `prev_node` is
+/// replaced with `next_node`, which takes the trivia of `prev_node`
+pub(crate) fn replace_descendant(
+    node: &JsSyntaxNode,
+    prev_node: &JsSyntaxNode,
+    next_node: JsSyntaxNode,
+) -> Option<JsSyntaxNode> {
+    let next_node = next_node
+        .with_leading_trivia_pieces(prev_node.first_leading_trivia()?.pieces())?
+        .with_trailing_trivia_pieces(prev_node.last_trailing_trivia()?.pieces())?;
+    if node == prev_node {
+        return Some(next_node);
+    }
+    node.clone()
+        .replace_child(prev_node.clone().into(), next_node.into())
+}
+
+/// Returns `true` if the code inside `node` runs in another function than the
+/// code around it, e.g. for a function expression or a class property
+pub(crate) fn is_function_boundary(node: &JsSyntaxNode) -> bool {
+    AnyJsFunction::can_cast(node.kind())
+        || AnyJsClassMember::can_cast(node.kind())
+        || matches!(
+            node.kind(),
+            JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        )
+}
+
+/// Returns `true` if the code inside `node` has its own `this`, `arguments`
+/// and `new.target`: arrow functions use the ones of the code around them
+pub(crate) fn is_this_boundary(node: &JsSyntaxNode) -> bool {
+    is_function_boundary(node) && !JsArrowFunctionExpression::can_cast(node.kind())
+}
+
+/// Returns `true` if the code of `node` uses `this`, `super`, `arguments` or
+/// `new.target` from the code around it
+pub(crate) fn uses_outer_this(node: &JsSyntaxNode) -> bool {
+    let mut preorder = node.preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(descendant) = event else {
+            continue;
+        };
+        if &descendant != node && is_this_boundary(&descendant) {
+            preorder.skip_subtree();
+            continue;
+        }
+        let uses_this = matches!(
+            descendant.kind(),
+            JsSyntaxKind::JS_THIS_EXPRESSION
+                | JsSyntaxKind::JS_SUPER_EXPRESSION
+                | JsSyntaxKind::JS_NEW_TARGET_EXPRESSION
+        ) || descendant.kind() == JsSyntaxKind::JS_REFERENCE_IDENTIFIER
+            && descendant.text_trimmed() == "arguments";
+        if uses_this {
+            return true;
+        }
+    }
+    false
+}
@@ -2,14 +2,16 @@ use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilt
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_js_syntax::{JsFileSource, JsLanguage, TextRange, TextSize};
+use biome_js_type_info::{is_typescript_file, ProjectDeclarations};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker, scripts_from_json,
     write_analyzer_snapshot, CheckActionType,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::sync::Arc;
+use std::{ffi::OsStr, fs::read_dir, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{cjs,js,jsx,tsx,ts,json,jsonc}", crate::run_suppression_test, "module"}
@@ -34,17 +36,19 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         panic!("could not find rule {group}/{rule}");
     }
 
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", input_file, err));
+    let (input_code, selection) = extract_selection(&input_code);
+
     let rule_filter = RuleFilter::Rule(group, rule);
     let filter = AnalysisFilter {
         enabled_rules: Some(slice::from_ref(&rule_filter)),
+        range: selection,
         ..AnalysisFilter::default()
     };
 
     let mut snapshot = String::new();
     let extension = input_file.extension().unwrap_or_default();
-
-    let input_code = read_to_string(input_file)
-        .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", input_file, err));
     let quantity_diagnostics = if let Some(scripts) = scripts_from_json(extension, &input_code) {
         for script in scripts {
             analyze_and_snap(
@@ -88,6 +92,23 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     }
 }
 
+/// Removes the markers `«` and `»` of the code selected in the editor from
+/// `input_code`, for the tests of the refactor actions. Returns the code and
+/// the range of the selection.
+fn extract_selection(input_code: &str) -> (String, Option<TextRange>) {
+    let (Some(start), Some(end)) = (input_code.find('«'), input_code.find('»')) else {
+        return (input_code.to_string(), None);
+    };
+    assert!(
+        start < end,
+        "the selection must start with « and end with »"
+    );
+    let code = input_code.replacen('«', "", 1).replacen('»', "", 1);
+    let start = TextSize::try_from(start).unwrap();
+    let end = TextSize::try_from(end - '«'.len_utf8()).unwrap();
+    (code, Some(TextRange::new(start, end)))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn analyze_and_snap(
     snapshot: &mut String,
@@ -105,12 +126,30 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let declarations = project_declarations(input_file, input_code);
 
-    let (_, errors) = biome_js_analyze::analyze(&root, filter, &options, source_type, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if check_action_type.is_suppression() {
-                    if action.is_suppression() {
+    let (_, errors) = biome_js_analyze::analyze_with_plugins(
+        &root,
+        filter,
+        &options,
+        &[],
+        &declarations,
+        source_type,
+        |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if check_action_type.is_suppression() {
+                        if action.is_suppression() {
+                            check_code_action(
+                                input_file,
+                                input_code,
+                                source_type,
+                                &action,
+                                parser_options.clone(),
+                            );
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(
                             input_file,
                             input_code,
@@ -120,26 +159,26 @@ pub(crate) fn analyze_and_snap(
                         );
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(
-                        input_file,
-                        input_code,
-                        source_type,
-                        &action,
-                        parser_options.clone(),
-                    );
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            let error = diag.with_severity(Severity::Warning);
-            diagnostics.push(diagnostic_to_string(file_name, input_code, error));
-            return ControlFlow::Continue(());
-        }
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if check_action_type.is_suppression() {
-                if action.category.matches("quickfix.suppressRule") {
+            for action in event.actions() {
+                if check_action_type.is_suppression() {
+                    if action.category.matches("quickfix.suppressRule") {
+                        check_code_action(
+                            input_file,
+                            input_code,
+                            source_type,
+                            &action,
+                            parser_options.clone(),
+                        );
+                        code_fixes.push(code_fix_to_string(input_code, action));
+                    }
+                } else if !action.category.matches("quickfix.suppressRule") {
                     check_code_action(
                         input_file,
                         input_code,
@@ -149,20 +188,11 @@ pub(crate) fn analyze_and_snap(
                     );
                     code_fixes.push(code_fix_to_string(input_code, action));
                 }
-            } else if !action.category.matches("quickfix.suppressRule") {
-                check_code_action(
-                    input_file,
-                    input_code,
-                    source_type,
-                    &action,
-                    parser_options.clone(),
-                );
-                code_fixes.push(code_fix_to_string(input_code, action));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -178,6 +208,31 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Records the declarations of the TypeScript files next to `input_file`, so
+/// that the specs of the type-aware rules can import them with a relative path
+fn project_declarations(input_file: &Path, input_code: &str) -> Arc<ProjectDeclarations> {
+    let declarations = Arc::new(ProjectDeclarations::default());
+    if !input_code.contains("from \"./") && !input_code.contains("from './") {
+        return declarations;
+    }
+
+    let Some(Ok(entries)) = input_file.parent().map(read_dir) else {
+        return declarations;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path == input_file || !is_typescript_file(&path) {
+            continue;
+        }
+        let (Ok(source_type), Ok(code)) = (path.as_path().try_into(), read_to_string(&path)) else {
+            continue;
+        };
+        let root = parse(&code, source_type, JsParserOptions::default()).tree();
+        declarations.insert(&path, &root);
+    }
+
+    declarations
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
@@ -0,0 +1,44 @@
+async function save(): Promise<void> {}
+function load(): Promise<string> {
+	return Promise.resolve("");
+}
+const fetchData = async () => 1;
+declare const request: Promise<string>;
+
+save();
+load();
+fetchData();
+request;
+request.then(() => {});
+request.finally(() => {});
+Promise.all([save(), load()]);
+new Promise((resolve) => resolve(1));
+condition ? save() : undefined;
+condition && save();
+(save(), undefined);
+(save());
+
+async function submit() {
+	// Saves the form
+	save(); // before reloading
+	condition ? save() : load();
+	request.then(() => {}).finally(() => {});
+}
+
+const handler = async () => {
+	fetchData();
+};
+
+class Form {
+	async submit() {
+		save();
+	}
+
+	reset() {
+		save();
+	}
+}
+
+function sync() {
+	save();
+}
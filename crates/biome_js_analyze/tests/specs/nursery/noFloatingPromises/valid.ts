@@ -0,0 +1,30 @@
+async function save(): Promise<void> {}
+function count(): number {
+	return 1;
+}
+declare const request: Promise<string>;
+let stored: Promise<void>;
+
+async function submit() {
+	await save();
+	await request;
+	save().catch(() => {});
+	save().then(
+		() => {},
+		() => {},
+	);
+	save()
+		.catch(() => {})
+		.finally(() => {});
+	void save();
+	stored = save();
+	count();
+	unknownFunction();
+	return save();
+}
+
+function chain() {
+	return request.then(() => {});
+}
+
+const arrow = () => save();
@@ -0,0 +1,24 @@
+import { Priority, Status } from "./status";
+
+function label(status: Status) {
+	switch (status) {
+		case Status.Active:
+			return "Active";
+	}
+}
+
+function weight(priority: Priority) {
+	switch (priority) {
+		case "low":
+			return 1;
+	}
+}
+
+function exhaustive(status: Status) {
+	switch (status) {
+		case Status.Active:
+		case Status.Inactive:
+		case Status.Pending:
+			break;
+	}
+}
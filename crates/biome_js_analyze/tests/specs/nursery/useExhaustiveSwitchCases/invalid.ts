@@ -0,0 +1,91 @@
+type Status = "active" | "disabled" | "deleted";
+
+function label(status: Status) {
+	switch (status) {
+		case "active":
+			return "Active";
+	}
+}
+
+function empty(status: Status) {
+	switch (status) {
+	}
+}
+
+type Shape =
+	| { kind: "circle"; radius: number }
+	| { kind: "square"; size: number }
+	| { kind: "triangle"; base: number; height: number };
+
+function area(shape: Shape) {
+	switch (shape.kind) {
+		case "circle":
+			return Math.PI * shape.radius ** 2;
+		case "square":
+			return shape.size ** 2;
+	}
+}
+
+enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+function move(direction: Direction) {
+	switch (direction) {
+		case Direction.Up:
+		case Direction.Down:
+			break;
+	}
+}
+
+const Dir = Direction;
+
+function alias(direction: Direction) {
+	switch (direction) {
+		case Dir.Up:
+			break;
+	}
+}
+
+enum Key {
+	"arrow-up" = "ArrowUp",
+	Enter = "Enter",
+}
+
+function key(value: Key) {
+	switch (value) {
+		case Key.Enter:
+			break;
+	}
+}
+
+function optional(status: Status | undefined) {
+	switch (status) {
+		case "active":
+		case "disabled":
+		case "deleted":
+			break;
+	}
+}
+
+function fallThrough(status: Status) {
+	switch (status) {
+		case "active":
+			console.log("active");
+	}
+}
+
+function singleLine(status: Status) {
+	switch (status) { case "active": return "Active"; }
+}
+
+function block(status: Status) {
+	switch (status) {
+		case "active": {
+			return "Active";
+		}
+	}
+}
@@ -0,0 +1,7 @@
+export enum Status {
+	Active,
+	Inactive,
+	Pending,
+}
+
+export type Priority = "low" | "high";
@@ -0,0 +1,62 @@
+type Status = "active" | "disabled" | "deleted";
+
+function label(status: Status) {
+	switch (status) {
+		case "active":
+			return "Active";
+		case "disabled":
+			return "Disabled";
+		case "deleted":
+			return "Deleted";
+	}
+}
+
+function isVisible(status: Status) {
+	switch (status) {
+		case "active":
+			return true;
+		default:
+			return false;
+	}
+}
+
+enum Direction {
+	Up,
+	Down,
+}
+
+function move(direction: Direction) {
+	switch (direction) {
+		case Direction.Up:
+		case Direction.Down:
+			break;
+	}
+}
+
+function count(value: number) {
+	switch (value) {
+		case 1:
+			break;
+	}
+}
+
+function text(value: string) {
+	switch (value) {
+		case "a":
+			break;
+	}
+}
+
+function unknown(value: Status, other: string) {
+	switch (value) {
+		case other:
+			break;
+	}
+}
+
+function digits(value: 1 | 2 | 3) {
+	switch (value) {
+		case 1:
+			break;
+	}
+}
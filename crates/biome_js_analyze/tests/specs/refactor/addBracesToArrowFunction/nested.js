@@ -0,0 +1 @@
+const outer = () => () => «»1;
@@ -0,0 +1,3 @@
+function createUsers(names) {
+	return names.map((name) => «»({ name }));
+}
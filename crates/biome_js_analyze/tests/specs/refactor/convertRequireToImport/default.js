@@ -0,0 +1 @@
+const «»path = require("node:path");
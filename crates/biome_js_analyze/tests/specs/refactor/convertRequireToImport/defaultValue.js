@@ -0,0 +1 @@
+const { «»sep = "/" } = require("node:path");
@@ -0,0 +1 @@
+const «»module = require(name);
@@ -0,0 +1,2 @@
+// Reads the files
+const { readFile, writeFile: «»write } = require("node:fs");
@@ -0,0 +1,3 @@
+function load() {
+	const «»path = require("node:path");
+}
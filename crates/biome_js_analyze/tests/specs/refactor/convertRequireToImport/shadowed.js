@@ -0,0 +1,2 @@
+import { require } from "./loader";
+const «»path = require("node:path");
@@ -0,0 +1,3 @@
+function double(value) {
+	return «»value * 2;
+}
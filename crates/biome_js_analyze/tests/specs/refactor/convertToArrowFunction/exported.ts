@@ -0,0 +1,4 @@
+export async function «»load<T>(url: string): Promise<T> {
+	const response = await fetch(url);
+	return response.json();
+}
@@ -0,0 +1,5 @@
+function parse(value: string): number;
+function parse(value: number): number;
+function «»parse(value: string | number): number {
+	return Number(value);
+}
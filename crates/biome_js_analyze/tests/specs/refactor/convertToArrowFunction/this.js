@@ -0,0 +1,3 @@
+function «»getName() {
+	return this.name;
+}
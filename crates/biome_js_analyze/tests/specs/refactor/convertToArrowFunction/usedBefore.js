@@ -0,0 +1,4 @@
+double(1);
+function «»double(value) {
+	return value * 2;
+}
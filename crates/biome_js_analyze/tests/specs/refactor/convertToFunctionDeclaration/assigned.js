@@ -0,0 +1,2 @@
+let «»double = (value) => value * 2;
+double = (value) => value + value;
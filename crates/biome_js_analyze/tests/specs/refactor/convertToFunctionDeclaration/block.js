@@ -0,0 +1,7 @@
+function setup() {
+	// Logs the value
+	const «»log = async (value) => {
+		console.log(value);
+	};
+	return log;
+}
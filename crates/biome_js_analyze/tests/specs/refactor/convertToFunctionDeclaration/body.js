@@ -0,0 +1 @@
+const double = (value) => «»value * 2;
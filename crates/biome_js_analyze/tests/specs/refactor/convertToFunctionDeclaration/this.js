@@ -0,0 +1 @@
+const «»getName = () => this.name;
@@ -0,0 +1,2 @@
+const message = `It's «»a \`template\` with \${dollars} and "quotes"
+on two lines`;
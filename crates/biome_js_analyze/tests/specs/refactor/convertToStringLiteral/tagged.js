@@ -0,0 +1 @@
+const query = sql`SELECT «»1`;
@@ -0,0 +1 @@
+const message = `Hello, «»world!`;
@@ -0,0 +1,3 @@
+enum Direction {
+	Up = «»"UP",
+}
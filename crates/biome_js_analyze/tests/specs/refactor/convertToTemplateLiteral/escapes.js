@@ -0,0 +1 @@
+const message = 'It\'s «»a `template` with ${dollars} and "quotes"\n';
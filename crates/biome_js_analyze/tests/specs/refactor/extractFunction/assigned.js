@@ -0,0 +1,9 @@
+function count(items) {
+	let total = 0;
+	let valid = 0;
+	«for (const item of items) {
+		total += item.count;
+		valid++;
+	}»
+	return total / valid;
+}
@@ -0,0 +1,4 @@
+async function load(url) {
+	«const response = await fetch(url);»
+	return response.json();
+}
@@ -0,0 +1,7 @@
+function search(items) {
+	for (const item of items) {
+		«if (item.done) {
+			break;
+		}»
+	}
+}
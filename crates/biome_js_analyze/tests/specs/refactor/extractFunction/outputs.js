@@ -0,0 +1,5 @@
+function checkout(cart, discount) {
+	«const subtotal = cart.total();
+	const total = subtotal - discount;»
+	pay(total);
+}
@@ -0,0 +1,4 @@
+function partial(a) {
+	const b = «a + 1;
+	const c» = b;
+}
@@ -0,0 +1,9 @@
+function find(items, name) {
+	log(name);
+	«for (const item of items) {
+		if (item.name === name) {
+			return item;
+		}
+	}
+	return null;»
+}
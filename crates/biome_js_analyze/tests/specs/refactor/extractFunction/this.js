@@ -0,0 +1,5 @@
+class Counter {
+	increment() {
+		«this.count++;»
+	}
+}
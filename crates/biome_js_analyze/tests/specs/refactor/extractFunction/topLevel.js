@@ -0,0 +1,3 @@
+const base = 10;
+«const doubled = base * 2;
+console.log(doubled);»
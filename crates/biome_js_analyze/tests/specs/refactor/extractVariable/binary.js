@@ -0,0 +1,3 @@
+function total(price, quantity) {
+	return «price * quantity» + shipping;
+}
@@ -0,0 +1 @@
+const first = 1, second = «first + 1»;
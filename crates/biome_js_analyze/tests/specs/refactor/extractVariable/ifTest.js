@@ -0,0 +1,8 @@
+function process(items) {
+	if (items.length > 0) {
+		// Only the first items
+		if («items.slice(0, 10).length» > 5) {
+			flush(items);
+		}
+	}
+}
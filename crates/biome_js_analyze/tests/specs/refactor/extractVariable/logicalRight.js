@@ -0,0 +1,4 @@
+function read(options) {
+	const value = options && «options.value»;
+	return value;
+}
@@ -0,0 +1,5 @@
+function drain(queue) {
+	while («queue.length» > 0) {
+		queue.pop();
+	}
+}
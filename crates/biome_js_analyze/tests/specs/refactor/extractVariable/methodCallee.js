@@ -0,0 +1 @@
+«list.push»(1);
@@ -0,0 +1,5 @@
+if («»a || b) {
+	first();
+} else {
+	second();
+}
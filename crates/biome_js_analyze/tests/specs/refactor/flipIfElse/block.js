@@ -0,0 +1,7 @@
+function show(user) {
+	«»if (user.isAdmin) {
+		showDashboard();
+	} else {
+		showHome();
+	}
+}
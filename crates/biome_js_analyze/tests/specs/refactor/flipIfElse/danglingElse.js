@@ -0,0 +1,3 @@
+if («»a) {
+	first();
+} else for (const item of items) if (item) second();
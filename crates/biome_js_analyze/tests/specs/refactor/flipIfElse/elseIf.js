@@ -0,0 +1,5 @@
+if («»a) {
+	first();
+} else if (b) {
+	second();
+}
@@ -0,0 +1 @@
+if (status «»=== "done") finish(); else retry();
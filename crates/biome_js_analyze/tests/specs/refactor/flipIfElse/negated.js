@@ -0,0 +1,5 @@
+if («»!(user && user.isAdmin)) {
+	showHome();
+} else {
+	showDashboard();
+}
@@ -0,0 +1,3 @@
+const «value» = compute();
+check(value);
+report(value);
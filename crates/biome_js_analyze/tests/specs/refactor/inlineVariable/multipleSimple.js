@@ -0,0 +1,3 @@
+const «limit» = 10;
+check(limit);
+report(limit);
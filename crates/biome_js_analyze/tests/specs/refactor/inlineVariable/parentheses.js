@@ -0,0 +1,4 @@
+function double(a, b) {
+	const «»sum = a + b;
+	return sum * 2;
+}
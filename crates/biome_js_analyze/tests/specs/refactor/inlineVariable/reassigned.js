@@ -0,0 +1,3 @@
+let «count» = 0;
+count++;
+report(count);
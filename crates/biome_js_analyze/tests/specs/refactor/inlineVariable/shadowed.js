@@ -0,0 +1,4 @@
+const «value» = x;
+function read(x) {
+	return value;
+}
@@ -0,0 +1,4 @@
+function describe(user) {
+	const «name» = user.name;
+	return { name };
+}
@@ -0,0 +1,4 @@
+function total(price) {
+	const «»shipping = price * 0.1;
+	return price + shipping;
+}
@@ -0,0 +1,4 @@
+const double = (value) => {
+	// Double the value
+	return «»value * 2;
+};
@@ -0,0 +1,7 @@
+function load(urls) {
+	return urls.map((url) => {
+		«»return fetch(url, {
+			method: "GET",
+		});
+	});
+}
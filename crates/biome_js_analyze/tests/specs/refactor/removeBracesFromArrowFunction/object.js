@@ -0,0 +1,3 @@
+const createUser = (name) => {
+	«»return { name };
+};
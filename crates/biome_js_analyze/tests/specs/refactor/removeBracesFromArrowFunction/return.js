@@ -0,0 +1,3 @@
+const double = (value) => {
+	return «»value * 2;
+};
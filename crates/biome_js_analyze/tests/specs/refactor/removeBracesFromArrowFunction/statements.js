@@ -0,0 +1,4 @@
+const log = (value) => {
+	«»console.log(value);
+	return value;
+};
@@ -252,6 +252,9 @@ fn compute_source_type_from_path_or_extension(
             "ts" => JsFileSource::ts(),
             "mts" | "cts" => JsFileSource::ts_restricted(),
             "tsx" => JsFileSource::tsx(),
+            // The scripts of Vue, Svelte and Astro files are parsed separately,
+            // TypeScript is the closest superset of the languages they can use
+            "vue" | "svelte" | "astro" => JsFileSource::ts(),
             _ => {
                 return Err(FileSourceError::UnknownExtension(
                     file_name.into(),
@@ -1,4 +1,4 @@
-use biome_diagnostics::{Category, Diagnostic};
+use biome_diagnostics::{category, Category, Diagnostic};
 use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
@@ -11,6 +11,9 @@ use biome_rowan::{TextRange, TextSize};
 /// suppression may specify one or more categories + values, for instance to
 /// disable multiple lints at once
 ///
+/// Rules defined by plugins aren't known statically: a `lint/plugin/<rule>`
+/// category is parsed as the `lint/plugin` category with the value `<rule>`.
+///
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
@@ -187,11 +190,29 @@ fn parse_suppression_line(
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
+        // The name of a rule defined by a plugin, in a `lint/plugin/<rule>` category
+        let mut plugin_rule = None;
         let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
-                message: SuppressionDiagnosticKind::ParseCategory(category.into()),
-                span: TextRange::at(offset_from(base, category), TextSize::of(category)),
-            })?;
+            let category = match category.strip_prefix("lint/plugin/") {
+                // Plugin rules aren't known statically, the rule is
+                // stored as the value of the `lint/plugin` category
+                Some(rule) if !rule.is_empty() => {
+                    plugin_rule = Some(rule);
+                    category!("lint/plugin")
+                }
+                _ => match category.parse() {
+                    Ok(category) => category,
+                    Err(()) => {
+                        return Err(SuppressionDiagnostic {
+                            message: SuppressionDiagnosticKind::ParseCategory(category.into()),
+                            span: TextRange::at(
+                                offset_from(base, category),
+                                TextSize::of(category),
+                            ),
+                        })
+                    }
+                },
+            };
             Some(category)
         } else {
             None
@@ -204,7 +225,7 @@ fn parse_suppression_line(
             // Colon token: stop parsing categories
             ":" => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push((category, plugin_rule));
                 }
 
                 line = rest.trim_start();
@@ -234,7 +255,7 @@ fn parse_suppression_line(
             // Whitespace: push a category without value
             _ => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push((category, plugin_rule));
                 }
 
                 line = rest.trim_start();
@@ -720,4 +741,32 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_plugin_rule_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/plugin/noFoo lint/plugin: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![
+                    (category!("lint/plugin"), Some("noFoo")),
+                    (category!("lint/plugin"), None)
+                ],
+                reason: "explanation",
+                is_legacy: false
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_unnamed_plugin_rule() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/plugin/: explanation")
+                .collect::<Vec<_>>(),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::ParseCategory(String::from("lint/plugin/")),
+                span: TextRange::new(TextSize::from(16), TextSize::from(28))
+            })],
+        );
+    }
 }
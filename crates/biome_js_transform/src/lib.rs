@@ -1,6 +1,7 @@
 mod declare_transformation;
 mod registry;
 mod transformers;
+mod utils;
 
 use crate::registry::visit_transformation_registry;
 use biome_analyze::{
@@ -57,7 +58,8 @@ where
     let mut analyzer = Analyzer::new(
         metadata(),
         InspectMatcher::new(registry, inspect_matcher),
-        |_| -> Vec<Result<_, Infallible>> { unreachable!() },
+        // Transformations can't be suppressed
+        |_| -> Vec<Result<_, Infallible>> { Vec::new() },
         |_| {},
         &mut emit_signal,
     );
@@ -1,4 +1,13 @@
+use crate::transformers::jsx::Jsx;
+use crate::transformers::ts_class_members::TsClassMembers;
 use crate::transformers::ts_enum::TsEnum;
+use crate::transformers::ts_namespace::TsNamespace;
+use crate::transformers::ts_parameter_properties::TsParameterProperties;
+use crate::transformers::ts_type_annotations::TsTypeAnnotations;
+use crate::transformers::ts_type_assertions::TsTypeAssertions;
+use crate::transformers::ts_type_declarations::TsTypeDeclarations;
+use crate::transformers::ts_type_exports::TsTypeExports;
+use crate::transformers::ts_type_imports::TsTypeImports;
 use biome_analyze::{GroupCategory, RegistryVisitor, RuleCategory, RuleGroup};
 use biome_js_syntax::JsLanguage;
 
@@ -20,7 +29,16 @@ impl RuleGroup for TransformationGroup {
     const NAME: &'static str = "transformations";
 
     fn record_rules<V: RegistryVisitor<Self::Language> + ?Sized>(registry: &mut V) {
+        registry.record_rule::<Jsx>();
+        registry.record_rule::<TsClassMembers>();
         registry.record_rule::<TsEnum>();
+        registry.record_rule::<TsNamespace>();
+        registry.record_rule::<TsParameterProperties>();
+        registry.record_rule::<TsTypeAnnotations>();
+        registry.record_rule::<TsTypeAssertions>();
+        registry.record_rule::<TsTypeDeclarations>();
+        registry.record_rule::<TsTypeExports>();
+        registry.record_rule::<TsTypeImports>();
     }
 }
 
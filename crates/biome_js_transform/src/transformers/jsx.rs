@@ -0,0 +1,809 @@
+use crate::utils::make_identifier_expression;
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::{
+    ident, js_array_element_list, js_array_expression, js_boolean_literal_expression,
+    js_call_argument_list, js_call_arguments, js_call_expression, js_identifier_binding, js_import,
+    js_import_named_clause, js_literal_export_name, js_literal_member_name, js_module_item_list,
+    js_module_source, js_name, js_named_import_specifier, js_named_import_specifier_list,
+    js_named_import_specifiers, js_null_literal_expression, js_object_expression,
+    js_object_member_list, js_property_object_member, js_spread, js_static_member_expression,
+    js_string_literal, js_string_literal_expression, js_this_expression, token,
+    token_decorated_with_space,
+};
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsBinding, AnyJsCallArgument, AnyJsExpression, AnyJsImportClause,
+    AnyJsLiteralExpression, AnyJsModuleItem, AnyJsName, AnyJsNamedImportSpecifier,
+    AnyJsObjectMember, AnyJsObjectMemberName, AnyJsRoot, AnyJsxAttribute, AnyJsxAttributeName,
+    AnyJsxAttributeValue, AnyJsxChild, AnyJsxElementName, AnyJsxObjectName, AnyJsxTag, JsLanguage,
+    JsModuleItemList, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsxChildList, JsxTagExpression, T,
+};
+use biome_rowan::{
+    AstNode, AstNodeExt, AstNodeList, BatchMutation, BatchMutationExt, Direction, TriviaPieceKind,
+};
+
+declare_transformation! {
+    /// Compile the JSX syntax to function calls.
+    ///
+    /// By default, the transformation targets the automatic runtime, which
+    /// imports its helpers from `react/jsx-runtime`:
+    ///
+    /// ```jsx
+    /// const a = <div className="a">{b}</div>;
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// import { jsx as _jsx } from "react/jsx-runtime";
+    /// const a = _jsx("div", { className: "a", children: b });
+    /// ```
+    ///
+    /// The classic runtime calls `React.createElement` instead:
+    ///
+    /// ```js
+    /// const a = React.createElement("div", { className: "a" }, b);
+    /// ```
+    ///
+    /// The runtime is configured with comments in the file:
+    /// - `@jsxRuntime classic` or `@jsxRuntime automatic` selects the runtime;
+    /// - `@jsx h` and `@jsxFrag Fragment` change the functions called by the
+    ///   classic runtime to create elements and fragments, and select it;
+    /// - `@jsxImportSource preact` changes the module that provides the helpers
+    ///   of the automatic runtime, and selects it.
+    ///
+    /// Scripts can't import modules, so they always use the classic runtime.
+    pub(crate) Jsx {
+        version: "next",
+        name: "transformJsx",
+    }
+}
+
+impl Rule for Jsx {
+    type Query = Ast<AnyJsRoot>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if matches!(node, AnyJsRoot::JsExpressionSnipped(_)) {
+            return None;
+        }
+
+        contains_jsx(node.syntax()).then_some(())
+    }
+
+    fn transform(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let pragmas = JsxPragmas::from_root(node.syntax());
+        let mut converter = JsxConverter::new(&pragmas);
+        let mut mutation = ctx.root().begin();
+
+        match node {
+            AnyJsRoot::JsModule(module) => {
+                let items = module.items();
+                let mut new_items = converter.convert_descendants(items.clone());
+                if let Some(import) = converter.runtime_import() {
+                    new_items = prepend_item(new_items, import)?;
+                }
+                mutation.replace_node_discard_trivia(items, new_items);
+            }
+            AnyJsRoot::JsScript(script) => {
+                let statements = script.statements();
+                let new_statements = converter.convert_descendants(statements.clone());
+                mutation.replace_node_discard_trivia(statements, new_statements);
+            }
+            AnyJsRoot::JsExpressionSnipped(_) => return None,
+        }
+
+        Some(mutation)
+    }
+}
+
+/// The functions of the automatic runtime, in the order they're imported
+const RUNTIME_HELPERS: [&str; 3] = ["jsx", "jsxs", "Fragment"];
+
+#[derive(Debug, Eq, PartialEq)]
+enum JsxRuntime {
+    /// Elements are created with `React.createElement`
+    Classic,
+    /// Elements are created with the functions imported from `react/jsx-runtime`
+    Automatic,
+}
+
+/// The options of the transformation, read from the comments of the file
+#[derive(Debug)]
+struct JsxPragmas {
+    runtime: JsxRuntime,
+    /// The function that creates the elements with the classic runtime
+    factory: String,
+    /// The component of the fragments with the classic runtime
+    fragment_factory: String,
+    /// The module that provides the automatic runtime
+    import_source: String,
+}
+
+impl JsxPragmas {
+    fn from_root(root: &JsSyntaxNode) -> Self {
+        let mut runtime = None;
+        let mut factory = None;
+        let mut fragment_factory = None;
+        let mut import_source = None;
+
+        let comments = root.descendants_tokens(Direction::Next).flat_map(|token| {
+            token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+                .filter(|piece| piece.is_comments())
+                .map(|piece| piece.text().to_string())
+                .collect::<Vec<_>>()
+        });
+        for comment in comments {
+            let mut words = comment
+                .split_whitespace()
+                .map(|word| word.trim_end_matches("*/"));
+            while let Some(word) = words.next() {
+                match word {
+                    "@jsxRuntime" => {
+                        runtime = match words.next() {
+                            Some("classic") => Some(JsxRuntime::Classic),
+                            Some("automatic") => Some(JsxRuntime::Automatic),
+                            _ => runtime,
+                        }
+                    }
+                    "@jsx" => {
+                        if let Some(value) = words.next().filter(|value| is_member_chain(value)) {
+                            factory = Some(value.to_string());
+                        }
+                    }
+                    "@jsxFrag" => {
+                        if let Some(value) = words.next().filter(|value| is_member_chain(value)) {
+                            fragment_factory = Some(value.to_string());
+                        }
+                    }
+                    "@jsxImportSource" => {
+                        if let Some(value) = words.next().filter(|value| is_module_name(value)) {
+                            import_source = Some(value.to_string());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let runtime = if root.kind() != JsSyntaxKind::JS_MODULE {
+            JsxRuntime::Classic
+        } else if let Some(runtime) = runtime {
+            runtime
+        } else if factory.is_some() || fragment_factory.is_some() {
+            JsxRuntime::Classic
+        } else {
+            JsxRuntime::Automatic
+        };
+
+        Self {
+            runtime,
+            factory: factory.unwrap_or_else(|| "React.createElement".to_string()),
+            fragment_factory: fragment_factory.unwrap_or_else(|| "React.Fragment".to_string()),
+            import_source: import_source.unwrap_or_else(|| "react".to_string()),
+        }
+    }
+}
+
+/// Returns the names of the bindings referenced by the code emitted for the JSX
+/// syntax of the file, e.g. `React` with the classic runtime.
+///
+/// The imports of these bindings must be preserved even though they don't
+/// appear in the source code.
+pub(crate) fn jsx_factory_references(root: &JsSyntaxNode) -> Vec<String> {
+    if !contains_jsx(root) {
+        return Vec::new();
+    }
+
+    let pragmas = JsxPragmas::from_root(root);
+    if pragmas.runtime != JsxRuntime::Classic {
+        return Vec::new();
+    }
+
+    [&pragmas.factory, &pragmas.fragment_factory]
+        .into_iter()
+        .filter_map(|path| path.split('.').next())
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn contains_jsx(root: &JsSyntaxNode) -> bool {
+    root.descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JSX_TAG_EXPRESSION)
+}
+
+/// A child of an element, passed to the function that creates the element
+enum JsxChildValue {
+    Expression(AnyJsExpression),
+    /// `{...children}`
+    Spread(AnyJsExpression),
+}
+
+struct JsxConverter<'a> {
+    pragmas: &'a JsxPragmas,
+    /// The functions of the automatic runtime referenced by the emitted code
+    helpers: Vec<&'static str>,
+}
+
+impl<'a> JsxConverter<'a> {
+    fn new(pragmas: &'a JsxPragmas) -> Self {
+        Self {
+            pragmas,
+            helpers: Vec::new(),
+        }
+    }
+
+    /// Returns a copy of `node` where the JSX tags are replaced with function calls
+    fn convert_descendants<N>(&mut self, node: N) -> N
+    where
+        N: AstNode<Language = JsLanguage>,
+    {
+        let node = node.detach();
+        // The nested tags are converted together with the outermost ones
+        let tags: Vec<_> = node
+            .syntax()
+            .descendants()
+            .skip(1)
+            .filter_map(JsxTagExpression::cast)
+            .filter(|tag| {
+                tag.syntax()
+                    .ancestors()
+                    .skip(1)
+                    .all(|ancestor| ancestor.kind() != JsSyntaxKind::JSX_TAG_EXPRESSION)
+            })
+            .collect();
+        if tags.is_empty() {
+            return node;
+        }
+
+        let mut mutation = BatchMutation::new(node.syntax().clone());
+        for tag in tags {
+            let Some(expression) = tag.tag().ok().and_then(|tag| self.convert_tag(&tag)) else {
+                continue;
+            };
+            mutation.replace_node(AnyJsExpression::JsxTagExpression(tag), expression);
+        }
+
+        N::unwrap_cast(mutation.commit())
+    }
+
+    fn convert_expression(&mut self, expression: AnyJsExpression) -> Option<AnyJsExpression> {
+        match expression {
+            AnyJsExpression::JsxTagExpression(expression) => {
+                self.convert_tag(&expression.tag().ok()?)
+            }
+            expression => Some(self.convert_descendants(expression)),
+        }
+    }
+
+    fn convert_tag(&mut self, tag: &AnyJsxTag) -> Option<AnyJsExpression> {
+        let (name, attributes, children) = match tag {
+            AnyJsxTag::JsxElement(element) => {
+                let opening_element = element.opening_element().ok()?;
+                (
+                    Some(opening_element.name().ok()?),
+                    Some(opening_element.attributes()),
+                    Some(element.children()),
+                )
+            }
+            AnyJsxTag::JsxSelfClosingElement(element) => {
+                (Some(element.name().ok()?), Some(element.attributes()), None)
+            }
+            AnyJsxTag::JsxFragment(fragment) => (None, None, Some(fragment.children())),
+        };
+
+        let element_type = match name {
+            Some(name) => convert_element_name(&name)?,
+            None => match self.pragmas.runtime {
+                JsxRuntime::Classic => make_member_chain(&self.pragmas.fragment_factory),
+                JsxRuntime::Automatic => self.helper("Fragment"),
+            },
+        };
+
+        let mut props = Vec::new();
+        let mut key = None;
+        for attribute in attributes.iter().flat_map(|attributes| attributes.iter()) {
+            match attribute {
+                AnyJsxAttribute::JsxAttribute(attribute) => {
+                    let name = match attribute.name().ok()? {
+                        AnyJsxAttributeName::JsxName(name) => {
+                            name.value_token().ok()?.text_trimmed().to_string()
+                        }
+                        AnyJsxAttributeName::JsxNamespaceName(name) => format!(
+                            "{}:{}",
+                            name.namespace().ok()?.value_token().ok()?.text_trimmed(),
+                            name.name().ok()?.value_token().ok()?.text_trimmed()
+                        ),
+                    };
+                    let value = match attribute.initializer() {
+                        Some(initializer) => {
+                            self.convert_attribute_value(initializer.value().ok()?)?
+                        }
+                        None => AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsBooleanLiteralExpression(
+                                js_boolean_literal_expression(token(T![true])),
+                            ),
+                        ),
+                    };
+
+                    // The automatic runtime receives the key separately from the props
+                    if name == "key" && self.pragmas.runtime == JsxRuntime::Automatic {
+                        key = Some(value);
+                    } else {
+                        props.push(make_property(&name, value));
+                    }
+                }
+                AnyJsxAttribute::JsxSpreadAttribute(attribute) => {
+                    let argument = self.convert_expression(attribute.argument().ok()?)?;
+                    props.push(AnyJsObjectMember::JsSpread(js_spread(
+                        token(T![...]),
+                        argument,
+                    )));
+                }
+            }
+        }
+
+        let children = match children {
+            Some(children) => self.convert_children(&children)?,
+            None => Vec::new(),
+        };
+
+        match self.pragmas.runtime {
+            JsxRuntime::Classic => {
+                let props = if props.is_empty() {
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsNullLiteralExpression(
+                            js_null_literal_expression(token(T![null])),
+                        ),
+                    )
+                } else {
+                    make_object(props)
+                };
+                let arguments = [
+                    AnyJsCallArgument::AnyJsExpression(element_type),
+                    AnyJsCallArgument::AnyJsExpression(props),
+                ]
+                .into_iter()
+                .chain(children.into_iter().map(|child| match child {
+                    JsxChildValue::Expression(expression) => {
+                        AnyJsCallArgument::AnyJsExpression(expression)
+                    }
+                    JsxChildValue::Spread(expression) => {
+                        AnyJsCallArgument::JsSpread(js_spread(token(T![...]), expression))
+                    }
+                }))
+                .collect();
+                Some(make_call(
+                    make_member_chain(&self.pragmas.factory),
+                    arguments,
+                ))
+            }
+            JsxRuntime::Automatic => {
+                let is_static = children.len() > 1
+                    || children
+                        .iter()
+                        .any(|child| matches!(child, JsxChildValue::Spread(_)));
+                match children.as_slice() {
+                    [] => {}
+                    [JsxChildValue::Expression(expression)] if !is_static => {
+                        props.push(make_property("children", expression.clone()));
+                    }
+                    _ => {
+                        let elements: Vec<_> = children
+                            .into_iter()
+                            .map(|child| match child {
+                                JsxChildValue::Expression(expression) => {
+                                    AnyJsArrayElement::AnyJsExpression(expression)
+                                }
+                                JsxChildValue::Spread(expression) => AnyJsArrayElement::JsSpread(
+                                    js_spread(token(T![...]), expression),
+                                ),
+                            })
+                            .collect();
+                        let separators = separators(elements.len());
+                        let array = js_array_expression(
+                            token(T!['[']),
+                            js_array_element_list(elements, separators),
+                            token(T![']']),
+                        );
+                        props.push(make_property(
+                            "children",
+                            AnyJsExpression::JsArrayExpression(array),
+                        ));
+                    }
+                }
+
+                let callee = self.helper(if is_static { "jsxs" } else { "jsx" });
+                let mut arguments = vec![
+                    AnyJsCallArgument::AnyJsExpression(element_type),
+                    AnyJsCallArgument::AnyJsExpression(make_object(props)),
+                ];
+                if let Some(key) = key {
+                    arguments.push(AnyJsCallArgument::AnyJsExpression(key));
+                }
+                Some(make_call(callee, arguments))
+            }
+        }
+    }
+
+    fn convert_attribute_value(&mut self, value: AnyJsxAttributeValue) -> Option<AnyJsExpression> {
+        match value {
+            AnyJsxAttributeValue::AnyJsxTag(tag) => self.convert_tag(&tag),
+            AnyJsxAttributeValue::JsxExpressionAttributeValue(value) => {
+                self.convert_expression(value.expression().ok()?)
+            }
+            AnyJsxAttributeValue::JsxString(value) => {
+                let token = value.value_token().ok()?;
+                let text = token.text_trimmed();
+                // Strips the quotes, JSX strings don't have escape sequences
+                let text = text.get(1..text.len() - 1)?;
+                Some(make_string(&collapse_newlines(&decode_entities(text))))
+            }
+        }
+    }
+
+    fn convert_children(&mut self, children: &JsxChildList) -> Option<Vec<JsxChildValue>> {
+        let mut values = Vec::new();
+        for child in children.iter() {
+            let value = match child {
+                AnyJsxChild::JsxText(text) => match clean_text(text.value_token().ok()?.text()) {
+                    Some(text) => JsxChildValue::Expression(make_string(&decode_entities(&text))),
+                    None => continue,
+                },
+                AnyJsxChild::JsxExpressionChild(child) => match child.expression() {
+                    Some(expression) => {
+                        JsxChildValue::Expression(self.convert_expression(expression)?)
+                    }
+                    // `{}` or `{/* comment */}`
+                    None => continue,
+                },
+                AnyJsxChild::JsxSpreadChild(child) => {
+                    JsxChildValue::Spread(self.convert_expression(child.expression().ok()?)?)
+                }
+                AnyJsxChild::JsxElement(element) => {
+                    JsxChildValue::Expression(self.convert_tag(&AnyJsxTag::JsxElement(element))?)
+                }
+                AnyJsxChild::JsxSelfClosingElement(element) => JsxChildValue::Expression(
+                    self.convert_tag(&AnyJsxTag::JsxSelfClosingElement(element))?,
+                ),
+                AnyJsxChild::JsxFragment(fragment) => {
+                    JsxChildValue::Expression(self.convert_tag(&AnyJsxTag::JsxFragment(fragment))?)
+                }
+            };
+            values.push(value);
+        }
+
+        Some(values)
+    }
+
+    /// Returns a reference to a function of the automatic runtime, and records
+    /// that it must be imported
+    fn helper(&mut self, name: &'static str) -> AnyJsExpression {
+        if !self.helpers.contains(&name) {
+            self.helpers.push(name);
+        }
+        make_identifier_expression(&format!("_{name}"))
+    }
+
+    /// Creates the import of the functions of the automatic runtime used by the file:
+    ///
+    /// ```js
+    /// import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+    /// ```
+    fn runtime_import(&self) -> Option<AnyJsModuleItem> {
+        if self.helpers.is_empty() {
+            return None;
+        }
+
+        let specifiers: Vec<_> = RUNTIME_HELPERS
+            .into_iter()
+            .filter(|name| self.helpers.contains(name))
+            .map(|name| {
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(
+                    js_named_import_specifier(
+                        js_literal_export_name(ident(name)),
+                        token_decorated_with_space(T![as]),
+                        AnyJsBinding::JsIdentifierBinding(js_identifier_binding(ident(&format!(
+                            "_{name}"
+                        )))),
+                    )
+                    .build(),
+                )
+            })
+            .collect();
+        let separators = (0..specifiers.len().saturating_sub(1))
+            .map(|_| token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+        let clause = js_import_named_clause(
+            js_named_import_specifiers(
+                token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                js_named_import_specifier_list(specifiers, separators),
+                token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ),
+            token_decorated_with_space(T![from]),
+            js_module_source(js_string_literal(&format!(
+                "{}/jsx-runtime",
+                self.pragmas.import_source
+            ))),
+        )
+        .build();
+        let import = js_import(
+            token(T![import]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyJsImportClause::JsImportNamedClause(clause),
+        )
+        .with_semicolon_token(token(T![;]))
+        .build();
+
+        Some(AnyJsModuleItem::JsImport(import))
+    }
+}
+
+/// Inserts `item` at the start of `items`. The comments at the start of the
+/// file, such as the pragmas, are moved before the new item.
+fn prepend_item(items: JsModuleItemList, item: AnyJsModuleItem) -> Option<JsModuleItemList> {
+    let mut items: Vec<_> = items.iter().collect();
+    let mut item = item;
+    if let Some(first) = items.first_mut() {
+        let first_token = first.syntax().first_token()?;
+        let item_token = item.syntax().first_token()?;
+        item = item.replace_token_discard_trivia(
+            item_token.clone(),
+            item_token.with_leading_trivia_pieces(first_token.leading_trivia().pieces()),
+        )?;
+        *first = first.clone().replace_token_discard_trivia(
+            first_token.clone(),
+            first_token.with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
+        )?;
+    }
+
+    items.insert(0, item);
+    Some(js_module_item_list(items))
+}
+
+/// Converts the name of an element to the first argument of the function
+/// that creates it: intrinsic elements like `div` are passed as strings, and
+/// components as references
+fn convert_element_name(name: &AnyJsxElementName) -> Option<AnyJsExpression> {
+    match name {
+        AnyJsxElementName::JsxName(name) => {
+            Some(make_tag_reference(name.value_token().ok()?.text_trimmed()))
+        }
+        AnyJsxElementName::JsxReferenceIdentifier(name) => {
+            Some(make_tag_reference(name.value_token().ok()?.text_trimmed()))
+        }
+        AnyJsxElementName::JsxNamespaceName(name) => Some(make_string(&format!(
+            "{}:{}",
+            name.namespace().ok()?.value_token().ok()?.text_trimmed(),
+            name.name().ok()?.value_token().ok()?.text_trimmed()
+        ))),
+        AnyJsxElementName::JsxMemberName(name) => {
+            let object = match name.object().ok()? {
+                AnyJsxObjectName::JsxMemberName(object) => {
+                    convert_element_name(&AnyJsxElementName::JsxMemberName(object))?
+                }
+                AnyJsxObjectName::JsxReferenceIdentifier(object) => {
+                    make_reference(object.value_token().ok()?.text_trimmed())
+                }
+                AnyJsxObjectName::JsxNamespaceName(_) => return None,
+            };
+            let member = name.member().ok()?.value_token().ok()?;
+            Some(AnyJsExpression::JsStaticMemberExpression(
+                js_static_member_expression(
+                    object,
+                    token(T![.]),
+                    AnyJsName::JsName(js_name(ident(member.text_trimmed()))),
+                ),
+            ))
+        }
+    }
+}
+
+fn make_tag_reference(name: &str) -> AnyJsExpression {
+    let is_intrinsic = name.starts_with(|c: char| c.is_ascii_lowercase()) || name.contains('-');
+    if name != "this" && is_intrinsic {
+        make_string(name)
+    } else {
+        make_reference(name)
+    }
+}
+
+fn make_reference(name: &str) -> AnyJsExpression {
+    if name == "this" {
+        AnyJsExpression::JsThisExpression(js_this_expression(token(T![this])))
+    } else {
+        make_identifier_expression(name)
+    }
+}
+
+/// Creates an expression from a path like `React.createElement`
+fn make_member_chain(path: &str) -> AnyJsExpression {
+    let mut segments = path.split('.');
+    let first = segments.next().unwrap_or_default();
+    segments.fold(make_identifier_expression(first), |object, member| {
+        AnyJsExpression::JsStaticMemberExpression(js_static_member_expression(
+            object,
+            token(T![.]),
+            AnyJsName::JsName(js_name(ident(member))),
+        ))
+    })
+}
+
+fn make_call(callee: AnyJsExpression, arguments: Vec<AnyJsCallArgument>) -> AnyJsExpression {
+    let separators = separators(arguments.len());
+    AnyJsExpression::JsCallExpression(
+        js_call_expression(
+            callee,
+            js_call_arguments(
+                token(T!['(']),
+                js_call_argument_list(arguments, separators),
+                token(T![')']),
+            ),
+        )
+        .build(),
+    )
+}
+
+fn make_object(members: Vec<AnyJsObjectMember>) -> AnyJsExpression {
+    let separators = separators(members.len());
+    AnyJsExpression::JsObjectExpression(js_object_expression(
+        token(T!['{']),
+        js_object_member_list(members, separators),
+        token(T!['}']),
+    ))
+}
+
+/// Creates the property `name: value`, quoting the name if it isn't an identifier
+fn make_property(name: &str, value: AnyJsExpression) -> AnyJsObjectMember {
+    let name = if is_identifier(name) {
+        js_literal_member_name(ident(name))
+    } else {
+        js_literal_member_name(js_string_literal(&escape_string(name)))
+    };
+    AnyJsObjectMember::JsPropertyObjectMember(js_property_object_member(
+        AnyJsObjectMemberName::JsLiteralMemberName(name),
+        token(T![:]),
+        value,
+    ))
+}
+
+fn make_string(value: &str) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        js_string_literal_expression(js_string_literal(&escape_string(value))),
+    ))
+}
+
+fn separators(count: usize) -> Vec<JsSyntaxToken> {
+    (0..count.saturating_sub(1)).map(|_| token(T![,])).collect()
+}
+
+/// Escapes the content of a string literal delimited by double quotes
+fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Whether the value of a pragma is a valid reference, e.g. `h` or `React.createElement`
+fn is_member_chain(value: &str) -> bool {
+    value.split('.').all(is_identifier)
+}
+
+fn is_module_name(value: &str) -> bool {
+    !value.is_empty() && !value.contains(['"', '\\'])
+}
+
+/// Removes the whitespace of a text child the same way as React: the lines
+/// are trimmed, the empty ones are removed, and the others are joined with a space.
+///
+/// Returns `None` if the text only contains whitespace.
+fn clean_text(text: &str) -> Option<String> {
+    let lines: Vec<_> = text
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    let is_blank = |line: &str| line.chars().all(|c| c == ' ' || c == '\t');
+    let last_non_empty_line = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .unwrap_or_default();
+
+    let mut result = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.replace('\t', " ");
+        let mut line = line.as_str();
+        if index != 0 {
+            line = line.trim_start_matches(' ');
+        }
+        if index != lines.len() - 1 {
+            line = line.trim_end_matches(' ');
+        }
+        if line.is_empty() {
+            continue;
+        }
+        result.push_str(line);
+        if index != last_non_empty_line {
+            result.push(' ');
+        }
+    }
+
+    (!result.is_empty()).then_some(result)
+}
+
+/// Replaces the line breaks of an attribute string, and the indentation that
+/// follows them, with a single space
+fn collapse_newlines(text: &str) -> String {
+    let mut lines = text.split('\n');
+    let mut result = lines.next().unwrap_or_default().to_string();
+    for line in lines {
+        result.push(' ');
+        result.push_str(line.trim_start());
+    }
+    result
+}
+
+/// Decodes the character references of a JSX text or string, e.g. `&amp;` or `&#123;`
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let character = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                entity => entity.strip_prefix('#').and_then(|code| {
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16),
+                        None => code.parse(),
+                    };
+                    code.ok().and_then(char::from_u32)
+                }),
+            };
+            character.map(|character| (character, end))
+        });
+
+        match decoded {
+            Some((character, end)) => {
+                result.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
@@ -1 +1,10 @@
+pub(crate) mod jsx;
+pub(crate) mod ts_class_members;
 pub(crate) mod ts_enum;
+pub(crate) mod ts_namespace;
+pub(crate) mod ts_parameter_properties;
+pub(crate) mod ts_type_annotations;
+pub(crate) mod ts_type_assertions;
+pub(crate) mod ts_type_declarations;
+pub(crate) mod ts_type_exports;
+pub(crate) mod ts_type_imports;
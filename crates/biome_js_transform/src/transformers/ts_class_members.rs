@@ -0,0 +1,125 @@
+use crate::utils::{is_in_type_position, remove_element_preserving_spacing};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_syntax::{
+    AnyJsClassMember, JsClassDeclaration, JsClassExportDefaultDeclaration, JsClassMemberList,
+    JsSyntaxKind, TsAccessibilityModifier, TsConstructorSignatureClassMember,
+    TsGetterSignatureClassMember, TsIndexSignatureClassMember,
+    TsInitializedPropertySignatureClassMember, TsMethodSignatureClassMember, TsOverrideModifier,
+    TsPropertySignatureClassMember, TsReadonlyModifier, TsSetterSignatureClassMember,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt};
+
+declare_transformation! {
+    /// Remove the TypeScript modifiers and the members that only exist in the
+    /// type system from classes.
+    ///
+    /// ```ts
+    /// abstract class A {
+    ///     [key: string]: unknown;
+    ///     declare b: string;
+    ///     private readonly c = 1;
+    ///     abstract d(): void;
+    ///     public e() {}
+    /// }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// class A {
+    ///     c = 1;
+    ///     e() {}
+    /// }
+    /// ```
+    pub(crate) TsClassMembers {
+        version: "next",
+        name: "transformClassMembers",
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsClassSyntax =
+        TsAccessibilityModifier
+        | TsOverrideModifier
+        | TsReadonlyModifier
+        | TsConstructorSignatureClassMember
+        | TsGetterSignatureClassMember
+        | TsSetterSignatureClassMember
+        | TsIndexSignatureClassMember
+        | TsInitializedPropertySignatureClassMember
+        | TsMethodSignatureClassMember
+        | TsPropertySignatureClassMember
+        | JsClassDeclaration
+        | JsClassExportDefaultDeclaration
+}
+
+impl Rule for TsClassMembers {
+    type Query = Ast<AnyTsClassSyntax>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_in_type_position(node.syntax()) {
+            return None;
+        }
+
+        match node {
+            // The class stays abstract until its abstract members are removed
+            AnyTsClassSyntax::JsClassDeclaration(class) => {
+                class.abstract_token()?;
+                (!has_signature_members(&class.members())).then_some(())
+            }
+            AnyTsClassSyntax::JsClassExportDefaultDeclaration(class) => {
+                class.abstract_token()?;
+                (!has_signature_members(&class.members())).then_some(())
+            }
+            AnyTsClassSyntax::TsAccessibilityModifier(_)
+            | AnyTsClassSyntax::TsOverrideModifier(_)
+            | AnyTsClassSyntax::TsReadonlyModifier(_) => {
+                // Modifiers of parameter properties are removed with the parameter,
+                // and modifiers of signatures are removed with the signature
+                let parent = node.syntax().parent()?;
+                matches!(
+                    parent.kind(),
+                    JsSyntaxKind::JS_PROPERTY_MODIFIER_LIST
+                        | JsSyntaxKind::JS_METHOD_MODIFIER_LIST
+                        | JsSyntaxKind::JS_CONSTRUCTOR_MODIFIER_LIST
+                )
+                .then_some(())
+            }
+            _ => Some(()),
+        }
+    }
+
+    fn transform(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match node {
+            AnyTsClassSyntax::JsClassDeclaration(class) => {
+                remove_element_preserving_spacing(&mut mutation, class.abstract_token()?.into());
+            }
+            AnyTsClassSyntax::JsClassExportDefaultDeclaration(class) => {
+                remove_element_preserving_spacing(&mut mutation, class.abstract_token()?.into());
+            }
+            _ => remove_element_preserving_spacing(&mut mutation, node.syntax().clone().into()),
+        }
+
+        Some(mutation)
+    }
+}
+
+fn has_signature_members(members: &JsClassMemberList) -> bool {
+    members.iter().any(|member| {
+        matches!(
+            member,
+            AnyJsClassMember::TsGetterSignatureClassMember(_)
+                | AnyJsClassMember::TsSetterSignatureClassMember(_)
+                | AnyJsClassMember::TsMethodSignatureClassMember(_)
+                | AnyJsClassMember::TsPropertySignatureClassMember(_)
+        )
+    })
+}
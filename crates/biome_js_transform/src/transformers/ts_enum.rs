@@ -1,24 +1,23 @@
+use crate::utils::{
+    is_in_type_position, make_identifier_expression, make_initializer_call,
+    make_static_member_expression, make_variable_declaration, replace_statement,
+    statement_or_export,
+};
 use crate::{declare_transformation, JsBatchMutation};
 use biome_analyze::context::RuleContext;
 use biome_analyze::{Ast, Rule};
 use biome_js_factory::make::{
-    ident, js_assignment_expression, js_call_argument_list, js_call_arguments, js_call_expression,
-    js_computed_member_assignment, js_decorator_list, js_directive_list, js_expression_statement,
-    js_formal_parameter, js_function_body, js_function_expression, js_identifier_assignment,
-    js_identifier_binding, js_identifier_expression, js_logical_expression, js_module_item_list,
-    js_number_literal_expression, js_object_expression, js_object_member_list, js_parameter_list,
-    js_parameters, js_parenthesized_expression, js_reference_identifier, js_statement_list,
-    js_string_literal, js_string_literal_expression, js_variable_declaration,
-    js_variable_declarator, js_variable_declarator_list, js_variable_statement, token,
+    js_assignment_expression, js_binary_expression, js_computed_member_assignment,
+    js_computed_member_expression, js_expression_statement, js_number_literal,
+    js_number_literal_expression, js_string_literal, js_string_literal_expression, token,
 };
 use biome_js_syntax::{
-    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
-    AnyJsExpression, AnyJsFormalParameter, AnyJsLiteralExpression, AnyJsModuleItem, AnyJsParameter,
-    AnyJsStatement, JsAssignmentExpression, JsComputedMemberAssignment, JsExpressionStatement,
-    JsFunctionExpression, JsInitializerClause, JsLogicalExpression, JsModuleItemList,
-    JsStatementList, JsVariableStatement, TsEnumDeclaration, T,
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsModuleItem, AnyJsStatement, JsExport, JsIdentifierExpression, JsInitializerClause,
+    JsLanguage, TsEnumDeclaration, T,
 };
-use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+use biome_rowan::{AstNode, BatchMutation, BatchMutationExt};
+use std::collections::HashSet;
 
 declare_transformation! {
     /// Transform a TypeScript [TsEnumDeclaration]
@@ -42,12 +41,17 @@ impl Rule for TsEnum {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
+        // Ambient enums don't exist at runtime, they are removed with their declaration
+        if is_in_type_position(node.syntax()) {
+            return None;
+        }
+
         let mut member_names = vec![];
         let id = node.id().ok()?;
         let name = id.text();
         for member in node.members() {
             let member = member.ok()?;
-            let key = member.name().ok()?.text();
+            let key = member.name().ok()?.name()?.text().to_string();
             let value = member.initializer().clone();
             member_names.push((key, value));
         }
@@ -57,216 +61,262 @@ impl Rule for TsEnum {
 
     fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
         let node = ctx.query();
-        let mut mutation = node.clone().begin();
-        let parent = node.syntax().parent();
-        if let Some(parent) = parent {
-            if let Some(module_list) = JsModuleItemList::cast(parent) {
-                let variable = make_variable(state);
-                let function = make_function_caller(state);
-                let statements = vec![
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsVariableStatement(variable)),
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
-                        function,
-                    )),
-                ];
-                let new_modules_list = js_module_item_list(statements);
-                mutation.replace_node(module_list, new_modules_list);
-            }
+        let mut mutation = ctx.root().begin();
+        let item = statement_or_export(node.syntax());
+        let is_exported = JsExport::can_cast(item.kind());
+
+        let statements = vec![
+            make_variable_declaration(T![var], &state.name, is_exported),
+            AnyJsModuleItem::AnyJsStatement(make_initializer_call(
+                &state.name,
+                None,
+                make_members(state),
+            )),
+        ];
+        if !replace_statement(&mut mutation, &item, statements) {
+            return None;
         }
 
         Some(mutation)
     }
 }
 
-/// Out of an enum, this functions emits the generation of the:
+/// Creates the statements that initialize the members of the enum:
 ///
-/// ```ts
-/// enum Foo {}
-/// var Foo;
+/// ```js
+/// Foo[Foo["Lorem"] = 0] = "Lorem";
+/// Foo["Ipsum"] = "ipsum";
 /// ```
-fn make_variable(node: &TsEnumMembers) -> JsVariableStatement {
-    let binding = js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
-        AnyJsBinding::JsIdentifierBinding(js_identifier_binding(ident(node.name.as_str()))),
-    ))
-    .build();
+///
+/// Members without initializer are numbered from the previous member. The
+/// references to other members in the initializers are qualified with the
+/// name of the enum, `B = A * 2` becomes `Foo["B"] = Foo.A * 2`.
+fn make_members(ts_enum: &TsEnumMembers) -> Vec<AnyJsStatement> {
+    let mut list = vec![];
+    let member_names: HashSet<&str> = ts_enum
+        .member_names
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    // The members with a string value, they don't have a reverse mapping
+    let mut string_members = HashSet::new();
+    // The value of the next member without initializer, if it's known
+    let mut next_value = Some(0f64);
+    let mut previous_name: Option<&str> = None;
+    for (name, value) in ts_enum.member_names.iter() {
+        let value = value
+            .as_ref()
+            .and_then(|initializer| initializer.expression().ok());
+        let statement = match value {
+            Some(value)
+                if is_string_expression(&value)
+                    || as_member_reference(&value, &member_names)
+                        .is_some_and(|member| string_members.contains(member.as_str())) =>
+            {
+                next_value = None;
+                string_members.insert(name.as_str());
+                let value = qualify_member_references(&ts_enum.name, value, &member_names);
+                make_member_assignment(ts_enum.name.as_str(), name, value)
+            }
+            Some(value) => {
+                next_value = as_number(&value).map(|value| value + 1.0);
+                let value = qualify_member_references(&ts_enum.name, value, &member_names);
+                make_reverse_member_assignment(ts_enum.name.as_str(), name, value)
+            }
+            None => {
+                let value = match (next_value, previous_name) {
+                    (Some(value), _) => {
+                        next_value = Some(value + 1.0);
+                        make_number(value)
+                    }
+                    // Unknown value, the member follows the previous one
+                    (None, Some(previous_name)) => {
+                        AnyJsExpression::JsBinaryExpression(js_binary_expression(
+                            make_member_expression(ts_enum.name.as_str(), previous_name),
+                            token(T![+]),
+                            make_number(1.0),
+                        ))
+                    }
+                    (None, None) => make_number(0.0),
+                };
+                make_reverse_member_assignment(ts_enum.name.as_str(), name, value)
+            }
+        };
+        list.push(statement);
+        previous_name = Some(name);
+    }
 
-    let list = js_variable_declarator_list([binding], []);
-    js_variable_statement(
-        js_variable_declaration(
-            token(T![var]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
-            list,
-        )
-        .build(),
-    )
-    .with_semicolon_token(token(T![;]))
-    .build()
+    list
 }
 
-fn make_function_caller(node: &TsEnumMembers) -> JsExpressionStatement {
-    let callee = js_parenthesized_expression(
-        token(T!['(']),
-        AnyJsExpression::JsFunctionExpression(make_function(node)),
-        token(T![')']),
-    );
-    let argument = AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsLogicalExpression(
-        make_logical_expression(node),
-    ));
-    let arguments = js_call_arguments(
-        token(T!['(']),
-        js_call_argument_list([argument], []),
-        token(T![')']),
-    );
-    let expression = js_call_expression(
-        AnyJsExpression::JsParenthesizedExpression(callee),
-        arguments,
-    )
-    .build();
-    js_expression_statement(AnyJsExpression::JsCallExpression(expression))
-        .with_semicolon_token(token(T![;]))
-        .build()
+/// Returns the name of the member referenced by `expression`, if it's a bare
+/// reference to a member of the enum: `F = A`.
+fn as_member_reference(
+    expression: &AnyJsExpression,
+    member_names: &HashSet<&str>,
+) -> Option<String> {
+    let AnyJsExpression::JsIdentifierExpression(identifier) = expression.clone().omit_parentheses()
+    else {
+        return None;
+    };
+    let name = identifier.name().ok()?.value_token().ok()?;
+    let name = name.text_trimmed();
+    member_names.contains(name).then(|| name.to_string())
 }
 
-fn make_function(node: &TsEnumMembers) -> JsFunctionExpression {
-    let parameters_list = js_parameter_list(
-        [AnyJsParameter::AnyJsFormalParameter(
-            AnyJsFormalParameter::JsFormalParameter(
-                js_formal_parameter(
-                    js_decorator_list(vec![]),
-                    AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
-                        js_identifier_binding(ident(node.name.as_str())),
-                    )),
-                )
-                .build(),
-            ),
-        )],
-        [],
-    );
-    let parameters = js_parameters(token(T!['(']), parameters_list, token(T![')']));
-
-    let body = js_function_body(
-        token(T!['{']),
-        js_directive_list([]),
-        make_members(node),
-        token(T!['}']),
-    );
-    js_function_expression(token(T![function]), parameters, body).build()
-}
-
-fn make_members(ts_enum: &TsEnumMembers) -> JsStatementList {
-    let mut list = vec![];
-    for (index, (name, value)) in ts_enum.member_names.iter().enumerate() {
-        let value = value
-            .as_ref()
-            .and_then(|initializer| initializer.expression().ok())
-            .unwrap_or_else(|| {
-                AnyJsExpression::AnyJsLiteralExpression(
-                    AnyJsLiteralExpression::JsNumberLiteralExpression(
-                        js_number_literal_expression(ident(&index.to_string())),
-                    ),
-                )
-            });
-        list.push(AnyJsStatement::JsExpressionStatement(
-            make_high_order_assignment(ts_enum.name.as_str(), name.as_str(), value),
-        ));
+/// Replaces the references to the members of the enum inside `expression` with
+/// a member expression on the enum: `A * 2` becomes `Foo.A * 2`.
+///
+/// At runtime, the members are properties of the enum object, not variables
+/// in scope.
+fn qualify_member_references(
+    enum_name: &str,
+    expression: AnyJsExpression,
+    member_names: &HashSet<&str>,
+) -> AnyJsExpression {
+    // Work on a copy of the initializer, so its nodes can be replaced in a
+    // single batch without touching the original tree
+    let root = expression.syntax().clone_subtree();
+    let mut mutation = BatchMutation::<JsLanguage>::new(root.clone());
+    let mut has_changes = false;
+    for identifier in root.descendants().filter_map(JsIdentifierExpression::cast) {
+        let Some(name) = identifier
+            .name()
+            .ok()
+            .and_then(|name| name.value_token().ok())
+        else {
+            continue;
+        };
+        let name = name.text_trimmed();
+        if member_names.contains(name) {
+            mutation.replace_node(
+                AnyJsExpression::JsIdentifierExpression(identifier),
+                make_static_member_expression(enum_name, name),
+            );
+            has_changes = true;
+        }
     }
 
-    js_statement_list(list)
+    if has_changes {
+        AnyJsExpression::unwrap_cast(mutation.commit())
+    } else {
+        expression
+    }
 }
 
-fn make_logical_expression(node: &TsEnumMembers) -> JsLogicalExpression {
-    let left = js_identifier_expression(js_reference_identifier(ident(node.name.as_str())));
+fn is_string_expression(expression: &AnyJsExpression) -> bool {
+    matches!(
+        expression.clone().omit_parentheses(),
+        AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+            _
+        )) | AnyJsExpression::JsTemplateExpression(_)
+    )
+}
 
-    let expression = js_assignment_expression(
-        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsIdentifierAssignment(
-            js_identifier_assignment(ident(node.name.as_str())),
-        )),
-        token(T![=]),
-        AnyJsExpression::JsObjectExpression(js_object_expression(
-            token(T!['{']),
-            js_object_member_list([], []),
-            token(T!['}']),
-        )),
-    );
+fn as_number(expression: &AnyJsExpression) -> Option<f64> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(number),
+        ) => number.as_number(),
+        _ => None,
+    }
+}
 
-    let right = js_parenthesized_expression(
-        token(T!['(']),
-        AnyJsExpression::JsAssignmentExpression(expression),
-        token(T![')']),
-    );
+fn make_number(value: f64) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsNumberLiteralExpression(
+        js_number_literal_expression(js_number_literal(value)),
+    ))
+}
 
-    js_logical_expression(
-        AnyJsExpression::JsIdentifierExpression(left),
-        token(T![||]),
-        AnyJsExpression::JsParenthesizedExpression(right),
+/// Creates
+/// ```js
+/// Foo["Lorem"]
+/// ```
+fn make_member_expression(enum_name: &str, member_name: &str) -> AnyJsExpression {
+    AnyJsExpression::JsComputedMemberExpression(
+        js_computed_member_expression(
+            make_identifier_expression(enum_name),
+            token(T!['[']),
+            make_string(member_name),
+            token(T![']']),
+        )
+        .build(),
     )
 }
 
-fn make_high_order_assignment(
+fn make_string(value: &str) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        js_string_literal_expression(js_string_literal(value)),
+    ))
+}
+
+/// Creates
+/// ```js
+/// Foo["Lorem"] = value
+/// ```
+fn make_assignment_expression_from_member(
     enum_name: &str,
     member_name: &str,
     member_value: AnyJsExpression,
-) -> JsExpressionStatement {
+) -> AnyJsExpression {
     let left = js_computed_member_assignment(
-        AnyJsExpression::JsIdentifierExpression(js_identifier_expression(js_reference_identifier(
-            ident(enum_name),
-        ))),
+        make_identifier_expression(enum_name),
         token(T!['[']),
-        AnyJsExpression::JsAssignmentExpression(make_assignment_expression_from_member(
-            enum_name,
-            member_name,
-            member_value,
-        )),
+        make_string(member_name),
         token(T![']']),
     );
-    let right = js_string_literal_expression(js_string_literal(member_name));
 
-    let expression = js_assignment_expression(
+    AnyJsExpression::JsAssignmentExpression(js_assignment_expression(
         AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsComputedMemberAssignment(left)),
         token(T![=]),
-        AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
-            right,
-        )),
-    );
-
-    js_expression_statement(AnyJsExpression::JsAssignmentExpression(expression))
-        .with_semicolon_token(token(T![;]))
-        .build()
+        member_value,
+    ))
 }
 
-/// Makes
+/// Creates
 /// ```js
-/// Foo["Lorem"] = 0
+/// Foo["Lorem"] = "lorem";
 /// ```
-fn make_assignment_expression_from_member(
+fn make_member_assignment(
     enum_name: &str,
     member_name: &str,
     member_value: AnyJsExpression,
-) -> JsAssignmentExpression {
-    let left = make_computed_member_assignment(enum_name, member_name);
-
-    js_assignment_expression(
-        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsComputedMemberAssignment(left)),
-        token(T![=]),
-        member_value,
+) -> AnyJsStatement {
+    AnyJsStatement::JsExpressionStatement(
+        js_expression_statement(make_assignment_expression_from_member(
+            enum_name,
+            member_name,
+            member_value,
+        ))
+        .with_semicolon_token(token(T![;]))
+        .build(),
     )
 }
 
 /// Creates
 /// ```js
-/// Foo["Lorem"]
+/// Foo[Foo["Lorem"] = 0] = "Lorem";
 /// ```
-fn make_computed_member_assignment(
+fn make_reverse_member_assignment(
     enum_name: &str,
     member_name: &str,
-) -> JsComputedMemberAssignment {
-    let object = js_identifier_expression(js_reference_identifier(ident(enum_name)));
-    let member = js_string_literal_expression(js_string_literal(member_name));
-    js_computed_member_assignment(
-        AnyJsExpression::JsIdentifierExpression(object),
+    member_value: AnyJsExpression,
+) -> AnyJsStatement {
+    let left = js_computed_member_assignment(
+        make_identifier_expression(enum_name),
         token(T!['[']),
-        AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
-            member,
-        )),
+        make_assignment_expression_from_member(enum_name, member_name, member_value),
         token(T![']']),
+    );
+    let expression = js_assignment_expression(
+        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsComputedMemberAssignment(left)),
+        token(T![=]),
+        make_string(member_name),
+    );
+
+    AnyJsStatement::JsExpressionStatement(
+        js_expression_statement(AnyJsExpression::JsAssignmentExpression(expression))
+            .with_semicolon_token(token(T![;]))
+            .build(),
     )
 }
@@ -0,0 +1,271 @@
+use crate::utils::{
+    binding_name, is_in_type_position, make_identifier_expression, make_initializer_call,
+    make_static_member_assignment, make_variable_declaration, remove_statement, replace_statement,
+    statement_or_export,
+};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::js_variable_statement;
+use biome_js_syntax::{
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsModuleItem, AnyJsStatement, AnyTsModuleName,
+    JsExport, JsModuleItemList, JsSyntaxKind, JsSyntaxNode, TsImportEqualsDeclaration,
+    TsModuleDeclaration, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_transformation! {
+    /// Transform a TypeScript namespace into an object populated by an
+    /// immediately invoked function.
+    ///
+    /// ```ts
+    /// namespace A {
+    ///     export const b = 1;
+    /// }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// var A;
+    /// (function (A) {
+    ///     const b = 1;
+    ///     A.b = b;
+    /// })(A || (A = {}));
+    /// ```
+    ///
+    /// Namespaces that only contain types are removed.
+    pub(crate) TsNamespace {
+        version: "next",
+        name: "transformNamespace",
+    }
+}
+
+pub(crate) struct TsNamespaceState {
+    /// The names of the namespace, `["A", "B"]` for `namespace A.B {}`.
+    /// Empty if the namespace isn't instantiated.
+    names: Vec<String>,
+}
+
+impl Rule for TsNamespace {
+    type Query = Ast<TsModuleDeclaration>;
+    type State = TsNamespaceState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_in_type_position(node.syntax()) {
+            return None;
+        }
+
+        let names = if is_instantiated(node) {
+            module_names(&node.name().ok()?)?
+        } else {
+            Vec::new()
+        };
+
+        Some(TsNamespaceState { names })
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let item = statement_or_export(node.syntax());
+        let Some((first, rest)) = state.names.split_first() else {
+            remove_statement(&mut mutation, node.syntax());
+            return Some(mutation);
+        };
+
+        // Nested namespaces, `namespace A.B.C {}`, are populated from the innermost one
+        let mut statements = convert_body(node, rest.last().unwrap_or(first));
+        for (index, name) in rest.iter().enumerate().rev() {
+            let parent = if index == 0 { first } else { &rest[index - 1] };
+            statements = vec![
+                as_statement(make_variable_declaration(T![let], name, false))?,
+                make_initializer_call(name, Some(parent), statements),
+            ];
+        }
+
+        let is_exported = JsExport::can_cast(item.kind());
+        let mut replacements = Vec::with_capacity(2);
+        if !is_declared_before(&item, first) {
+            replacements.push(make_variable_declaration(T![var], first, is_exported));
+        }
+        replacements.push(AnyJsModuleItem::AnyJsStatement(make_initializer_call(
+            first, None, statements,
+        )));
+
+        if !replace_statement(&mut mutation, &item, replacements) {
+            return None;
+        }
+
+        Some(mutation)
+    }
+}
+
+/// Returns the names of a namespace, from the outermost to the innermost
+fn module_names(name: &AnyTsModuleName) -> Option<Vec<String>> {
+    match name {
+        AnyTsModuleName::TsIdentifierBinding(binding) => {
+            Some(vec![binding.name_token().ok()?.text_trimmed().to_string()])
+        }
+        AnyTsModuleName::TsQualifiedModuleName(name) => {
+            let mut names = module_names(&name.left().ok()?)?;
+            names.push(
+                name.right()
+                    .ok()?
+                    .value_token()
+                    .ok()?
+                    .text_trimmed()
+                    .to_string(),
+            );
+            Some(names)
+        }
+    }
+}
+
+/// Returns `true` if the namespace contains at least a value
+fn is_instantiated(node: &TsModuleDeclaration) -> bool {
+    node.body()
+        .is_ok_and(|body| body.items().into_iter().any(|item| is_value(item.syntax())))
+}
+
+fn is_value(node: &JsSyntaxNode) -> bool {
+    match node.kind() {
+        JsSyntaxKind::TS_INTERFACE_DECLARATION
+        | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_STATEMENT
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+        | JsSyntaxKind::JS_EMPTY_STATEMENT => false,
+        JsSyntaxKind::TS_MODULE_DECLARATION => {
+            TsModuleDeclaration::cast_ref(node).is_some_and(|node| is_instantiated(&node))
+        }
+        JsSyntaxKind::TS_IMPORT_EQUALS_DECLARATION => TsImportEqualsDeclaration::cast_ref(node)
+            .is_some_and(|declaration| declaration.type_token().is_none()),
+        JsSyntaxKind::JS_EXPORT => JsExport::cast_ref(node)
+            .and_then(|export| export.export_clause().ok())
+            .is_some_and(|clause| is_value(clause.syntax())),
+        _ => true,
+    }
+}
+
+/// Returns `true` if a class or a function named `name` is declared before `item`,
+/// in which case the namespace merges with it.
+fn is_declared_before(item: &JsSyntaxNode, name: &str) -> bool {
+    item.siblings(biome_rowan::Direction::Prev)
+        .skip(1)
+        .any(|sibling| {
+            let declaration = JsExport::cast_ref(&sibling)
+                .and_then(|export| export.export_clause().ok())
+                .map_or(sibling, |clause| clause.into_syntax());
+            declaration_name(&declaration).is_some_and(|declared| declared == name)
+                && matches!(
+                    declaration.kind(),
+                    JsSyntaxKind::JS_CLASS_DECLARATION | JsSyntaxKind::JS_FUNCTION_DECLARATION
+                )
+        })
+}
+
+/// Returns the name declared by a function, a class, an enum or a namespace
+fn declaration_name(node: &JsSyntaxNode) -> Option<String> {
+    match AnyJsDeclarationClause::cast_ref(node)? {
+        AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+            binding_name(&declaration.id().ok()?)
+        }
+        AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+            binding_name(&declaration.id().ok()?)
+        }
+        AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+            binding_name(&declaration.id().ok()?)
+        }
+        AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+            module_names(&declaration.name().ok()?)?.into_iter().next()
+        }
+        _ => None,
+    }
+}
+
+fn as_statement(item: AnyJsModuleItem) -> Option<AnyJsStatement> {
+    match item {
+        AnyJsModuleItem::AnyJsStatement(statement) => Some(statement),
+        _ => None,
+    }
+}
+
+/// Converts the items of the namespace into the statements of the function
+/// body that populates it. The exported declarations are assigned to the
+/// namespace object.
+fn convert_body(node: &TsModuleDeclaration, name: &str) -> Vec<AnyJsStatement> {
+    let items = node
+        .body()
+        .map(|body| body.items())
+        .ok()
+        .map(|items: JsModuleItemList| items.into_iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut statements = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => statements.push(statement),
+            AnyJsModuleItem::JsExport(export) => {
+                let Ok(AnyJsExportClause::AnyJsDeclarationClause(declaration)) =
+                    export.export_clause()
+                else {
+                    continue;
+                };
+                let exported_names = match &declaration {
+                    AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+                        let Ok(declaration) = clause.declaration() else {
+                            continue;
+                        };
+                        let names = declaration
+                            .syntax()
+                            .descendants()
+                            .filter(|node| {
+                                node.kind() == JsSyntaxKind::JS_IDENTIFIER_BINDING
+                                    && !node.ancestors().any(|ancestor| {
+                                        ancestor.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                                    })
+                            })
+                            .map(|node| node.text_trimmed().to_string())
+                            .collect::<Vec<_>>();
+                        let mut builder = js_variable_statement(declaration);
+                        if let Some(semicolon) = clause.semicolon_token() {
+                            builder = builder.with_semicolon_token(semicolon);
+                        }
+                        statements.push(AnyJsStatement::JsVariableStatement(builder.build()));
+                        names
+                    }
+                    AnyJsDeclarationClause::TsModuleDeclaration(declaration)
+                        if !is_instantiated(declaration) =>
+                    {
+                        continue;
+                    }
+                    declaration => {
+                        let Some(statement) = AnyJsStatement::cast_ref(declaration.syntax()) else {
+                            continue;
+                        };
+                        statements.push(statement);
+                        if is_value(declaration.syntax()) {
+                            declaration_name(declaration.syntax()).into_iter().collect()
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                };
+                statements.extend(exported_names.iter().map(|exported_name| {
+                    make_static_member_assignment(
+                        make_identifier_expression(name),
+                        exported_name,
+                        make_identifier_expression(exported_name),
+                    )
+                }));
+            }
+            AnyJsModuleItem::JsImport(_) => {}
+        }
+    }
+
+    statements
+}
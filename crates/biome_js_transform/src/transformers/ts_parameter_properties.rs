@@ -0,0 +1,129 @@
+use crate::utils::{
+    binding_name, is_in_type_position, make_identifier_expression, make_static_member_assignment,
+};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::{
+    js_constructor_parameter_list, js_statement_list, js_this_expression, token,
+};
+use biome_js_syntax::{
+    AnyJsConstructorParameter, AnyJsExpression, AnyJsFormalParameter, AnyJsStatement,
+    JsConstructorClassMember, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+
+declare_transformation! {
+    /// Transform the parameter properties of a class constructor into
+    /// assignments to `this`.
+    ///
+    /// ```ts
+    /// class A extends B {
+    ///     constructor(private readonly a: string) {
+    ///         super();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// class A extends B {
+    ///     constructor(a) {
+    ///         super();
+    ///         this.a = a;
+    ///     }
+    /// }
+    /// ```
+    pub(crate) TsParameterProperties {
+        version: "next",
+        name: "transformParameterProperties",
+    }
+}
+
+impl Rule for TsParameterProperties {
+    type Query = Ast<JsConstructorClassMember>;
+    /// The names of the parameter properties
+    type State = Vec<String>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_in_type_position(node.syntax()) {
+            return None;
+        }
+
+        let names = node
+            .parameters()
+            .ok()?
+            .parameters()
+            .iter()
+            .filter_map(|parameter| match parameter.ok()? {
+                AnyJsConstructorParameter::TsPropertyParameter(parameter) => {
+                    match parameter.formal_parameter().ok()? {
+                        AnyJsFormalParameter::JsFormalParameter(parameter) => {
+                            binding_name(parameter.binding().ok()?.as_any_js_binding()?)
+                        }
+                        AnyJsFormalParameter::JsBogusParameter(_) => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        (!names.is_empty()).then_some(names)
+    }
+
+    fn transform(ctx: &RuleContext<Self>, names: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+
+        let list = node.parameters().ok()?.parameters();
+        let mut parameters = Vec::with_capacity(list.len());
+        for parameter in list.iter() {
+            parameters.push(match parameter.ok()? {
+                AnyJsConstructorParameter::TsPropertyParameter(parameter) => {
+                    AnyJsConstructorParameter::AnyJsFormalParameter(
+                        parameter.formal_parameter().ok()?,
+                    )
+                }
+                parameter => parameter,
+            });
+        }
+        let separators = list.separators().filter_map(Result::ok).collect::<Vec<_>>();
+        mutation.replace_node(list, js_constructor_parameter_list(parameters, separators));
+
+        let body = node.body().ok()?;
+        let statements = body.statements();
+        // The assignments must happen after `super()` is called
+        let super_call_index = statements
+            .iter()
+            .position(|statement| is_super_call(&statement))
+            .map_or(0, |index| index + 1);
+
+        let assignments = names.iter().map(|name| {
+            make_static_member_assignment(
+                AnyJsExpression::JsThisExpression(js_this_expression(token(T![this]))),
+                name,
+                make_identifier_expression(name),
+            )
+        });
+        let mut new_statements = statements.iter().collect::<Vec<_>>();
+        new_statements.splice(super_call_index..super_call_index, assignments);
+        mutation.replace_node(statements, js_statement_list(new_statements));
+
+        Some(mutation)
+    }
+}
+
+/// Returns `true` if the statement is `super(...);`
+fn is_super_call(statement: &AnyJsStatement) -> bool {
+    let Some(statement) = statement.as_js_expression_statement() else {
+        return false;
+    };
+    let Ok(AnyJsExpression::JsCallExpression(call)) = statement.expression() else {
+        return false;
+    };
+    matches!(call.callee(), Ok(AnyJsExpression::JsSuperExpression(_)))
+}
@@ -0,0 +1,110 @@
+use crate::utils::{is_in_type_position, remove_element_preserving_spacing, retain_separated};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::js_parameter_list;
+use biome_js_syntax::{
+    AnyJsParameter, JsFormalParameter, JsMethodClassMember, JsParameterList,
+    TsDefinitePropertyAnnotation, TsDefiniteVariableAnnotation, TsImplementsClause,
+    TsOptionalPropertyAnnotation, TsReturnTypeAnnotation, TsThisParameter, TsTypeAnnotation,
+    TsTypeArguments, TsTypeParameters,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_transformation! {
+    /// Erase the type annotations, type parameters and type arguments
+    /// attached to JavaScript syntax.
+    ///
+    /// ```ts
+    /// function id<T>(value: T, fallback?: T): T {}
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// function id(value, fallback) {}
+    /// ```
+    pub(crate) TsTypeAnnotations {
+        version: "next",
+        name: "transformTypeAnnotations",
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsAnnotationSyntax =
+        TsTypeAnnotation
+        | TsReturnTypeAnnotation
+        | TsTypeParameters
+        | TsTypeArguments
+        | TsDefiniteVariableAnnotation
+        | TsOptionalPropertyAnnotation
+        | TsDefinitePropertyAnnotation
+        | TsImplementsClause
+        | TsThisParameter
+        | JsFormalParameter
+        | JsMethodClassMember
+}
+
+impl Rule for TsTypeAnnotations {
+    type Query = Ast<AnyTsAnnotationSyntax>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        match node {
+            // Optional parameters and methods carry a `?` that must be erased
+            AnyTsAnnotationSyntax::JsFormalParameter(parameter) => {
+                parameter.question_mark_token()?;
+            }
+            AnyTsAnnotationSyntax::JsMethodClassMember(member) => {
+                member.question_mark_token()?;
+            }
+            _ => {
+                // Annotations nested inside a type, or inside another annotation,
+                // are erased together with their parent.
+                if is_in_type_position(node.syntax()) {
+                    return None;
+                }
+                let parent = node.syntax().parent()?;
+                if AnyTsAnnotationSyntax::can_cast(parent.kind())
+                    && !matches!(
+                        parent.kind(),
+                        biome_js_syntax::JsSyntaxKind::JS_FORMAL_PARAMETER
+                            | biome_js_syntax::JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                    )
+                {
+                    return None;
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    fn transform(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match node {
+            AnyTsAnnotationSyntax::JsFormalParameter(parameter) => {
+                mutation.remove_token(parameter.question_mark_token()?);
+            }
+            AnyTsAnnotationSyntax::JsMethodClassMember(member) => {
+                mutation.remove_token(member.question_mark_token()?);
+            }
+            AnyTsAnnotationSyntax::TsThisParameter(parameter) => {
+                // `this` parameters are part of a separated list, the
+                // separator that follows them needs to be removed too
+                let list = JsParameterList::cast(parameter.syntax().parent()?)?;
+                let (parameters, separators) = retain_separated(&list, |item| {
+                    !matches!(item, AnyJsParameter::TsThisParameter(_))
+                });
+                mutation.replace_node(list, js_parameter_list(parameters, separators));
+            }
+            _ => remove_element_preserving_spacing(&mut mutation, node.syntax().clone().into()),
+        }
+
+        Some(mutation)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::{js_parenthesized_expression, token};
+use biome_js_syntax::{
+    AnyJsExpression, JsSyntaxKind, JsSyntaxNode, TsAsAssignment, TsAsExpression,
+    TsInstantiationExpression, TsNonNullAssertionAssignment, TsNonNullAssertionExpression,
+    TsSatisfiesAssignment, TsSatisfiesExpression, TsTypeAssertionAssignment,
+    TsTypeAssertionExpression, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_transformation! {
+    /// Erase type assertions, `satisfies` expressions, non-null assertions and
+    /// instantiation expressions, keeping the expression they apply to.
+    ///
+    /// ```ts
+    /// const a = <string>b!;
+    /// const c = d as unknown satisfies C;
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const a = b;
+    /// const c = d;
+    /// ```
+    pub(crate) TsTypeAssertions {
+        version: "next",
+        name: "transformTypeAssertions",
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsTypeAssertion =
+        TsAsExpression
+        | TsSatisfiesExpression
+        | TsNonNullAssertionExpression
+        | TsTypeAssertionExpression
+        | TsInstantiationExpression
+        | TsAsAssignment
+        | TsSatisfiesAssignment
+        | TsNonNullAssertionAssignment
+        | TsTypeAssertionAssignment
+}
+
+impl AnyTsTypeAssertion {
+    /// Returns the expression or the assignment the type assertion applies to
+    fn inner(&self) -> Option<JsSyntaxNode> {
+        let inner = match self {
+            AnyTsTypeAssertion::TsAsExpression(node) => node.expression().ok()?.into_syntax(),
+            AnyTsTypeAssertion::TsSatisfiesExpression(node) => {
+                node.expression().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsNonNullAssertionExpression(node) => {
+                node.expression().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsTypeAssertionExpression(node) => {
+                node.expression().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsInstantiationExpression(node) => {
+                node.expression().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsAsAssignment(node) => node.assignment().ok()?.into_syntax(),
+            AnyTsTypeAssertion::TsSatisfiesAssignment(node) => {
+                node.assignment().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsNonNullAssertionAssignment(node) => {
+                node.assignment().ok()?.into_syntax()
+            }
+            AnyTsTypeAssertion::TsTypeAssertionAssignment(node) => {
+                node.assignment().ok()?.into_syntax()
+            }
+        };
+        Some(inner)
+    }
+}
+
+impl Rule for TsTypeAssertions {
+    type Query = Ast<AnyTsTypeAssertion>;
+    type State = JsSyntaxNode;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query().inner()
+    }
+
+    fn transform(ctx: &RuleContext<Self>, inner: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+
+        // `() => <T>{}` must keep parentheses around the object literal,
+        // otherwise it becomes the body of the arrow function
+        let is_arrow_body = node
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION);
+        let replacement = match AnyJsExpression::cast_ref(inner) {
+            Some(expression @ AnyJsExpression::JsObjectExpression(_)) if is_arrow_body => {
+                js_parenthesized_expression(token(T!['(']), expression, token(T![')']))
+                    .into_syntax()
+            }
+            _ => inner.clone(),
+        };
+
+        mutation.replace_element(node.syntax().clone().into(), replacement.into());
+
+        Some(mutation)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::utils::{is_in_type_position, remove_statement};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_syntax::{
+    TsDeclareFunctionDeclaration, TsDeclareFunctionExportDefaultDeclaration, TsDeclareStatement,
+    TsExportAsNamespaceClause, TsExportDeclareClause, TsGlobalDeclaration,
+    TsImportEqualsDeclaration, TsInterfaceDeclaration, TsTypeAliasDeclaration,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_transformation! {
+    /// Remove the declarations that only exist in the type system: interfaces,
+    /// type aliases, ambient (`declare`) declarations, function overloads and
+    /// `import type` aliases.
+    ///
+    /// ```ts
+    /// interface A {}
+    /// type B = A;
+    /// declare const c: B;
+    /// export function d(a: string): void;
+    /// export function d(a) {}
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// export function d(a) {}
+    /// ```
+    pub(crate) TsTypeDeclarations {
+        version: "next",
+        name: "transformTypeDeclarations",
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsTypeDeclaration =
+        TsInterfaceDeclaration
+        | TsTypeAliasDeclaration
+        | TsDeclareStatement
+        | TsDeclareFunctionDeclaration
+        | TsDeclareFunctionExportDefaultDeclaration
+        | TsExportDeclareClause
+        | TsGlobalDeclaration
+        | TsExportAsNamespaceClause
+        | TsImportEqualsDeclaration
+}
+
+impl Rule for TsTypeDeclarations {
+    type Query = Ast<AnyTsTypeDeclaration>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // Declarations nested inside an ambient context are removed
+        // with the outermost declaration
+        if is_in_type_position(node.syntax()) {
+            return None;
+        }
+
+        match node {
+            // `import A = B.C` creates a value, unless it's marked as `type`
+            AnyTsTypeDeclaration::TsImportEqualsDeclaration(declaration) => {
+                declaration.type_token().map(|_| ())
+            }
+            _ => Some(()),
+        }
+    }
+
+    fn transform(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        remove_statement(&mut mutation, node.syntax());
+
+        Some(mutation)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::utils::{remove_statement, retain_separated};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::{js_export_named_from_specifier_list, js_export_named_specifier_list};
+use biome_js_syntax::{
+    AnyJsExportClause, AnyJsExportNamedSpecifier, AnyJsImportClause, AnyJsModuleItem, JsExport,
+    JsModule, JsSyntaxKind, TsIdentifierBinding,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+use std::collections::HashSet;
+
+declare_transformation! {
+    /// Remove type-only exports, and the exported names that refer to a type.
+    ///
+    /// ```ts
+    /// interface A {}
+    /// const b = 1;
+    /// export type { C } from "c";
+    /// export { A, b, type D };
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// interface A {}
+    /// const b = 1;
+    /// export { b };
+    /// ```
+    pub(crate) TsTypeExports {
+        version: "next",
+        name: "transformTypeExports",
+    }
+}
+
+pub(crate) enum ExportElision {
+    /// The whole export statement must be removed
+    Remove,
+    /// The export clause must be replaced with the given one
+    Replace(AnyJsExportClause),
+}
+
+impl Rule for TsTypeExports {
+    type Query = Ast<JsExport>;
+    type State = ExportElision;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        match node.export_clause().ok()? {
+            AnyJsExportClause::JsExportFromClause(clause) => {
+                clause.type_token().map(|_| ExportElision::Remove)
+            }
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                if clause.type_token().is_some() {
+                    return Some(ExportElision::Remove);
+                }
+                let list = clause.specifiers();
+                let (kept, separators) =
+                    retain_separated(&list, |specifier| specifier.type_token().is_none());
+                if kept.len() == list.len() {
+                    None
+                } else if kept.is_empty() {
+                    Some(ExportElision::Remove)
+                } else {
+                    Some(ExportElision::Replace(
+                        clause
+                            .with_specifiers(js_export_named_from_specifier_list(kept, separators))
+                            .into(),
+                    ))
+                }
+            }
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                if clause.type_token().is_some() {
+                    return Some(ExportElision::Remove);
+                }
+                let type_names = collect_type_only_names(ctx.root().syntax());
+                let list = clause.specifiers();
+                let (kept, separators) = retain_separated(&list, |specifier| {
+                    specifier.type_token().is_none() && !refers_to_type(specifier, &type_names)
+                });
+                if kept.len() == list.len() {
+                    None
+                } else if kept.is_empty() {
+                    Some(ExportElision::Remove)
+                } else {
+                    Some(ExportElision::Replace(
+                        clause
+                            .with_specifiers(js_export_named_specifier_list(kept, separators))
+                            .into(),
+                    ))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match state {
+            ExportElision::Remove => remove_statement(&mut mutation, node.syntax()),
+            ExportElision::Replace(clause) => {
+                mutation.replace_node(node.export_clause().ok()?, clause.clone());
+            }
+        }
+
+        Some(mutation)
+    }
+}
+
+fn refers_to_type(specifier: &AnyJsExportNamedSpecifier, type_names: &HashSet<String>) -> bool {
+    specifier
+        .local_name()
+        .is_ok_and(|name| type_names.contains(name.text().as_str()))
+}
+
+/// Collects the names declared at the top level of the module that only
+/// exist in the type system: interfaces, type aliases and type-only imports.
+fn collect_type_only_names(root: &biome_js_syntax::JsSyntaxNode) -> HashSet<String> {
+    let mut names = HashSet::new();
+    let Some(module) = JsModule::cast_ref(root) else {
+        return names;
+    };
+
+    for item in module.items() {
+        match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => {
+                if matches!(
+                    statement.syntax().kind(),
+                    JsSyntaxKind::TS_INTERFACE_DECLARATION
+                        | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+                ) {
+                    names.extend(
+                        statement
+                            .syntax()
+                            .children()
+                            .filter_map(TsIdentifierBinding::cast)
+                            .filter_map(|binding| binding.name_token().ok())
+                            .map(|token| token.text_trimmed().to_string()),
+                    );
+                }
+            }
+            AnyJsModuleItem::JsImport(import) => {
+                let Ok(clause) = import.import_clause() else {
+                    continue;
+                };
+                let is_type_clause = clause.type_token().is_some();
+                if let AnyJsImportClause::JsImportBareClause(_) = clause {
+                    continue;
+                }
+                names.extend(
+                    clause
+                        .syntax()
+                        .descendants()
+                        .filter(|node| node.kind() == JsSyntaxKind::JS_IDENTIFIER_BINDING)
+                        .filter(|binding| {
+                            is_type_clause
+                                || binding.ancestors().any(|ancestor| {
+                                    matches!(
+                                        ancestor.kind(),
+                                        JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER
+                                            | JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER
+                                    ) && ancestor.first_token().is_some_and(|token| {
+                                        token.kind() == biome_js_syntax::T![type]
+                                    })
+                                })
+                        })
+                        .map(|binding| binding.text_trimmed().to_string()),
+                );
+            }
+            AnyJsModuleItem::JsExport(_) => {}
+        }
+    }
+
+    // A type can share its name with a value, e.g. `interface A {}` and `class A {}`
+    for item in module.items() {
+        if matches!(item, AnyJsModuleItem::JsImport(_)) {
+            continue;
+        }
+        for binding in item
+            .syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JS_IDENTIFIER_BINDING)
+        {
+            names.remove(binding.text_trimmed().to_string().as_str());
+        }
+    }
+
+    names
+}
@@ -0,0 +1,204 @@
+use crate::transformers::jsx::jsx_factory_references;
+use crate::utils::{binding_name, collect_value_references, remove_statement, retain_separated};
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule};
+use biome_js_factory::make::{
+    js_import_default_clause, js_import_named_clause, js_import_namespace_clause,
+    js_named_import_specifier_list, token_decorated_with_space,
+};
+use biome_js_syntax::{
+    AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsNamedImportSpecifier, JsImport,
+    JsNamedImportSpecifiers, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+use std::collections::HashSet;
+
+declare_transformation! {
+    /// Remove type-only imports, and the imported bindings that are never
+    /// referenced as a value.
+    ///
+    /// ```ts
+    /// import type { A } from "a";
+    /// import { type B, C, D } from "b";
+    /// let c: C = D;
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// import { D } from "b";
+    /// let c = D;
+    /// ```
+    ///
+    /// Imports without bindings, such as `import "a"`, are preserved because
+    /// they may have side effects.
+    pub(crate) TsTypeImports {
+        version: "next",
+        name: "transformTypeImports",
+    }
+}
+
+pub(crate) enum ImportElision {
+    /// The whole import statement must be removed
+    Remove,
+    /// The import clause must be replaced with the given one
+    Replace(AnyJsImportClause),
+}
+
+impl Rule for TsTypeImports {
+    type Query = Ast<JsImport>;
+    type State = ImportElision;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let clause = node.import_clause().ok()?;
+        if matches!(clause, AnyJsImportClause::JsImportBareClause(_)) {
+            return None;
+        }
+        if clause.type_token().is_some() {
+            return Some(ImportElision::Remove);
+        }
+
+        let mut references = collect_value_references(ctx.root().syntax());
+        // e.g. `React` is referenced by `React.createElement`, once the JSX is compiled
+        references.extend(jsx_factory_references(ctx.root().syntax()));
+        let is_referenced = |binding| {
+            binding_name(&binding).map_or(true, |name| references.contains(name.as_str()))
+        };
+
+        match &clause {
+            AnyJsImportClause::JsImportBareClause(_) => None,
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                let binding = clause.default_specifier().ok()?.local_name().ok()?;
+                (!is_referenced(binding)).then_some(ImportElision::Remove)
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                let binding = clause.namespace_specifier().ok()?.local_name().ok()?;
+                (!is_referenced(binding)).then_some(ImportElision::Remove)
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                let specifiers = clause.named_specifiers().ok()?;
+                match retain_named_specifiers(&specifiers, &references)? {
+                    Some(specifiers) => Some(ImportElision::Replace(
+                        clause.clone().with_named_specifiers(specifiers).into(),
+                    )),
+                    None => Some(ImportElision::Remove),
+                }
+            }
+            AnyJsImportClause::JsImportCombinedClause(clause) => {
+                let default_specifier = clause.default_specifier().ok()?;
+                let keep_default = is_referenced(default_specifier.local_name().ok()?);
+                let from_token = clause.from_token().ok()?;
+                let source = clause.source().ok()?;
+
+                // `None` if the specifier must be removed
+                let specifier = match clause.specifier().ok()? {
+                    AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+                        match retain_named_specifiers(&specifiers, &references) {
+                            None => Some(AnyJsCombinedSpecifier::from(specifiers)),
+                            Some(Some(specifiers)) => Some(specifiers.into()),
+                            Some(None) => None,
+                        }
+                    }
+                    AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => {
+                        is_referenced(specifier.local_name().ok()?).then_some(specifier.into())
+                    }
+                };
+
+                let new_clause: AnyJsImportClause = match (keep_default, specifier) {
+                    (false, None) => return Some(ImportElision::Remove),
+                    (true, None) => {
+                        // The default specifier isn't followed by whitespace in a combined clause
+                        let mut builder = js_import_default_clause(
+                            default_specifier,
+                            token_decorated_with_space(T![from]),
+                            source,
+                        );
+                        if let Some(assertion) = clause.assertion() {
+                            builder = builder.with_assertion(assertion);
+                        }
+                        builder.build().into()
+                    }
+                    (false, Some(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers))) => {
+                        let mut builder = js_import_named_clause(specifiers, from_token, source);
+                        if let Some(assertion) = clause.assertion() {
+                            builder = builder.with_assertion(assertion);
+                        }
+                        builder.build().into()
+                    }
+                    (
+                        false,
+                        Some(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier)),
+                    ) => {
+                        let mut builder = js_import_namespace_clause(specifier, from_token, source);
+                        if let Some(assertion) = clause.assertion() {
+                            builder = builder.with_assertion(assertion);
+                        }
+                        builder.build().into()
+                    }
+                    (true, Some(specifier)) => {
+                        if clause.specifier().ok()?.syntax() == specifier.syntax() {
+                            return None;
+                        }
+                        clause.clone().with_specifier(specifier).into()
+                    }
+                };
+                Some(ImportElision::Replace(new_clause))
+            }
+        }
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match state {
+            ImportElision::Remove => remove_statement(&mut mutation, node.syntax()),
+            ImportElision::Replace(clause) => {
+                mutation.replace_node(node.import_clause().ok()?, clause.clone());
+            }
+        }
+
+        Some(mutation)
+    }
+}
+
+/// Removes the specifiers that are marked as `type`, or that aren't referenced as values.
+///
+/// Returns `None` if all the specifiers are kept, and `Some(None)` if all of them
+/// are removed.
+fn retain_named_specifiers(
+    specifiers: &JsNamedImportSpecifiers,
+    references: &HashSet<String>,
+) -> Option<Option<JsNamedImportSpecifiers>> {
+    let list = specifiers.specifiers();
+    let (kept, separators) = retain_separated(&list, |specifier| {
+        if specifier.type_token().is_some() {
+            return false;
+        }
+        let binding = match specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                specifier.local_name().ok()
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                specifier.local_name().ok()
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+        };
+        binding
+            .and_then(|binding| binding_name(&binding))
+            .map_or(true, |name| references.contains(name.as_str()))
+    });
+
+    if kept.len() == list.len() {
+        None
+    } else if kept.is_empty() {
+        Some(None)
+    } else {
+        Some(Some(specifiers.clone().with_specifiers(
+            js_named_import_specifier_list(kept, separators),
+        )))
+    }
+}
@@ -0,0 +1,411 @@
+use crate::JsBatchMutation;
+use biome_js_factory::make::{
+    ident, js_assignment_expression, js_call_argument_list, js_call_arguments, js_call_expression,
+    js_decorator_list, js_directive_list, js_export, js_expression_statement, js_formal_parameter,
+    js_function_body, js_function_expression, js_identifier_assignment, js_identifier_binding,
+    js_identifier_expression, js_logical_expression, js_module_item_list, js_name,
+    js_object_expression, js_object_member_list, js_parameter_list, js_parameters,
+    js_parenthesized_expression, js_reference_identifier, js_statement_list,
+    js_static_member_assignment, js_static_member_expression, js_variable_declaration,
+    js_variable_declaration_clause, js_variable_declarator, js_variable_declarator_list,
+    js_variable_statement, token,
+};
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportNamedSpecifier, AnyJsExpression,
+    AnyJsFormalParameter, AnyJsModuleItem, AnyJsName, AnyJsParameter, AnyJsStatement, JsExport,
+    JsModuleItemList, JsStatementList, JsSyntaxElement, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TriviaPieceKind};
+use std::collections::HashSet;
+
+/// Returns the node that should be replaced or removed when erasing `node`.
+///
+/// Declarations that are exported (`export interface A {}`, `export declare const a`,
+/// `export default interface A {}`) are wrapped inside a [JsExport], which needs to be
+/// erased together with the declaration.
+pub(crate) fn statement_or_export(node: &JsSyntaxNode) -> JsSyntaxNode {
+    node.ancestors()
+        .skip(1)
+        .take_while(|ancestor| {
+            matches!(
+                ancestor.kind(),
+                JsSyntaxKind::JS_EXPORT
+                    | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+                    | JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+            )
+        })
+        .find(|ancestor| JsExport::can_cast(ancestor.kind()))
+        .unwrap_or_else(|| node.clone())
+}
+
+/// Removes a statement, or the export wrapping it, from its parent list.
+pub(crate) fn remove_statement(mutation: &mut JsBatchMutation, node: &JsSyntaxNode) {
+    mutation.remove_element(statement_or_export(node).into());
+}
+
+/// Replaces `item`, which must be a child of a [JsModuleItemList] or a
+/// [JsStatementList], with the given list of statements.
+///
+/// Returns `false` if the parent of the item isn't a list of statements.
+pub(crate) fn replace_statement(
+    mutation: &mut JsBatchMutation,
+    item: &JsSyntaxNode,
+    replacements: Vec<AnyJsModuleItem>,
+) -> bool {
+    let Some(parent) = item.parent() else {
+        return false;
+    };
+
+    if let Some(list) = JsModuleItemList::cast_ref(&parent) {
+        let mut items = Vec::with_capacity(list.len() + replacements.len());
+        let mut replacements = Some(replacements);
+        for current in list.iter() {
+            if current.syntax() == item {
+                items.extend(replacements.take().unwrap_or_default());
+            } else {
+                items.push(current);
+            }
+        }
+        mutation.replace_node(list, js_module_item_list(items));
+        true
+    } else if let Some(list) = JsStatementList::cast_ref(&parent) {
+        let replacements = replacements
+            .into_iter()
+            .filter_map(|item| match item {
+                AnyJsModuleItem::AnyJsStatement(statement) => Some(statement),
+                AnyJsModuleItem::JsExport(_) | AnyJsModuleItem::JsImport(_) => None,
+            })
+            .collect::<Vec<AnyJsStatement>>();
+        let mut statements = Vec::with_capacity(list.len() + replacements.len());
+        let mut replacements = Some(replacements);
+        for current in list.iter() {
+            if current.syntax() == item {
+                statements.extend(replacements.take().unwrap_or_default());
+            } else {
+                statements.push(current);
+            }
+        }
+        mutation.replace_node(list, js_statement_list(statements));
+        true
+    } else {
+        false
+    }
+}
+
+/// Collects the elements of a separated list that satisfy `predicate`, along
+/// with the separators that should be kept between them.
+///
+/// The trailing separator of the list is only preserved if the original list
+/// had one.
+pub(crate) fn retain_separated<L, F>(
+    list: &L,
+    mut predicate: F,
+) -> (Vec<L::Node>, Vec<JsSyntaxToken>)
+where
+    L: AstSeparatedList<Language = biome_js_syntax::JsLanguage>,
+    F: FnMut(&L::Node) -> bool,
+{
+    let mut nodes = Vec::new();
+    let mut separators = Vec::new();
+    for element in list.elements() {
+        let Ok(node) = element.node() else {
+            continue;
+        };
+        if !predicate(node) {
+            continue;
+        }
+        nodes.push(node.clone());
+        if let Ok(Some(separator)) = element.trailing_separator() {
+            separators.push(separator.clone());
+        }
+    }
+
+    if list.trailing_separator().is_none() && separators.len() >= nodes.len() {
+        separators.truncate(nodes.len().saturating_sub(1));
+    }
+
+    (nodes, separators)
+}
+
+/// Returns `true` if the node is inside a TypeScript type or an ambient declaration,
+/// for example the `A` in `let a: A`, in `class B implements A {}` or in `declare let c: A`
+pub(crate) fn is_in_type_position(node: &JsSyntaxNode) -> bool {
+    node.ancestors().skip(1).any(|ancestor| {
+        let kind = ancestor.kind();
+        biome_js_syntax::AnyTsType::can_cast(kind)
+            || matches!(
+                kind,
+                JsSyntaxKind::TS_TYPE_ANNOTATION
+                    | JsSyntaxKind::TS_RETURN_TYPE_ANNOTATION
+                    | JsSyntaxKind::TS_TYPE_ARGUMENTS
+                    | JsSyntaxKind::TS_TYPE_PARAMETERS
+                    | JsSyntaxKind::TS_NAME_WITH_TYPE_ARGUMENTS
+                    | JsSyntaxKind::TS_INTERFACE_DECLARATION
+                    | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+                    | JsSyntaxKind::TS_DECLARE_STATEMENT
+                    | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+                    | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+                    | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION
+                    | JsSyntaxKind::TS_INDEX_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_PROPERTY_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_GETTER_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_SETTER_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_CONSTRUCTOR_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_INITIALIZED_PROPERTY_SIGNATURE_CLASS_MEMBER
+            )
+    })
+}
+
+/// Collects the names of the identifiers referenced in value positions in the
+/// tree rooted at `root`.
+///
+/// This is a purely syntactic check that doesn't resolve bindings: an identifier
+/// that is shadowed by a local binding is still considered referenced.
+pub(crate) fn collect_value_references(root: &JsSyntaxNode) -> HashSet<String> {
+    root.descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                JsSyntaxKind::JS_REFERENCE_IDENTIFIER
+                    | JsSyntaxKind::JSX_REFERENCE_IDENTIFIER
+                    | JsSyntaxKind::JS_IDENTIFIER_ASSIGNMENT
+            ) && !is_in_type_position(node)
+                && !is_type_only_export(node)
+        })
+        .map(|node| node.text_trimmed().to_string())
+        .collect()
+}
+
+/// Returns `true` if the node is exported with `export type { A }` or `export { type A }`
+fn is_type_only_export(node: &JsSyntaxNode) -> bool {
+    node.parent()
+        .and_then(AnyJsExportNamedSpecifier::cast)
+        .is_some_and(|specifier| specifier.exports_only_types())
+}
+
+/// Returns the name of a binding, if it's an identifier
+pub(crate) fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let identifier = binding.as_js_identifier_binding()?;
+    Some(identifier.name_token().ok()?.text_trimmed().to_string())
+}
+
+/// Creates an identifier expression, `name`
+pub(crate) fn make_identifier_expression(name: &str) -> AnyJsExpression {
+    AnyJsExpression::JsIdentifierExpression(js_identifier_expression(js_reference_identifier(
+        ident(name),
+    )))
+}
+
+/// Creates a static member expression, `object.member`
+pub(crate) fn make_static_member_expression(object: &str, member: &str) -> AnyJsExpression {
+    AnyJsExpression::JsStaticMemberExpression(js_static_member_expression(
+        make_identifier_expression(object),
+        token(T![.]),
+        AnyJsName::JsName(js_name(ident(member))),
+    ))
+}
+
+/// Creates a static member assignment, `object.member = value;`
+pub(crate) fn make_static_member_assignment(
+    object: AnyJsExpression,
+    member: &str,
+    value: AnyJsExpression,
+) -> AnyJsStatement {
+    let left = js_static_member_assignment(
+        object,
+        token(T![.]),
+        AnyJsName::JsName(js_name(ident(member))),
+    );
+    let expression = js_assignment_expression(
+        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsStaticMemberAssignment(left)),
+        token(T![=]),
+        value,
+    );
+    AnyJsStatement::JsExpressionStatement(
+        js_expression_statement(AnyJsExpression::JsAssignmentExpression(expression))
+            .with_semicolon_token(token(T![;]))
+            .build(),
+    )
+}
+
+/// Creates the declaration of a variable without initializer, `var name;`
+///
+/// When `export` is `true`, it creates `export var name;` instead.
+pub(crate) fn make_variable_declaration(
+    kind: JsSyntaxKind,
+    name: &str,
+    export: bool,
+) -> AnyJsModuleItem {
+    let declarator = js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+        AnyJsBinding::JsIdentifierBinding(js_identifier_binding(ident(name))),
+    ))
+    .build();
+    let declaration = js_variable_declaration(
+        token(kind).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        js_variable_declarator_list([declarator], []),
+    )
+    .build();
+
+    if export {
+        let clause = js_variable_declaration_clause(declaration)
+            .with_semicolon_token(token(T![;]))
+            .build();
+        AnyJsModuleItem::JsExport(js_export(
+            js_decorator_list([]),
+            token(T![export]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyJsExportClause::AnyJsDeclarationClause(
+                AnyJsDeclarationClause::JsVariableDeclarationClause(clause),
+            ),
+        ))
+    } else {
+        AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsVariableStatement(
+            js_variable_statement(declaration)
+                .with_semicolon_token(token(T![;]))
+                .build(),
+        ))
+    }
+}
+
+/// Creates the immediately invoked function that populates the object
+/// backing an enum or a namespace:
+///
+/// ```js
+/// (function (name) {
+///     statements
+/// })(name || (name = {}));
+/// ```
+///
+/// When the declaration is nested inside the namespace `parent`, the object is
+/// also assigned to the parent:
+///
+/// ```js
+/// (function (name) {
+///     statements
+/// })(name = parent.name || (parent.name = {}));
+/// ```
+pub(crate) fn make_initializer_call(
+    name: &str,
+    parent: Option<&str>,
+    statements: Vec<AnyJsStatement>,
+) -> AnyJsStatement {
+    let parameter = AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+        js_formal_parameter(
+            js_decorator_list([]),
+            AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+                js_identifier_binding(ident(name)),
+            )),
+        )
+        .build(),
+    ));
+    let parameters = js_parameters(
+        token(T!['(']),
+        js_parameter_list([parameter], []),
+        token(T![')']),
+    );
+    let body = js_function_body(
+        token(T!['{']),
+        js_directive_list([]),
+        js_statement_list(statements),
+        token(T!['}']),
+    );
+    let function = js_function_expression(token(T![function]), parameters, body).build();
+    let callee = js_parenthesized_expression(
+        token(T!['(']),
+        AnyJsExpression::JsFunctionExpression(function),
+        token(T![')']),
+    );
+
+    let (target, assignment) = match parent {
+        Some(parent) => (
+            make_static_member_expression(parent, name),
+            AnyJsAssignment::JsStaticMemberAssignment(js_static_member_assignment(
+                make_identifier_expression(parent),
+                token(T![.]),
+                AnyJsName::JsName(js_name(ident(name))),
+            )),
+        ),
+        None => (
+            make_identifier_expression(name),
+            AnyJsAssignment::JsIdentifierAssignment(js_identifier_assignment(ident(name))),
+        ),
+    };
+    let empty_object = AnyJsExpression::JsObjectExpression(js_object_expression(
+        token(T!['{']),
+        js_object_member_list([], []),
+        token(T!['}']),
+    ));
+    let initialization = js_parenthesized_expression(
+        token(T!['(']),
+        AnyJsExpression::JsAssignmentExpression(js_assignment_expression(
+            AnyJsAssignmentPattern::AnyJsAssignment(assignment),
+            token(T![=]),
+            empty_object,
+        )),
+        token(T![')']),
+    );
+    let mut argument = AnyJsExpression::JsLogicalExpression(js_logical_expression(
+        target,
+        token(T![||]),
+        AnyJsExpression::JsParenthesizedExpression(initialization),
+    ));
+    if parent.is_some() {
+        argument = AnyJsExpression::JsAssignmentExpression(js_assignment_expression(
+            AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsIdentifierAssignment(
+                js_identifier_assignment(ident(name)),
+            )),
+            token(T![=]),
+            argument,
+        ));
+    }
+
+    let arguments = js_call_arguments(
+        token(T!['(']),
+        js_call_argument_list([AnyJsCallArgument::AnyJsExpression(argument)], []),
+        token(T![')']),
+    );
+    let call = js_call_expression(
+        AnyJsExpression::JsParenthesizedExpression(callee),
+        arguments,
+    )
+    .build();
+
+    AnyJsStatement::JsExpressionStatement(
+        js_expression_statement(AnyJsExpression::JsCallExpression(call))
+            .with_semicolon_token(token(T![;]))
+            .build(),
+    )
+}
+
+/// Removes `element` from the tree. If the element is followed by whitespace
+/// and the token that precedes it isn't, a space is added to the preceding
+/// token so that the surrounding tokens don't get merged, e.g. `class A<T> implements B`
+/// becomes `class A implements B` instead of `class Aimplements B`.
+pub(crate) fn remove_element_preserving_spacing(
+    mutation: &mut JsBatchMutation,
+    element: JsSyntaxElement,
+) {
+    let (first_token, last_token) = match &element {
+        JsSyntaxElement::Node(node) => (node.first_token(), node.last_token()),
+        JsSyntaxElement::Token(token) => (Some(token.clone()), Some(token.clone())),
+    };
+    let previous_token = first_token.and_then(|token| token.prev_token());
+    let has_trailing_space = last_token.is_some_and(|token| {
+        token
+            .trailing_trivia()
+            .pieces()
+            .any(|piece| piece.is_whitespace() || piece.is_newline())
+    });
+
+    if let Some(previous_token) = previous_token {
+        if has_trailing_space && previous_token.trailing_trivia().pieces().next().is_none() {
+            mutation.replace_token_discard_trivia(
+                previous_token.clone(),
+                previous_token.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+        }
+    }
+    mutation.remove_element(element);
+}
@@ -0,0 +1,13 @@
+abstract class A {
+	[key: string]: unknown;
+	declare b: string;
+	private readonly c = 1;
+	abstract d(): void;
+	public e() {}
+	protected static f = 2;
+	override g() {}
+	private constructor() {}
+	h(a: string): void;
+	h(a) {}
+}
+export default abstract class {}
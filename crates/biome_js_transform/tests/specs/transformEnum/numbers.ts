@@ -0,0 +1,7 @@
+export enum A {
+	One = 1,
+	Two,
+	Three = "three".length,
+	Four,
+	"Quoted",
+}
@@ -0,0 +1,13 @@
+enum Flags {
+	A = 1,
+	B = A * 2,
+	C = A | B,
+	D,
+	F = A,
+}
+
+enum Labels {
+	Yes = "yes",
+	Default = Yes,
+	No = "no",
+}
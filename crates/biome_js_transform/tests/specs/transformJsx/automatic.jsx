@@ -0,0 +1,16 @@
+import { Button } from "./button";
+
+export function App({ items, title, ...rest }) {
+	return (
+		<div className="app" data-id="a &amp; b" {...rest}>
+			<h1>{title}</h1>
+			<Button disabled onClick={() => alert(<span>clicked</span>)} />
+			<>
+				Hello,
+				world &gt; all
+			</>
+			{items.map((item) => <Item.Row key={item.id} {...item} />)}
+			{/* a comment */}
+		</div>
+	);
+}
@@ -0,0 +1,11 @@
+/** @jsxRuntime classic */
+import React from "react";
+
+const a = <div className="a">{b}</div>;
+const c = (
+	<>
+		<svg:rect aria-hidden />
+		<my-element {...props} key="c" />
+		text
+	</>
+);
@@ -0,0 +1,2 @@
+/** @jsxImportSource preact */
+const a = <div>{b} {c}</div>;
@@ -0,0 +1,5 @@
+/** @jsx h */
+/** @jsxFrag Fragment */
+import { h, Fragment } from "preact";
+
+const a = <><A.B.C value={1} /></>;
@@ -0,0 +1,5 @@
+import React from "react";
+import type { Props } from "./props";
+
+/** @jsxRuntime classic */
+export const A = (props: Props) => <div title={props.title as string} />;
@@ -0,0 +1,26 @@
+namespace A {
+	const a = 1;
+	export const b = a;
+	export function c() {}
+	export class D {}
+	export enum E {}
+	export interface F {}
+}
+
+export namespace G {
+	export let h = 1;
+}
+
+namespace OnlyTypes {
+	export interface A {}
+	export type B = string;
+}
+
+namespace Outer.Inner {
+	export const i = 1;
+}
+
+class J {}
+namespace J {
+	export const k = 1;
+}
@@ -0,0 +1,13 @@
+class A {
+	constructor(private a: string, public readonly b = 1, c: number) {
+		console.log(a);
+	}
+}
+
+class B extends A {
+	constructor(protected d: string) {
+		"use strict";
+		super(d, 1, 2);
+		console.log(this.d);
+	}
+}
@@ -0,0 +1,13 @@
+let a: string = "a";
+let b!: number;
+function id<T>(value: T, fallback?: T): T {
+	return value;
+}
+const arrow = <T,>(value: T): T => value;
+function withThis(this: Window, event: Event) {}
+class A<T> implements B<T> {
+	property?: T;
+	definite!: string;
+	method?(): void {}
+}
+id<string>("a");
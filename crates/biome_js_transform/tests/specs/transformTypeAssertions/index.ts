@@ -0,0 +1,8 @@
+const a = b as string;
+const c = <number>d;
+const e = f!.g;
+const h = i satisfies J;
+const k = l<string>;
+const m = () => <object>{};
+(n as any) = 1;
+o! = 2;
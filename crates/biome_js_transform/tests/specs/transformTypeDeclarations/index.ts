@@ -0,0 +1,14 @@
+interface A {
+	a: string;
+}
+type B = A | string;
+export interface C {}
+export type D = C;
+declare const e: B;
+export declare function f(): void;
+declare module "g" {}
+declare global {}
+export function h(a: string): void;
+export function h(a: number): void;
+export function h(a) {}
+import type I = J.K;
@@ -0,0 +1,11 @@
+import type { A } from "a";
+interface B {}
+type C = B;
+const d = 1;
+interface E {}
+class E {}
+export type { F } from "f";
+export { type G, H } from "g";
+export type * from "i";
+export { A, B, C, d, E };
+export type { C as Cee };
@@ -0,0 +1,11 @@
+import "side-effect";
+import type { A } from "a";
+import type B from "b";
+import { type C, D, E } from "c";
+import F, { G } from "f";
+import H, * as I from "h";
+import * as J from "j";
+
+const d: E = D;
+const h: I.T = new H();
+let j: J.T;
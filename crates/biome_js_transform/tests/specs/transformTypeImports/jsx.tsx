@@ -0,0 +1,5 @@
+/** @jsxRuntime classic */
+import React from "react";
+import type { Props } from "./props";
+
+export const A = (props: Props) => <div title={props.title} />;
@@ -0,0 +1,387 @@
+use crate::{Type, TypeModel};
+use biome_fs::normalize_path;
+use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportNamedSpecifier,
+    AnyJsModuleItem, AnyJsRoot, JsExportNamedFromSpecifier, JsLanguage, JsReferenceIdentifier,
+};
+use biome_rowan::{AstNode, AstSeparatedList, SendNode};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The declarations of the TypeScript files of the project, including the
+/// `.d.ts` files, that the other files can import.
+///
+/// The types of the declarations are resolved when they're imported, not when
+/// the files are recorded, so that they don't depend on the order the files
+/// are recorded in: a file can re-export the declarations of a file recorded
+/// after it.
+#[derive(Debug, Default)]
+pub struct ProjectDeclarations {
+    modules: RwLock<FxHashMap<PathBuf, Arc<ModuleDeclarations>>>,
+    /// Incremented each time a file is recorded or removed, which invalidates
+    /// the exports resolved before
+    generation: AtomicU64,
+}
+
+impl ProjectDeclarations {
+    /// Records the declarations exported by the file at `path`, replacing
+    /// the ones recorded previously for this file
+    pub fn insert(&self, path: &Path, root: &AnyJsRoot) {
+        let Some(root) = root.syntax().as_send() else {
+            return;
+        };
+        let path = normalize_path(path);
+        let module = ModuleDeclarations {
+            path: path.clone(),
+            root,
+            exports: Mutex::default(),
+        };
+        self.modules.write().unwrap().insert(path, Arc::new(module));
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Removes the declarations of the file at `path`
+    pub fn remove(&self, path: &Path) {
+        if self
+            .modules
+            .write()
+            .unwrap()
+            .remove(&normalize_path(path))
+            .is_some()
+        {
+            self.generation.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the declarations of the module imported from `source` by the
+    /// file at `importer`. Only the relative imports are resolved.
+    pub fn resolve(&self, importer: &Path, source: &str) -> Option<Arc<ModuleDeclarations>> {
+        if !source.starts_with("./") && !source.starts_with("../") {
+            return None;
+        }
+
+        let path = normalize_path(&importer.parent()?.join(source));
+        let modules = self.modules.read().unwrap();
+        if let Some(module) = modules.get(&path) {
+            return Some(module.clone());
+        }
+
+        // The extensions are tried in the order of TypeScript
+        let path = path.to_str()?;
+        let stem = [".js", ".mjs", ".cjs"]
+            .into_iter()
+            .find_map(|extension| path.strip_suffix(extension))
+            .unwrap_or(path);
+        let index = format!("{path}/index");
+        for stem in [stem, index.as_str()] {
+            for extension in [".ts", ".tsx", ".d.ts"] {
+                if let Some(module) = modules.get(Path::new(&format!("{stem}{extension}"))) {
+                    return Some(module.clone());
+                }
+            }
+        }
+
+        None
+    }
+}
+
+thread_local! {
+    /// The modules whose exports are being resolved by the current thread,
+    /// used to bail out of circular imports, and whether their exports depend
+    /// on a circular import
+    static RESOLVING: RefCell<Vec<(PathBuf, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The types of the declarations exported by a module, by exported name
+type ResolvedExports = Arc<FxHashMap<String, Type>>;
+
+/// The declarations exported by a module
+pub struct ModuleDeclarations {
+    path: PathBuf,
+    root: SendNode,
+    /// The exports resolved for a generation of the [ProjectDeclarations]
+    exports: Mutex<Option<(u64, ResolvedExports)>>,
+}
+
+impl Debug for ModuleDeclarations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModuleDeclarations")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModuleDeclarations {
+    /// Returns the type of the declaration exported as `name`, resolving the
+    /// declarations it imports from the other files of `declarations`
+    pub fn export(&self, declarations: &Arc<ProjectDeclarations>, name: &str) -> Option<Type> {
+        self.exports(declarations)?.get(name).cloned()
+    }
+
+    fn exports(&self, declarations: &Arc<ProjectDeclarations>) -> Option<ResolvedExports> {
+        let generation = declarations.generation.load(Ordering::Relaxed);
+        if let Some((resolved, exports)) = &*self.exports.lock().unwrap() {
+            if *resolved == generation {
+                return Some(exports.clone());
+            }
+        }
+
+        let is_circular = RESOLVING.with_borrow_mut(|resolving| {
+            let index = resolving.iter().position(|(path, _)| *path == self.path)?;
+            // The modules that import this one, directly or not, can't be
+            // completely resolved
+            for (_, is_incomplete) in &mut resolving[index..] {
+                *is_incomplete = true;
+            }
+            Some(())
+        });
+        if is_circular.is_some() {
+            return None;
+        }
+
+        RESOLVING.with_borrow_mut(|resolving| resolving.push((self.path.clone(), false)));
+        let root = self
+            .root
+            .clone()
+            .into_node::<JsLanguage>()
+            .and_then(AnyJsRoot::cast);
+        let imports = ImportResolver::new(declarations.clone(), self.path.clone());
+        let exports =
+            Arc::new(root.map_or_else(FxHashMap::default, |root| resolve_exports(&root, imports)));
+        let is_incomplete = RESOLVING
+            .with_borrow_mut(|resolving| resolving.pop())
+            .is_some_and(|(_, is_incomplete)| is_incomplete);
+
+        // The exports that depend on a circular import are resolved again
+        // the next time, from the module that is imported first
+        if !is_incomplete {
+            *self.exports.lock().unwrap() = Some((generation, exports.clone()));
+        }
+        Some(exports)
+    }
+}
+
+/// Returns the types of the declarations exported by `root`
+fn resolve_exports(root: &AnyJsRoot, imports: ImportResolver) -> FxHashMap<String, Type> {
+    let mut exports = Exports::default();
+    let Some(module) = root.as_js_module() else {
+        return exports.0;
+    };
+
+    let model = TypeModel::new(
+        semantic_model(root, SemanticModelOptions::default()),
+        imports,
+    );
+    for item in module.items() {
+        let AnyJsModuleItem::JsExport(export) = item else {
+            continue;
+        };
+        match export.export_clause() {
+            Ok(AnyJsExportClause::AnyJsDeclarationClause(declaration)) => {
+                exports.insert_declaration(&model, &declaration);
+            }
+            Ok(AnyJsExportClause::TsExportDeclareClause(clause)) => {
+                if let Ok(declaration) = clause.declaration() {
+                    exports.insert_declaration(&model, &declaration);
+                }
+            }
+            Ok(AnyJsExportClause::JsExportNamedClause(clause)) => {
+                for specifier in clause.specifiers().iter().flatten() {
+                    exports.insert_specifier(&model, &specifier);
+                }
+            }
+            Ok(AnyJsExportClause::JsExportNamedFromClause(clause)) => {
+                let Ok(source) = clause.source() else {
+                    continue;
+                };
+                let Ok(source) = source.inner_string_text() else {
+                    continue;
+                };
+                for specifier in clause.specifiers().iter().flatten() {
+                    exports.insert_reexport(&model, source.text(), &specifier);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    exports.0
+}
+
+/// The types of the declarations exported by a module, by exported name
+#[derive(Default)]
+struct Exports(FxHashMap<String, Type>);
+
+impl Exports {
+    fn insert_declaration(&mut self, model: &TypeModel, declaration: &AnyJsDeclarationClause) {
+        let bindings: Vec<AnyJsIdentifierBinding> = match declaration {
+            AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+                identifier_binding(declaration.id().ok())
+                    .into_iter()
+                    .collect()
+            }
+            AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+                identifier_binding(declaration.id().ok())
+                    .into_iter()
+                    .collect()
+            }
+            AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => {
+                identifier_binding(declaration.id().ok())
+                    .into_iter()
+                    .collect()
+            }
+            AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+                identifier_binding(declaration.id().ok())
+                    .into_iter()
+                    .collect()
+            }
+            AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => declaration
+                .binding_identifier()
+                .ok()
+                .map(AnyJsIdentifierBinding::from)
+                .into_iter()
+                .collect(),
+            AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => declaration
+                .id()
+                .ok()
+                .map(AnyJsIdentifierBinding::from)
+                .into_iter()
+                .collect(),
+            AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => clause
+                .declaration()
+                .into_iter()
+                .flat_map(|declaration| declaration.declarators().iter())
+                .filter_map(|declarator| {
+                    let id = declarator.ok()?.id().ok()?;
+                    identifier_binding(id.as_any_js_binding().cloned())
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        for binding in bindings {
+            let Ok(name) = binding.name_token() else {
+                continue;
+            };
+            // The first signature of an overloaded function is kept
+            self.0
+                .entry(name.text_trimmed().to_string())
+                .or_insert_with(|| model.type_of_binding(&binding));
+        }
+    }
+
+    fn insert_specifier(&mut self, model: &TypeModel, specifier: &AnyJsExportNamedSpecifier) {
+        let (local, exported) = match specifier {
+            AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                let Ok(local) = specifier.name() else {
+                    return;
+                };
+                let Some(exported) = reference_name(&local) else {
+                    return;
+                };
+                (local, exported)
+            }
+            AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                let (Ok(local), Ok(exported)) = (specifier.local_name(), specifier.exported_name())
+                else {
+                    return;
+                };
+                let Ok(exported) = exported.inner_string_text() else {
+                    return;
+                };
+                (local, exported.to_string())
+            }
+        };
+
+        let ty = match model.semantic_model().binding(&local) {
+            Some(binding) => model.type_of_binding(&binding.tree()),
+            None => Type::Unknown,
+        };
+        self.0.insert(exported, ty);
+    }
+
+    fn insert_reexport(
+        &mut self,
+        model: &TypeModel,
+        source: &str,
+        specifier: &JsExportNamedFromSpecifier,
+    ) {
+        let Ok(name) = specifier.source_name() else {
+            return;
+        };
+        let Ok(name) = name.inner_string_text() else {
+            return;
+        };
+        let exported = match specifier.export_as() {
+            Some(export_as) => match export_as
+                .exported_name()
+                .and_then(|name| name.inner_string_text())
+            {
+                Ok(exported) => exported.to_string(),
+                Err(_) => return,
+            },
+            None => name.to_string(),
+        };
+
+        let ty = model
+            .imports()
+            .resolve(source, name.text())
+            .unwrap_or(Type::Unknown);
+        self.0.insert(exported, ty);
+    }
+}
+
+/// Resolves the declarations imported by a file from the TypeScript files of
+/// the project
+#[derive(Debug, Clone, Default)]
+pub struct ImportResolver {
+    declarations: Arc<ProjectDeclarations>,
+    /// The path of the importing file
+    path: PathBuf,
+}
+
+impl ImportResolver {
+    pub fn new(declarations: Arc<ProjectDeclarations>, path: PathBuf) -> Self {
+        Self { declarations, path }
+    }
+
+    /// Returns the type of the declaration imported as `name` from `source`
+    pub(crate) fn resolve(&self, source: &str, name: &str) -> Option<Type> {
+        let module = self.declarations.resolve(&self.path, source)?;
+        module.export(&self.declarations, name)
+    }
+}
+
+/// Returns `true` if the file at `path` is a TypeScript declaration file
+pub fn is_declaration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.ends_with(".d.ts") || name.ends_with(".d.mts") || name.ends_with(".d.cts")
+        })
+}
+
+/// Returns `true` if the file at `path` is a TypeScript file, whose
+/// declarations the other files can import
+pub fn is_typescript_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension, "ts" | "tsx" | "mts" | "cts"))
+}
+
+fn identifier_binding(binding: Option<AnyJsBinding>) -> Option<AnyJsIdentifierBinding> {
+    binding?
+        .as_js_identifier_binding()
+        .cloned()
+        .map(AnyJsIdentifierBinding::from)
+}
+
+fn reference_name(reference: &JsReferenceIdentifier) -> Option<String> {
+    Some(reference.name().ok()?.to_string())
+}
@@ -0,0 +1,22 @@
+//! Lightweight type inference for JavaScript and TypeScript.
+//!
+//! The [TypeModel] infers the [Type] of the expressions and declarations of a
+//! file from its [SemanticModel](biome_js_semantic::SemanticModel): the types
+//! of the literals, the type annotations, the return types of the functions
+//! declared in the file, and the declarations imported from the TypeScript
+//! files of the project, recorded in [ProjectDeclarations].
+//!
+//! It doesn't check the types: what can't be inferred is [Type::Unknown].
+
+mod declarations;
+mod model;
+#[cfg(test)]
+mod tests;
+mod types;
+
+pub use declarations::{
+    is_declaration_file, is_typescript_file, ImportResolver, ModuleDeclarations,
+    ProjectDeclarations,
+};
+pub use model::TypeModel;
+pub use types::{EnumType, Literal, Property, Type};
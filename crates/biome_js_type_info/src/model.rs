@@ -0,0 +1,671 @@
+use crate::declarations::ImportResolver;
+use crate::{EnumType, Literal, Property, Type};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
+use biome_js_syntax::{
+    inner_string_text, AnyJsArrayElement, AnyJsExpression, AnyJsFunction, AnyJsFunctionBody,
+    AnyJsLiteralExpression, AnyJsNamedImportSpecifier, AnyJsObjectMember, AnyTsName,
+    AnyTsReturnType, AnyTsType, AnyTsTypeMember, JsBinaryOperator, JsFunctionBody,
+    JsReferenceIdentifier, JsReturnStatement, JsSyntaxKind, JsUnaryOperator,
+    TsInterfaceDeclaration, TsReferenceType, TsTypeMemberList,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TextRange, WalkEvent};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Infers the types of the expressions and declarations of a file.
+///
+/// The inference is local and lightweight: it relies on the literals, the
+/// type annotations, the return types of the functions declared in the file,
+/// and the declarations imported from the TypeScript files of the project. The
+/// types that can't be inferred are [Type::Unknown].
+#[derive(Clone)]
+pub struct TypeModel {
+    data: Rc<TypeModelData>,
+}
+
+struct TypeModelData {
+    semantic: SemanticModel,
+    imports: ImportResolver,
+    /// The types of the bindings that were already inferred, by the range
+    /// of the binding
+    bindings: RefCell<FxHashMap<TextRange, Type>>,
+    /// The bindings whose type is being inferred, used to bail out of
+    /// recursive declarations, e.g. `type A = B; type B = A;`
+    pending: RefCell<FxHashSet<TextRange>>,
+}
+
+impl TypeModel {
+    pub fn new(semantic: SemanticModel, imports: ImportResolver) -> Self {
+        Self {
+            data: Rc::new(TypeModelData {
+                semantic,
+                imports,
+                bindings: RefCell::default(),
+                pending: RefCell::default(),
+            }),
+        }
+    }
+
+    /// Returns the semantic model the types are inferred from
+    pub fn semantic_model(&self) -> &SemanticModel {
+        &self.data.semantic
+    }
+
+    /// Returns the resolver of the declarations imported by the file
+    pub(crate) fn imports(&self) -> &ImportResolver {
+        &self.data.imports
+    }
+
+    /// Returns the type of the value of `expression`
+    pub fn type_of_expression(&self, expression: &AnyJsExpression) -> Type {
+        self.infer_expression(expression).unwrap_or(Type::Unknown)
+    }
+
+    /// Returns the type of the declaration of `binding`. It's the type of
+    /// the value of variables, parameters and functions, and the declared
+    /// type of type aliases, interfaces and enums.
+    pub fn type_of_binding(&self, binding: &AnyJsIdentifierBinding) -> Type {
+        let range = binding.syntax().text_trimmed_range();
+        if let Some(ty) = self.data.bindings.borrow().get(&range) {
+            return ty.clone();
+        }
+        if !self.data.pending.borrow_mut().insert(range) {
+            return Type::Unknown;
+        }
+
+        let ty = self.infer_binding(binding).unwrap_or(Type::Unknown);
+
+        self.data.pending.borrow_mut().remove(&range);
+        self.data.bindings.borrow_mut().insert(range, ty.clone());
+        ty
+    }
+
+    /// Returns the type of the value `reference` refers to
+    pub fn type_of_reference(&self, reference: &JsReferenceIdentifier) -> Type {
+        if let Some(binding) = self.data.semantic.binding(reference) {
+            return self.type_of_binding(&binding.tree());
+        }
+
+        match reference.name() {
+            Ok(name) => match name.text() {
+                "undefined" => Type::Undefined,
+                "NaN" | "Infinity" => Type::Number,
+                _ => Type::Unknown,
+            },
+            Err(_) => Type::Unknown,
+        }
+    }
+
+    /// Returns the type a type annotation stands for
+    pub fn resolve_type(&self, ty: &AnyTsType) -> Type {
+        self.resolve(ty).unwrap_or(Type::Unknown)
+    }
+
+    /// Returns the type returned by `function`: its declared return type, or
+    /// the type of the values it returns
+    pub fn return_type(&self, function: &AnyJsFunction) -> Type {
+        if function.is_generator() {
+            return Type::Unknown;
+        }
+
+        if let Some(annotation) = function.return_type_annotation() {
+            return annotation
+                .ty()
+                .map_or(Type::Unknown, |ty| self.resolve_return_type(&ty));
+        }
+
+        // The literal types widen as in a mutable binding, e.g. a function
+        // returning `1` returns a `number`
+        let returned = match function.body() {
+            Ok(AnyJsFunctionBody::AnyJsExpression(expression)) => {
+                self.type_of_expression(&expression).widened()
+            }
+            Ok(AnyJsFunctionBody::JsFunctionBody(body)) => self.type_of_returns(&body).widened(),
+            Err(_) => Type::Unknown,
+        };
+
+        if function.is_async() {
+            Type::Promise(Box::new(returned.awaited()))
+        } else {
+            returned
+        }
+    }
+
+    fn infer_binding(&self, binding: &AnyJsIdentifierBinding) -> Option<Type> {
+        let name = binding.name_token().ok()?;
+        let name = name.text_trimmed();
+
+        match binding.declaration()? {
+            AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                if binding.is_under_pattern_binding()? {
+                    return None;
+                }
+                let annotation = declarator
+                    .variable_annotation()
+                    .and_then(|annotation| annotation.type_annotation().ok()?);
+                if let Some(annotation) = annotation {
+                    return self.resolve(&annotation.ty().ok()?);
+                }
+                let initializer = declarator.initializer()?.expression().ok()?;
+                let ty = self.type_of_expression(&initializer);
+                let is_const = declarator
+                    .declaration()
+                    .is_some_and(|declaration| declaration.is_const());
+                Some(if is_const { ty } else { ty.widened() })
+            }
+            AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+                if binding.is_under_pattern_binding()? {
+                    return None;
+                }
+                let ty = match parameter.type_annotation() {
+                    Some(annotation) => self.resolve(&annotation.ty().ok()?)?,
+                    None => {
+                        let initializer = parameter.initializer()?.expression().ok()?;
+                        return Some(self.type_of_expression(&initializer).widened());
+                    }
+                };
+                if parameter.question_mark_token().is_some() {
+                    Some(Type::union([ty, Type::Undefined]))
+                } else {
+                    Some(ty)
+                }
+            }
+            AnyJsBindingDeclaration::JsFunctionDeclaration(function) => Some(Type::Function(
+                Box::new(self.return_type(&AnyJsFunction::from(function))),
+            )),
+            AnyJsBindingDeclaration::JsFunctionExpression(function) => Some(Type::Function(
+                Box::new(self.return_type(&AnyJsFunction::from(function))),
+            )),
+            AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(function) => {
+                let ty = function.return_type_annotation()?.ty().ok()?;
+                Some(Type::Function(Box::new(self.resolve_return_type(&ty))))
+            }
+            AnyJsBindingDeclaration::TsTypeAliasDeclaration(alias) => {
+                self.resolve(&alias.ty().ok()?)
+            }
+            AnyJsBindingDeclaration::TsInterfaceDeclaration(interface) => {
+                Some(Type::Object(self.interface_properties(&interface)))
+            }
+            AnyJsBindingDeclaration::TsEnumDeclaration(declaration) => {
+                let members = declaration
+                    .members()
+                    .iter()
+                    .filter_map(|member| Some(member.ok()?.name().ok()?.name()?.to_string()))
+                    .collect();
+                Some(Type::Enum(Arc::new(EnumType {
+                    name: name.to_string(),
+                    members,
+                })))
+            }
+            AnyJsBindingDeclaration::JsClassDeclaration(_)
+            | AnyJsBindingDeclaration::JsClassExpression(_) => {
+                Some(Type::Reference(name.to_string()))
+            }
+            AnyJsBindingDeclaration::JsNamedImportSpecifier(specifier) => {
+                self.resolve_import(&AnyJsNamedImportSpecifier::from(specifier))
+            }
+            AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(specifier) => {
+                self.resolve_import(&AnyJsNamedImportSpecifier::from(specifier))
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve_import(&self, specifier: &AnyJsNamedImportSpecifier) -> Option<Type> {
+        let name = specifier.imported_name()?;
+        let source = specifier.import_clause()?.source().ok()?;
+        let source = source.inner_string_text().ok()?;
+        self.imports().resolve(source.text(), name.text_trimmed())
+    }
+
+    fn infer_expression(&self, expression: &AnyJsExpression) -> Option<Type> {
+        let ty = match expression {
+            AnyJsExpression::AnyJsLiteralExpression(literal) => match literal {
+                AnyJsLiteralExpression::JsStringLiteralExpression(literal) => Type::Literal(
+                    Literal::String(literal.inner_string_text().ok()?.to_string()),
+                ),
+                AnyJsLiteralExpression::JsNumberLiteralExpression(literal) => Type::Literal(
+                    Literal::Number(literal.value_token().ok()?.text_trimmed().to_string()),
+                ),
+                AnyJsLiteralExpression::JsBigintLiteralExpression(literal) => Type::Literal(
+                    Literal::BigInt(literal.value_token().ok()?.text_trimmed().to_string()),
+                ),
+                AnyJsLiteralExpression::JsBooleanLiteralExpression(literal) => Type::Literal(
+                    Literal::Boolean(literal.value_token().ok()?.text_trimmed() == "true"),
+                ),
+                AnyJsLiteralExpression::JsNullLiteralExpression(_) => Type::Null,
+                AnyJsLiteralExpression::JsRegexLiteralExpression(_) => {
+                    Type::Reference("RegExp".to_string())
+                }
+            },
+            AnyJsExpression::JsTemplateExpression(template) => {
+                if template.tag().is_some() {
+                    return None;
+                }
+                Type::String
+            }
+            AnyJsExpression::JsArrayExpression(array) => {
+                let elements: Vec<_> = array
+                    .elements()
+                    .iter()
+                    .map(|element| match element {
+                        Ok(AnyJsArrayElement::AnyJsExpression(expression)) => {
+                            self.type_of_expression(&expression).widened()
+                        }
+                        Ok(AnyJsArrayElement::JsArrayHole(_)) => Type::Undefined,
+                        _ => Type::Unknown,
+                    })
+                    .collect();
+                if elements.is_empty() {
+                    Type::Array(Box::new(Type::Unknown))
+                } else {
+                    Type::Array(Box::new(Type::union(elements)))
+                }
+            }
+            AnyJsExpression::JsArrowFunctionExpression(function) => Type::Function(Box::new(
+                self.return_type(&AnyJsFunction::from(function.clone())),
+            )),
+            AnyJsExpression::JsFunctionExpression(function) => Type::Function(Box::new(
+                self.return_type(&AnyJsFunction::from(function.clone())),
+            )),
+            AnyJsExpression::JsAssignmentExpression(assignment) => {
+                if assignment.operator_token().ok()?.kind() != JsSyntaxKind::EQ {
+                    return None;
+                }
+                self.type_of_expression(&assignment.right().ok()?)
+            }
+            AnyJsExpression::JsAwaitExpression(expression) => self
+                .type_of_expression(&expression.argument().ok()?)
+                .awaited(),
+            AnyJsExpression::JsBinaryExpression(expression) => match expression.operator().ok()? {
+                JsBinaryOperator::LessThan
+                | JsBinaryOperator::GreaterThan
+                | JsBinaryOperator::LessThanOrEqual
+                | JsBinaryOperator::GreaterThanOrEqual
+                | JsBinaryOperator::Equality
+                | JsBinaryOperator::StrictEquality
+                | JsBinaryOperator::Inequality
+                | JsBinaryOperator::StrictInequality => Type::Boolean,
+                JsBinaryOperator::Plus => {
+                    let left = self.type_of_expression(&expression.left().ok()?).widened();
+                    let right = self.type_of_expression(&expression.right().ok()?).widened();
+                    match (left, right) {
+                        (Type::String, _) | (_, Type::String) => Type::String,
+                        (Type::Number, Type::Number) => Type::Number,
+                        (Type::BigInt, Type::BigInt) => Type::BigInt,
+                        _ => return None,
+                    }
+                }
+                _ => Type::Number,
+            },
+            AnyJsExpression::JsInExpression(_) | AnyJsExpression::JsInstanceofExpression(_) => {
+                Type::Boolean
+            }
+            AnyJsExpression::JsCallExpression(call) => {
+                let callee = call.callee().ok()?;
+                if self.is_promise_constructor_member(&callee) {
+                    return Some(Type::Promise(Box::new(Type::Unknown)));
+                }
+                self.type_of_expression(&callee).return_type()
+            }
+            AnyJsExpression::JsComputedMemberExpression(member) => {
+                let object = self.type_of_expression(&member.object().ok()?);
+                match member.member().ok()? {
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsStringLiteralExpression(name),
+                    ) => object.property(name.inner_string_text().ok()?.text()),
+                    _ => match object {
+                        Type::Array(element) => *element,
+                        _ => return None,
+                    },
+                }
+            }
+            AnyJsExpression::JsConditionalExpression(expression) => Type::union([
+                self.type_of_expression(&expression.consequent().ok()?),
+                self.type_of_expression(&expression.alternate().ok()?),
+            ]),
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                self.type_of_reference(&identifier.name().ok()?)
+            }
+            AnyJsExpression::JsLogicalExpression(expression) => Type::union([
+                self.type_of_expression(&expression.left().ok()?),
+                self.type_of_expression(&expression.right().ok()?),
+            ]),
+            AnyJsExpression::JsNewExpression(expression) => {
+                let callee = expression.callee().ok()?;
+                let reference = callee.as_js_identifier_expression()?.name().ok()?;
+                match self.data.semantic.binding(&reference) {
+                    Some(binding) => match self.type_of_binding(&binding.tree()) {
+                        ty @ Type::Reference(_) => ty,
+                        _ => return None,
+                    },
+                    None if reference.has_name("Promise") => Type::Promise(Box::new(Type::Unknown)),
+                    None => Type::Reference(reference.name().ok()?.to_string()),
+                }
+            }
+            AnyJsExpression::JsObjectExpression(object) => {
+                let properties = object
+                    .members()
+                    .iter()
+                    .filter_map(|member| match member.ok()? {
+                        AnyJsObjectMember::JsPropertyObjectMember(member) => Some(Property {
+                            name: member.name().ok()?.name()?.to_string(),
+                            ty: self.type_of_expression(&member.value().ok()?).widened(),
+                            optional: false,
+                        }),
+                        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+                            let reference = member.name().ok()?;
+                            Some(Property {
+                                name: reference.name().ok()?.to_string(),
+                                ty: self.type_of_reference(&reference).widened(),
+                                optional: false,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                Type::Object(properties)
+            }
+            AnyJsExpression::JsParenthesizedExpression(expression) => {
+                self.type_of_expression(&expression.expression().ok()?)
+            }
+            AnyJsExpression::JsPostUpdateExpression(_)
+            | AnyJsExpression::JsPreUpdateExpression(_) => Type::Number,
+            AnyJsExpression::JsSequenceExpression(expression) => {
+                self.type_of_expression(&expression.right().ok()?)
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                let object = self.type_of_expression(&member.object().ok()?);
+                let name = member.member().ok()?.value_token().ok()?;
+                object.property(name.text_trimmed())
+            }
+            AnyJsExpression::JsUnaryExpression(expression) => match expression.operator().ok()? {
+                JsUnaryOperator::Void => Type::Undefined,
+                JsUnaryOperator::Typeof => Type::String,
+                JsUnaryOperator::Delete | JsUnaryOperator::LogicalNot => Type::Boolean,
+                JsUnaryOperator::Minus => match expression.argument().ok()? {
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsNumberLiteralExpression(literal),
+                    ) => Type::Literal(Literal::Number(format!(
+                        "-{}",
+                        literal.value_token().ok()?.text_trimmed()
+                    ))),
+                    _ => Type::Number,
+                },
+                JsUnaryOperator::Plus | JsUnaryOperator::BitwiseNot => Type::Number,
+            },
+            AnyJsExpression::TsAsExpression(expression) => {
+                let ty = expression.ty().ok()?;
+                if is_const_type(&ty) {
+                    self.type_of_expression(&expression.expression().ok()?)
+                } else {
+                    self.resolve(&ty)?
+                }
+            }
+            AnyJsExpression::TsSatisfiesExpression(expression) => {
+                self.type_of_expression(&expression.expression().ok()?)
+            }
+            AnyJsExpression::TsTypeAssertionExpression(expression) => {
+                self.resolve(&expression.ty().ok()?)?
+            }
+            AnyJsExpression::TsNonNullAssertionExpression(expression) => {
+                let ty = self.type_of_expression(&expression.expression().ok()?);
+                Type::union(
+                    ty.variants()
+                        .iter()
+                        .filter(|ty| !matches!(ty, Type::Null | Type::Undefined))
+                        .cloned(),
+                )
+            }
+            _ => return None,
+        };
+
+        Some(ty)
+    }
+
+    /// Returns the union of the types of the values returned in `body`,
+    /// excluding the functions it contains
+    fn type_of_returns(&self, body: &JsFunctionBody) -> Type {
+        let mut types = Vec::new();
+        let mut preorder = body.syntax().preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            if node != *body.syntax() && is_function_boundary(node.kind()) {
+                preorder.skip_subtree();
+                continue;
+            }
+            if let Some(statement) = JsReturnStatement::cast(node) {
+                types.push(match statement.argument() {
+                    Some(argument) => self.type_of_expression(&argument),
+                    None => Type::Undefined,
+                });
+            }
+        }
+
+        if types.is_empty() {
+            Type::Void
+        } else {
+            Type::union(types)
+        }
+    }
+
+    fn resolve(&self, ty: &AnyTsType) -> Option<Type> {
+        let ty = match ty {
+            AnyTsType::TsAnyType(_) => Type::Any,
+            AnyTsType::TsUnknownType(_) => Type::Unknown,
+            AnyTsType::TsNumberType(_) => Type::Number,
+            AnyTsType::TsBooleanType(_) => Type::Boolean,
+            AnyTsType::TsBigintType(_) => Type::BigInt,
+            AnyTsType::TsStringType(_) => Type::String,
+            AnyTsType::TsSymbolType(_) => Type::Symbol,
+            AnyTsType::TsVoidType(_) => Type::Void,
+            AnyTsType::TsUndefinedType(_) => Type::Undefined,
+            AnyTsType::TsNeverType(_) => Type::Never,
+            AnyTsType::TsNullLiteralType(_) => Type::Null,
+            AnyTsType::TsParenthesizedType(ty) => self.resolve(&ty.ty().ok()?)?,
+            AnyTsType::TsReferenceType(ty) => self.resolve_reference_type(ty)?,
+            AnyTsType::TsArrayType(ty) => {
+                Type::Array(Box::new(self.resolve_type(&ty.element_type().ok()?)))
+            }
+            AnyTsType::TsObjectType(ty) => Type::Object(self.properties(&ty.members())),
+            AnyTsType::TsNonPrimitiveType(_) => Type::Object(Vec::new()),
+            AnyTsType::TsStringLiteralType(ty) => Type::Literal(Literal::String(
+                inner_string_text(&ty.literal_token().ok()?).to_string(),
+            )),
+            AnyTsType::TsNumberLiteralType(ty) => {
+                let literal = ty.literal_token().ok()?;
+                let minus = if ty.minus_token().is_some() { "-" } else { "" };
+                Type::Literal(Literal::Number(format!(
+                    "{minus}{}",
+                    literal.text_trimmed()
+                )))
+            }
+            AnyTsType::TsBigintLiteralType(ty) => {
+                let literal = ty.literal_token().ok()?;
+                let minus = if ty.minus_token().is_some() { "-" } else { "" };
+                Type::Literal(Literal::BigInt(format!(
+                    "{minus}{}",
+                    literal.text_trimmed()
+                )))
+            }
+            AnyTsType::TsBooleanLiteralType(ty) => Type::Literal(Literal::Boolean(
+                ty.literal().ok()?.text_trimmed() == "true",
+            )),
+            AnyTsType::TsTemplateLiteralType(_) => Type::String,
+            AnyTsType::TsUnionType(ty) => Type::union(
+                ty.types()
+                    .iter()
+                    .map(|ty| ty.map_or(Type::Unknown, |ty| self.resolve_type(&ty))),
+            ),
+            AnyTsType::TsFunctionType(ty) => {
+                Type::Function(Box::new(self.resolve_return_type(&ty.return_type().ok()?)))
+            }
+            AnyTsType::TsTypeofType(ty) => match ty.expression_name().ok()? {
+                AnyTsName::JsReferenceIdentifier(reference) => self.type_of_reference(&reference),
+                AnyTsName::TsQualifiedName(_) => return None,
+            },
+            _ => return None,
+        };
+
+        Some(ty)
+    }
+
+    fn resolve_return_type(&self, ty: &AnyTsReturnType) -> Type {
+        match ty {
+            AnyTsReturnType::AnyTsType(ty) => self.resolve_type(ty),
+            AnyTsReturnType::TsPredicateReturnType(_) => Type::Boolean,
+            AnyTsReturnType::TsAssertsReturnType(_) => Type::Void,
+        }
+    }
+
+    fn resolve_reference_type(&self, ty: &TsReferenceType) -> Option<Type> {
+        let type_argument = || {
+            let ty = ty.type_arguments()?.ts_type_argument_list().iter().next()?;
+            Some(self.resolve_type(&ty.ok()?))
+        };
+
+        match ty.name().ok()? {
+            AnyTsName::JsReferenceIdentifier(reference) => {
+                self.resolve_type_name(&reference, || type_argument().unwrap_or(Type::Unknown))
+            }
+            AnyTsName::TsQualifiedName(name) => {
+                let AnyTsName::JsReferenceIdentifier(reference) = name.left().ok()? else {
+                    return None;
+                };
+                let member = name.right().ok()?.value_token().ok()?;
+                Some(
+                    self.type_of_reference(&reference)
+                        .property(member.text_trimmed()),
+                )
+            }
+        }
+    }
+
+    /// Returns the type `reference` names, where `type_argument` returns the
+    /// first type argument of the reference
+    fn resolve_type_name(
+        &self,
+        reference: &JsReferenceIdentifier,
+        type_argument: impl FnOnce() -> Type,
+    ) -> Option<Type> {
+        if let Some(binding) = self.data.semantic.binding(reference) {
+            return Some(self.type_of_binding(&binding.tree()));
+        }
+
+        let name = reference.name().ok()?;
+        let ty = match name.text() {
+            "Promise" | "PromiseLike" => Type::Promise(Box::new(type_argument())),
+            "Array" | "ReadonlyArray" => Type::Array(Box::new(type_argument())),
+            name => Type::Reference(name.to_string()),
+        };
+        Some(ty)
+    }
+
+    fn interface_properties(&self, interface: &TsInterfaceDeclaration) -> Vec<Property> {
+        let mut properties = self.properties(&interface.members());
+
+        let extended = interface
+            .extends_clause()
+            .into_iter()
+            .flat_map(|clause| clause.types().iter());
+        for ty in extended {
+            let Some(AnyTsName::JsReferenceIdentifier(reference)) =
+                ty.ok().and_then(|ty| ty.name().ok())
+            else {
+                continue;
+            };
+            let Some(Type::Object(inherited)) =
+                self.resolve_type_name(&reference, || Type::Unknown)
+            else {
+                continue;
+            };
+            for property in inherited {
+                if !properties.iter().any(|own| own.name == property.name) {
+                    properties.push(property);
+                }
+            }
+        }
+
+        properties
+    }
+
+    fn properties(&self, members: &TsTypeMemberList) -> Vec<Property> {
+        members
+            .iter()
+            .filter_map(|member| match member {
+                AnyTsTypeMember::TsPropertySignatureTypeMember(member) => Some(Property {
+                    name: member.name().ok()?.name()?.to_string(),
+                    ty: member
+                        .type_annotation()
+                        .and_then(|annotation| annotation.ty().ok())
+                        .map_or(Type::Any, |ty| self.resolve_type(&ty)),
+                    optional: member.optional_token().is_some(),
+                }),
+                AnyTsTypeMember::TsMethodSignatureTypeMember(member) => Some(Property {
+                    name: member.name().ok()?.name()?.to_string(),
+                    ty: Type::Function(Box::new(
+                        member
+                            .return_type_annotation()
+                            .and_then(|annotation| annotation.ty().ok())
+                            .map_or(Type::Any, |ty| self.resolve_return_type(&ty)),
+                    )),
+                    optional: member.optional_token().is_some(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `callee` is a static method of the global `Promise`
+    /// that returns a promise, e.g. `Promise.all`
+    fn is_promise_constructor_member(&self, callee: &AnyJsExpression) -> bool {
+        let Some(member) = callee.as_js_static_member_expression() else {
+            return false;
+        };
+        let is_promise = member
+            .object()
+            .ok()
+            .and_then(|object| object.as_js_identifier_expression()?.name().ok())
+            .is_some_and(|reference| {
+                reference.has_name("Promise") && self.data.semantic.binding(&reference).is_none()
+            });
+        is_promise
+            && member
+                .member()
+                .ok()
+                .and_then(|member| member.value_token().ok())
+                .is_some_and(|name| {
+                    matches!(
+                        name.text_trimmed(),
+                        "all" | "allSettled" | "any" | "race" | "reject" | "resolve"
+                    )
+                })
+    }
+}
+
+/// Returns `true` if `ty` is `const`, as in `value as const`
+fn is_const_type(ty: &AnyTsType) -> bool {
+    ty.as_ts_reference_type()
+        .and_then(|ty| ty.name().ok())
+        .and_then(|name| name.as_js_reference_identifier()?.value_token().ok())
+        .is_some_and(|name| name.text_trimmed() == "const")
+}
+
+/// Returns `true` if the nodes of `kind` have their own `return` statements
+fn is_function_boundary(kind: JsSyntaxKind) -> bool {
+    AnyJsFunction::can_cast(kind)
+        || matches!(
+            kind,
+            JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_CLASS_DECLARATION
+                | JsSyntaxKind::JS_CLASS_EXPRESSION
+                | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+        )
+}
@@ -0,0 +1,281 @@
+use crate::{ImportResolver, ProjectDeclarations, TypeModel};
+use biome_js_parser::JsParserOptions;
+use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::{AnyJsRoot, JsFileSource};
+use biome_rowan::AstNode;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+fn parse(source: &str, source_type: JsFileSource) -> AnyJsRoot {
+    let parse = biome_js_parser::parse(source, source_type, JsParserOptions::default());
+    assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+    parse.tree()
+}
+
+/// Returns the inferred types of the bindings `names` of `source`, a
+/// TypeScript file at `path` that can import the `declarations`
+fn infer_with(
+    declarations: Arc<ProjectDeclarations>,
+    path: &str,
+    source: &str,
+    names: &[&str],
+) -> Vec<String> {
+    let root = parse(source, JsFileSource::ts());
+    let model = TypeModel::new(
+        semantic_model(&root, SemanticModelOptions::default()),
+        ImportResolver::new(declarations, PathBuf::from(path)),
+    );
+
+    names
+        .iter()
+        .map(|name| {
+            let binding = root
+                .syntax()
+                .descendants()
+                .filter_map(AnyJsIdentifierBinding::cast)
+                .find(|binding| {
+                    binding
+                        .name_token()
+                        .is_ok_and(|token| token.text_trimmed() == *name)
+                })
+                .unwrap_or_else(|| panic!("the binding {name} wasn't found"));
+            model.type_of_binding(&binding).to_string()
+        })
+        .collect()
+}
+
+fn infer(source: &str, names: &[&str]) -> Vec<String> {
+    infer_with(Arc::default(), "file.ts", source, names)
+}
+
+#[test]
+fn infers_literals() {
+    let types = infer(
+        r#"const a = "a"; let b = "b"; const c = -1; const d = [1, 2]; const e = { x: true };"#,
+        &["a", "b", "c", "d", "e"],
+    );
+    assert_eq!(
+        types,
+        [r#""a""#, "string", "-1", "number[]", "{ x: boolean; }"]
+    );
+}
+
+#[test]
+fn infers_annotations() {
+    let types = infer(
+        r#"type Shape = "circle" | "square";
+        let shape: Shape;
+        function area(size?: number, unit = "cm"): string {}"#,
+        &["shape", "size", "unit", "area"],
+    );
+    assert_eq!(
+        types,
+        [
+            r#""circle" | "square""#,
+            "number | undefined",
+            "string",
+            "() => string"
+        ]
+    );
+}
+
+#[test]
+fn infers_return_types_of_local_functions() {
+    let types = infer(
+        r#"async function load() { return 1; }
+        const pending = load();
+        const chained = load().then((value) => value);
+        async function run() { const loaded = await load(); }
+        const all = Promise.all([load()]);
+        const parse = (text: string) => text.length;"#,
+        &["pending", "chained", "loaded", "all", "parse"],
+    );
+    assert_eq!(
+        types,
+        [
+            "Promise<number>",
+            "Promise<unknown>",
+            "number",
+            "Promise<unknown>",
+            "() => number"
+        ]
+    );
+}
+
+#[test]
+fn infers_properties_and_enum_members() {
+    let types = infer(
+        r#"interface Named { name: string }
+        interface Circle extends Named { kind: "circle"; radius: number }
+        interface Square { kind: "square"; size: number }
+        declare const shape: Circle | Square;
+        const kind = shape.kind;
+        enum Color { Red, Green }
+        const red = Color.Red;
+        let color = Color.Red;
+        let circle: Circle;"#,
+        &["kind", "red", "color", "circle"],
+    );
+    assert_eq!(
+        types,
+        [
+            r#""circle" | "square""#,
+            "Color.Red",
+            "Color",
+            r#"{ kind: "circle"; radius: number; name: string; }"#
+        ]
+    );
+}
+
+#[test]
+fn bails_out_of_recursive_declarations() {
+    let types = infer("type A = B; type B = A; let a: A;", &["a"]);
+    assert_eq!(types, ["unknown"]);
+}
+
+#[test]
+fn infers_declarations_imported_from_declaration_files() {
+    let declarations = Arc::new(ProjectDeclarations::default());
+    declarations.insert(
+        Path::new("src/api/types.d.ts"),
+        &parse(
+            r#"interface User { name: string }
+            export declare function fetchUser(): Promise<User>;
+            export declare enum Status { Active, Inactive }
+            export type { User };"#,
+            JsFileSource::d_ts(),
+        ),
+    );
+
+    let types = infer_with(
+        declarations,
+        "src/main.ts",
+        r#"import { fetchUser, Status } from "./api/types.js";
+        import type { User as Person } from "./api/types";
+        import { unknown } from "./api/types";
+        const user = fetchUser();
+        const status = Status.Active;
+        let person: Person;"#,
+        &["user", "status", "person", "unknown"],
+    );
+    assert_eq!(
+        types,
+        [
+            "Promise<{ name: string; }>",
+            "Status.Active",
+            "{ name: string; }",
+            "unknown"
+        ]
+    );
+}
+
+#[test]
+fn infers_declarations_imported_from_typescript_modules() {
+    let declarations = Arc::new(ProjectDeclarations::default());
+    declarations.insert(
+        Path::new("src/status.ts"),
+        &parse(
+            "export enum Status { Active, Inactive }",
+            JsFileSource::ts(),
+        ),
+    );
+    declarations.insert(
+        Path::new("src/status.d.ts"),
+        &parse(
+            "export declare enum Status { Pending }",
+            JsFileSource::d_ts(),
+        ),
+    );
+    declarations.insert(
+        Path::new("src/shapes/index.tsx"),
+        &parse(
+            r#"export type Shape = "circle" | "square";"#,
+            JsFileSource::tsx(),
+        ),
+    );
+
+    let types = infer_with(
+        declarations,
+        "src/main.ts",
+        r#"import { Status } from "./status.js";
+        import type { Shape } from "./shapes";
+        const status = Status.Active;
+        let shape: Shape;"#,
+        &["status", "shape"],
+    );
+    assert_eq!(types, ["Status.Active", r#""circle" | "square""#]);
+}
+
+#[test]
+fn resolves_the_reexports_of_the_files_recorded_later() {
+    let declarations = Arc::new(ProjectDeclarations::default());
+    declarations.insert(
+        Path::new("src/index.d.ts"),
+        &parse(
+            r#"export { Status } from "./status";
+            import { Shape } from "./shape";
+            export type Shapes = Shape[];"#,
+            JsFileSource::d_ts(),
+        ),
+    );
+
+    let source = r#"import { Status, Shapes } from "./index";
+        const status = Status.Active;
+        let shapes: Shapes;"#;
+    let names = ["status", "shapes"];
+    let types = infer_with(declarations.clone(), "src/main.ts", source, &names);
+    assert_eq!(types, ["unknown", "unknown[]"]);
+
+    declarations.insert(
+        Path::new("src/status.d.ts"),
+        &parse(
+            "export declare enum Status { Active, Inactive }",
+            JsFileSource::d_ts(),
+        ),
+    );
+    declarations.insert(
+        Path::new("src/shape.d.ts"),
+        &parse(
+            r#"export type Shape = "circle" | "square";"#,
+            JsFileSource::d_ts(),
+        ),
+    );
+
+    let types = infer_with(declarations, "src/main.ts", source, &names);
+    assert_eq!(types, ["Status.Active", r#"("circle" | "square")[]"#]);
+}
+
+#[test]
+fn bails_out_of_circular_imports() {
+    let declarations = Arc::new(ProjectDeclarations::default());
+    declarations.insert(
+        Path::new("a.d.ts"),
+        &parse(
+            r#"import { B } from "./b";
+            export type A = B;
+            export type Name = "a";"#,
+            JsFileSource::d_ts(),
+        ),
+    );
+    declarations.insert(
+        Path::new("b.d.ts"),
+        &parse(
+            r#"import { A, Name } from "./a";
+            export type B = A;
+            export type Names = Name[];"#,
+            JsFileSource::d_ts(),
+        ),
+    );
+
+    let types = infer_with(
+        declarations,
+        "main.ts",
+        r#"import { A } from "./a";
+        import { Names } from "./b";
+        let a: A;
+        let names: Names;"#,
+        &["a", "names"],
+    );
+    assert_eq!(types, ["unknown", r#""a"[]"#]);
+}
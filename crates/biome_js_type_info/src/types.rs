@@ -0,0 +1,270 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+/// The type of an expression or of a declaration, as inferred by the
+/// [TypeModel](crate::TypeModel)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    /// The type `unknown`, also used when the type couldn't be inferred
+    Unknown,
+    Any,
+    Never,
+    Void,
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    /// A literal type, e.g. `"circle"` or `42`
+    Literal(Literal),
+    /// A union of types, e.g. `"circle" | "square"`. It contains at least two
+    /// types, none of them being a union
+    Union(Vec<Type>),
+    /// An array of the given type
+    Array(Box<Type>),
+    /// A promise resolving to the given type
+    Promise(Box<Type>),
+    /// A function returning the given type
+    Function(Box<Type>),
+    /// An object with the given properties, e.g. an interface or an object
+    /// literal
+    Object(Vec<Property>),
+    /// An enum, standing for any of its members
+    Enum(Arc<EnumType>),
+    /// A member of an enum, e.g. `Color.Red`
+    EnumMember(Arc<EnumType>, String),
+    /// A named type whose structure isn't known, e.g. a class or a type
+    /// parameter
+    Reference(String),
+}
+
+/// The value of a literal type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Literal {
+    Boolean(bool),
+    /// The text of a number literal, e.g. `-1` or `0.5`
+    Number(String),
+    /// The text of a bigint literal, e.g. `1n`
+    BigInt(String),
+    /// The content of a string literal, without its quotes
+    String(String),
+}
+
+/// A property of an object type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Property {
+    pub name: String,
+    pub ty: Type,
+    /// Whether the property is declared with `?`
+    pub optional: bool,
+}
+
+/// The members of an enum declaration, in the order they're declared
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct EnumType {
+    pub name: String,
+    pub members: Vec<String>,
+}
+
+impl Type {
+    /// Returns the union of `types`: the unions are flattened and the
+    /// duplicated types are removed. It returns [Type::Never] if `types` is
+    /// empty, the only type if there's one, and [Type::Any] or [Type::Unknown]
+    /// if one of the types is.
+    pub fn union(types: impl IntoIterator<Item = Type>) -> Self {
+        let mut members = Vec::new();
+        let mut is_unknown = false;
+        for ty in types {
+            match ty {
+                Type::Any => return Type::Any,
+                Type::Unknown => is_unknown = true,
+                Type::Union(types) => {
+                    for ty in types {
+                        if !members.contains(&ty) {
+                            members.push(ty);
+                        }
+                    }
+                }
+                Type::Never => {}
+                ty => {
+                    if !members.contains(&ty) {
+                        members.push(ty);
+                    }
+                }
+            }
+        }
+
+        if is_unknown {
+            return Type::Unknown;
+        }
+
+        match members.len() {
+            0 => Type::Never,
+            1 => members.pop().unwrap_or(Type::Never),
+            _ => Type::Union(members),
+        }
+    }
+
+    /// Returns the types of the union, or the type itself when it isn't a union
+    pub fn variants(&self) -> &[Type] {
+        match self {
+            Type::Union(types) => types,
+            ty => std::slice::from_ref(ty),
+        }
+    }
+
+    /// Returns `true` if the type is a promise, or a union of which a member
+    /// is a promise
+    pub fn is_promise(&self) -> bool {
+        self.variants()
+            .iter()
+            .any(|ty| matches!(ty, Type::Promise(_)))
+    }
+
+    /// Returns `true` if nothing is known about the type
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown | Type::Any)
+    }
+
+    /// Returns the type a promise resolves to, e.g. the type of `await value`
+    /// when `value` has this type
+    pub fn awaited(&self) -> Type {
+        match self {
+            Type::Promise(ty) => ty.awaited(),
+            Type::Union(types) => Type::union(types.iter().map(Type::awaited)),
+            ty => ty.clone(),
+        }
+    }
+
+    /// Returns the type of a mutable binding initialized with a value of this
+    /// type: the literals widen to their primitive type, e.g. `let x = "a"`
+    /// declares a `string`
+    pub fn widened(&self) -> Type {
+        match self {
+            Type::Literal(Literal::Boolean(_)) => Type::Boolean,
+            Type::Literal(Literal::Number(_)) => Type::Number,
+            Type::Literal(Literal::BigInt(_)) => Type::BigInt,
+            Type::Literal(Literal::String(_)) => Type::String,
+            Type::EnumMember(enum_type, _) => Type::Enum(enum_type.clone()),
+            Type::Union(types) => Type::union(types.iter().map(Type::widened)),
+            ty => ty.clone(),
+        }
+    }
+
+    /// Returns the type of the property `name` of a value of this type
+    pub fn property(&self, name: &str) -> Type {
+        match self {
+            Type::Object(properties) => properties
+                .iter()
+                .find(|property| property.name == name)
+                .map_or(Type::Unknown, |property| {
+                    if property.optional {
+                        Type::union([property.ty.clone(), Type::Undefined])
+                    } else {
+                        property.ty.clone()
+                    }
+                }),
+            Type::Enum(enum_type) => {
+                if enum_type.members.iter().any(|member| member == name) {
+                    Type::EnumMember(enum_type.clone(), name.to_string())
+                } else {
+                    Type::Unknown
+                }
+            }
+            Type::Promise(_) => match name {
+                "then" | "catch" | "finally" => {
+                    Type::Function(Box::new(Type::Promise(Box::new(Type::Unknown))))
+                }
+                _ => Type::Unknown,
+            },
+            Type::Array(_) | Type::String | Type::Literal(Literal::String(_))
+                if name == "length" =>
+            {
+                Type::Number
+            }
+            Type::Union(types) => Type::union(
+                types
+                    .iter()
+                    .filter(|ty| !matches!(ty, Type::Null | Type::Undefined))
+                    .map(|ty| ty.property(name)),
+            ),
+            _ => Type::Unknown,
+        }
+    }
+
+    /// Returns the type returned by a call of a value of this type
+    pub fn return_type(&self) -> Type {
+        match self {
+            Type::Function(ty) => (**ty).clone(),
+            Type::Union(types) => Type::union(types.iter().map(Type::return_type)),
+            _ => Type::Unknown,
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Unknown => f.write_str("unknown"),
+            Type::Any => f.write_str("any"),
+            Type::Never => f.write_str("never"),
+            Type::Void => f.write_str("void"),
+            Type::Undefined => f.write_str("undefined"),
+            Type::Null => f.write_str("null"),
+            Type::Boolean => f.write_str("boolean"),
+            Type::Number => f.write_str("number"),
+            Type::BigInt => f.write_str("bigint"),
+            Type::String => f.write_str("string"),
+            Type::Symbol => f.write_str("symbol"),
+            Type::Literal(literal) => write!(f, "{literal}"),
+            Type::Union(types) => {
+                for (index, ty) in types.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    if matches!(ty, Type::Function(_)) {
+                        write!(f, "({ty})")?;
+                    } else {
+                        write!(f, "{ty}")?;
+                    }
+                }
+                Ok(())
+            }
+            Type::Array(ty) => {
+                if matches!(**ty, Type::Union(_) | Type::Function(_)) {
+                    write!(f, "({ty})[]")
+                } else {
+                    write!(f, "{ty}[]")
+                }
+            }
+            Type::Promise(ty) => write!(f, "Promise<{ty}>"),
+            Type::Function(ty) => write!(f, "() => {ty}"),
+            Type::Object(properties) => {
+                if properties.is_empty() {
+                    return f.write_str("{}");
+                }
+                f.write_str("{ ")?;
+                for property in properties {
+                    let optional = if property.optional { "?" } else { "" };
+                    write!(f, "{}{optional}: {}; ", property.name, property.ty)?;
+                }
+                f.write_str("}")
+            }
+            Type::Enum(enum_type) => f.write_str(&enum_type.name),
+            Type::EnumMember(enum_type, member) => write!(f, "{}.{member}", enum_type.name),
+            Type::Reference(name) => f.write_str(name),
+        }
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::Boolean(value) => write!(f, "{value}"),
+            Literal::Number(value) | Literal::BigInt(value) => f.write_str(value),
+            Literal::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
@@ -17,9 +17,10 @@ pub(crate) fn format(
     let doc = session.document(&url)?;
 
     debug!("Formatting...");
-    let printed = session
-        .workspace
-        .format_file(FormatFileParams { path: rome_path })?;
+    let printed = session.workspace.format_file(FormatFileParams {
+        path: rome_path,
+        source_map: false,
+    })?;
 
     let num_lines: u32 = doc.line_index.len();
 
@@ -30,6 +30,7 @@ pub(crate) async fn did_open(
     })?;
 
     session.insert_document(url.clone(), doc);
+    session.load_nested_configurations(&url);
 
     if let Err(err) = session.update_diagnostics(url).await {
         error!("Failed to update diagnostics: {}", err);
@@ -15,6 +15,7 @@ use futures::future::ready;
 use futures::FutureExt;
 use rustc_hash::FxHashMap;
 use serde_json::json;
+use std::ffi::OsStr;
 use std::panic::RefUnwindSafe;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -136,7 +137,7 @@ impl LSPServer {
                     watchers: vec![
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
+                                "{}/**/biome.json",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
@@ -144,7 +145,15 @@ impl LSPServer {
                         // TODO: Biome 2.0 remove it
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/rome.json",
+                                "{}/**/rome.json",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
+                        },
+                        // The declarations of the type-aware lint rules
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/*.{{ts,tsx,mts,cts}}",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
@@ -312,23 +321,25 @@ impl LanguageServer for LSPServer {
             .changes
             .iter()
             .map(|change| change.uri.to_file_path());
+        let mut declarations_changed = false;
         for file_path in file_paths {
             match file_path {
                 Ok(file_path) => {
                     let base_path = self.session.base_path();
                     if let Some(base_path) = base_path {
-                        let possible_rome_json = file_path.strip_prefix(&base_path);
-                        if let Ok(possible_rome_json) = possible_rome_json {
-                            if possible_rome_json.display().to_string() == ROME_JSON
-                                || possible_rome_json.display().to_string() == BIOME_JSON
-                            {
+                        // The configuration files of the nested directories
+                        // are derived from the one of the workspace
+                        if file_path.starts_with(&base_path) {
+                            let file_name = file_path.file_name().and_then(OsStr::to_str);
+                            if matches!(file_name, Some(ROME_JSON | BIOME_JSON)) {
                                 self.session.load_workspace_settings().await;
                                 self.setup_capabilities().await;
                                 self.session.update_all_diagnostics().await;
-                                // for now we are only interested to the configuration file,
-                                // so it's OK to exist the loop
-                                break;
+                                // Loading the configuration records the
+                                // declarations again, so it's OK to exit the loop
+                                return;
                             }
+                            declarations_changed |= self.session.update_declarations(&file_path);
                         }
                     }
                 }
@@ -338,6 +349,9 @@ impl LanguageServer for LSPServer {
                 }
             }
         }
+        if declarations_changed {
+            self.session.update_all_diagnostics().await;
+        }
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
@@ -569,6 +583,9 @@ impl ServerFactory {
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, transform_file);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, rewrite_file);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
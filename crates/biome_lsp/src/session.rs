@@ -7,19 +7,27 @@ use anyhow::Result;
 use biome_analyze::RuleCategories;
 use biome_console::markup;
 use biome_diagnostics::PrintDescription;
-use biome_fs::{FileSystem, OsFileSystem, RomePath};
-use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_fs::{
+    normalize_path, FileSystem, OpenOptions, OsFileSystem, PathInterner, RomePath,
+    TraversalContext, TraversalScope,
+};
+use biome_js_type_info::is_typescript_file;
+use biome_service::configuration::{
+    load_configuration, load_directory_configuration, load_plugins, nested_directories,
+    LoadedConfiguration,
+};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
+    CloseFileParams, FeatureName, FeaturesBuilder, IsPathIgnoredParams, OpenFileParams,
+    PullDiagnosticsParams, SupportsFeatureParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
-use biome_service::{ConfigurationBasePath, Workspace};
+use biome_service::{Configuration, ConfigurationBasePath, Workspace};
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicU8;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -69,6 +77,68 @@ pub(crate) struct Session {
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
+
+    /// The configuration files of the directories of the documents, [None]
+    /// until the configuration of the workspace is loaded
+    nested_configurations: RwLock<Option<NestedConfigurations>>,
+}
+
+/// The configuration files found in the directories of the documents, e.g. the
+/// ones of the packages of a monorepo. A configuration file applies to the files
+/// of its directory instead of the configuration of the workspace, like in the CLI.
+struct NestedConfigurations {
+    /// The directory of the configuration of the workspace, where the lookup stops
+    root: PathBuf,
+    /// The configuration of the workspace
+    configuration: Arc<Configuration>,
+    /// The VCS settings of the workspace, shared by the directories
+    vcs_base_path: Option<PathBuf>,
+    gitignore_matches: Vec<String>,
+    /// The configuration of each directory checked so far, [None] when the
+    /// directory doesn't have a configuration file, or it can't be loaded
+    directories: FxHashMap<PathBuf, Option<Arc<Configuration>>>,
+}
+
+impl NestedConfigurations {
+    /// Returns the configuration that applies to the parent of `directory`
+    fn parent_configuration(&self, directory: &Path) -> Arc<Configuration> {
+        directory
+            .ancestors()
+            .skip(1)
+            .find_map(|directory| self.directories.get(directory).cloned().flatten())
+            .unwrap_or_else(|| self.configuration.clone())
+    }
+}
+
+/// Visits the TypeScript files of the project, see [Session::load_declarations]
+struct DeclarationsContext<'a> {
+    session: &'a Session,
+    interner: PathInterner,
+}
+
+impl TraversalContext for DeclarationsContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: biome_diagnostics::Error) {}
+
+    fn can_handle(&self, rome_path: &RomePath) -> bool {
+        if !rome_path.is_dir() && !is_typescript_file(rome_path) {
+            return false;
+        }
+        self.session
+            .workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                rome_path: self.session.workspace_path(rome_path),
+                feature: FeatureName::Lint,
+            })
+            .is_ok_and(|ignored| !ignored)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        self.session.register_declaration_file(path);
+    }
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -150,6 +220,7 @@ impl Session {
             fs: DynRef::Owned(Box::new(OsFileSystem)),
             cancellation,
             config_path: None,
+            nested_configurations: RwLock::default(),
         }
     }
 
@@ -426,21 +497,43 @@ impl Session {
                     debug!("{configuration:#?}");
                     let fs = &self.fs;
 
-                    let result =
-                        configuration.retrieve_gitignore_matches(fs, configuration_path.as_deref());
+                    let result = configuration
+                        .retrieve_gitignore_matches(fs, configuration_path.as_deref())
+                        .and_then(|gitignore| {
+                            Ok((gitignore, load_plugins(&**fs, &configuration)?))
+                        });
 
                     match result {
-                        Ok((vcs_base_path, gitignore_matches)) => {
+                        Ok(((vcs_base_path, gitignore_matches), plugins)) => {
+                            let nested_configurations = configuration_path
+                                .or(self.base_path())
+                                .map(|root| NestedConfigurations {
+                                    root: normalize_path(&root),
+                                    configuration: Arc::new(configuration.clone()),
+                                    vcs_base_path: vcs_base_path.clone(),
+                                    gitignore_matches: gitignore_matches.clone(),
+                                    directories: FxHashMap::default(),
+                                });
                             let result = self.workspace.update_settings(UpdateSettingsParams {
                                 configuration,
                                 vcs_base_path,
                                 gitignore_matches,
+                                directory: None,
+                                plugins,
                             });
 
                             if let Err(error) = result {
                                 error!("Failed to set workspace settings: {}", error);
                                 ConfigurationStatus::Error
                             } else {
+                                *self.nested_configurations.write().unwrap() =
+                                    nested_configurations;
+                                let urls: Vec<_> =
+                                    self.documents.read().unwrap().keys().cloned().collect();
+                                for url in &urls {
+                                    self.load_nested_configurations(url);
+                                }
+                                self.load_declarations();
                                 ConfigurationStatus::Loaded
                             }
                         }
@@ -458,9 +551,181 @@ impl Session {
             }
         };
 
+        if !matches!(status, ConfigurationStatus::Loaded) {
+            *self.nested_configurations.write().unwrap() = None;
+        }
         self.set_configuration_status(status);
     }
 
+    /// Loads the configuration files of the directories that contain the
+    /// document, from the outermost one, and applies them to the workspace
+    pub(crate) fn load_nested_configurations(&self, url: &lsp_types::Url) {
+        let Ok(path) = url.to_file_path() else {
+            return;
+        };
+        let mut nested_configurations = self.nested_configurations.write().unwrap();
+        let Some(nested_configurations) = nested_configurations.as_mut() else {
+            return;
+        };
+
+        for directory in nested_directories(&nested_configurations.root, &path, normalize_path) {
+            if nested_configurations.directories.contains_key(&directory) {
+                continue;
+            }
+            let parent = nested_configurations.parent_configuration(&directory);
+            let configuration =
+                self.load_directory_configuration(nested_configurations, &directory, &parent);
+            nested_configurations
+                .directories
+                .insert(directory, configuration.map(Arc::new));
+        }
+    }
+
+    /// Opens the TypeScript files of the project in the workspace, which
+    /// records their declarations for the type-aware lint rules, like the CLI
+    /// does before linting the files. It does nothing if none of these rules
+    /// is enabled.
+    fn load_declarations(&self) {
+        let root = match self.nested_configurations.read().unwrap().as_ref() {
+            Some(nested_configurations)
+                if nested_configurations.configuration.has_type_aware_rules() =>
+            {
+                nested_configurations.root.clone()
+            }
+            _ => return,
+        };
+
+        let (interner, _) = PathInterner::new();
+        let ctx = DeclarationsContext {
+            session: self,
+            interner,
+        };
+        self.fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+            scope.spawn(&ctx, root);
+        }));
+    }
+
+    /// Records the declarations of the file at `path` again, after it changed
+    /// on the file system. Returns `false` if the declarations weren't updated.
+    pub(crate) fn update_declarations(&self, path: &Path) -> bool {
+        let has_type_aware_rules = self
+            .nested_configurations
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|nested_configurations| {
+                nested_configurations.configuration.has_type_aware_rules()
+            });
+        has_type_aware_rules && is_typescript_file(path) && self.register_declaration_file(path)
+    }
+
+    /// Opens the TypeScript file at `path` in the workspace, unless it's
+    /// opened in the editor, in which case the workspace already has its
+    /// content. The workspace keeps the declarations of a file once it's
+    /// closed.
+    fn register_declaration_file(&self, path: &Path) -> bool {
+        let rome_path = self.workspace_path(path);
+        let is_open = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .any(|url| self.file_path(url).is_ok_and(|path| path == rome_path));
+        if is_open {
+            return false;
+        }
+
+        // A deleted file doesn't export any declaration
+        let mut content = String::new();
+        let _ = self
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content));
+        let result = self
+            .workspace
+            .open_file(OpenFileParams {
+                path: rome_path.clone(),
+                content,
+                version: 0,
+                language_hint: Default::default(),
+            })
+            .and_then(|()| {
+                self.workspace
+                    .close_file(CloseFileParams { path: rome_path })
+            });
+        if let Err(error) = result {
+            error!(
+                "Couldn't record the declarations of {}: {error}",
+                path.display()
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Returns the path of the file at `path` in the workspace, which is
+    /// relative to its root like the paths of the documents
+    fn workspace_path(&self, path: &Path) -> RomePath {
+        match self.base_path() {
+            Some(base_path) => RomePath::new(path.strip_prefix(base_path).unwrap_or(path)),
+            None => RomePath::new(path),
+        }
+    }
+
+    /// Loads the configuration file of `directory`, if any, and applies it to
+    /// its files
+    fn load_directory_configuration(
+        &self,
+        nested_configurations: &NestedConfigurations,
+        directory: &Path,
+        parent: &Configuration,
+    ) -> Option<Configuration> {
+        let loaded_configuration = match load_directory_configuration(&*self.fs, directory, parent)
+        {
+            Ok(loaded_configuration) => loaded_configuration?,
+            Err(error) => {
+                error!(
+                    "Couldn't load the configuration file of {}, reason:\n {error}",
+                    directory.display()
+                );
+                return None;
+            }
+        };
+        if loaded_configuration.has_errors() {
+            error!(
+                "Couldn't load the configuration file of {}",
+                directory.display()
+            );
+            return None;
+        }
+
+        let configuration = loaded_configuration.configuration;
+        // The paths of the documents are relative to the root of the workspace
+        let settings_directory = match self.base_path() {
+            Some(base_path) => directory.strip_prefix(base_path).unwrap_or(directory),
+            None => directory,
+        };
+        let result = load_plugins(&*self.fs, &configuration).and_then(|plugins| {
+            self.workspace.update_settings(UpdateSettingsParams {
+                configuration: configuration.clone(),
+                vcs_base_path: nested_configurations.vcs_base_path.clone(),
+                gitignore_matches: nested_configurations.gitignore_matches.clone(),
+                directory: Some(settings_directory.to_path_buf()),
+                plugins,
+            })
+        });
+        match result {
+            Ok(()) => Some(configuration),
+            Err(error) => {
+                error!(
+                    "Failed to set the settings of {}: {error}",
+                    directory.display()
+                );
+                None
+            }
+        }
+    }
+
     /// Requests "workspace/configuration" from client and updates Session config
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn load_extension_settings(&self) {
@@ -1621,10 +1621,135 @@ async fn pull_refactors() -> Result<()> {
         ],
     );
 
-    let _expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Inline variable"),
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Inline the variable"),
         kind: Some(lsp::CodeActionKind::new(
-            "refactor.inline.biome.correctness.inlineVariable",
+            "refactor.inline.biome.refactor.inlineVariable",
+        )),
+        diagnostics: None,
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_rewrites() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("if (ready) start(); else wait();")
+        .await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: lsp::TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: lsp::Range {
+                    start: lsp::Position {
+                        line: 0,
+                        character: 1,
+                    },
+                    end: lsp::Position {
+                        line: 0,
+                        character: 1,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![lsp::CodeActionKind::REFACTOR_REWRITE]),
+                    ..Default::default()
+                },
+                work_done_progress_params: lsp::WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut changes = HashMap::default();
+
+    changes.insert(
+        url!("document.js"),
+        vec![
+            lsp::TextEdit {
+                range: lsp::Range {
+                    start: lsp::Position {
+                        line: 0,
+                        character: 4,
+                    },
+                    end: lsp::Position {
+                        line: 0,
+                        character: 4,
+                    },
+                },
+                new_text: String::from("!"),
+            },
+            lsp::TextEdit {
+                range: lsp::Range {
+                    start: lsp::Position {
+                        line: 0,
+                        character: 11,
+                    },
+                    end: lsp::Position {
+                        line: 0,
+                        character: 16,
+                    },
+                },
+                new_text: String::from("wait"),
+            },
+            lsp::TextEdit {
+                range: lsp::Range {
+                    start: lsp::Position {
+                        line: 0,
+                        character: 25,
+                    },
+                    end: lsp::Position {
+                        line: 0,
+                        character: 29,
+                    },
+                },
+                new_text: String::from("start"),
+            },
+        ],
+    );
+
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Flip the if and else branches"),
+        kind: Some(lsp::CodeActionKind::new(
+            "refactor.rewrite.biome.refactor.flipIfElse",
         )),
         diagnostics: None,
         edit: Some(lsp::WorkspaceEdit {
@@ -1638,7 +1763,7 @@ async fn pull_refactors() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![]);
+    assert_eq!(res, vec![expected_action]);
 
     server.close_document().await?;
 
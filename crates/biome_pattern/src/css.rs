@@ -0,0 +1,130 @@
+use crate::{Metavariable, Pattern, PatternDiagnostic, PatternMatch, Template};
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::{AnyCssDeclarationListBlock, AnyCssRule, CssLanguage, CssSyntaxNode};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutation, TextRange};
+
+/// Parses a pattern that matches CSS code.
+///
+/// The pattern is a single rule or at-rule, e.g. `$selector { color: red; }`,
+/// a declaration, e.g. `color: $value`, or a selector, e.g. `.$class:hover`.
+/// It's parsed in this order, so `a:hover` is a declaration.
+pub fn parse_css_pattern(pattern: &str) -> Result<Pattern<CssLanguage>, PatternDiagnostic> {
+    let (node, code) = parse_css_node(pattern)?;
+
+    if Metavariable::of_node(&node, &code).is_some() {
+        return Err(PatternDiagnostic::only_a_metavariable(
+            node.text_trimmed_range(),
+        ));
+    }
+
+    Ok(Pattern::new(&node, &code))
+}
+
+/// Parses the template that replaces the matches of `pattern`. Like the
+/// pattern, it's a single rule, declaration or selector.
+pub fn parse_css_template(
+    template: &str,
+    pattern: &Pattern<CssLanguage>,
+) -> Result<Template, PatternDiagnostic> {
+    let (node, code) = parse_css_node(template)?;
+    Template::new(&node, &code, pattern)
+}
+
+/// Adds to `mutation` the replacement of the code matched by `pattern_match`
+/// with the code of `template`
+pub fn replace_css_match(
+    mutation: &mut BatchMutation<CssLanguage>,
+    pattern_match: &PatternMatch<CssLanguage>,
+    template: &Template,
+) -> Result<(), PatternDiagnostic> {
+    let code = template.instantiate(pattern_match, |_, _| false);
+    let (replacement, _) = parse_css_node(&code)
+        .map_err(|_| PatternDiagnostic::invalid_replacement(&code, pattern_match.range()))?;
+
+    mutation.replace_element(
+        pattern_match.node().clone().into(),
+        replacement.detach().into(),
+    );
+    Ok(())
+}
+
+/// A form of CSS code: the code wrapping a pattern, and the function
+/// returning the node of the pattern from the rule of the wrapped code
+type CssForm = (
+    &'static str,
+    &'static str,
+    fn(AnyCssRule) -> Option<CssSyntaxNode>,
+);
+
+/// Parses `code`, which must contain a single rule, declaration or selector,
+/// and returns its node with the code it was parsed from.
+///
+/// `$$$name` isn't a CSS identifier, so it's parsed as `$__name`: the returned
+/// code has the same length as the parsed one, and contains the metavariables
+/// as they're written in `code`.
+fn parse_css_node(code: &str) -> Result<(CssSyntaxNode, String), PatternDiagnostic> {
+    let parsed_code = code.replace("$$$", "$__");
+
+    // The rules, then the declarations inside a rule, then the selectors of a rule
+    let forms: [CssForm; 3] = [
+        ("", "", |rule| Some(rule.into_syntax())),
+        ("a{", "}", single_declaration),
+        ("", "{}", single_selector),
+    ];
+
+    let mut error = None;
+    for (prefix, suffix, node_of) in forms {
+        let parse = parse_css(
+            &format!("{prefix}{parsed_code}{suffix}"),
+            CssParserOptions::default(),
+        );
+        if let Some(diagnostic) = parse.diagnostics().first() {
+            // The diagnostics of the other forms have the positions of their wrapping code
+            error.get_or_insert_with(|| PatternDiagnostic::invalid_syntax(diagnostic));
+            continue;
+        }
+
+        let root = parse.tree();
+        let mut rules = root.rules().iter();
+        let node = rules
+            .next()
+            .filter(|_| rules.next().is_none())
+            .and_then(node_of);
+        match node {
+            Some(node) => return Ok((node, format!("{prefix}{code}{suffix}"))),
+            None => {
+                error = Some(PatternDiagnostic::not_a_single_css_node(
+                    root.syntax().text_trimmed_range(),
+                ));
+                break;
+            }
+        }
+    }
+
+    Err(error.unwrap_or_else(|| PatternDiagnostic::not_a_single_css_node(None::<TextRange>)))
+}
+
+/// Returns the declaration of `rule` when it's its only declaration
+fn single_declaration(rule: AnyCssRule) -> Option<CssSyntaxNode> {
+    let AnyCssDeclarationListBlock::CssDeclarationListBlock(block) =
+        rule.as_css_rule()?.block().ok()?
+    else {
+        return None;
+    };
+    let mut declarations = block.declarations().iter();
+    declarations
+        .next()
+        .filter(|_| declarations.next().is_none())?
+        .ok()
+        .map(AstNode::into_syntax)
+}
+
+/// Returns the selector of `rule` when it's its only selector
+fn single_selector(rule: AnyCssRule) -> Option<CssSyntaxNode> {
+    let mut selectors = rule.as_css_rule()?.prelude().iter();
+    selectors
+        .next()
+        .filter(|_| selectors.next().is_none())?
+        .ok()
+        .map(AstNode::into_syntax)
+}
@@ -0,0 +1,94 @@
+use biome_console::markup;
+use biome_diagnostics::location::AsSpan;
+use biome_diagnostics::{Diagnostic, MessageAndDescription, PrintDescription};
+use biome_rowan::TextRange;
+use serde::{Deserialize, Serialize};
+
+/// Diagnostic emitted when a pattern can't be used to search the code
+#[derive(Debug, Diagnostic, Deserialize, Serialize, Clone)]
+#[diagnostic(category = "search", severity = Error)]
+pub struct PatternDiagnostic {
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+    /// The range of the pattern where the error is
+    #[location(span)]
+    span: Option<TextRange>,
+}
+
+impl PatternDiagnostic {
+    /// The pattern contains a syntax error, reported by `diagnostic`
+    pub(crate) fn invalid_syntax(diagnostic: &impl Diagnostic) -> Self {
+        let description = PrintDescription(diagnostic).to_string();
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The pattern isn't valid: "{description} }.to_owned(),
+            ),
+            span: diagnostic.location().span,
+        }
+    }
+
+    /// The pattern contains several nodes, or none
+    pub(crate) fn not_a_single_node(range: impl AsSpan) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The pattern must be a single expression or statement." }.to_owned(),
+            ),
+            span: range.as_span(),
+        }
+    }
+
+    /// The CSS pattern contains several nodes, or none
+    pub(crate) fn not_a_single_css_node(range: impl AsSpan) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The pattern must be a single rule, declaration or selector." }
+                    .to_owned(),
+            ),
+            span: range.as_span(),
+        }
+    }
+
+    /// The pattern only contains a metavariable, it would match every node
+    pub(crate) fn only_a_metavariable(range: TextRange) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The pattern must contain some code besides a metavariable." }.to_owned(),
+            ),
+            span: Some(range),
+        }
+    }
+
+    /// The template contains a metavariable that the pattern doesn't capture
+    pub(crate) fn unknown_metavariable(name: &str, range: TextRange) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The metavariable "<Emphasis>{name}</Emphasis>" isn't captured by the pattern." }
+                    .to_owned(),
+            ),
+            span: Some(range),
+        }
+    }
+
+    /// The template contains `$_` or `$$$`, which don't capture any code
+    pub(crate) fn anonymous_metavariable(range: TextRange) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The replacement can't contain an anonymous metavariable, because it doesn't capture any code." }
+                    .to_owned(),
+            ),
+            span: Some(range),
+        }
+    }
+
+    /// The code of a replacement isn't valid, once the metavariables are replaced
+    pub(crate) fn invalid_replacement(code: &str, range: TextRange) -> Self {
+        Self {
+            message: MessageAndDescription::from(
+                markup! { "The replacement of the code isn't valid: "<Emphasis>{code}</Emphasis> }
+                    .to_owned(),
+            ),
+            span: Some(range),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{Capture, Metavariable, Pattern, PatternDiagnostic, PatternMatch, Template};
+use biome_js_factory::make;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsOptionalChainExpression, AnyJsRoot, AnyJsStatement,
+    JsBinaryExpression, JsComputedMemberExpression, JsFileSource, JsLanguage, JsLogicalExpression,
+    JsSyntaxKind, JsSyntaxNode, OperatorPrecedence, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutation, SyntaxElement, TextRange};
+
+/// Parses a pattern that matches JavaScript code. It can contain TypeScript
+/// and JSX syntax.
+///
+/// The pattern is a single expression or statement. An expression statement,
+/// e.g. `foo();`, only matches the expression.
+pub fn parse_js_pattern(pattern: &str) -> Result<Pattern<JsLanguage>, PatternDiagnostic> {
+    let item = parse_js_item(pattern, JsFileSource::tsx())?;
+    let node = expression_of(item);
+
+    let range = node.text_trimmed_range();
+    if Metavariable::parse(&node.text_trimmed().to_string()).is_some() {
+        return Err(PatternDiagnostic::only_a_metavariable(range));
+    }
+
+    Ok(Pattern::new(&node, pattern))
+}
+
+/// Parses the template that replaces the matches of `pattern`. Like the
+/// pattern, it's a single expression or statement.
+pub fn parse_js_template(
+    template: &str,
+    pattern: &Pattern<JsLanguage>,
+) -> Result<Template, PatternDiagnostic> {
+    let item = parse_js_item(template, JsFileSource::tsx())?;
+    Template::new(&expression_of(item), template, pattern)
+}
+
+/// Replaces the code of `root` matched by `pattern` with the code of `template`,
+/// and returns the mutation with the number of replaced matches.
+///
+/// The matches inside a replaced match are left untouched. The code of each
+/// replacement is parsed with `source_type`.
+pub fn rewrite_js(
+    root: &JsSyntaxNode,
+    pattern: &Pattern<JsLanguage>,
+    template: &Template,
+    source_type: JsFileSource,
+) -> Result<(BatchMutation<JsLanguage>, usize), PatternDiagnostic> {
+    let mut mutation = BatchMutation::new(root.clone());
+    let mut replaced_range: Option<TextRange> = None;
+    let mut count = 0;
+
+    for pattern_match in pattern.find_matches(root) {
+        let range = pattern_match.range();
+        if replaced_range.is_some_and(|replaced_range| replaced_range.contains_range(range)) {
+            continue;
+        }
+
+        replace_js_match(&mut mutation, &pattern_match, template, source_type)?;
+        replaced_range = Some(range);
+        count += 1;
+    }
+
+    Ok((mutation, count))
+}
+
+/// Adds to `mutation` the replacement of the code matched by `pattern_match`
+/// with the code of `template`, parsed with `source_type`
+pub fn replace_js_match(
+    mutation: &mut BatchMutation<JsLanguage>,
+    pattern_match: &PatternMatch<JsLanguage>,
+    template: &Template,
+    source_type: JsFileSource,
+) -> Result<(), PatternDiagnostic> {
+    let code = instantiate_js_template(template, pattern_match);
+    let item = parse_js_item(&code, source_type)
+        .map_err(|_| PatternDiagnostic::invalid_replacement(&code, pattern_match.range()))?;
+    // A statement is replaced by the whole statement, so it keeps its semicolon
+    let replacement = if AnyJsStatement::can_cast(pattern_match.node().kind()) {
+        item.into_syntax()
+    } else {
+        expression_of(item)
+    };
+    let replacement = match (
+        AnyJsExpression::cast_ref(&replacement),
+        AnyJsExpression::cast_ref(pattern_match.node()),
+    ) {
+        (Some(expression), Some(matched)) if needs_parentheses(&expression, &matched) => {
+            make::js_parenthesized_expression(
+                make::token(T!['(']),
+                expression,
+                make::token(T![')']),
+            )
+            .into_syntax()
+        }
+        _ => replacement,
+    };
+
+    mutation.replace_element(
+        pattern_match.node().clone().into(),
+        replacement.detach().into(),
+    );
+    Ok(())
+}
+
+/// Returns the code of `template` for `pattern_match`. A captured expression is
+/// wrapped in parentheses when it binds less tightly than its position in the
+/// template requires, e.g. `a + b` captured by `$a` in `$a * 2`.
+fn instantiate_js_template(
+    template: &Template,
+    pattern_match: &PatternMatch<JsLanguage>,
+) -> String {
+    // The template was parsed successfully when it was created
+    let template_node = parse_js_item(template.text(), JsFileSource::tsx())
+        .ok()
+        .map(expression_of);
+    template.instantiate(pattern_match, |range, capture| {
+        let (Some(template_node), Capture::Node(node)) = (&template_node, capture) else {
+            return false;
+        };
+        // The position of a metavariable that is the whole template is the one of the match
+        if range == template_node.text_trimmed_range() {
+            return false;
+        }
+        match (
+            AnyJsExpression::cast_ref(node),
+            expression_at(template_node, range),
+        ) {
+            (Some(expression), Some(metavariable)) => needs_parentheses(&expression, &metavariable),
+            _ => false,
+        }
+    })
+}
+
+/// Returns the outermost expression of `root` whose code is at `range`
+fn expression_at(root: &JsSyntaxNode, range: TextRange) -> Option<AnyJsExpression> {
+    let node = match root.covering_element(range) {
+        SyntaxElement::Node(node) => node,
+        SyntaxElement::Token(token) => token.parent()?,
+    };
+    node.ancestors()
+        .take_while(|node| node.text_trimmed_range() == range)
+        .filter_map(AnyJsExpression::cast)
+        .last()
+}
+
+/// Returns `true` if `value` must be wrapped in parentheses to take the place
+/// of `expression` in its parent
+fn needs_parentheses(value: &AnyJsExpression, expression: &AnyJsExpression) -> bool {
+    if let AnyJsExpression::JsSequenceExpression(_) = value {
+        return true;
+    }
+    let is_primary = match value {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsThisExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::JsTemplateExpression(_)
+        | AnyJsExpression::JsxTagExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(_)
+        | AnyJsExpression::JsComputedMemberExpression(_)
+        | AnyJsExpression::JsCallExpression(_) => {
+            AnyJsOptionalChainExpression::cast_ref(value.syntax())
+                .is_some_and(|chain| !chain.is_optional_chain())
+        }
+        _ => false,
+    };
+    if is_primary {
+        return false;
+    }
+
+    let Some(parent) = expression.syntax().parent() else {
+        return false;
+    };
+    if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+        return member.member().ok().as_ref() != Some(expression);
+    }
+    let operator = match (
+        JsBinaryExpression::cast_ref(&parent),
+        JsLogicalExpression::cast_ref(&parent),
+    ) {
+        (Some(binary), _) => binary
+            .operator()
+            .map(|operator| (operator.precedence(), binary.left())),
+        (_, Some(logical)) => logical
+            .operator()
+            .map(|operator| (operator.precedence(), logical.left())),
+        _ => {
+            // These positions accept any expression, except a sequence
+            return match parent.kind() {
+                JsSyntaxKind::JS_EXPRESSION_STATEMENT => matches!(
+                    value,
+                    AnyJsExpression::JsObjectExpression(_)
+                        | AnyJsExpression::JsFunctionExpression(_)
+                        | AnyJsExpression::JsClassExpression(_)
+                ),
+                kind => !matches!(
+                    kind,
+                    JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+                        | JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+                        | JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                        | JsSyntaxKind::JS_RETURN_STATEMENT
+                        | JsSyntaxKind::JS_THROW_STATEMENT
+                        | JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+                        | JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+                        | JsSyntaxKind::JS_TEMPLATE_ELEMENT
+                        | JsSyntaxKind::JS_SPREAD
+                        | JsSyntaxKind::JSX_EXPRESSION_CHILD
+                        | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+                ),
+            };
+        }
+    };
+
+    // The operator of the value must bind tighter than the operator using it
+    let (Ok((parent_precedence, left)), Ok(precedence)) = (operator, value.precedence()) else {
+        return true;
+    };
+    // `??` can't be mixed with `||` and `&&` without parentheses
+    let is_coalesce = |precedence| precedence == OperatorPrecedence::Coalesce;
+    if is_coalesce(precedence) != is_coalesce(parent_precedence)
+        && JsLogicalExpression::can_cast(value.syntax().kind())
+        && JsLogicalExpression::can_cast(parent.kind())
+    {
+        return true;
+    }
+    let is_left = left.ok().as_ref() == Some(expression);
+    precedence < parent_precedence
+        || precedence == parent_precedence && (!is_left || precedence.is_right_to_left())
+}
+
+/// Parses `code`, which must contain a single module item
+fn parse_js_item(
+    code: &str,
+    source_type: JsFileSource,
+) -> Result<AnyJsModuleItem, PatternDiagnostic> {
+    let parse = parse(code, source_type, JsParserOptions::default());
+    if let Some(diagnostic) = parse.diagnostics().first() {
+        return Err(PatternDiagnostic::invalid_syntax(diagnostic));
+    }
+
+    let root = parse.tree();
+    let item = match &root {
+        AnyJsRoot::JsModule(module) if module.directives().is_empty() => {
+            let mut items = module.items().iter();
+            items.next().filter(|_| items.next().is_none())
+        }
+        // The CommonJS files are parsed as scripts
+        AnyJsRoot::JsScript(script) if script.directives().is_empty() => {
+            let mut statements = script.statements().iter();
+            statements
+                .next()
+                .filter(|_| statements.next().is_none())
+                .map(AnyJsModuleItem::AnyJsStatement)
+        }
+        _ => None,
+    };
+    item.ok_or_else(|| PatternDiagnostic::not_a_single_node(root.range()))
+}
+
+/// Returns the expression of an expression statement, or the item itself.
+/// A JSX tag is returned without its expression, so it matches the nested tags.
+fn expression_of(item: AnyJsModuleItem) -> JsSyntaxNode {
+    let AnyJsModuleItem::AnyJsStatement(statement) = item else {
+        return item.into_syntax();
+    };
+    match statement
+        .as_js_expression_statement()
+        .and_then(|statement| statement.expression().ok())
+    {
+        Some(AnyJsExpression::JsxTagExpression(expression)) => match expression.tag() {
+            Ok(tag) => tag.into_syntax(),
+            Err(_) => expression.into_syntax(),
+        },
+        Some(expression) => expression.into_syntax(),
+        None => statement.into_syntax(),
+    }
+}
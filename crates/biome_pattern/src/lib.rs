@@ -0,0 +1,632 @@
+//! Structural search of code.
+//!
+//! A [Pattern] is a snippet of code in which metavariables stand for nodes of
+//! the syntax tree:
+//! - `$name` matches any node, e.g. `$a + $b` matches `1 + f(x)`;
+//! - `$$$name` matches any number of nodes of a list, e.g. `f($$$args)`
+//!   matches `f()` and `f(a, b)`;
+//! - `$_` and `$$$` match the same nodes, without capturing them.
+//!
+//! A metavariable that occurs several times in a pattern matches the same
+//! code each time, e.g. `$a === $a` matches `x === x` but not `x === y`.
+//!
+//! The other nodes of the pattern match the nodes of the same kind whose
+//! children match. The trivia, e.g. whitespace and comments, are ignored.
+//!
+//! A [Template] is the code that replaces the matches of a pattern. Its
+//! metavariables are replaced by the code they captured, e.g. the pattern
+//! `$a.concat($b)` and the template `[...$a, ...$b]` rewrite `x.concat(y)` to
+//! `[...x, ...y]`.
+
+mod css;
+mod diagnostics;
+mod js;
+mod template;
+
+pub use css::{parse_css_pattern, parse_css_template, replace_css_match};
+pub use diagnostics::PatternDiagnostic;
+pub use js::{parse_js_pattern, parse_js_template, replace_js_match, rewrite_js};
+pub use template::Template;
+
+use biome_rowan::{
+    Direction, Language, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxSlot, TextRange,
+};
+use std::collections::BTreeMap;
+
+/// A snippet of code that matches the nodes with the same structure
+#[derive(Debug, Clone)]
+pub struct Pattern<L: Language> {
+    root: PatternNode<L>,
+}
+
+impl<L: Language> Pattern<L> {
+    /// Creates a pattern from the syntax tree of its code. The metavariables
+    /// are read from `code`, the code `node` was parsed from: a metavariable
+    /// that isn't valid syntax can be parsed from a different text of the same
+    /// length, e.g. `$$$args` from `$__args`.
+    pub fn new(node: &SyntaxNode<L>, code: &str) -> Self {
+        Self {
+            root: PatternNode::from_element(node.clone().into(), code),
+        }
+    }
+
+    /// Returns the matches of the pattern in `root` and its descendants, in
+    /// the order of the source code
+    pub fn find_matches(&self, root: &SyntaxNode<L>) -> Vec<PatternMatch<L>> {
+        root.descendants()
+            .filter_map(|node| self.match_node(&node))
+            .collect()
+    }
+
+    /// Returns the match of the pattern when it matches `node`
+    pub fn match_node(&self, node: &SyntaxNode<L>) -> Option<PatternMatch<L>> {
+        let mut captures = Captures::new();
+        self.root
+            .matches(&node.clone().into(), &mut captures)
+            .then(|| PatternMatch {
+                node: node.clone(),
+                captures,
+            })
+    }
+
+    /// Whether the pattern contains the metavariable `$name` or `$$$name`
+    pub fn has_metavariable(&self, name: &str) -> bool {
+        self.root.has_metavariable(name)
+    }
+
+    /// Returns the kind of the nodes the pattern can match, [None] when it's a
+    /// metavariable that matches any node
+    pub fn kind(&self) -> Option<L::Kind> {
+        match &self.root {
+            PatternNode::Node { kind, .. }
+            | PatternNode::List { kind, .. }
+            | PatternNode::Token { kind, .. } => Some(*kind),
+            PatternNode::Metavariable(_) => None,
+        }
+    }
+}
+
+/// The node matched by a [Pattern], with the nodes captured by its metavariables
+#[derive(Debug, Clone)]
+pub struct PatternMatch<L: Language> {
+    node: SyntaxNode<L>,
+    captures: Captures<L>,
+}
+
+impl<L: Language> PatternMatch<L> {
+    /// The node matched by the pattern
+    pub fn node(&self) -> &SyntaxNode<L> {
+        &self.node
+    }
+
+    /// The range of the code matched by the pattern
+    pub fn range(&self) -> TextRange {
+        self.node.text_trimmed_range()
+    }
+
+    /// Returns what the metavariable `$name` or `$$$name` captured
+    pub fn capture(&self, name: &str) -> Option<&Capture<L>> {
+        self.captures.get(name)
+    }
+
+    /// Returns the captures of the metavariables, by name
+    pub fn captures(&self) -> impl Iterator<Item = (&str, &Capture<L>)> {
+        self.captures
+            .iter()
+            .map(|(name, capture)| (name.as_str(), capture))
+    }
+}
+
+/// The code captured by a metavariable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Capture<L: Language> {
+    /// The node captured by `$name`
+    Node(SyntaxNode<L>),
+    /// The nodes of a list captured by `$$$name`
+    List(Vec<SyntaxNode<L>>),
+}
+
+impl<L: Language> Capture<L> {
+    /// The range of the captured code, [None] for an empty list
+    pub fn range(&self) -> Option<TextRange> {
+        match self {
+            Capture::Node(node) => Some(node.text_trimmed_range()),
+            Capture::List(nodes) => {
+                let first = nodes.first()?.text_trimmed_range();
+                let last = nodes.last()?.text_trimmed_range();
+                Some(first.cover(last))
+            }
+        }
+    }
+
+    /// The captured code, with the trivia between the nodes of a list
+    pub fn text(&self) -> String {
+        match self {
+            Capture::Node(node) => node.text_trimmed().to_string(),
+            Capture::List(nodes) => {
+                let (Some(list), Some(range)) =
+                    (nodes.first().and_then(SyntaxNode::parent), self.range())
+                else {
+                    return String::new();
+                };
+                list.text()
+                    .slice(range - list.text_range().start())
+                    .to_string()
+            }
+        }
+    }
+
+    /// Whether the two captures contain the same code, ignoring the trivia
+    fn is_equivalent(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Capture::Node(node), Capture::Node(other)) => are_equivalent(node, other),
+            (Capture::List(nodes), Capture::List(others)) => {
+                nodes.len() == others.len()
+                    && nodes
+                        .iter()
+                        .zip(others)
+                        .all(|(node, other)| are_equivalent(node, other))
+            }
+            _ => false,
+        }
+    }
+}
+
+type Captures<L> = BTreeMap<String, Capture<L>>;
+
+/// Whether the nodes contain the same tokens
+fn are_equivalent<L: Language>(node: &SyntaxNode<L>, other: &SyntaxNode<L>) -> bool {
+    let mut tokens = node.descendants_tokens(Direction::Next);
+    let mut others = other.descendants_tokens(Direction::Next);
+    loop {
+        match (tokens.next(), others.next()) {
+            (None, None) => return true,
+            (Some(token), Some(other))
+                if token.kind() == other.kind() && token.text_trimmed() == other.text_trimmed() => {
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// A metavariable of a pattern, anonymous when it doesn't have a name
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Metavariable {
+    /// `$name` or `$_`
+    Single(Option<String>),
+    /// `$$$name` or `$$$`
+    Variadic(Option<String>),
+}
+
+impl Metavariable {
+    /// Returns the metavariable written as `text`, if any
+    fn parse(text: &str) -> Option<Self> {
+        let (name, is_variadic) = match text.strip_prefix("$$$") {
+            Some(name) => (name, true),
+            None => (text.strip_prefix('$')?, false),
+        };
+        let is_valid_name = name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_');
+        if !is_valid_name || (name.is_empty() && !is_variadic) {
+            return None;
+        }
+        let name = (!name.is_empty() && name != "_").then(|| name.to_string());
+        Some(if is_variadic {
+            Self::Variadic(name)
+        } else {
+            Self::Single(name)
+        })
+    }
+
+    /// Returns the metavariable written at the range of `node` in `code`, the
+    /// code it was parsed from, if any
+    fn of_node<L: Language>(node: &SyntaxNode<L>, code: &str) -> Option<Self> {
+        Self::parse(&code[node.text_trimmed_range()])
+    }
+
+    /// The name of the metavariable, [None] when it's anonymous
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Single(name) | Self::Variadic(name) => name.as_deref(),
+        }
+    }
+
+    /// Binds the metavariable to `capture`. It fails when the metavariable
+    /// already captured different code.
+    fn bind<L: Language>(&self, capture: Capture<L>, captures: &mut Captures<L>) -> bool {
+        let Some(name) = self.name() else {
+            return true;
+        };
+        match captures.get(name) {
+            Some(captured) => captured.is_equivalent(&capture),
+            None => {
+                captures.insert(name.to_string(), capture);
+                true
+            }
+        }
+    }
+}
+
+/// The syntax tree of a pattern, where the metavariables are resolved
+#[derive(Debug, Clone)]
+enum PatternNode<L: Language> {
+    Metavariable(Metavariable),
+    /// A node and its slots, [None] for the empty ones
+    Node {
+        kind: L::Kind,
+        slots: Vec<Option<PatternNode<L>>>,
+    },
+    /// A list and its elements, without the separators
+    List {
+        kind: L::Kind,
+        elements: Vec<PatternNode<L>>,
+    },
+    Token {
+        kind: L::Kind,
+        text: String,
+    },
+}
+
+impl<L: Language> PatternNode<L> {
+    fn has_metavariable(&self, name: &str) -> bool {
+        match self {
+            Self::Metavariable(metavariable) => metavariable.name() == Some(name),
+            Self::Token { .. } => false,
+            Self::Node { slots, .. } => slots
+                .iter()
+                .flatten()
+                .any(|slot| slot.has_metavariable(name)),
+            Self::List { elements, .. } => elements
+                .iter()
+                .any(|element| element.has_metavariable(name)),
+        }
+    }
+
+    fn from_element(element: SyntaxElement<L>, code: &str) -> Self {
+        let node = match element {
+            SyntaxElement::Node(node) => node,
+            SyntaxElement::Token(token) => {
+                return Self::Token {
+                    kind: token.kind(),
+                    text: token.text_trimmed().to_string(),
+                }
+            }
+        };
+
+        let kind = node.kind();
+        if kind.is_list() {
+            // A list that only contains a metavariable is still a list, the
+            // metavariable is one of its elements
+            Self::List {
+                kind,
+                elements: node
+                    .children()
+                    .map(|child| Self::from_element(child.into(), code))
+                    .collect(),
+            }
+        } else if let Some(metavariable) = Metavariable::of_node(&node, code) {
+            Self::Metavariable(metavariable)
+        } else {
+            Self::Node {
+                kind,
+                slots: node
+                    .slots()
+                    .map(|slot| match slot {
+                        SyntaxSlot::Node(node) => Some(Self::from_element(node.into(), code)),
+                        SyntaxSlot::Token(token) => Some(Self::from_element(token.into(), code)),
+                        SyntaxSlot::Empty => None,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    fn matches(&self, element: &SyntaxElement<L>, captures: &mut Captures<L>) -> bool {
+        match (self, element) {
+            (Self::Metavariable(metavariable), SyntaxElement::Node(node)) => {
+                let capture = match metavariable {
+                    Metavariable::Single(_) => Capture::Node(node.clone()),
+                    Metavariable::Variadic(_) => Capture::List(vec![node.clone()]),
+                };
+                metavariable.bind(capture, captures)
+            }
+            (Self::Node { kind, slots }, SyntaxElement::Node(node)) if *kind == node.kind() => {
+                let mut node_slots = node.slots();
+                slots.len() == node_slots.len()
+                    && slots.iter().zip(&mut node_slots).all(|(slot, node_slot)| {
+                        match (slot, node_slot) {
+                            (None, SyntaxSlot::Empty) => true,
+                            (Some(slot), SyntaxSlot::Node(node)) => {
+                                slot.matches(&node.into(), captures)
+                            }
+                            (Some(slot), SyntaxSlot::Token(token)) => {
+                                slot.matches(&token.into(), captures)
+                            }
+                            _ => false,
+                        }
+                    })
+            }
+            (Self::List { kind, elements }, SyntaxElement::Node(node)) if *kind == node.kind() => {
+                let nodes: Vec<_> = node.children().collect();
+                match_list(elements, &nodes, captures)
+            }
+            (Self::Token { kind, text }, SyntaxElement::Token(token)) => {
+                *kind == token.kind() && text == token.text_trimmed()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether the elements of a list match `nodes`. A variadic metavariable
+/// captures as few nodes as possible.
+fn match_list<L: Language>(
+    elements: &[PatternNode<L>],
+    nodes: &[SyntaxNode<L>],
+    captures: &mut Captures<L>,
+) -> bool {
+    let Some((element, elements)) = elements.split_first() else {
+        return nodes.is_empty();
+    };
+
+    if let PatternNode::Metavariable(metavariable @ Metavariable::Variadic(_)) = element {
+        return (0..=nodes.len()).any(|count| {
+            let mut candidate = captures.clone();
+            let capture = Capture::List(nodes[..count].to_vec());
+            let is_match = metavariable.bind(capture, &mut candidate)
+                && match_list(elements, &nodes[count..], &mut candidate);
+            if is_match {
+                *captures = candidate;
+            }
+            is_match
+        });
+    }
+
+    let Some((node, nodes)) = nodes.split_first() else {
+        return false;
+    };
+    let mut candidate = captures.clone();
+    let is_match = element.matches(&node.clone().into(), &mut candidate)
+        && match_list(elements, nodes, &mut candidate);
+    if is_match {
+        *captures = candidate;
+    }
+    is_match
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_css_parser::{parse_css, CssParserOptions};
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+    use biome_rowan::BatchMutation;
+
+    fn find_matches(pattern: &str, code: &str) -> Vec<String> {
+        let pattern = parse_js_pattern(pattern).unwrap();
+        let root = parse(code, JsFileSource::tsx(), JsParserOptions::default()).syntax();
+        pattern
+            .find_matches(&root)
+            .iter()
+            .map(|pattern_match| pattern_match.node().text_trimmed().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn matches_the_nodes_with_the_same_structure() {
+        assert_eq!(
+            find_matches("foo.bar()", "foo.bar(); foo .bar( ); foo.baz(); bar.bar();"),
+            ["foo.bar()", "foo .bar( )"]
+        );
+    }
+
+    #[test]
+    fn captures_the_nodes_matched_by_metavariables() {
+        let pattern = parse_js_pattern("$value as $_").unwrap();
+        let root = parse(
+            "const a = (b as any) + c;",
+            JsFileSource::ts(),
+            JsParserOptions::default(),
+        )
+        .syntax();
+        let matches = pattern.find_matches(&root);
+
+        assert_eq!(matches.len(), 1);
+        let Some(Capture::Node(value)) = matches[0].capture("value") else {
+            panic!("the value isn't captured");
+        };
+        assert_eq!(value.text_trimmed().to_string(), "b");
+        assert_eq!(matches[0].captures().count(), 1);
+    }
+
+    #[test]
+    fn matches_the_same_code_with_a_repeated_metavariable() {
+        assert_eq!(
+            find_matches("$a === $a", "x === x; x === y; f(1) === f( 1 );"),
+            ["x === x", "f(1) === f( 1 )"]
+        );
+    }
+
+    #[test]
+    fn matches_any_number_of_elements_with_a_variadic_metavariable() {
+        assert_eq!(
+            find_matches(
+                "useEffect($$$, [])",
+                "useEffect(f, []); useEffect(f, [a]); useEffect([]);"
+            ),
+            ["useEffect(f, [])", "useEffect([])"]
+        );
+        assert_eq!(
+            find_matches("f(a, $$$rest)", "f(a); f(a, b, c); f(b, a);"),
+            ["f(a)", "f(a, b, c)"]
+        );
+    }
+
+    #[test]
+    fn matches_any_node_with_a_metavariable_in_a_list() {
+        assert_eq!(
+            find_matches("f($x)", "f(); f(a); f(a, b); f(...a);"),
+            ["f(a)", "f(...a)"]
+        );
+    }
+
+    fn rewrite(pattern: &str, template: &str, code: &str) -> String {
+        let pattern = parse_js_pattern(pattern).unwrap();
+        let template = parse_js_template(template, &pattern).unwrap();
+        let root = parse(code, JsFileSource::tsx(), JsParserOptions::default()).syntax();
+        let (mutation, _) = rewrite_js(&root, &pattern, &template, JsFileSource::tsx()).unwrap();
+        mutation.commit().to_string()
+    }
+
+    #[test]
+    fn rewrites_the_matches_with_the_captured_code() {
+        assert_eq!(
+            rewrite(
+                "$a.concat($b)",
+                "[...$a, ...$b]",
+                "const c = a.concat(b);\n// keep\nf(x.y.concat([1, 2]));\n"
+            ),
+            "const c = [...a, ...b];\n// keep\nf([...x.y, ...[1, 2]]);\n"
+        );
+        assert_eq!(
+            rewrite("if ($c) $body", "if (!$c) {} else $body", "if (a) b();\n"),
+            "if (!a) {} else b();\n"
+        );
+    }
+
+    #[test]
+    fn rewrites_the_captured_lists() {
+        assert_eq!(
+            rewrite("f($$$args)", "g($$$args, 1)", "f();\nf(a, /* b */ b);\n"),
+            "g(1);\ng(a, /* b */ b, 1);\n"
+        );
+        assert_eq!(rewrite("f($$$args)", "g(1, $$$args)", "f();\n"), "g(1);\n");
+    }
+
+    #[test]
+    fn parenthesizes_the_replacement_in_its_parent() {
+        assert_eq!(rewrite("f($a)", "$a + 1", "x * f(y);"), "x * (y + 1);");
+        assert_eq!(
+            rewrite("f($a)", "$a + 1", "f(y);\ng(f(y));"),
+            "y + 1;\ng(y + 1);"
+        );
+        assert_eq!(rewrite("f($a)", "$a ?? 1", "x || f(y);"), "x || (y ?? 1);");
+    }
+
+    #[test]
+    fn parenthesizes_the_captured_code_in_the_template() {
+        assert_eq!(rewrite("h($a)", "$a * 2", "h(a + b);"), "(a + b) * 2;");
+        assert_eq!(rewrite("h($a)", "2 - $a", "h(a - b);"), "2 - (a - b);");
+        assert_eq!(rewrite("h($a)", "$a - 2", "h(a - b);"), "a - b - 2;");
+        assert_eq!(
+            rewrite("h($a)", "g($a)", "h(a, b);\nh((a, b));"),
+            "h(a, b);\ng((a, b));"
+        );
+        assert_eq!(rewrite("h($a)", "$a.x", "h(a ? b : c);"), "(a ? b : c).x;");
+    }
+
+    #[test]
+    fn rewrites_the_outer_match_only() {
+        assert_eq!(rewrite("f($a)", "g($a)", "f(f(1));"), "g(f(1));");
+    }
+
+    #[test]
+    fn rejects_the_metavariables_that_the_pattern_doesnt_capture() {
+        let pattern = parse_js_pattern("f($a, $_)").unwrap();
+        assert!(parse_js_template("g($a)", &pattern).is_ok());
+        assert!(parse_js_template("g($b)", &pattern).is_err());
+        assert!(parse_js_template("g($_)", &pattern).is_err());
+    }
+
+    fn find_css_matches(pattern: &str, code: &str) -> Vec<String> {
+        let pattern = parse_css_pattern(pattern).unwrap();
+        let root = parse_css(code, CssParserOptions::default()).syntax();
+        pattern
+            .find_matches(&root)
+            .iter()
+            .map(|pattern_match| pattern_match.node().text_trimmed().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn matches_the_css_declarations() {
+        assert_eq!(
+            find_css_matches(
+                "color: $value",
+                "a { color: red; margin: 0; }\nb { color : blue }"
+            ),
+            ["color: red", "color : blue"]
+        );
+        assert_eq!(
+            find_css_matches(
+                "margin: 0 $$$rest",
+                "a { margin: 0; } b { margin: 0 auto 1px; } c { margin: 1px 0; }"
+            ),
+            ["margin: 0", "margin: 0 auto 1px"]
+        );
+    }
+
+    #[test]
+    fn matches_the_css_rules_and_selectors() {
+        assert_eq!(
+            find_css_matches(
+                "$selector { display: none; }",
+                "a { display: none; } b { display: none; color: red; }"
+            ),
+            ["a { display: none; }"]
+        );
+        assert_eq!(
+            find_css_matches(".$class:hover", ".a:hover, .b:focus {} c:hover {}"),
+            [".a:hover"]
+        );
+    }
+
+    #[test]
+    fn rewrites_the_css_matches() {
+        let pattern = parse_css_pattern("margin: $$$values").unwrap();
+        let template = parse_css_template("margin-block: $$$values", &pattern).unwrap();
+        let root = parse_css(
+            "a {\n  margin: 0 auto;\n  color: red;\n}\n",
+            CssParserOptions::default(),
+        )
+        .syntax();
+        let mut mutation = BatchMutation::new(root.clone());
+        for pattern_match in pattern.find_matches(&root) {
+            replace_css_match(&mut mutation, &pattern_match, &template).unwrap();
+        }
+        assert_eq!(
+            mutation.commit().to_string(),
+            "a {\n  margin-block: 0 auto;\n  color: red;\n}\n"
+        );
+    }
+
+    #[test]
+    fn rejects_the_invalid_css_patterns() {
+        assert!(parse_css_pattern("color: red; margin: 0").is_err());
+        assert!(parse_css_pattern("a {} b {}").is_err());
+        assert!(parse_css_pattern("$value").is_err());
+        let pattern = parse_css_pattern("color: $value").unwrap();
+        assert!(parse_css_template("color: $other", &pattern).is_err());
+    }
+
+    #[test]
+    fn parses_the_metavariables() {
+        assert_eq!(
+            Metavariable::parse("$a"),
+            Some(Metavariable::Single(Some("a".to_string())))
+        );
+        assert_eq!(Metavariable::parse("$_"), Some(Metavariable::Single(None)));
+        assert_eq!(
+            Metavariable::parse("$$$args"),
+            Some(Metavariable::Variadic(Some("args".to_string())))
+        );
+        assert_eq!(
+            Metavariable::parse("$$$"),
+            Some(Metavariable::Variadic(None))
+        );
+        assert_eq!(Metavariable::parse("$"), None);
+        assert_eq!(Metavariable::parse("$a.b"), None);
+        assert_eq!(Metavariable::parse("a"), None);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Capture, Metavariable, Pattern, PatternDiagnostic, PatternMatch};
+use biome_rowan::{Language, SyntaxElement, SyntaxKind, SyntaxNode, TextRange, TextSize};
+
+/// The code that replaces the matches of a [Pattern]
+#[derive(Debug, Clone)]
+pub struct Template {
+    /// The code of the template, without its leading and trailing trivia
+    text: String,
+    /// The metavariables of the template, in the order of the code
+    holes: Vec<Hole>,
+}
+
+/// A metavariable of a template, replaced by the code it captured
+#[derive(Debug, Clone)]
+struct Hole {
+    name: String,
+    /// The range of the metavariable in the code of the template
+    range: TextRange,
+    /// The range removed when the metavariable captured an empty list, which
+    /// includes a separator of the list, e.g. `$$$args, ` in `f($$$args, a)`
+    empty_range: TextRange,
+}
+
+impl Template {
+    /// Creates a template from the syntax tree of its code, where the
+    /// metavariables are read from `code` like in [Pattern::new]. It fails when
+    /// it contains a metavariable that isn't captured by `pattern`.
+    pub fn new<L: Language>(
+        node: &SyntaxNode<L>,
+        code: &str,
+        pattern: &Pattern<L>,
+    ) -> Result<Self, PatternDiagnostic> {
+        let offset = node.text_trimmed_range().start();
+        let mut holes: Vec<Hole> = Vec::new();
+
+        for descendant in node.descendants() {
+            let range = descendant.text_trimmed_range();
+            let is_in_hole = holes
+                .last()
+                .is_some_and(|hole| hole.range.contains_range(range - offset));
+            // A list that only contains a metavariable isn't replaced, its element is
+            if is_in_hole || descendant.kind().is_list() {
+                continue;
+            }
+            let Some(metavariable) = Metavariable::of_node(&descendant, code) else {
+                continue;
+            };
+
+            let name = match metavariable.name() {
+                Some(name) if pattern.has_metavariable(name) => name.to_string(),
+                Some(name) => return Err(PatternDiagnostic::unknown_metavariable(name, range)),
+                None => return Err(PatternDiagnostic::anonymous_metavariable(range)),
+            };
+            holes.push(Hole {
+                name,
+                range: range - offset,
+                empty_range: empty_range(&descendant) - offset,
+            });
+        }
+
+        Ok(Self {
+            text: code[node.text_trimmed_range()].to_string(),
+            holes,
+        })
+    }
+
+    /// The code of the template, without its leading and trailing trivia
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the code of the template, where the metavariables are replaced
+    /// by the code captured in `pattern_match`.
+    ///
+    /// A capture is wrapped in parentheses when `needs_parentheses` returns
+    /// `true` for the range of its metavariable in the code of the template.
+    pub fn instantiate<L: Language>(
+        &self,
+        pattern_match: &PatternMatch<L>,
+        needs_parentheses: impl Fn(TextRange, &Capture<L>) -> bool,
+    ) -> String {
+        let mut code = String::with_capacity(self.text.len());
+        let mut position = TextSize::from(0);
+
+        for hole in &self.holes {
+            let capture = pattern_match.capture(&hole.name);
+            let is_empty = capture.map_or(true, |capture| capture.range().is_none());
+            let range = if is_empty {
+                hole.empty_range
+            } else {
+                hole.range
+            };
+
+            // The separator of an empty list can be removed by the previous hole
+            let start = range.start().max(position);
+            code.push_str(&self.text[TextRange::new(position, start)]);
+            if let Some(capture) = capture {
+                if needs_parentheses(hole.range, capture) {
+                    code.push('(');
+                    code.push_str(&capture.text());
+                    code.push(')');
+                } else {
+                    code.push_str(&capture.text());
+                }
+            }
+            position = range.end();
+        }
+        code.push_str(&self.text[TextRange::new(position, self.text_len())]);
+
+        code
+    }
+
+    fn text_len(&self) -> TextSize {
+        TextSize::of(self.text.as_str())
+    }
+}
+
+/// Returns the range of the metavariable `node` with the separator that
+/// follows it in its list, or the one that precedes it for the last element
+fn empty_range<L: Language>(node: &SyntaxNode<L>) -> TextRange {
+    let range = node.text_trimmed_range();
+    let is_list_element = node.parent().is_some_and(|parent| parent.kind().is_list());
+    if !is_list_element {
+        return range;
+    }
+
+    match (node.next_sibling_or_token(), node.prev_sibling_or_token()) {
+        (Some(SyntaxElement::Token(separator)), _) => {
+            TextRange::new(range.start(), separator.text_range().end())
+        }
+        (_, Some(SyntaxElement::Token(separator))) => {
+            TextRange::new(separator.text_trimmed_range().start(), range.end())
+        }
+        _ => range,
+    }
+}
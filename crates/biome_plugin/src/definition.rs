@@ -0,0 +1,356 @@
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+use biome_diagnostics::Severity;
+use biome_rowan::TextRange;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The lint rules defined by a plugin file
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Plugin {
+    /// The path of the plugin file
+    #[serde(default)]
+    pub path: PathBuf,
+    /// The rules defined by the plugin
+    pub rules: Vec<PluginRule>,
+    /// The WebAssembly module of the plugin, when it's a `.wasm` file. The
+    /// module declares its own rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<Vec<u8>>,
+}
+
+/// A lint rule that reports the code matching a pattern
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginRule {
+    /// The name of the rule, e.g. `noConsoleLog`
+    pub name: String,
+    /// The language of the pattern and of the files checked by the rule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<PluginRuleLanguage>,
+    /// The pattern of the code reported by the rule, e.g. `console.log($$$args)`
+    pub pattern: String,
+    /// The message of the diagnostic
+    pub message: String,
+    /// The severity of the diagnostics, when the configuration doesn't set it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<PluginRuleLevel>,
+    /// The conditions on the code captured by the metavariables of the pattern
+    #[serde(default, rename = "where", skip_serializing_if = "BTreeMap::is_empty")]
+    pub conditions: BTreeMap<String, MetavariableCondition>,
+    /// The code is only reported inside the code matching this pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inside: Option<String>,
+    /// The code isn't reported inside the code matching this pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_inside: Option<String>,
+    /// The code that replaces the reported code, where the metavariables are
+    /// replaced by the code they captured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl PluginRule {
+    /// The severity of the diagnostics, given the level of the rule
+    pub fn severity(&self) -> Severity {
+        self.level.unwrap_or_default().into()
+    }
+
+    /// The language of the rule, JavaScript when it isn't set
+    pub fn language(&self) -> PluginRuleLanguage {
+        self.language.unwrap_or_default()
+    }
+}
+
+/// The language of a plugin rule. A JavaScript rule also checks the
+/// TypeScript and JSX files.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PluginRuleLanguage {
+    #[default]
+    Js,
+    Css,
+}
+
+/// The severity of the diagnostics of a plugin rule
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PluginRuleLevel {
+    Warn,
+    #[default]
+    Error,
+}
+
+impl From<PluginRuleLevel> for Severity {
+    fn from(level: PluginRuleLevel) -> Self {
+        match level {
+            PluginRuleLevel::Warn => Self::Warning,
+            PluginRuleLevel::Error => Self::Error,
+        }
+    }
+}
+
+/// Regular expressions that the code captured by a metavariable must match
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MetavariableCondition {
+    /// The captured code must match this regular expression
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matches: Option<String>,
+    /// The captured code must not match this regular expression
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_matches: Option<String>,
+}
+
+impl Deserializable for Plugin {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PluginVisitor, name, diagnostics)
+    }
+}
+
+struct PluginVisitor;
+impl DeserializationVisitor for PluginVisitor {
+    type Output = Plugin;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["$schema", "rules"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "$schema" => {}
+                "rules" => {
+                    result.rules = Deserializable::deserialize(&value, &key_text, diagnostics)
+                        .unwrap_or_default();
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for PluginRule {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PluginRuleVisitor, name, diagnostics)
+    }
+}
+
+struct PluginRuleVisitor;
+impl DeserializationVisitor for PluginRuleVisitor {
+    type Output = PluginRule;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &[
+            "name",
+            "language",
+            "pattern",
+            "message",
+            "level",
+            "where",
+            "inside",
+            "notInside",
+            "fix",
+        ];
+        let mut name = None;
+        let mut pattern = None;
+        let mut message = None;
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "name" => {
+                    name = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "language" => {
+                    result.language = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "pattern" => {
+                    pattern = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "message" => {
+                    message = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "level" => {
+                    result.level = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "where" => {
+                    result.conditions = Deserializable::deserialize(&value, &key_text, diagnostics)
+                        .unwrap_or_default();
+                }
+                "inside" => {
+                    result.inside = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "notInside" => {
+                    result.not_inside = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "fix" => {
+                    result.fix = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+
+        let mut required = |key: &str, value: Option<String>| {
+            if value.is_none() {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(
+                        biome_console::markup! { "The rule requires the key "<Emphasis>{key}</Emphasis>"." },
+                    )
+                    .with_range(range),
+                );
+            }
+            value
+        };
+        let name = required("name", name);
+        let pattern = required("pattern", pattern);
+        let message = required("message", message);
+        Some(PluginRule {
+            name: name?,
+            pattern: pattern?,
+            message: message?,
+            ..result
+        })
+    }
+}
+
+impl Deserializable for PluginRuleLevel {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["warn", "error"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        match value_text.text() {
+            "warn" => Some(Self::Warn),
+            "error" => Some(Self::Error),
+            unknown_variant => {
+                diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                    unknown_variant,
+                    value.range(),
+                    ALLOWED_VARIANTS,
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Deserializable for PluginRuleLanguage {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["js", "css"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        match value_text.text() {
+            "js" => Some(Self::Js),
+            "css" => Some(Self::Css),
+            unknown_variant => {
+                diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                    unknown_variant,
+                    value.range(),
+                    ALLOWED_VARIANTS,
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Deserializable for MetavariableCondition {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(MetavariableConditionVisitor, name, diagnostics)
+    }
+}
+
+struct MetavariableConditionVisitor;
+impl DeserializationVisitor for MetavariableConditionVisitor {
+    type Output = MetavariableCondition;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["matches", "notMatches"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "matches" => {
+                    result.matches = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "notMatches" => {
+                    result.not_matches =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,120 @@
+use biome_console::markup;
+use biome_diagnostics::{Diagnostic, Error, MessageAndDescription, PrintDescription};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Diagnostic emitted when a plugin can't be loaded, or one of its rules isn't valid
+#[derive(Debug, Diagnostic, Deserialize, Serialize, Clone)]
+#[diagnostic(category = "plugin", severity = Error)]
+pub struct PluginDiagnostic {
+    /// The path of the plugin file
+    #[location(resource)]
+    path: String,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl PluginDiagnostic {
+    /// The plugin file can't be read
+    pub fn cant_read(path: &Path, error: impl std::fmt::Display) -> Self {
+        let error = error.to_string();
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "Biome couldn't read the plugin: "{error} }.to_owned(),
+            ),
+        }
+    }
+
+    /// The plugin file isn't valid, as reported by `diagnostic`
+    pub(crate) fn invalid_file(path: &Path, diagnostic: &Error) -> Self {
+        let description = PrintDescription(diagnostic).to_string();
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The plugin isn't valid: "{description} }.to_owned(),
+            ),
+        }
+    }
+
+    /// The rule `name` isn't valid, as reported by `diagnostic`
+    pub(crate) fn invalid_rule(path: &Path, name: &str, diagnostic: &impl Diagnostic) -> Self {
+        let description = PrintDescription(diagnostic).to_string();
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule "<Emphasis>{name}</Emphasis>" isn't valid: "{description} }
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// The rule `name` has a regular expression that isn't valid
+    pub(crate) fn invalid_regex(path: &Path, name: &str, error: &regex::Error) -> Self {
+        let error = error.to_string();
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule "<Emphasis>{name}</Emphasis>" has an invalid regular expression: "{error} }
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// The rule `name` has a condition on a metavariable that the pattern doesn't capture
+    pub(crate) fn unknown_metavariable(path: &Path, name: &str, metavariable: &str) -> Self {
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule "<Emphasis>{name}</Emphasis>" has a condition on the metavariable "<Emphasis>{metavariable}</Emphasis>", which isn't captured by its pattern." }
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// The name of the rule can't be used in a suppression comment
+    pub(crate) fn invalid_name(path: &Path, name: &str) -> Self {
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule name "<Emphasis>{name}</Emphasis>" isn't valid, it must start with a letter and only contain letters and digits, e.g. "<Emphasis>"noConsoleLog"</Emphasis>"." }
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// The WebAssembly module of the plugin can't be compiled, or doesn't
+    /// declare its rules as expected
+    pub(crate) fn invalid_module(path: &Path, error: &impl std::fmt::Display) -> Self {
+        let error = error.to_string();
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The WebAssembly plugin isn't valid: "{error} }.to_owned(),
+            ),
+        }
+    }
+
+    /// The rule `name` queries a kind of node that doesn't exist
+    pub(crate) fn unknown_node_kind(path: &Path, name: &str, kind: &str) -> Self {
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule "<Emphasis>{name}</Emphasis>" queries the node kind "<Emphasis>{kind}</Emphasis>", which doesn't exist." }
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// Two rules have the same name
+    pub(crate) fn duplicate_rule(path: &Path, name: &str) -> Self {
+        Self {
+            path: path.display().to_string(),
+            message: MessageAndDescription::from(
+                markup! { "The rule "<Emphasis>{name}</Emphasis>" is defined by several plugins, or several times by the same plugin." }
+                    .to_owned(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+//! Lint rules defined by plugins.
+//!
+//! A plugin is a JSON file that defines lint rules with the patterns of
+//! [biome_pattern]. Each rule reports the code matching its pattern:
+//!
+//! ```json
+//! {
+//!     "rules": [
+//!         {
+//!             "name": "noConsoleLog",
+//!             "pattern": "console.$method($$$args)",
+//!             "where": { "$method": { "matches": "^(log|debug)$" } },
+//!             "notInside": "if (DEBUG) { $$$ }",
+//!             "message": "Use the logger instead of the console.",
+//!             "level": "warn",
+//!             "fix": "logger.$method($$$args)"
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! - `where` holds regular expressions that the code captured by a metavariable
+//!   `matches`, or `notMatches`;
+//! - the code is only reported `inside` the code matching a pattern, or when it
+//!   isn't inside it, with `notInside`;
+//! - `fix` is the template that replaces the reported code. It's an unsafe fix,
+//!   since the plugin can't guarantee that it keeps the semantics of the code.
+//!
+//! The rules run on JavaScript, TypeScript and JSX files. A rule with
+//! `"language": "css"` runs on CSS files instead, and its patterns and its fix
+//! are CSS code: a rule, a declaration, e.g. `color: $value`, or a selector.
+//! The rules belong to the `plugin` group: `// biome-ignore lint/plugin/noConsoleLog`
+//! suppresses a rule.
+//!
+//! ## WebAssembly plugins
+//!
+//! A plugin can also be a `.wasm` file, compiled from any language, for the
+//! rules that a pattern can't express. The module exports its `memory` and:
+//!
+//! - `biome_rules() -> i64` returns the JSON declaration of its rules, e.g.
+//!   `{ "rules": [{ "name": "noFoo", "query": ["JS_CALL_EXPRESSION"], "level": "warn" }] }`,
+//!   where `query` lists the kinds of the nodes a rule runs on;
+//! - `biome_evaluate(rule: i32, node: i32)` runs the rule at the index `rule` of
+//!   the declaration on the syntax element `node`.
+//!
+//! A function returning a string returns its pointer in the high 32 bits and
+//! its length in the low 32 bits. The module can import these functions from
+//! the `biome_v1` module, which takes and returns the syntax elements as
+//! indexes, `-1` standing for no element:
+//!
+//! | Function | Description |
+//! |---|---|
+//! | `node_kind(element, ptr, len) -> i32` | The kind of the element, e.g. `JS_CALL_EXPRESSION` |
+//! | `node_text(element, ptr, len) -> i32` | The text of the element, without its trivia |
+//! | `node_range(element) -> i64` | The start offset of the element in the high 32 bits, and its end offset in the low 32 bits |
+//! | `node_is_token(element) -> i32` | `1` when the element is a token, `0` when it's a node |
+//! | `node_parent(element) -> i32` | The parent node of the element |
+//! | `node_child_count(element) -> i32` | The number of children, nodes and tokens, of the element |
+//! | `node_child(element, index) -> i32` | The child of the element at `index` |
+//! | `node_binding(element) -> i32` | The binding declaring the reference `element` |
+//! | `node_reference_count(element) -> i32` | The number of references to the binding `element`, or `-1` |
+//! | `node_reference(element, index) -> i32` | The reference to the binding `element` at `index` |
+//! | `rule_options(ptr, len) -> i32` | The options of the rule set in the configuration, as JSON |
+//! | `report(ptr, len)` | Reports the JSON diagnostic at `ptr` |
+//!
+//! The functions returning a text write it at `ptr` when it fits in `len`
+//! bytes, and return its length. A diagnostic reports a node and can replace
+//! the text of some nodes:
+//! `{ "node": 0, "message": "Don't call foo.", "fix": { "message": "Call bar.", "edits": [{ "node": 3, "text": "bar" }] } }`.
+//!
+//! Each run of a rule has a limited fuel and memory, and a rule that exceeds
+//! them, or traps, reports a diagnostic instead of stopping the analysis. The
+//! functions of `biome_v1` consume fuel too, in proportion to the text they
+//! copy and to the elements they collect.
+
+mod definition;
+mod diagnostics;
+mod rule;
+mod wasm;
+
+pub use definition::{
+    MetavariableCondition, Plugin, PluginRule, PluginRuleLanguage, PluginRuleLevel,
+};
+pub use diagnostics::PluginDiagnostic;
+pub use rule::{CssPluginRule, JsPluginRule, PatternLanguage, PatternRule};
+pub use wasm::WasmPluginRule;
+
+use biome_analyze::AnalyzerPlugin;
+use biome_css_syntax::CssLanguage;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::Severity;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_syntax::JsLanguage;
+use biome_json_parser::JsonParserOptions;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::Arc;
+use wasm::WasmPlugin;
+
+/// Reads the plugin file at `path`
+pub fn load_plugin(fs: &dyn FileSystem, path: &Path) -> Result<Plugin, PluginDiagnostic> {
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "wasm")
+    {
+        let mut module = Vec::new();
+        fs.open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_end(&mut module))
+            .map_err(|error| PluginDiagnostic::cant_read(path, error))?;
+        return Ok(Plugin {
+            path: path.to_path_buf(),
+            rules: Vec::new(),
+            module: Some(module),
+        });
+    }
+
+    let mut content = String::new();
+    fs.open_with_options(path, OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map_err(|error| PluginDiagnostic::cant_read(path, error))?;
+
+    let options = JsonParserOptions::default().with_allow_comments();
+    let deserialized = deserialize_from_json_str::<Plugin>(&content, options);
+    if let Some(diagnostic) = deserialized
+        .diagnostics()
+        .iter()
+        .find(|diagnostic| diagnostic.severity() >= Severity::Error)
+    {
+        return Err(PluginDiagnostic::invalid_file(path, diagnostic));
+    }
+
+    let mut plugin = deserialized.into_deserialized().unwrap_or_default();
+    plugin.path = path.to_path_buf();
+    Ok(plugin)
+}
+
+/// The settings of a plugin rule, set by the configuration
+#[derive(Debug, Clone)]
+pub struct PluginRuleSettings {
+    /// The severity of the diagnostics of the rule
+    pub severity: Severity,
+    /// The options of the rule, as JSON. Only the rules of WebAssembly plugins
+    /// read them.
+    pub options: Option<String>,
+}
+
+impl From<Severity> for PluginRuleSettings {
+    fn from(severity: Severity) -> Self {
+        Self {
+            severity,
+            options: None,
+        }
+    }
+}
+
+/// Compiles the rules of `plugins` that run on JavaScript code. `settings`
+/// takes the name of a rule and the default severity of its diagnostics, and
+/// returns its settings, or [None] when the rule is disabled.
+///
+/// It fails when a name is used by several rules, whatever their language.
+pub fn compile_js_plugins(
+    plugins: &[Plugin],
+    settings: impl Fn(&str, Severity) -> Option<PluginRuleSettings>,
+) -> Result<Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>>, PluginDiagnostic> {
+    let mut names = BTreeSet::new();
+    let mut rules: Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>> = Vec::new();
+    for plugin in plugins {
+        for rule in &plugin.rules {
+            if !names.insert(rule.name.clone()) {
+                return Err(PluginDiagnostic::duplicate_rule(&plugin.path, &rule.name));
+            }
+            if rule.language() != PluginRuleLanguage::Js {
+                continue;
+            }
+            let Some(settings) = settings(&rule.name, rule.severity()) else {
+                continue;
+            };
+            rules.push(Arc::new(JsPluginRule::new(
+                &plugin.path,
+                rule,
+                settings.severity,
+            )?));
+        }
+
+        let Some(module) = &plugin.module else {
+            continue;
+        };
+        let module = WasmPlugin::compile(&plugin.path, module)?;
+        for (index, (name, severity)) in module.rules().enumerate() {
+            if !names.insert(name.to_string()) {
+                return Err(PluginDiagnostic::duplicate_rule(&plugin.path, name));
+            }
+            let Some(settings) = settings(name, severity) else {
+                continue;
+            };
+            rules.push(Arc::new(module.rule(
+                index,
+                settings.severity,
+                settings.options.as_deref(),
+            )));
+        }
+    }
+    Ok(rules)
+}
+
+/// Compiles the rules of `plugins` that run on CSS code, with the `settings`
+/// of [compile_js_plugins], which reports the rules that have the same name
+pub fn compile_css_plugins(
+    plugins: &[Plugin],
+    settings: impl Fn(&str, Severity) -> Option<PluginRuleSettings>,
+) -> Result<Vec<Arc<dyn AnalyzerPlugin<CssLanguage>>>, PluginDiagnostic> {
+    let mut rules: Vec<Arc<dyn AnalyzerPlugin<CssLanguage>>> = Vec::new();
+    for plugin in plugins {
+        for rule in &plugin.rules {
+            if rule.language() != PluginRuleLanguage::Css {
+                continue;
+            }
+            let Some(settings) = settings(&rule.name, rule.severity()) else {
+                continue;
+            };
+            rules.push(Arc::new(CssPluginRule::new(
+                &plugin.path,
+                rule,
+                settings.severity,
+            )?));
+        }
+    }
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_analyze::{AnalysisFilter, ControlFlow, Never, RuleFilter, PLUGIN_GROUP};
+    use biome_css_parser::{parse_css, CssParserOptions};
+    use biome_diagnostics::Diagnostic;
+    use biome_fs::MemoryFileSystem;
+    use biome_js_analyze::analyze_with_plugins;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    fn plugin(rule: &str) -> Plugin {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "plugin.json".into(),
+            format!(r#"{{ "rules": [{rule}] }}"#).as_bytes(),
+        );
+        load_plugin(&fs, Path::new("plugin.json")).unwrap()
+    }
+
+    fn compile_error(rule: &str) -> String {
+        let plugins = [plugin(rule)];
+        let error = compile_js_plugins(&plugins, |_, severity| Some(severity.into())).unwrap_err();
+        biome_diagnostics::PrintDescription(&error).to_string()
+    }
+
+    /// Returns the code reported by the rule, with the severity of the diagnostics
+    fn lint(rule: &str, code: &str) -> Vec<(String, Severity)> {
+        let plugins =
+            compile_js_plugins(&[plugin(rule)], |_, severity| Some(severity.into())).unwrap();
+        let parse = parse(code, JsFileSource::tsx(), JsParserOptions::default());
+        let mut reported = Vec::new();
+        analyze_with_plugins(
+            &parse.tree(),
+            AnalysisFilter::from_enabled_rules(Some(&[RuleFilter::Group(PLUGIN_GROUP)])),
+            &Default::default(),
+            &plugins,
+            &Default::default(),
+            JsFileSource::tsx(),
+            |signal| {
+                if let Some(diagnostic) = signal.diagnostic() {
+                    let range = diagnostic.get_span().unwrap();
+                    reported.push((code[range].to_string(), diagnostic.severity()));
+                }
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        reported
+    }
+
+    /// Returns the code once the fixes of the rule are applied, one by one
+    fn fix(rule: &str, code: &str, source_type: JsFileSource) -> Vec<String> {
+        let plugins =
+            compile_js_plugins(&[plugin(rule)], |_, severity| Some(severity.into())).unwrap();
+        let parse = parse(code, source_type, JsParserOptions::default());
+        let mut fixed = Vec::new();
+        analyze_with_plugins(
+            &parse.tree(),
+            AnalysisFilter::from_enabled_rules(Some(&[RuleFilter::Group(PLUGIN_GROUP)])),
+            &Default::default(),
+            &plugins,
+            &Default::default(),
+            source_type,
+            |signal| {
+                for action in signal.actions() {
+                    if !action.is_suppression() {
+                        fixed.push(action.mutation.commit().to_string());
+                    }
+                }
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        fixed
+    }
+
+    /// Returns the code reported by the CSS rule, with the code once its action,
+    /// the fix or the suppression, is applied
+    fn lint_css(rule: &str, code: &str, suppression: bool) -> Vec<(String, String)> {
+        let plugins =
+            compile_css_plugins(&[plugin(rule)], |_, severity| Some(severity.into())).unwrap();
+        let parse = parse_css(code, CssParserOptions::default());
+        let mut reported = Vec::new();
+        biome_css_analyze::analyze_with_plugins(
+            &parse.tree(),
+            AnalysisFilter::from_enabled_rules(Some(&[RuleFilter::Group(PLUGIN_GROUP)])),
+            &Default::default(),
+            &plugins,
+            |signal| {
+                if let Some(diagnostic) = signal.diagnostic() {
+                    let range = diagnostic.get_span().unwrap();
+                    let fixed = signal
+                        .actions()
+                        .find(|action| action.is_suppression() == suppression)
+                        .map(|action| action.mutation.commit().to_string())
+                        .unwrap_or_default();
+                    reported.push((code[range].to_string(), fixed));
+                }
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        reported
+    }
+
+    const NO_RED: &str = r#"{
+        "name": "noRed",
+        "language": "css",
+        "pattern": "color: $color",
+        "where": { "$color": { "matches": "^red$" } },
+        "message": "Use a variable.",
+        "fix": "color: var(--$color)"
+    }"#;
+
+    #[test]
+    fn reports_the_css_code_matching_the_pattern() {
+        let code = "a {\n  color: red;\n  /* biome-ignore lint/plugin/noRed: test */\n  color: red;\n}\nb { color: blue; }\n";
+        assert_eq!(
+            lint_css(NO_RED, code, false),
+            [(
+                "color: red".to_string(),
+                "a {\n  color: var(--red);\n  /* biome-ignore lint/plugin/noRed: test */\n  color: red;\n}\nb { color: blue; }\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn suppresses_the_css_code_on_the_previous_line() {
+        assert_eq!(
+            lint_css(NO_RED, "a {\n  margin: 0;\n  color: red;\n}\n", true),
+            [(
+                "color: red".to_string(),
+                "a {\n  margin: 0;\n  /* biome-ignore lint/plugin/noRed: <explanation> */\n  color: red;\n}\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn compiles_the_rules_of_each_language() {
+        let plugins = [plugin(NO_CONSOLE), plugin(NO_RED)];
+        let settings = |_: &str, severity: Severity| Some(severity.into());
+        let js_rules = compile_js_plugins(&plugins, settings).unwrap();
+        let css_rules = compile_css_plugins(&plugins, settings).unwrap();
+        assert_eq!(js_rules.len(), 1);
+        assert_eq!(js_rules[0].name(), "noConsole");
+        assert_eq!(css_rules.len(), 1);
+        assert_eq!(css_rules[0].name(), "noRed");
+    }
+
+    const NO_CONSOLE: &str = r#"{
+        "name": "noConsole",
+        "pattern": "console.$method($$$args)",
+        "message": "Use the logger.",
+        "where": { "$method": { "matches": "^(log|debug)$" } },
+        "notInside": "if (DEBUG) { $$$ }"
+    }"#;
+
+    #[test]
+    fn reports_the_code_matching_the_pattern() {
+        assert_eq!(
+            lint(
+                NO_CONSOLE,
+                "console.log(a); console.error(b); if (DEBUG) { console.debug(c); } console.debug();"
+            ),
+            [
+                ("console.log(a)".to_string(), Severity::Error),
+                ("console.debug()".to_string(), Severity::Error)
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_the_code_inside_a_pattern() {
+        let rule = r#"{
+            "name": "noAwaitInLoop",
+            "pattern": "await $value",
+            "message": "Don't await in a loop.",
+            "level": "warn",
+            "inside": "for (const $item of $items) { $$$ }"
+        }"#;
+        assert_eq!(
+            lint(
+                rule,
+                "async function f() { await a; for (const x of y) { await g(x); } }"
+            ),
+            [("await g(x)".to_string(), Severity::Warning)]
+        );
+    }
+
+    #[test]
+    fn fixes_the_code_with_the_source_type_of_the_file() {
+        let rule = r#"{
+            "name": "noDouble",
+            "pattern": "double($value)",
+            "message": "Multiply the value.",
+            "fix": "$value * 2"
+        }"#;
+        assert_eq!(
+            fix(rule, "x * double(y);", JsFileSource::js_module()),
+            ["x * (y * 2);"]
+        );
+
+        // The fix isn't valid JavaScript
+        let rule = r#"{
+            "name": "noAny",
+            "pattern": "toAny($value)",
+            "message": "Use a type assertion.",
+            "fix": "$value as any"
+        }"#;
+        assert_eq!(
+            fix(rule, "f(toAny(y));", JsFileSource::ts()),
+            ["f(y as any);"]
+        );
+        assert!(fix(rule, "f(toAny(y));", JsFileSource::js_module()).is_empty());
+    }
+
+    #[test]
+    fn doesnt_report_the_suppressed_code() {
+        assert_eq!(
+            lint(
+                NO_CONSOLE,
+                "// biome-ignore lint/plugin/noConsole: test\nconsole.log(a);\n// biome-ignore lint/plugin: test\nconsole.log(b);\nconsole.log(c);"
+            ),
+            [("console.log(c)".to_string(), Severity::Error)]
+        );
+    }
+
+    #[test]
+    fn doesnt_compile_the_disabled_rules() {
+        let plugins = compile_js_plugins(&[plugin(NO_CONSOLE)], |_, _| None).unwrap();
+        assert!(plugins.is_empty());
+    }
+
+    #[test]
+    fn reports_the_invalid_rules() {
+        assert_eq!(
+            compile_error(r#"{ "name": "no-foo", "pattern": "foo()", "message": "" }"#),
+            "The rule name no-foo isn't valid, it must start with a letter and only contain letters and digits, e.g. noConsoleLog."
+        );
+        assert_eq!(
+            compile_error(r#"{ "name": "noFoo", "pattern": "foo(", "message": "" }"#),
+            "The rule noFoo isn't valid: The pattern isn't valid: expected `)` but instead the file ends"
+        );
+        assert_eq!(
+            compile_error(
+                r#"{ "name": "noFoo", "pattern": "foo($a)", "message": "", "where": { "$b": { "matches": "x" } } }"#
+            ),
+            "The rule noFoo has a condition on the metavariable b, which isn't captured by its pattern."
+        );
+        assert_eq!(
+            compile_error(
+                r#"{ "name": "noFoo", "pattern": "foo($a)", "message": "", "where": { "$a": { "matches": "(" } } }"#
+            )
+            .lines()
+            .next(),
+            Some("The rule noFoo has an invalid regular expression: regex parse error:")
+        );
+    }
+
+    #[test]
+    fn reports_the_duplicate_rules() {
+        let plugins = [plugin(NO_CONSOLE), plugin(NO_CONSOLE)];
+        let error = compile_js_plugins(&plugins, |_, severity| Some(severity.into())).unwrap_err();
+        assert_eq!(
+            biome_diagnostics::PrintDescription(&error).to_string(),
+            "The rule noConsole is defined by several plugins, or several times by the same plugin."
+        );
+    }
+
+    #[test]
+    fn reports_the_invalid_files() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "plugin.json".into(),
+            r#"{ "rules": [{ "name": "noFoo", "message": "" }] }"#.as_bytes(),
+        );
+        let error = load_plugin(&fs, Path::new("plugin.json")).unwrap_err();
+        assert_eq!(
+            biome_diagnostics::PrintDescription(&error).to_string(),
+            "The plugin isn't valid: The rule requires the key pattern."
+        );
+
+        let error = load_plugin(&fs, Path::new("missing.json")).unwrap_err();
+        assert!(biome_diagnostics::PrintDescription(&error)
+            .to_string()
+            .starts_with("Biome couldn't read the plugin"));
+    }
+}
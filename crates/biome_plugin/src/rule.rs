@@ -0,0 +1,282 @@
+use crate::{PluginDiagnostic, PluginRule};
+use biome_analyze::{ActionCategory, AnalyzerPlugin, PluginSignal, RuleAction, ServiceBag};
+use biome_console::markup;
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::{Applicability, Severity};
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_pattern::{
+    parse_css_pattern, parse_css_template, parse_js_pattern, parse_js_template, replace_css_match,
+    replace_js_match, Pattern, PatternDiagnostic, PatternMatch, Template,
+};
+use biome_rowan::{BatchMutation, Language, SyntaxNode};
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// A language whose code the patterns of the plugin rules match
+pub trait PatternLanguage: Language + 'static {
+    /// Parses a pattern of a rule
+    fn parse_pattern(pattern: &str) -> Result<Pattern<Self>, PatternDiagnostic>;
+
+    /// Parses the fix of a rule, which replaces the matches of `pattern`
+    fn parse_template(
+        template: &str,
+        pattern: &Pattern<Self>,
+    ) -> Result<Template, PatternDiagnostic>;
+
+    /// Adds to `mutation` the replacement of the code matched by
+    /// `pattern_match` with the code of `template`. `services` are the
+    /// services of the analyzed file.
+    fn replace_match(
+        mutation: &mut BatchMutation<Self>,
+        pattern_match: &PatternMatch<Self>,
+        template: &Template,
+        services: &ServiceBag,
+    ) -> Result<(), PatternDiagnostic>;
+}
+
+impl PatternLanguage for JsLanguage {
+    fn parse_pattern(pattern: &str) -> Result<Pattern<Self>, PatternDiagnostic> {
+        parse_js_pattern(pattern)
+    }
+
+    fn parse_template(
+        template: &str,
+        pattern: &Pattern<Self>,
+    ) -> Result<Template, PatternDiagnostic> {
+        parse_js_template(template, pattern)
+    }
+
+    /// The code of the fix is parsed with the source type of the file
+    fn replace_match(
+        mutation: &mut BatchMutation<Self>,
+        pattern_match: &PatternMatch<Self>,
+        template: &Template,
+        services: &ServiceBag,
+    ) -> Result<(), PatternDiagnostic> {
+        replace_js_match(mutation, pattern_match, template, source_type(services))
+    }
+}
+
+impl PatternLanguage for CssLanguage {
+    fn parse_pattern(pattern: &str) -> Result<Pattern<Self>, PatternDiagnostic> {
+        parse_css_pattern(pattern)
+    }
+
+    fn parse_template(
+        template: &str,
+        pattern: &Pattern<Self>,
+    ) -> Result<Template, PatternDiagnostic> {
+        parse_css_template(template, pattern)
+    }
+
+    fn replace_match(
+        mutation: &mut BatchMutation<Self>,
+        pattern_match: &PatternMatch<Self>,
+        template: &Template,
+        _services: &ServiceBag,
+    ) -> Result<(), PatternDiagnostic> {
+        replace_css_match(mutation, pattern_match, template)
+    }
+}
+
+/// A rule of a plugin, compiled to run on the code of the language `L`
+#[derive(Debug)]
+pub struct PatternRule<L: PatternLanguage> {
+    name: &'static str,
+    pattern: Pattern<L>,
+    message: String,
+    severity: Severity,
+    conditions: Vec<Condition>,
+    inside: Option<Pattern<L>>,
+    not_inside: Option<Pattern<L>>,
+    fix: Option<Template>,
+}
+
+/// A rule of a plugin, compiled to run on JavaScript code
+pub type JsPluginRule = PatternRule<JsLanguage>;
+
+/// A rule of a plugin, compiled to run on CSS code
+pub type CssPluginRule = PatternRule<CssLanguage>;
+
+/// A regular expression that the code captured by a metavariable must match,
+/// or must not match when `negated`
+#[derive(Debug)]
+struct Condition {
+    metavariable: String,
+    regex: Regex,
+    negated: bool,
+}
+
+impl<L: PatternLanguage> PatternRule<L> {
+    /// Compiles `rule`, defined by the plugin at `path`, whose diagnostics
+    /// have the given `severity`
+    pub fn new(
+        path: &Path,
+        rule: &PluginRule,
+        severity: Severity,
+    ) -> Result<Self, PluginDiagnostic> {
+        let name = rule.name.as_str();
+        check_rule_name(path, name)?;
+
+        let parse_pattern = |pattern: &str| {
+            L::parse_pattern(pattern)
+                .map_err(|diagnostic| PluginDiagnostic::invalid_rule(path, name, &diagnostic))
+        };
+        let pattern = parse_pattern(&rule.pattern)?;
+        let inside = rule.inside.as_deref().map(parse_pattern).transpose()?;
+        let not_inside = rule.not_inside.as_deref().map(parse_pattern).transpose()?;
+        let fix = rule
+            .fix
+            .as_deref()
+            .map(|fix| {
+                L::parse_template(fix, &pattern)
+                    .map_err(|diagnostic| PluginDiagnostic::invalid_rule(path, name, &diagnostic))
+            })
+            .transpose()?;
+
+        let mut conditions = Vec::new();
+        for (metavariable, condition) in &rule.conditions {
+            // The metavariables can be written with or without their `$`
+            let metavariable = metavariable.trim_start_matches('$');
+            if !pattern.has_metavariable(metavariable) {
+                return Err(PluginDiagnostic::unknown_metavariable(
+                    path,
+                    name,
+                    metavariable,
+                ));
+            }
+            let regexes = [(&condition.matches, false), (&condition.not_matches, true)];
+            for (regex, negated) in regexes {
+                let Some(regex) = regex else {
+                    continue;
+                };
+                conditions.push(Condition {
+                    metavariable: metavariable.to_string(),
+                    regex: Regex::new(regex)
+                        .map_err(|error| PluginDiagnostic::invalid_regex(path, name, &error))?,
+                    negated,
+                });
+            }
+        }
+
+        Ok(Self {
+            name: intern_name(name),
+            pattern,
+            message: rule.message.clone(),
+            severity,
+            conditions,
+            inside,
+            not_inside,
+            fix,
+        })
+    }
+
+    /// Whether the code captured by the metavariables satisfies the conditions of the rule
+    fn satisfies_conditions(&self, pattern_match: &PatternMatch<L>) -> bool {
+        self.conditions.iter().all(|condition| {
+            let text = pattern_match
+                .capture(&condition.metavariable)
+                .map(|capture| capture.text())
+                .unwrap_or_default();
+            condition.regex.is_match(&text) != condition.negated
+        })
+    }
+
+    /// Returns the action that replaces the code matched by the pattern with
+    /// the fix of the rule, in the file analyzed with `services`
+    fn action(
+        &self,
+        pattern_match: &PatternMatch<L>,
+        services: &ServiceBag,
+    ) -> Option<RuleAction<L>> {
+        let fix = self.fix.as_ref()?;
+        let root = pattern_match.node().ancestors().last()?;
+        let mut mutation = BatchMutation::new(root);
+        L::replace_match(&mut mutation, pattern_match, fix, services).ok()?;
+
+        Some(RuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Apply the fix of the rule." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+impl<L: PatternLanguage> AnalyzerPlugin<L> for PatternRule<L>
+where
+    L::Kind: Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn query(&self) -> Vec<L::Kind> {
+        self.pattern.kind().into_iter().collect()
+    }
+
+    fn evaluate(&self, node: &SyntaxNode<L>, services: &ServiceBag) -> Vec<PluginSignal<L>> {
+        let Some(pattern_match) = self.pattern.match_node(node) else {
+            return Vec::new();
+        };
+        if !self.satisfies_conditions(&pattern_match) {
+            return Vec::new();
+        }
+
+        let is_inside = |pattern: &Pattern<L>| {
+            node.ancestors()
+                .skip(1)
+                .any(|ancestor| pattern.match_node(&ancestor).is_some())
+        };
+        if self
+            .inside
+            .as_ref()
+            .is_some_and(|inside| !is_inside(inside))
+            || self.not_inside.as_ref().is_some_and(is_inside)
+        {
+            return Vec::new();
+        }
+
+        vec![PluginSignal {
+            span: pattern_match.range(),
+            message: markup! { {self.message} }.to_owned(),
+            severity: self.severity,
+            action: self.action(&pattern_match, services),
+        }]
+    }
+}
+
+/// Returns the source type of the analyzed file, provided by the analyzer
+fn source_type(services: &ServiceBag) -> JsFileSource {
+    services
+        .get_service::<JsFileSource>()
+        .copied()
+        .unwrap_or_else(JsFileSource::tsx)
+}
+
+/// Checks that the rule `name` can be used in a suppression comment
+pub(crate) fn check_rule_name(path: &Path, name: &str) -> Result<(), PluginDiagnostic> {
+    let is_valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric());
+    if is_valid_name {
+        Ok(())
+    } else {
+        Err(PluginDiagnostic::invalid_name(path, name))
+    }
+}
+
+/// Returns the name of a rule with a static lifetime, as the analyzer requires.
+/// Each name is only allocated once, however often the plugins are loaded.
+pub(crate) fn intern_name(name: &str) -> &'static str {
+    static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut names = NAMES.lock().unwrap();
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.into());
+    names.insert(name);
+    name
+}
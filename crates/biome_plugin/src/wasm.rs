@@ -0,0 +1,961 @@
+use crate::rule::{check_rule_name, intern_name};
+use crate::{PluginDiagnostic, PluginRuleLevel};
+use biome_analyze::{ActionCategory, AnalyzerPlugin, Phases, PluginSignal, RuleAction, ServiceBag};
+use biome_console::markup;
+use biome_diagnostics::{Applicability, Severity};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::{
+    JsIdentifierAssignment, JsLanguage, JsReferenceIdentifier, JsSyntaxElement, JsSyntaxKind,
+    JsSyntaxNode, JsSyntaxToken, JsSyntaxTrivia, JsxReferenceIdentifier,
+};
+use biome_rowan::{
+    AstNode, BatchMutation, Direction, RawSyntaxKind, SyntaxKind, TextRange, TriviaPiece,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use wasmi::core::{Trap, TrapCode};
+use wasmi::{
+    AsContext, Caller, Config, Engine, Extern, Instance, Linker, Memory, Module, Store,
+    StoreLimits, StoreLimitsBuilder,
+};
+
+/// Name of the module of the functions that Biome provides to the plugins.
+/// Its version changes when these functions change in a breaking way.
+const HOST_MODULE: &str = "biome_v1";
+
+/// The fuel given to each run of a plugin, roughly the number of instructions
+/// it can execute before it's stopped
+const FUEL_LIMIT: u64 = 10_000_000;
+
+/// The maximum size of the memory of a plugin, in bytes
+const MEMORY_LIMIT: usize = 16 * 1024 * 1024;
+
+/// A WebAssembly plugin, compiled once and shared by its rules
+#[derive(Debug)]
+pub(crate) struct WasmPlugin {
+    module: Arc<WasmModule>,
+    rules: Vec<WasmRuleDeclaration>,
+}
+
+#[derive(Debug)]
+struct WasmModule {
+    engine: Engine,
+    module: Module,
+    /// The functions of [HOST_MODULE], defined once for all the runs of the module
+    linker: Linker<HostState>,
+}
+
+/// The rules declared by the `biome_rules` function of a plugin
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WasmRules {
+    rules: Vec<WasmRuleDeclaration>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WasmRuleDeclaration {
+    /// The name of the rule
+    name: String,
+    /// The kinds of the nodes the rule is run on, e.g. `JS_CALL_EXPRESSION`
+    query: Vec<String>,
+    /// The severity of the diagnostics, when the configuration doesn't set it
+    #[serde(default)]
+    level: Option<PluginRuleLevel>,
+    /// The kinds of `query`, resolved when the plugin is compiled
+    #[serde(skip)]
+    kinds: Vec<JsSyntaxKind>,
+}
+
+impl WasmPlugin {
+    /// Compiles the `module` of the plugin at `path`, and reads the rules it declares
+    pub(crate) fn compile(path: &Path, module: &[u8]) -> Result<Self, PluginDiagnostic> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, module)
+            .map_err(|error| PluginDiagnostic::invalid_module(path, &error))?;
+        let linker = host_functions(&engine)
+            .map_err(|error| PluginDiagnostic::invalid_module(path, &error))?;
+        let module = Arc::new(WasmModule {
+            engine,
+            module,
+            linker,
+        });
+
+        let (declarations, _) = module
+            .run(HostState::default(), |store, instance| {
+                let rules = instance
+                    .get_typed_func::<(), i64>(&*store, "biome_rules")
+                    .map_err(|_| Trap::new("the module doesn't export the function biome_rules"))?
+                    .call(&mut *store, ())?;
+                let memory = memory(instance.get_export(&*store, "memory"))?;
+                let (ptr, len) = unpack(rules);
+                Ok(read_memory(memory, &*store, ptr, len)?)
+            })
+            .map_err(|error| PluginDiagnostic::invalid_module(path, &error))?;
+        let WasmRules { mut rules } = serde_json::from_slice(&declarations)
+            .map_err(|error| PluginDiagnostic::invalid_module(path, &error))?;
+
+        for rule in &mut rules {
+            check_rule_name(path, &rule.name)?;
+            for kind in &rule.query {
+                let kind = syntax_kind(kind)
+                    .ok_or_else(|| PluginDiagnostic::unknown_node_kind(path, &rule.name, kind))?;
+                rule.kinds.push(kind);
+            }
+        }
+
+        Ok(Self { module, rules })
+    }
+
+    /// The names of the rules declared by the plugin, with the default severity of their diagnostics
+    pub(crate) fn rules(&self) -> impl Iterator<Item = (&str, Severity)> {
+        self.rules
+            .iter()
+            .map(|rule| (rule.name.as_str(), rule.level.unwrap_or_default().into()))
+    }
+
+    /// Returns the rule of the plugin at `index`, whose diagnostics have the
+    /// given `severity`, and that reads `options`
+    pub(crate) fn rule(
+        &self,
+        index: usize,
+        severity: Severity,
+        options: Option<&str>,
+    ) -> WasmPluginRule {
+        let rule = &self.rules[index];
+        WasmPluginRule {
+            name: intern_name(&rule.name),
+            index: index as i32,
+            query: rule.kinds.clone(),
+            severity,
+            options: options.unwrap_or("null").to_string(),
+            module: self.module.clone(),
+        }
+    }
+}
+
+/// A rule declared by a WebAssembly plugin
+#[derive(Debug)]
+pub struct WasmPluginRule {
+    name: &'static str,
+    /// The index of the rule in the rules declared by the plugin
+    index: i32,
+    query: Vec<JsSyntaxKind>,
+    severity: Severity,
+    /// The options of the rule, as JSON
+    options: String,
+    module: Arc<WasmModule>,
+}
+
+impl AnalyzerPlugin<JsLanguage> for WasmPluginRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn query(&self) -> Vec<JsSyntaxKind> {
+        self.query.clone()
+    }
+
+    fn phase(&self) -> Phases {
+        // The plugins can access the semantic model
+        Phases::Semantic
+    }
+
+    fn evaluate(
+        &self,
+        node: &JsSyntaxNode,
+        services: &ServiceBag,
+    ) -> Vec<PluginSignal<JsLanguage>> {
+        let state = HostState {
+            elements: vec![node.clone().into()],
+            model: services.get_service::<SemanticModel>().cloned(),
+            options: self.options.clone(),
+            ..HostState::default()
+        };
+        let result = self.module.run(state, |store, instance| {
+            instance
+                .get_typed_func::<(i32, i32), ()>(&*store, "biome_evaluate")
+                .map_err(|_| Trap::new("the module doesn't export the function biome_evaluate"))?
+                .call(&mut *store, (self.index, 0))?;
+            Ok(())
+        });
+
+        match result {
+            Ok(((), state)) => state
+                .reports
+                .into_iter()
+                .map(|report| PluginSignal {
+                    span: report.span,
+                    message: markup! { {report.message} }.to_owned(),
+                    severity: self.severity,
+                    action: report.fix.and_then(|fix| fix.action(node)),
+                })
+                .collect(),
+            Err(error) => {
+                let error = error.to_string();
+                vec![PluginSignal {
+                    span: node.text_trimmed_range(),
+                    message: markup! { "The plugin rule failed: "{error} }.to_owned(),
+                    severity: self.severity,
+                    action: None,
+                }]
+            }
+        }
+    }
+}
+
+/// The state of a run of a plugin, accessed by the functions of [HOST_MODULE]
+struct HostState {
+    limits: StoreLimits,
+    /// The syntax elements that the plugin accesses through their index. The
+    /// first element is the node the rule is run on.
+    elements: Vec<JsSyntaxElement>,
+    model: Option<SemanticModel>,
+    options: String,
+    reports: Vec<Report>,
+    /// The children of the nodes, collected once per node
+    children: HashMap<JsSyntaxNode, Vec<JsSyntaxElement>>,
+    /// The references to the bindings, collected once per binding
+    references: HashMap<JsSyntaxNode, Vec<JsSyntaxNode>>,
+}
+
+impl Default for HostState {
+    fn default() -> Self {
+        Self {
+            limits: StoreLimitsBuilder::new()
+                .memory_size(MEMORY_LIMIT)
+                .instances(1)
+                .build(),
+            elements: Vec::new(),
+            model: None,
+            options: String::new(),
+            reports: Vec::new(),
+            children: HashMap::new(),
+            references: HashMap::new(),
+        }
+    }
+}
+
+/// A diagnostic reported by a plugin with the `report` function
+struct Report {
+    span: TextRange,
+    message: String,
+    fix: Option<Fix>,
+}
+
+/// The edits that fix the code reported by a plugin
+struct Fix {
+    message: String,
+    edits: Vec<(JsSyntaxElement, String)>,
+}
+
+impl Fix {
+    /// Returns the action that replaces the text of each edited element of
+    /// the tree of `node`
+    fn action(self, node: &JsSyntaxNode) -> Option<RuleAction<JsLanguage>> {
+        let root = node.ancestors().last()?;
+        let mut mutation = BatchMutation::new(root);
+        for (element, text) in self.edits {
+            let tokens: Vec<JsSyntaxToken> = match element {
+                JsSyntaxElement::Node(node) => node.descendants_tokens(Direction::Next).collect(),
+                JsSyntaxElement::Token(token) => vec![token],
+            };
+            let (Some(first), Some(last)) = (tokens.first(), tokens.last()) else {
+                continue;
+            };
+
+            // The text replaces the first token, and keeps the trivia around the element
+            let leading = first.leading_trivia();
+            let trailing = last.trailing_trivia();
+            let token = JsSyntaxToken::new_detached(
+                first.kind(),
+                &format!("{}{text}{}", leading.text(), trailing.text()),
+                trivia_pieces(&leading),
+                trivia_pieces(&trailing),
+            );
+            mutation.replace_token_discard_trivia(first.clone(), token);
+            for token in &tokens[1..] {
+                mutation.remove_token(token.clone());
+            }
+        }
+
+        let message = self.message;
+        Some(RuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { {message} }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn trivia_pieces(trivia: &JsSyntaxTrivia) -> Vec<TriviaPiece> {
+    trivia
+        .pieces()
+        .map(|piece| TriviaPiece::new(piece.kind(), piece.text_len()))
+        .collect()
+}
+
+/// A diagnostic, as passed as JSON to the `report` function
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReportPayload {
+    /// The element whose range is reported
+    node: i32,
+    message: String,
+    #[serde(default)]
+    fix: Option<FixPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixPayload {
+    message: String,
+    edits: Vec<EditPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EditPayload {
+    /// The element whose text is replaced
+    node: i32,
+    text: String,
+}
+
+impl WasmModule {
+    /// Instantiates the module with `state` and calls `run` with the instance.
+    /// Returns the result of `run` along with the final state.
+    fn run<R>(
+        &self,
+        state: HostState,
+        run: impl FnOnce(&mut Store<HostState>, &Instance) -> Result<R, wasmi::Error>,
+    ) -> Result<(R, HostState), wasmi::Error> {
+        let mut store = Store::new(&self.engine, state);
+        store.limiter(|state| &mut state.limits);
+        store.add_fuel(FUEL_LIMIT)?;
+
+        let instance = self
+            .linker
+            .instantiate(&mut store, &self.module)?
+            .start(&mut store)?;
+        let result = run(&mut store, &instance)?;
+        Ok((result, store.into_data()))
+    }
+}
+
+/// Defines the functions of [HOST_MODULE]. The elements are passed by their
+/// index in [HostState::elements], and `-1` stands for no element.
+///
+/// A function consumes fuel in proportion to the work it does for the plugin,
+/// e.g. the length of the text it copies or the number of children it collects.
+fn host_functions(engine: &Engine) -> Result<Linker<HostState>, wasmi::Error> {
+    let mut linker = Linker::new(engine);
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_kind",
+        |mut caller: Caller<HostState>, element: i32, ptr: i32, len: i32| {
+            let kind = format!("{:?}", get_element(&caller, element)?.kind());
+            write_text(&mut caller, &kind, ptr, len)
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_text",
+        |mut caller: Caller<HostState>, element: i32, ptr: i32, len: i32| {
+            let element = get_element(&caller, element)?;
+            let length = u32::from(element.text_trimmed_range().len()) as i32;
+            // The text is only built when it fits in the buffer of the plugin
+            if length > len {
+                return Ok(length);
+            }
+            consume_fuel(&mut caller, length as usize)?;
+            let text = match element {
+                JsSyntaxElement::Node(node) => node.text_trimmed().to_string(),
+                JsSyntaxElement::Token(token) => token.text_trimmed().to_string(),
+            };
+            write_text(&mut caller, &text, ptr, len)
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_range",
+        |caller: Caller<HostState>, element: i32| {
+            let range = get_element(&caller, element)?.text_trimmed_range();
+            Ok(i64::from(u32::from(range.start())) << 32 | i64::from(u32::from(range.end())))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_is_token",
+        |caller: Caller<HostState>, element: i32| {
+            Ok(i32::from(
+                get_element(&caller, element)?.as_token().is_some(),
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_parent",
+        |mut caller: Caller<HostState>, element: i32| {
+            let parent = get_element(&caller, element)?.parent();
+            Ok(push_element(&mut caller, parent.map(Into::into)))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_child_count",
+        |mut caller: Caller<HostState>, element: i32| {
+            let count = children(&mut caller, element)?.map_or(0, |children| children.len());
+            Ok(count as i32)
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_child",
+        |mut caller: Caller<HostState>, element: i32, index: i32| {
+            let child = children(&mut caller, element)?.and_then(|children| {
+                let index = usize::try_from(index).ok()?;
+                children.get(index).cloned()
+            });
+            Ok(push_element(&mut caller, child))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_binding",
+        |mut caller: Caller<HostState>, element: i32| {
+            let binding = binding(&caller, element)?;
+            Ok(push_element(&mut caller, binding.map(Into::into)))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_reference_count",
+        |mut caller: Caller<HostState>, element: i32| {
+            Ok(references(&mut caller, element)?.map_or(-1, |references| references.len() as i32))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "node_reference",
+        |mut caller: Caller<HostState>, element: i32, index: i32| {
+            let reference = references(&mut caller, element)?.and_then(|references| {
+                let index = usize::try_from(index).ok()?;
+                references.get(index).cloned()
+            });
+            Ok(push_element(&mut caller, reference.map(Into::into)))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "rule_options",
+        |mut caller: Caller<HostState>, ptr: i32, len: i32| {
+            let options = caller.data().options.clone();
+            write_text(&mut caller, &options, ptr, len)
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "report",
+        |mut caller: Caller<HostState>, ptr: i32, len: i32| {
+            let memory = memory(caller.get_export("memory"))?;
+            let payload = read_memory(memory, &caller, ptr, len)?;
+            let payload: ReportPayload = serde_json::from_slice(&payload)
+                .map_err(|error| Trap::new(format!("the report isn't valid: {error}")))?;
+
+            let fix = payload
+                .fix
+                .map(|fix| -> Result<Fix, Trap> {
+                    let edits = fix
+                        .edits
+                        .into_iter()
+                        .map(|edit| Ok((get_element(&caller, edit.node)?, edit.text)))
+                        .collect::<Result<_, Trap>>()?;
+                    Ok(Fix {
+                        message: fix.message,
+                        edits,
+                    })
+                })
+                .transpose()?;
+            let report = Report {
+                span: get_element(&caller, payload.node)?.text_trimmed_range(),
+                message: payload.message,
+                fix,
+            };
+            caller.data_mut().reports.push(report);
+            Ok(())
+        },
+    )?;
+
+    Ok(linker)
+}
+
+fn get_element(caller: &Caller<HostState>, element: i32) -> Result<JsSyntaxElement, Trap> {
+    usize::try_from(element)
+        .ok()
+        .and_then(|index| caller.data().elements.get(index))
+        .cloned()
+        .ok_or_else(|| Trap::new(format!("{element} isn't the index of a syntax element")))
+}
+
+/// Stores `element` in the state, and returns its index
+fn push_element(caller: &mut Caller<HostState>, element: Option<JsSyntaxElement>) -> i32 {
+    let Some(element) = element else {
+        return -1;
+    };
+    let elements = &mut caller.data_mut().elements;
+    elements.push(element);
+    (elements.len() - 1) as i32
+}
+
+/// Returns the identifier binding that declares the reference `element`
+fn binding(caller: &Caller<HostState>, element: i32) -> Result<Option<JsSyntaxNode>, Trap> {
+    let (JsSyntaxElement::Node(node), Some(model)) =
+        (get_element(caller, element)?, &caller.data().model)
+    else {
+        return Ok(None);
+    };
+    let binding = if let Some(reference) = JsReferenceIdentifier::cast_ref(&node) {
+        model.binding(&reference)
+    } else if let Some(reference) = JsIdentifierAssignment::cast_ref(&node) {
+        model.binding(&reference)
+    } else if let Some(reference) = JsxReferenceIdentifier::cast_ref(&node) {
+        model.binding(&reference)
+    } else {
+        None
+    };
+    Ok(binding.map(|binding| binding.syntax().clone()))
+}
+
+/// Returns the children, nodes and tokens, of the node `element`. They're
+/// collected on the first call for a node, which consumes fuel for each child.
+fn children<'a>(
+    caller: &'a mut Caller<HostState>,
+    element: i32,
+) -> Result<Option<&'a [JsSyntaxElement]>, Trap> {
+    let JsSyntaxElement::Node(node) = get_element(caller, element)? else {
+        return Ok(None);
+    };
+    if !caller.data().children.contains_key(&node) {
+        let children: Vec<_> = node.children_with_tokens().collect();
+        consume_fuel(caller, children.len())?;
+        caller.data_mut().children.insert(node.clone(), children);
+    }
+    Ok(caller.data().children.get(&node).map(Vec::as_slice))
+}
+
+/// Returns the references to the identifier binding `element`. They're
+/// collected on the first call for a binding, which consumes fuel for each
+/// reference.
+fn references<'a>(
+    caller: &'a mut Caller<HostState>,
+    element: i32,
+) -> Result<Option<&'a [JsSyntaxNode]>, Trap> {
+    let JsSyntaxElement::Node(node) = get_element(caller, element)? else {
+        return Ok(None);
+    };
+    if !caller.data().references.contains_key(&node) {
+        let (Some(binding), Some(model)) = (
+            AnyJsIdentifierBinding::cast_ref(&node),
+            &caller.data().model,
+        ) else {
+            return Ok(None);
+        };
+        let references: Vec<_> = model
+            .as_binding(&binding)
+            .all_references()
+            .map(|reference| reference.syntax().clone())
+            .collect();
+        consume_fuel(caller, references.len())?;
+        caller
+            .data_mut()
+            .references
+            .insert(node.clone(), references);
+    }
+    Ok(caller.data().references.get(&node).map(Vec::as_slice))
+}
+
+/// Consumes the fuel of the work done by a host function, `cost` being e.g.
+/// the number of bytes or elements it handles
+fn consume_fuel(caller: &mut Caller<HostState>, cost: usize) -> Result<(), Trap> {
+    caller
+        .consume_fuel(cost as u64)
+        .map(drop)
+        .map_err(|_| Trap::from(TrapCode::OutOfFuel))
+}
+
+fn memory(export: Option<Extern>) -> Result<Memory, Trap> {
+    export
+        .and_then(Extern::into_memory)
+        .ok_or_else(|| Trap::new("the module doesn't export its memory"))
+}
+
+/// Copies `len` bytes of the memory of the plugin at `ptr`. The range is
+/// checked against the size of the memory before anything is allocated.
+fn read_memory(memory: Memory, store: impl AsContext, ptr: i32, len: i32) -> Result<Vec<u8>, Trap> {
+    let start = ptr as u32 as usize;
+    let end = start.checked_add(len as u32 as usize);
+    let data = memory.data(&store);
+    end.and_then(|end| data.get(start..end))
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| Trap::new("out of bounds memory access"))
+}
+
+/// Writes `text` in the memory of the plugin at `ptr` if it fits in `len`
+/// bytes, and returns its length
+fn write_text(caller: &mut Caller<HostState>, text: &str, ptr: i32, len: i32) -> Result<i32, Trap> {
+    let length = text.len() as i32;
+    if length <= len {
+        let memory = memory(caller.get_export("memory"))?;
+        memory
+            .write(caller, ptr as u32 as usize, text.as_bytes())
+            .map_err(|error| Trap::new(error.to_string()))?;
+    }
+    Ok(length)
+}
+
+/// Splits the pointer and the length packed in the result of a function
+fn unpack(value: i64) -> (i32, i32) {
+    ((value >> 32) as i32, value as i32)
+}
+
+/// Returns the syntax kind named `name`, e.g. `JS_CALL_EXPRESSION`
+fn syntax_kind(name: &str) -> Option<JsSyntaxKind> {
+    (0..JsSyntaxKind::__LAST as u16)
+        .map(|kind| JsSyntaxKind::from_raw(RawSyntaxKind(kind)))
+        .find(|kind| format!("{kind:?}") == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compile_js_plugins, load_plugin, Plugin, PluginRuleSettings};
+    use biome_analyze::{AnalysisFilter, ControlFlow, Never, RuleFilter, PLUGIN_GROUP};
+    use biome_diagnostics::{PrintDescription, Severity};
+    use biome_fs::MemoryFileSystem;
+    use biome_js_analyze::analyze_with_plugins;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+    use std::cell::Cell;
+    use std::path::Path;
+
+    /// Returns a WebAssembly plugin declaring `rules`, and defining the
+    /// `biome_evaluate` function and the data segments in `items`
+    fn wasm_plugin(rules: &str, items: &str) -> Plugin {
+        let module = format!(
+            r#"(module
+                (import "biome_v1" "node_text" (func $node_text (param i32 i32 i32) (result i32)))
+                (import "biome_v1" "node_child" (func $node_child (param i32 i32) (result i32)))
+                (import "biome_v1" "node_binding" (func $node_binding (param i32) (result i32)))
+                (import "biome_v1" "node_reference_count" (func $node_reference_count (param i32) (result i32)))
+                (import "biome_v1" "rule_options" (func $rule_options (param i32 i32) (result i32)))
+                (import "biome_v1" "report" (func $report (param i32 i32)))
+                (memory (export "memory") 1)
+                {}
+                (func (export "biome_rules") (result i64) (i64.const {}))
+                {items}
+            )"#,
+            data(0, rules),
+            rules.len()
+        );
+        let mut fs = MemoryFileSystem::default();
+        fs.insert("plugin.wasm".into(), wat::parse_str(module).unwrap());
+        load_plugin(&fs, Path::new("plugin.wasm")).unwrap()
+    }
+
+    /// Returns the data segment storing `text` at `offset`
+    fn data(offset: usize, text: &str) -> String {
+        format!(
+            r#"(data (i32.const {offset}) "{}")"#,
+            text.replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+        )
+    }
+
+    /// Returns the code reported by the plugin, with the message of the
+    /// diagnostics, and the code fixed by the first reported fix
+    fn lint(
+        plugin: Plugin,
+        options: Option<&str>,
+        code: &str,
+    ) -> (Vec<(String, String)>, Option<String>) {
+        let plugins = compile_js_plugins(&[plugin], |_, severity| {
+            Some(PluginRuleSettings {
+                severity,
+                options: options.map(ToString::to_string),
+            })
+        })
+        .unwrap();
+        let parse = parse(code, JsFileSource::tsx(), JsParserOptions::default());
+        let mut reported = Vec::new();
+        let mut fixed = None;
+        analyze_with_plugins(
+            &parse.tree(),
+            AnalysisFilter::from_enabled_rules(Some(&[RuleFilter::Group(PLUGIN_GROUP)])),
+            &Default::default(),
+            &plugins,
+            &Default::default(),
+            JsFileSource::tsx(),
+            |signal| {
+                if let Some(diagnostic) = signal.diagnostic() {
+                    let range = diagnostic.get_span().unwrap();
+                    reported.push((
+                        code[range].to_string(),
+                        PrintDescription(&diagnostic).to_string(),
+                    ));
+                }
+                if let Some(action) = signal.actions().next().filter(|_| fixed.is_none()) {
+                    fixed = Some(action.mutation.commit().to_string());
+                }
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        (reported, fixed)
+    }
+
+    fn compile_error(plugin: Plugin) -> String {
+        let error = compile_js_plugins(&[plugin], |_, severity| Some(severity.into())).unwrap_err();
+        PrintDescription(&error).to_string()
+    }
+
+    #[test]
+    fn reports_the_nodes_of_the_query() {
+        let report = r#"{ "node": 0, "message": "Don't call functions." }"#;
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noCall", "query": ["JS_CALL_EXPRESSION"] }] }"#,
+            &format!(
+                r#"{}
+                (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                    (call $report (i32.const 1024) (i32.const {})))"#,
+                data(1024, report),
+                report.len()
+            ),
+        );
+        let (reported, _) = lint(plugin, None, "f(a); g(h());");
+        assert_eq!(
+            reported,
+            [
+                ("f(a)".to_string(), "Don't call functions.".to_string()),
+                ("g(h())".to_string(), "Don't call functions.".to_string()),
+                ("h()".to_string(), "Don't call functions.".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_the_options_of_the_rule() {
+        // The message of the report is the JSON string set as the options of the rule
+        let prefix = r#"{ "node": 0, "message": "#;
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noDebugger", "query": ["JS_DEBUGGER_STATEMENT"] }] }"#,
+            &format!(
+                r#"{}
+                (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                    (local $len i32)
+                    (local.set $len
+                        (i32.add
+                            (i32.const {prefix_len})
+                            (call $rule_options (i32.const {options}) (i32.const 256))))
+                    (i32.store8 (i32.add (i32.const 1024) (local.get $len)) (i32.const 125))
+                    (call $report (i32.const 1024) (i32.add (local.get $len) (i32.const 1))))"#,
+                data(1024, prefix),
+                prefix_len = prefix.len(),
+                options = 1024 + prefix.len(),
+            ),
+        );
+        let (reported, _) = lint(plugin, Some(r#""Remove the debugger.""#), "debugger;");
+        assert_eq!(
+            reported,
+            [("debugger;".to_string(), "Remove the debugger.".to_string())]
+        );
+    }
+
+    #[test]
+    fn reads_the_semantic_model() {
+        let unresolved = r#"{ "node": 0, "message": "Unresolved." }"#;
+        let unused = r#"{ "node": 0, "message": "Unused." }"#;
+        let plugin = wasm_plugin(
+            r#"{ "rules": [
+                { "name": "noUnresolved", "query": ["JS_REFERENCE_IDENTIFIER"] },
+                { "name": "noUnused", "query": ["JS_IDENTIFIER_BINDING"] }
+            ] }"#,
+            &format!(
+                r#"{}{}
+                (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                    (if (i32.eqz (local.get $rule))
+                        (then
+                            (if (i32.eq (call $node_binding (local.get $node)) (i32.const -1))
+                                (then (call $report (i32.const 1024) (i32.const {})))))
+                        (else
+                            (if (i32.eqz (call $node_reference_count (local.get $node)))
+                                (then (call $report (i32.const 2048) (i32.const {})))))))"#,
+                data(1024, unresolved),
+                data(2048, unused),
+                unresolved.len(),
+                unused.len()
+            ),
+        );
+        let (reported, _) = lint(plugin, None, "let a = 1; let b = a + c;");
+        assert_eq!(
+            reported,
+            [
+                ("b".to_string(), "Unused.".to_string()),
+                ("c".to_string(), "Unresolved.".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_the_edits_of_the_fix() {
+        // The callee of the call is the first element accessed by the rule, at
+        // the index 1. The calls whose callee isn't `bar` are reported.
+        let report = r#"{ "node": 0, "message": "Call bar.", "fix": { "message": "Replace the callee.", "edits": [{ "node": 1, "text": "bar" }] } }"#;
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "useBar", "query": ["JS_CALL_EXPRESSION"] }] }"#,
+            &format!(
+                r#"{}{}
+                (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                    (drop (call $node_child (local.get $node) (i32.const 0)))
+                    (drop (call $node_text (i32.const 1) (i32.const 4096) (i32.const 16)))
+                    (if (i32.ne (i32.load (i32.const 4096)) (i32.load (i32.const 2048)))
+                        (then (call $report (i32.const 1024) (i32.const {})))))"#,
+                data(1024, report),
+                data(2048, "bar"),
+                report.len()
+            ),
+        );
+        let (reported, fixed) = lint(plugin, None, "/* a */ foo.baz(1) /* b */;\nbar(2);");
+        assert_eq!(
+            reported,
+            [("foo.baz(1)".to_string(), "Call bar.".to_string())]
+        );
+        assert_eq!(fixed.as_deref(), Some("/* a */ bar(1) /* b */;\nbar(2);"));
+    }
+
+    #[test]
+    fn stops_the_rules_exceeding_their_fuel() {
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noLoop", "query": ["JS_DEBUGGER_STATEMENT"] }] }"#,
+            r#"(func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                (loop $loop (br $loop)))"#,
+        );
+        let (reported, _) = lint(plugin, None, "debugger;");
+        assert_eq!(
+            reported,
+            [(
+                "debugger;".to_string(),
+                "The plugin rule failed: all fuel consumed by WebAssembly".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn charges_the_fuel_of_the_host_functions() {
+        // Each call copies the text of the module, which is larger than the
+        // number of instructions of the loop
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noCopy", "query": ["JS_MODULE"] }] }"#,
+            r#"(func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                (local $count i32)
+                (loop $loop
+                    (drop (call $node_text (local.get $node) (i32.const 4096) (i32.const 60000)))
+                    (local.set $count (i32.add (local.get $count) (i32.const 1)))
+                    (br_if $loop (i32.lt_u (local.get $count) (i32.const 400)))))"#,
+        );
+        let code = format!("'{}';", "a".repeat(30_000));
+        let (reported, _) = lint(plugin, None, &code);
+        assert_eq!(
+            reported,
+            [(
+                code,
+                "The plugin rule failed: all fuel consumed by WebAssembly".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn doesnt_read_outside_of_the_memory() {
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noRead", "query": ["JS_DEBUGGER_STATEMENT"] }] }"#,
+            r#"(func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                (call $report (i32.const 1024) (i32.const 2147483647)))"#,
+        );
+        let (reported, _) = lint(plugin, None, "debugger;");
+        assert_eq!(
+            reported,
+            [(
+                "debugger;".to_string(),
+                "The plugin rule failed: out of bounds memory access".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn limits_the_memory_of_the_rules() {
+        let report = r#"{ "node": 0, "message": "Out of memory." }"#;
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noGrowth", "query": ["JS_DEBUGGER_STATEMENT"] }] }"#,
+            &format!(
+                r#"{}
+                (func (export "biome_evaluate") (param $rule i32) (param $node i32)
+                    (if (i32.eq (memory.grow (i32.const 1024)) (i32.const -1))
+                        (then (call $report (i32.const 1024) (i32.const {})))))"#,
+                data(1024, report),
+                report.len()
+            ),
+        );
+        let (reported, _) = lint(plugin, None, "debugger;");
+        assert_eq!(
+            reported,
+            [("debugger;".to_string(), "Out of memory.".to_string())]
+        );
+    }
+
+    #[test]
+    fn reports_the_invalid_plugins() {
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noFoo", "query": ["JS_FOO"] }] }"#,
+            r#"(func (export "biome_evaluate") (param i32 i32))"#,
+        );
+        assert_eq!(
+            compile_error(plugin),
+            "The rule noFoo queries the node kind JS_FOO, which doesn't exist."
+        );
+
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noFoo" }] }"#,
+            r#"(func (export "biome_evaluate") (param i32 i32))"#,
+        );
+        assert_eq!(
+            compile_error(plugin),
+            "The WebAssembly plugin isn't valid: missing field `query` at line 1 column 31"
+        );
+
+        let plugin = Plugin {
+            module: Some(b"not wasm".to_vec()),
+            ..Plugin::default()
+        };
+        assert!(compile_error(plugin).starts_with("The WebAssembly plugin isn't valid:"));
+    }
+
+    #[test]
+    fn uses_the_default_severity_of_the_rules() {
+        let plugin = wasm_plugin(
+            r#"{ "rules": [{ "name": "noFoo", "query": ["JS_DEBUGGER_STATEMENT"], "level": "warn" }] }"#,
+            r#"(func (export "biome_evaluate") (param i32 i32))"#,
+        );
+        let default_severity = Cell::new(None);
+        compile_js_plugins(&[plugin], |_, severity| {
+            default_severity.set(Some(severity));
+            None
+        })
+        .unwrap();
+        assert_eq!(default_severity.get(), Some(Severity::Warning));
+    }
+}
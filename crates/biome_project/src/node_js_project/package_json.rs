@@ -18,6 +18,18 @@ pub struct PackageJson {
     pub dev_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
+    pub exports: Option<PackageExports>,
+}
+
+impl PackageJson {
+    /// Returns the path, relative to the package, of the entry point `subpath`
+    /// declared in the `exports` field, e.g. `.` or `./strict`.
+    ///
+    /// The conditional exports are resolved with `conditions`, along with the
+    /// `default` condition.
+    pub fn resolve_export(&self, subpath: &str, conditions: &[&str]) -> Option<String> {
+        self.exports.as_ref()?.resolve(subpath, conditions)
+    }
 }
 
 impl Manifest for PackageJson {
@@ -34,6 +46,78 @@ pub struct Dependencies(FxHashMap<String, Version>);
 #[derive(Debug)]
 pub struct Version(node_semver::Version);
 
+/// The `exports` field of a `package.json`, which maps the entry points of the
+/// package to its files
+#[derive(Debug)]
+pub enum PackageExports {
+    /// The path of a file of the package, e.g. `"./index.js"`
+    Path(String),
+    /// Targets tried in order, until one of them resolves
+    Fallbacks(Vec<PackageExports>),
+    /// The entry points of the package, e.g. `"./strict"`, or the targets of
+    /// conditions, e.g. `"import"`, in the order of their declaration
+    Map(Vec<(String, PackageExports)>),
+    /// An entry point that isn't exported
+    Null,
+}
+
+impl PackageExports {
+    /// Returns the target of the entry point `subpath`. A pattern, e.g. `"./*"`,
+    /// matches an entry point when there isn't an exact match, and the longest
+    /// pattern wins.
+    fn resolve(&self, subpath: &str, conditions: &[&str]) -> Option<String> {
+        let entry_points = match self {
+            PackageExports::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+                entries
+            }
+            // the target of the main entry point
+            _ => {
+                return if subpath == "." {
+                    self.resolve_target(None, conditions)
+                } else {
+                    None
+                };
+            }
+        };
+
+        if let Some((_, target)) = entry_points
+            .iter()
+            .find(|(key, _)| key == subpath && !key.contains('*'))
+        {
+            return target.resolve_target(None, conditions);
+        }
+
+        entry_points
+            .iter()
+            .filter_map(|(key, target)| {
+                let (prefix, suffix) = key.split_once('*')?;
+                let matched = subpath.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((prefix.len(), matched, target))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .and_then(|(_, matched, target)| target.resolve_target(Some(matched), conditions))
+    }
+
+    fn resolve_target(&self, matched: Option<&str>, conditions: &[&str]) -> Option<String> {
+        match self {
+            PackageExports::Path(path) => Some(match matched {
+                Some(matched) => path.replace('*', matched),
+                None => path.clone(),
+            }),
+            PackageExports::Fallbacks(targets) => targets
+                .iter()
+                .find_map(|target| target.resolve_target(matched, conditions)),
+            PackageExports::Map(targets) => targets
+                .iter()
+                .filter(|(condition, _)| {
+                    condition == "default" || conditions.contains(&condition.as_str())
+                })
+                .find_map(|(_, target)| target.resolve_target(matched, conditions)),
+            PackageExports::Null => None,
+        }
+    }
+}
+
 impl Deserializable for PackageJson {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -97,6 +181,9 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.optional_dependencies = deps;
                     }
                 }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Rome deems important
@@ -146,3 +233,130 @@ impl Deserializable for Version {
         }
     }
 }
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::NULL
+        .union(VisitableType::STR)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Null)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Path(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let targets = items
+            .flatten()
+            .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(PackageExports::Fallbacks(targets))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = vec![];
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(target) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), target));
+            }
+        }
+        Some(PackageExports::Map(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackageJson;
+    use biome_deserialize::json::deserialize_from_json_str;
+    use biome_json_parser::JsonParserOptions;
+
+    fn package_json(content: &str) -> PackageJson {
+        deserialize_from_json_str::<PackageJson>(content, JsonParserOptions::default())
+            .into_deserialized()
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_the_main_entry_point() {
+        let package = package_json(r#"{ "exports": "./biome.json" }"#);
+        assert_eq!(
+            package.resolve_export(".", &[]).as_deref(),
+            Some("./biome.json")
+        );
+        assert_eq!(package.resolve_export("./strict", &[]), None);
+
+        let package =
+            package_json(r#"{ "exports": { "import": "./index.mjs", "biome": "./biome.json" } }"#);
+        assert_eq!(
+            package.resolve_export(".", &["biome"]).as_deref(),
+            Some("./biome.json")
+        );
+        assert_eq!(package.resolve_export(".", &[]), None);
+    }
+
+    #[test]
+    fn resolves_the_entry_points() {
+        let package = package_json(
+            r#"{
+                "exports": {
+                    ".": "./biome.json",
+                    "./strict": { "biome": ["./strict.jsonc", "./strict.json"] },
+                    "./configs/*": "./dist/configs/*.json",
+                    "./configs/internal/*": null
+                }
+            }"#,
+        );
+        assert_eq!(
+            package.resolve_export("./strict", &["biome"]).as_deref(),
+            Some("./strict.jsonc")
+        );
+        assert_eq!(
+            package.resolve_export("./configs/react", &[]).as_deref(),
+            Some("./dist/configs/react.json")
+        );
+        assert_eq!(package.resolve_export("./configs/internal/base", &[]), None);
+        assert_eq!(package.resolve_export("./biome.json", &[]), None);
+    }
+}
@@ -305,6 +305,18 @@ where
     /// a list of individual text edits to be performed on the source code, or
     /// [None] if the mutation is empty
     pub fn as_text_edits(&self) -> Option<(TextRange, TextEdit)> {
+        let text_range = self.changed_range()?;
+
+        let old = self.root.to_string();
+        let new = self.clone().commit().to_string();
+        let text_edit = TextEdit::from_unicode_words(&old, &new);
+
+        Some((text_range, text_edit))
+    }
+
+    /// Returns the range of the document modified by this mutation, or [None]
+    /// if the mutation is empty
+    pub fn changed_range(&self) -> Option<TextRange> {
         let mut range = None;
 
         debug!(" changes {:?}", &self.changes);
@@ -323,13 +335,60 @@ where
             };
         }
 
-        let text_range = range?;
+        range
+    }
 
-        let old = self.root.to_string();
-        let new = self.clone().commit().to_string();
-        let text_edit = TextEdit::from_unicode_words(&old, &new);
+    /// Returns the replacements performed by this mutation, sorted by range:
+    /// the range of each element it replaces in the original tree, and the
+    /// text replacing it.
+    ///
+    /// The replacements only describe the mutation when the elements it
+    /// replaces don't contain each other.
+    pub fn replacements(&self) -> Vec<(TextRange, String)> {
+        let mut replacements: Vec<_> = self
+            .changes
+            .iter()
+            .map(|change| {
+                let range = match &change.parent {
+                    Some(parent) => match parent.slots().nth(change.new_node_slot) {
+                        Some(SyntaxSlot::Node(node)) => node.text_range(),
+                        Some(SyntaxSlot::Token(token)) => token.text_range(),
+                        // An empty slot is filled after the element that precedes it
+                        _ => {
+                            let start = parent
+                                .slots()
+                                .take(change.new_node_slot)
+                                .filter_map(|slot| match slot {
+                                    SyntaxSlot::Node(node) => Some(node.text_range().end()),
+                                    SyntaxSlot::Token(token) => Some(token.text_range().end()),
+                                    SyntaxSlot::Empty => None,
+                                })
+                                .last()
+                                .unwrap_or_else(|| parent.text_range().start());
+                            TextRange::empty(start)
+                        }
+                    },
+                    None => self.root.text_range(),
+                };
+                let text = change
+                    .new_node
+                    .as_ref()
+                    .map_or_else(String::new, ToString::to_string);
+                (range, text)
+            })
+            .collect();
+        replacements.sort_by_key(|(range, _)| range.start());
+        replacements
+    }
 
-        Some((text_range, text_edit))
+    /// Adds the changes of `other` to this mutation.
+    ///
+    /// Both mutations must have been created from the same root, and their
+    /// changes must not overlap: the changes to the same slot are applied in
+    /// an unspecified order.
+    pub fn extend(&mut self, other: Self) {
+        debug_assert_eq!(self.root, other.root);
+        self.changes.extend(other.changes);
     }
 
     /// The core of the batch mutation algorithm can be summarized as:
@@ -547,4 +606,41 @@ pub mod test {
 
         assert_eq!(expected_debug, format!("{:#?}", after));
     }
+
+    #[test]
+    pub fn ok_batch_mutation_extend() {
+        let (before, _) = tree_two("a", "b");
+        let (expected, expected_debug) = tree_two("c", "d");
+
+        let a = find(&before, "a");
+        let b = find(&before, "b");
+        let c = clone_detach(&expected, "c");
+        let d = clone_detach(&expected, "d");
+
+        let mut batch = before.clone().begin();
+        batch.replace_node(a, c);
+        let mut other = before.begin();
+        other.replace_node(b, d);
+        batch.extend(other);
+        let after = batch.commit();
+
+        assert_eq!(expected_debug, format!("{:#?}", after));
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_replacements() {
+        let (before, _) = tree_two("a", "b");
+        let (expected, _) = tree_two("c", "d");
+
+        let b = find(&before, "b");
+        let d = clone_detach(&expected, "d");
+
+        let mut batch = before.begin();
+        batch.replace_node(b.clone(), d);
+
+        assert_eq!(
+            batch.replacements(),
+            vec![(b.syntax().text_range(), "d".to_string())]
+        );
+    }
 }
@@ -6,6 +6,7 @@ use biome_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory, MessageAndDescription,
     Severity, Visit,
 };
+use biome_plugin::PluginDiagnostic;
 use biome_rowan::SyntaxError;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
@@ -33,6 +34,9 @@ pub enum ConfigurationDiagnostic {
 
     /// Thrown when there's something wrong with the files specified inside `"extends"`
     CantLoadExtendFile(CantLoadExtendFile),
+
+    /// Thrown when a plugin listed in `linter.plugins` can't be loaded, or defines invalid rules
+    Plugin(PluginDiagnostic),
 }
 
 impl From<SyntaxError> for ConfigurationDiagnostic {
@@ -49,6 +53,12 @@ impl From<DeserializationDiagnostic> for ConfigurationDiagnostic {
     }
 }
 
+impl From<PluginDiagnostic> for ConfigurationDiagnostic {
+    fn from(value: PluginDiagnostic) -> Self {
+        ConfigurationDiagnostic::Plugin(value)
+    }
+}
+
 impl ConfigurationDiagnostic {
     pub(crate) fn new_serialization_error() -> Self {
         Self::SerializationError(SerializationError)
@@ -99,6 +109,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.severity(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.severity(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.severity(),
+            ConfigurationDiagnostic::Plugin(error) => error.severity(),
         }
     }
 
@@ -110,6 +121,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.category(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.category(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.category(),
+            ConfigurationDiagnostic::Plugin(error) => error.category(),
         }
     }
 
@@ -121,6 +133,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.tags(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.tags(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.tags(),
+            ConfigurationDiagnostic::Plugin(error) => error.tags(),
         }
     }
 
@@ -132,6 +145,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.location(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.location(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.location(),
+            ConfigurationDiagnostic::Plugin(error) => error.location(),
         }
     }
 
@@ -143,6 +157,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.source(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.source(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.source(),
+            ConfigurationDiagnostic::Plugin(error) => error.source(),
         }
     }
 
@@ -154,6 +169,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.message(fmt),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.message(fmt),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.message(fmt),
+            ConfigurationDiagnostic::Plugin(error) => error.message(fmt),
         }
     }
 
@@ -165,6 +181,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.description(fmt),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.description(fmt),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.description(fmt),
+            ConfigurationDiagnostic::Plugin(error) => error.description(fmt),
         }
     }
 
@@ -176,6 +193,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.advices(visitor),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.advices(visitor),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.advices(visitor),
+            ConfigurationDiagnostic::Plugin(error) => error.advices(visitor),
         }
     }
 
@@ -187,6 +205,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.verbose_advices(visitor),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.verbose_advices(visitor),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.verbose_advices(visitor),
+            ConfigurationDiagnostic::Plugin(error) => error.verbose_advices(visitor),
         }
     }
 }
@@ -266,6 +285,12 @@ impl CantLoadExtendFile {
     }
 }
 
+impl From<PluginDiagnostic> for WorkspaceError {
+    fn from(value: PluginDiagnostic) -> Self {
+        WorkspaceError::Configuration(ConfigurationDiagnostic::Plugin(value))
+    }
+}
+
 impl From<CantLoadExtendFile> for WorkspaceError {
     fn from(value: CantLoadExtendFile) -> Self {
         WorkspaceError::Configuration(ConfigurationDiagnostic::CantLoadExtendFile(value))
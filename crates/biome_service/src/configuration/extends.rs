@@ -0,0 +1,290 @@
+//! The resolution of the configuration files listed in `extends`. An entry is
+//! either a path relative to the configuration that extends it, or the name of
+//! a package installed in `node_modules`, e.g. `@company/biome-config` or
+//! `@company/biome-config/strict`.
+
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::configuration::plugins::{resolve_plugin_paths, resolve_plugin_values};
+use crate::configuration::{apply_values, merge_values};
+use crate::{Configuration, MergeWith, WorkspaceError};
+use biome_console::markup;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::Error;
+use biome_fs::{normalize_path, FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_project::PackageJson;
+use serde_json::json;
+use std::path::{Component, Path, PathBuf};
+
+/// The conditions of the `exports` of a package that are resolved, along with `default`
+const EXPORTS_CONDITIONS: &[&str] = &["biome"];
+
+/// The file of a package that doesn't declare its `exports`
+const PACKAGE_CONFIGURATION_FILE: &str = "biome.json";
+
+/// A configuration file listed in `extends`
+#[derive(Debug)]
+pub struct ExtendedConfigurationFile {
+    /// The entry of `extends`, as written in the configuration
+    pub specifier: String,
+    /// The path of the configuration file it resolves to
+    pub file_path: PathBuf,
+    /// The configuration of the file, once the files it extends are applied
+    pub configuration: Configuration,
+    /// The configuration files extended by this file
+    pub extends: Vec<ExtendedConfigurationFile>,
+    /// The values declared in the file and in the files it extends, without the defaults
+    /// filled by the deserialization
+    pub(super) values: serde_json::Value,
+}
+
+impl ExtendedConfigurationFile {
+    /// Returns `configuration` once the values declared in the file, and in the files it
+    /// extends, are applied on top of it
+    pub fn apply_to(&self, configuration: Configuration) -> Result<Configuration, WorkspaceError> {
+        apply_values(configuration, self.values.clone())
+    }
+}
+
+/// Loads the configuration file `specifier`, extended by a configuration file of
+/// `directory_path`, and applies to it the files it extends itself.
+///
+/// `chain` holds the configuration files being extended, to detect the loops.
+pub(super) fn load_extended_file(
+    fs: &dyn FileSystem,
+    directory_path: &Path,
+    specifier: &str,
+    chain: &mut Vec<PathBuf>,
+) -> Result<(Configuration, Vec<Error>, ExtendedConfigurationFile), WorkspaceError> {
+    let config_path = resolve_extends(fs, directory_path, specifier)?;
+    if chain.contains(&config_path) {
+        return Err(CantLoadExtendFile::new(
+            config_path.display().to_string(),
+            markup! {
+                "The configuration file "<Emphasis>{specifier}</Emphasis>" extends itself."
+            },
+        )
+        .into());
+    }
+
+    let mut file = fs
+        .open_with_options(config_path.as_path(), OpenOptions::default().read(true))
+        .map_err(|err| {
+            CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
+                markup!{
+                    "Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
+                }
+            )
+        })?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).map_err(|err| {
+        CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
+            markup!{
+                "It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
+            }
+        )
+    })?;
+    let (configuration, mut diagnostics) =
+        deserialize_from_json_str::<Configuration>(content.as_str(), JsonParserOptions::default())
+            .consume();
+    let mut configuration = configuration.unwrap_or_default();
+    let mut values = serde_json::from_str(&content).unwrap_or_else(|_| json!({}));
+    if let Some(config_directory) = config_path.parent() {
+        resolve_plugin_paths(&mut configuration, config_directory);
+        resolve_plugin_values(&mut values, config_directory);
+    }
+
+    let mut extends = vec![];
+    if let Some(specifiers) = configuration.extends.clone() {
+        let extended_directory = config_path.parent().unwrap_or(directory_path);
+        let mut extended_configuration: Option<Configuration> = None;
+        chain.push(config_path.clone());
+        for extended_specifier in specifiers.iter() {
+            let (extended, extended_diagnostics, extended_file) =
+                load_extended_file(fs, extended_directory, extended_specifier, chain)?;
+            extended_configuration = match extended_configuration {
+                Some(mut previous_configuration) => {
+                    previous_configuration.merge_with(extended);
+                    Some(previous_configuration)
+                }
+                None => Some(extended),
+            };
+            diagnostics.extend(extended_diagnostics);
+            extends.push(extended_file);
+        }
+        chain.pop();
+
+        let mut extended_values = json!({});
+        for extended_file in &extends {
+            merge_values(&mut extended_values, extended_file.values.clone());
+        }
+        merge_values(&mut extended_values, values);
+        values = extended_values;
+
+        if let Some(mut extended_configuration) = extended_configuration {
+            // Here we want to keep only the values that aren't a default
+            extended_configuration.merge_with_if_not_default(configuration);
+            configuration = extended_configuration;
+        }
+    }
+
+    let extended_file = ExtendedConfigurationFile {
+        specifier: specifier.to_string(),
+        file_path: config_path,
+        configuration: configuration.clone(),
+        extends,
+        values,
+    };
+    Ok((configuration, diagnostics, extended_file))
+}
+
+/// Returns the path of the configuration file `specifier`, extended by a
+/// configuration file of `directory_path`.
+///
+/// A path relative to the configuration takes precedence over a package with
+/// the same name.
+fn resolve_extends(
+    fs: &dyn FileSystem,
+    directory_path: &Path,
+    specifier: &str,
+) -> Result<PathBuf, CantLoadExtendFile> {
+    let path = normalize_path(&directory_path.join(specifier));
+    if !is_bare_specifier(specifier) || fs.path_exists(&path) {
+        return Ok(path);
+    }
+
+    let (package_name, subpath) = split_package_specifier(specifier);
+    match find_package(fs, directory_path, package_name) {
+        Some(package_path) => resolve_package_entry(fs, &package_path, package_name, &subpath),
+        None if looks_like_package(specifier) => Err(CantLoadExtendFile::new(
+            specifier,
+            markup! {
+                "Biome couldn't find the package "<Emphasis>{package_name}</Emphasis>" in the "<Emphasis>"node_modules"</Emphasis>" directories. Make sure that it's installed."
+            },
+        )
+        .with_verbose_advice(markup! {
+            "Biome looked for the package starting from the directory "<Emphasis>{directory_path.display().to_string()}</Emphasis>"."
+        })),
+        // The error of the missing file is emitted when it's read
+        None => Ok(path),
+    }
+}
+
+/// Returns the path of the file that `subpath` points to in the package of `package_path`
+fn resolve_package_entry(
+    fs: &dyn FileSystem,
+    package_path: &Path,
+    package_name: &str,
+    subpath: &str,
+) -> Result<PathBuf, CantLoadExtendFile> {
+    let manifest_path = package_path.join("package.json");
+    let package = read_package_json(fs, &manifest_path)
+        .map_err(|message| CantLoadExtendFile::new(manifest_path.display().to_string(), message))?;
+
+    if package.exports.is_none() {
+        return Ok(if subpath == "." {
+            package_path.join(PACKAGE_CONFIGURATION_FILE)
+        } else {
+            let path = normalize_path(&package_path.join(subpath));
+            if path.extension().is_some() {
+                path
+            } else {
+                path.with_extension("json")
+            }
+        });
+    }
+
+    match package.resolve_export(subpath, EXPORTS_CONDITIONS) {
+        Some(target) => Ok(normalize_path(&package_path.join(target))),
+        None => Err(CantLoadExtendFile::new(
+            manifest_path.display().to_string(),
+            markup! {
+                "The package "<Emphasis>{package_name}</Emphasis>" doesn't export "<Emphasis>{subpath}</Emphasis>"."
+            },
+        )
+        .with_verbose_advice(markup! {
+            "Biome resolves the entry points listed in the "<Emphasis>"exports"</Emphasis>" field of the "<Emphasis>"package.json"</Emphasis>" of the package, with the "<Emphasis>"biome"</Emphasis>" and "<Emphasis>"default"</Emphasis>" conditions."
+        })),
+    }
+}
+
+fn read_package_json(fs: &dyn FileSystem, manifest_path: &Path) -> Result<PackageJson, String> {
+    let mut content = String::new();
+    fs.open_with_options(manifest_path, OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map_err(|err| err.to_string())?;
+    deserialize_from_json_str::<PackageJson>(content.as_str(), JsonParserOptions::default())
+        .into_deserialized()
+        .ok_or_else(|| "The package.json of the package isn't valid.".to_string())
+}
+
+/// Returns the directory of the package `package_name`, installed in the
+/// `node_modules` of `directory_path` or one of its parents
+fn find_package(fs: &dyn FileSystem, directory_path: &Path, package_name: &str) -> Option<PathBuf> {
+    directory_path
+        .ancestors()
+        .map(|directory| directory.join("node_modules").join(package_name))
+        .find(|package_path| fs.path_exists(&package_path.join("package.json")))
+}
+
+/// A bare specifier isn't a relative or an absolute path, e.g. `biome.json`
+/// or `@company/biome-config`
+fn is_bare_specifier(specifier: &str) -> bool {
+    matches!(
+        Path::new(specifier).components().next(),
+        Some(Component::Normal(_))
+    )
+}
+
+/// Whether a bare specifier can't be a file, which is the case of a scoped
+/// package or an entry without the extension of a configuration file
+fn looks_like_package(specifier: &str) -> bool {
+    specifier.starts_with('@')
+        || !matches!(
+            Path::new(specifier)
+                .extension()
+                .and_then(|extension| extension.to_str()),
+            Some("json" | "jsonc")
+        )
+}
+
+/// Splits a specifier into the name of the package and the subpath of its
+/// entry point, e.g. `@company/biome-config/strict` into
+/// `@company/biome-config` and `./strict`
+fn split_package_specifier(specifier: &str) -> (&str, String) {
+    let name_length = if specifier.starts_with('@') {
+        specifier
+            .match_indices('/')
+            .nth(1)
+            .map_or(specifier.len(), |(index, _)| index)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+    let (package_name, subpath) = specifier.split_at(name_length);
+    (package_name, format!(".{subpath}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_package_specifier;
+
+    #[test]
+    fn splits_the_package_specifiers() {
+        assert_eq!(
+            split_package_specifier("biome-config"),
+            ("biome-config", ".".to_string())
+        );
+        assert_eq!(
+            split_package_specifier("biome-config/strict"),
+            ("biome-config", "./strict".to_string())
+        );
+        assert_eq!(
+            split_package_specifier("@company/biome-config"),
+            ("@company/biome-config", ".".to_string())
+        );
+        assert_eq!(
+            split_package_specifier("@company/biome-config/configs/strict"),
+            ("@company/biome-config", "./configs/strict".to_string())
+        );
+    }
+}
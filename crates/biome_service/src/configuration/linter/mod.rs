@@ -6,9 +6,12 @@ use crate::configuration::merge::MergeWith;
 use crate::configuration::overrides::OverrideLinterConfiguration;
 use crate::settings::{to_matcher, LinterSettings};
 use crate::{Matcher, WorkspaceError};
+use biome_analyze::{AnalysisFilter, Phases, Queryable, RegistryVisitor, Rule};
 use biome_deserialize::StringSet;
 use biome_diagnostics::Severity;
 use biome_js_analyze::options::PossibleOptions;
+use biome_js_analyze::visit_registry;
+use biome_js_syntax::JsLanguage;
 use bpaf::Bpaf;
 pub use rules::*;
 #[cfg(feature = "schema")]
@@ -42,6 +45,13 @@ pub struct LinterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub include: Option<StringSet>,
+
+    /// A list of paths to plugin files, relative to the configuration file. The lint rules
+    /// defined by the plugins are run on JavaScript, TypeScript and JSX files, or on CSS files
+    /// when their `language` is `css`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub plugins: Option<StringSet>,
 }
 
 impl MergeWith<LinterConfiguration> for LinterConfiguration {
@@ -49,6 +59,9 @@ impl MergeWith<LinterConfiguration> for LinterConfiguration {
         if let Some(enabled) = other.enabled {
             self.enabled = Some(enabled);
         }
+        if let Some(plugins) = other.plugins {
+            self.plugins = Some(plugins);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: LinterConfiguration)
@@ -67,6 +80,38 @@ impl LinterConfiguration {
     }
 }
 
+impl Rules {
+    /// Returns `true` if one of the enabled rules runs in the type-aware phase
+    /// of the analyzer, which resolves the declarations of the project
+    pub fn has_type_aware_rules(&self) -> bool {
+        let enabled_rules: Vec<_> = self.as_enabled_rules().into_iter().collect();
+        let mut visitor = TypeAwareRulesVisitor {
+            filter: AnalysisFilter::from_enabled_rules(Some(&enabled_rules)),
+            found: false,
+        };
+        visit_registry(&mut visitor);
+        visitor.found
+    }
+}
+
+struct TypeAwareRulesVisitor<'a> {
+    filter: AnalysisFilter<'a>,
+    found: bool,
+}
+
+impl RegistryVisitor<JsLanguage> for TypeAwareRulesVisitor<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        if R::phase() == Phases::Type && self.filter.match_rule::<R>() {
+            self.found = true;
+        }
+    }
+}
+
 impl Default for LinterConfiguration {
     fn default() -> Self {
         Self {
@@ -74,6 +119,7 @@ impl Default for LinterConfiguration {
             rules: Some(Rules::default()),
             ignore: None,
             include: None,
+            plugins: None,
         }
     }
 }
@@ -88,6 +134,8 @@ pub fn to_linter_settings(
         rules: conf.rules,
         ignored_files: to_matcher(conf.ignore.as_ref(), vcs_path.clone(), gitignore_matches)?,
         included_files: to_matcher(conf.include.as_ref(), vcs_path, gitignore_matches)?,
+        plugins: Vec::new(),
+        css_plugins: Vec::new(),
     })
 }
 
@@ -100,6 +148,8 @@ impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
             rules: conf.rules,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            plugins: Vec::new(),
+            css_plugins: Vec::new(),
         })
     }
 }
@@ -141,6 +191,16 @@ impl RuleConfiguration {
     pub fn is_enabled(&self) -> bool {
         !self.is_disabled()
     }
+
+    /// Returns the policy that overrides the safety of the fixes of the rule,
+    /// if the configuration sets it
+    pub fn fix(&self) -> Option<&RuleFixConfiguration> {
+        if let Self::WithOptions(rule) = self {
+            rule.fix.as_ref()
+        } else {
+            None
+        }
+    }
 }
 impl Default for RuleConfiguration {
     fn default() -> Self {
@@ -193,11 +253,40 @@ impl FromStr for RulePlainConfiguration {
     }
 }
 
+/// Overrides the safety of the fixes of a rule, when the linter applies them
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleFixConfiguration {
+    /// The fixes of the rule are never applied
+    None,
+    /// The fixes of the rule are applied with `--apply`, and on save
+    Safe,
+    /// The fixes of the rule are only applied with `--apply-unsafe`
+    Unsafe,
+}
+
+impl FromStr for RuleFixConfiguration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "safe" => Ok(Self::Safe),
+            "unsafe" => Ok(Self::Unsafe),
+            _ => Err("Invalid fix configuration for rule".to_string()),
+        }
+    }
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RuleWithOptions {
     pub level: RulePlainConfiguration,
+    /// Overrides the safety of the fixes of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<RuleFixConfiguration>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<PossibleOptions>,
 }
@@ -207,7 +296,89 @@ impl FromStr for RuleWithOptions {
     fn from_str(_s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             level: RulePlainConfiguration::default(),
+            fix: None,
             options: None,
         })
     }
 }
+
+/// The configuration of a lint rule defined by a plugin
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
+pub enum PluginRuleConfiguration {
+    Plain(RulePlainConfiguration),
+    WithOptions(PluginRuleWithOptions),
+}
+
+impl PluginRuleConfiguration {
+    pub fn level(&self) -> &RulePlainConfiguration {
+        match self {
+            Self::Plain(level) => level,
+            Self::WithOptions(rule) => &rule.level,
+        }
+    }
+
+    pub fn options(&self) -> Option<&PluginRuleOptions> {
+        match self {
+            Self::Plain(_) => None,
+            Self::WithOptions(rule) => rule.options.as_ref(),
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginRuleWithOptions {
+    pub level: RulePlainConfiguration,
+    /// The options of the rule, which only the rules of WebAssembly plugins read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<PluginRuleOptions>,
+}
+
+/// The options of a lint rule defined by a plugin, kept as JSON text: the
+/// configuration can't hold a recursive type like [serde_json::Value]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct PluginRuleOptions(String);
+
+impl PluginRuleOptions {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<serde_json::Value> for PluginRuleOptions {
+    fn from(value: serde_json::Value) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl Serialize for PluginRuleOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value: serde_json::Value =
+            serde_json::from_str(&self.0).map_err(serde::ser::Error::custom)?;
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PluginRuleOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchema for PluginRuleOptions {
+    fn schema_name() -> String {
+        serde_json::Value::schema_name()
+    }
+
+    fn is_referenceable() -> bool {
+        serde_json::Value::is_referenceable()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        serde_json::Value::json_schema(gen)
+    }
+}
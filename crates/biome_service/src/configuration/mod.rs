@@ -4,28 +4,33 @@
 //! by language. The language might further options divided by tool.
 pub mod css;
 pub mod diagnostics;
+mod extends;
 pub mod formatter;
 mod generated;
 pub mod javascript;
 pub mod json;
 pub mod linter;
 mod merge;
+mod nested;
 pub mod organize_imports;
 mod overrides;
 mod parse;
+pub(crate) mod plugins;
 pub mod vcs;
 
-use crate::configuration::diagnostics::CantLoadExtendFile;
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
+use crate::configuration::extends::load_extended_file;
+pub use crate::configuration::extends::ExtendedConfigurationFile;
 pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
 pub use crate::configuration::merge::MergeWith;
+pub use crate::configuration::nested::nested_directories;
 use crate::configuration::organize_imports::{organize_imports, OrganizeImports};
 use crate::configuration::overrides::Overrides;
-use crate::configuration::vcs::{vcs_configuration, VcsConfiguration};
+use crate::configuration::plugins::{resolve_plugin_paths, resolve_plugin_values};
+use crate::configuration::vcs::{vcs_configuration, VcsClientKind, VcsConfiguration};
 use crate::settings::WorkspaceSettings;
-use crate::{DynRef, WorkspaceError, VERSION};
+use crate::{DynRef, IgnoreFiles, WorkspaceError, VERSION};
 use biome_analyze::AnalyzerRules;
-use biome_console::markup;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, StringSet};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
@@ -43,6 +48,7 @@ pub use javascript::{javascript_configuration, JavascriptConfiguration, Javascri
 pub use json::{json_configuration, JsonConfiguration, JsonFormatter};
 pub use linter::{linter_configuration, LinterConfiguration, RuleConfiguration, Rules};
 pub use overrides::to_override_settings;
+pub use plugins::load_plugins;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::io::ErrorKind;
@@ -101,7 +107,14 @@ pub struct Configuration {
     #[bpaf(external(css_configuration), optional, hide)]
     pub css: Option<CssConfiguration>,
 
-    /// A list of paths to other JSON files, used to extends the current configuration.
+    /// Whether this is the configuration of the whole project. A configuration file of a
+    /// sub-directory with `root` set to `false` inherits the configuration of its parent directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub root: Option<bool>,
+
+    /// A list of paths to other JSON files, or names of packages that provide them, used to
+    /// extends the current configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub extends: Option<StringSet>,
@@ -127,6 +140,7 @@ impl Default for Configuration {
             json: None,
             schema: None,
             vcs: None,
+            root: None,
             extends: None,
             overrides: None,
         }
@@ -195,6 +209,27 @@ impl Configuration {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if the linter runs a type-aware rule on some of the
+    /// files, see [Rules::has_type_aware_rules]
+    pub fn has_type_aware_rules(&self) -> bool {
+        let linter_rules = self
+            .linter
+            .as_ref()
+            .filter(|linter| !linter.is_disabled())
+            .and_then(|linter| linter.rules.as_ref());
+        let override_rules = self
+            .overrides
+            .iter()
+            .flat_map(|overrides| overrides.0.iter())
+            .filter_map(|pattern| pattern.linter.as_ref())
+            .filter(|linter| linter.enabled != Some(false))
+            .filter_map(|linter| linter.rules.as_ref());
+        linter_rules
+            .into_iter()
+            .chain(override_rules)
+            .any(Rules::has_type_aware_rules)
+    }
+
     pub fn is_organize_imports_disabled(&self) -> bool {
         self.organize_imports
             .as_ref()
@@ -211,11 +246,16 @@ impl Configuration {
     }
 
     /// This function checks if the VCS integration is enabled, and if so, it will attempts to resolve the
-    /// VCS root directory and the `.gitignore` file.
+    /// VCS root directory and the ignore patterns of the repository.
+    ///
+    /// With git, they include `.git/info/exclude` and `core.excludesFile`. When the
+    /// repository can't be read, Biome looks for the `.gitignore` file in the VCS root
+    /// directory and its parents. The nested ignore files are read during the
+    /// traversal, see [Configuration::vcs_ignore_files].
     ///
     /// ## Returns
     ///
-    /// A tuple with VCS root folder and the contents of the `.gitignore` file
+    /// A tuple with VCS root folder and the ignore patterns
     pub fn retrieve_gitignore_matches(
         &self,
         file_system: &DynRef<'_, dyn FileSystem>,
@@ -225,14 +265,29 @@ impl Configuration {
             return Ok((None, vec![]));
         };
         if vcs.is_enabled() {
-            let vcs_base_path = match (vcs_base_path, &vcs.root) {
-                (Some(vcs_base_path), Some(root)) => vcs_base_path.join(root),
-                (None, Some(root)) => PathBuf::from(root),
-                (Some(vcs_base_path), None) => PathBuf::from(vcs_base_path),
-                (None, None) => return Err(WorkspaceError::vcs_disabled()),
-            };
+            let vcs_base_path = vcs
+                .resolve_root(vcs_base_path)
+                .ok_or_else(WorkspaceError::vcs_disabled)?;
             if let Some(client_kind) = &vcs.client_kind {
                 if !vcs.ignore_file_disabled() {
+                    match client_kind {
+                        VcsClientKind::Git => {
+                            let matches = file_system
+                                .get_git_ignore_matches(&vcs_base_path)
+                                .map_err(|_| {
+                                    WorkspaceError::cant_read_file(format!(
+                                        "{}",
+                                        vcs_base_path.display()
+                                    ))
+                                })?;
+                            if let Some(matches) = matches {
+                                return Ok((Some(matches.directory_path), matches.patterns));
+                            }
+                        }
+                    }
+
+                    // The file system can't read the repository, only its
+                    // ignore file is used
                     let result = file_system
                         .auto_search(vcs_base_path, client_kind.ignore_file(), false)
                         .map_err(WorkspaceError::from)?;
@@ -252,6 +307,28 @@ impl Configuration {
         }
         Ok((None, vec![]))
     }
+
+    /// Returns the ignore files declared in the directories of the VCS root, which are
+    /// read during the traversal, when the VCS integration and its ignore file are enabled.
+    pub fn vcs_ignore_files(
+        &self,
+        vcs_base_path: Option<&Path>,
+    ) -> Result<Option<IgnoreFiles>, WorkspaceError> {
+        let Some(vcs) = &self.vcs else {
+            return Ok(None);
+        };
+        let Some(client_kind) = &vcs.client_kind else {
+            return Ok(None);
+        };
+        if vcs.is_disabled() || vcs.ignore_file_disabled() {
+            return Ok(None);
+        }
+        let root = vcs
+            .resolve_root(vcs_base_path)
+            .ok_or_else(WorkspaceError::vcs_disabled)?;
+
+        Ok(Some(IgnoreFiles::new(root, client_kind.ignore_file())))
+    }
 }
 
 impl MergeWith<Option<VcsConfiguration>> for Configuration {
@@ -537,6 +614,8 @@ pub struct ConfigurationPayload {
     /// The base path of where the `biome.json` file was found.
     /// This has to be used to resolve other configuration files.
     pub configuration_directory_path: PathBuf,
+    /// The content of the `biome.json` file
+    pub content: String,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -565,7 +644,29 @@ pub fn load_configuration(
 ) -> Result<LoadedConfiguration, WorkspaceError> {
     let config = load_config(fs, config_path)?;
     let loaded_configuration = LoadedConfiguration::from(config);
-    loaded_configuration.apply_extends(fs)
+    loaded_configuration
+        .apply_extends(&**fs)?
+        .apply_parent(&**fs)
+}
+
+/// Load the configuration file of `directory`, without looking for it in the parent directories.
+///
+/// If the configuration has `root` set to `false`, its values are merged into `parent`, the
+/// configuration that applies to the parent directory.
+pub fn load_directory_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    parent: &Configuration,
+) -> Result<Option<LoadedConfiguration>, WorkspaceError> {
+    let Some(config) = read_directory_config(fs, directory)? else {
+        return Ok(None);
+    };
+    let loaded_configuration = LoadedConfiguration::from(Some(config)).apply_extends(fs)?;
+    if loaded_configuration.inherits_from_parent() && !loaded_configuration.has_errors() {
+        loaded_configuration.inherit(parent.clone()).map(Some)
+    } else {
+        Ok(Some(loaded_configuration))
+    }
 }
 
 /// Load the configuration from the file system.
@@ -581,8 +682,6 @@ fn load_config(
     file_system: &DynRef<'_, dyn FileSystem>,
     base_path: ConfigurationBasePath,
 ) -> LoadConfig {
-    let config_name = file_system.config_name();
-    let deprecated_config_name = file_system.deprecated_config_name();
     let working_directory = file_system.working_directory();
     let configuration_directory = match base_path {
         ConfigurationBasePath::Lsp(ref path) | ConfigurationBasePath::FromUser(ref path) => {
@@ -594,6 +693,17 @@ fn load_config(
         },
     };
     let should_error = base_path.is_from_user();
+    search_config(&**file_system, configuration_directory, should_error)
+}
+
+/// Looks for the configuration file in `configuration_directory` and its parents.
+fn search_config(
+    file_system: &dyn FileSystem,
+    configuration_directory: PathBuf,
+    should_error: bool,
+) -> LoadConfig {
+    let config_name = file_system.config_name();
+    let deprecated_config_name = file_system.deprecated_config_name();
 
     let auto_search_result;
     let result =
@@ -628,12 +738,50 @@ fn load_config(
             deserialized,
             configuration_file_path: file_path,
             configuration_directory_path: directory_path,
+            content,
         }))
     } else {
         Ok(None)
     }
 }
 
+/// Reads the configuration file of `directory`, the deprecated one is read if the other
+/// doesn't exist.
+fn read_directory_config(file_system: &dyn FileSystem, directory: &Path) -> LoadConfig {
+    for config_name in [
+        file_system.config_name(),
+        file_system.deprecated_config_name(),
+    ] {
+        let file_path = directory.join(config_name);
+        let mut content = String::new();
+        let result = file_system
+            .open_with_options(&file_path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content));
+        match result {
+            Ok(()) => {
+                let deserialized = deserialize_from_json_str::<Configuration>(
+                    &content,
+                    JsonParserOptions::default(),
+                );
+                return Ok(Some(ConfigurationPayload {
+                    deserialized,
+                    configuration_file_path: file_path,
+                    configuration_directory_path: directory.to_path_buf(),
+                    content,
+                }));
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(_) => {
+                return Err(WorkspaceError::cant_read_file(
+                    file_path.display().to_string(),
+                ))
+            }
+        }
+    }
+
+    Ok(None)
+}
+
 /// Creates a new configuration on file system
 ///
 /// ## Errors
@@ -688,6 +836,40 @@ pub fn create_config(
     Ok(())
 }
 
+/// Merges the JSON `values` into `base`: the objects are merged key by key, the other
+/// values are replaced
+fn merge_values(base: &mut serde_json::Value, values: serde_json::Value) {
+    match (base, values) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(values)) => {
+            for (key, value) in values {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_values(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, values) => *base = values,
+    }
+}
+
+/// Returns `configuration` once the JSON `values` are applied on top of it
+fn apply_values(
+    configuration: Configuration,
+    values: serde_json::Value,
+) -> Result<Configuration, WorkspaceError> {
+    let mut configuration_values = serde_json::to_value(configuration).map_err(|_| {
+        WorkspaceError::Configuration(ConfigurationDiagnostic::new_serialization_error())
+    })?;
+    merge_values(&mut configuration_values, values);
+    serde_json::from_value(configuration_values).map_err(|error| {
+        WorkspaceError::Configuration(ConfigurationDiagnostic::invalid_configuration(
+            error.to_string(),
+        ))
+    })
+}
+
 /// Returns the rules applied to a specific [Path], given the [WorkspaceSettings]
 pub fn to_analyzer_rules(settings: &WorkspaceSettings, path: &Path) -> AnalyzerRules {
     let linter_settings = &settings.linter;
@@ -711,6 +893,11 @@ pub struct LoadedConfiguration {
     pub configuration: Configuration,
     /// All diagnostics that were emitted during parsing and deserialization
     pub diagnostics: Vec<Error>,
+    /// The configuration files listed in `extends`, and the ones they extend
+    pub extended_files: Vec<ExtendedConfigurationFile>,
+    /// The values declared in the file, without the defaults filled by the deserialization.
+    /// They're only kept when the configuration inherits the one of its parent directory.
+    values: Option<serde_json::Value>,
 }
 
 impl LoadedConfiguration {
@@ -719,16 +906,8 @@ impl LoadedConfiguration {
     /// applied is itself.
     ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
-    pub fn apply_extends(
-        mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
-    ) -> Result<Self, WorkspaceError> {
-        let deserialized = self.deserialize_extends(fs)?;
-        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
-            .into_iter()
-            .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
-            .unzip();
+    pub fn apply_extends(mut self, fs: &dyn FileSystem) -> Result<Self, WorkspaceError> {
+        let (configurations, errors): (Vec<_>, Vec<_>) = self.load_extends(fs)?.into_iter().unzip();
 
         let extended_configuration = configurations.into_iter().reduce(
             |mut previous_configuration, current_configuration| {
@@ -737,8 +916,19 @@ impl LoadedConfiguration {
             },
         );
         let configuration = if let Some(mut extended_configuration) = extended_configuration {
+            if let Some(values) = self.values.take() {
+                let mut extended_values = serde_json::json!({});
+                for extended_file in &self.extended_files {
+                    merge_values(&mut extended_values, extended_file.values.clone());
+                }
+                merge_values(&mut extended_values, values);
+                self.values = Some(extended_values);
+            }
+            // `root` isn't merged, it only concerns the file that declares it
+            let root = self.configuration.root;
             // Here we want to keep only the values that aren't a default
             extended_configuration.merge_with_if_not_default(self.configuration);
+            extended_configuration.root = root;
             extended_configuration
         } else {
             self.configuration
@@ -762,14 +952,83 @@ impl LoadedConfiguration {
             diagnostics: self.diagnostics,
             file_path: self.file_path,
             directory_path: self.directory_path,
+            extended_files: self.extended_files,
+            values: self.values,
         })
     }
 
-    /// It attempts to deserialize all the configuration files that were specified in the `extends` property
-    fn deserialize_extends(
+    /// Whether the configuration inherits the one of its parent directory, see [Configuration::root]
+    pub fn inherits_from_parent(&self) -> bool {
+        self.configuration.root == Some(false)
+    }
+
+    /// Returns the paths of the configuration files listed in `extends`, and of the
+    /// files they extend themselves
+    pub fn extended_file_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![];
+        let mut extended_files: Vec<_> = self.extended_files.iter().collect();
+        while let Some(extended_file) = extended_files.pop() {
+            paths.push(extended_file.file_path.clone());
+            extended_files.extend(extended_file.extends.iter());
+        }
+        paths
+    }
+
+    /// Consumes itself to generate a new [LoadedConfiguration] that inherits the configuration
+    /// found in the parent directories, when `root` is set to `false`.
+    fn apply_parent(self, fs: &dyn FileSystem) -> Result<Self, WorkspaceError> {
+        let parent_directory = self
+            .directory_path
+            .as_deref()
+            .filter(|_| self.inherits_from_parent())
+            .and_then(Path::parent);
+        let Some(parent_directory) = parent_directory else {
+            return Ok(self);
+        };
+
+        let parent = search_config(fs, parent_directory.to_path_buf(), false)?;
+        let mut parent = LoadedConfiguration::from(parent)
+            .apply_extends(fs)?
+            .apply_parent(fs)?;
+        let mut loaded_configuration = if self.has_errors() || parent.has_errors() {
+            self
+        } else {
+            self.inherit(parent.configuration)?
+        };
+        parent
+            .diagnostics
+            .append(&mut loaded_configuration.diagnostics);
+        loaded_configuration.diagnostics = parent.diagnostics;
+        Ok(loaded_configuration)
+    }
+
+    /// Consumes itself to generate a new [LoadedConfiguration] where the `configuration` of the
+    /// parent directory is extended with the values declared in its own configuration file.
+    fn inherit(self, parent: Configuration) -> Result<Self, WorkspaceError> {
+        let Some(values) = self.values else {
+            // the file isn't plain JSON, only the values that aren't a default are kept
+            let mut configuration = parent;
+            configuration.merge_with_if_not_default(self.configuration);
+            return Ok(Self {
+                configuration,
+                ..self
+            });
+        };
+
+        let configuration = apply_values(parent, values)?;
+        Ok(Self {
+            configuration,
+            values: None,
+            ..self
+        })
+    }
+
+    /// Loads the configuration files listed in `extends`, resolved from the directory of the
+    /// configuration, and records them in `extended_files`.
+    fn load_extends(
         &mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
+        fs: &dyn FileSystem,
+    ) -> Result<Vec<(Configuration, Vec<Error>)>, WorkspaceError> {
         let Some(extends) = &self.configuration.extends else {
             return Ok(vec![]);
         };
@@ -779,34 +1038,17 @@ impl LoadedConfiguration {
             .as_ref()
             .cloned()
             .unwrap_or(fs.working_directory().unwrap_or(PathBuf::from("./")));
-        let mut deserialized_configurations = vec![];
-        for path in extends.iter() {
-            let config_path = directory_path.join(path);
-            let mut file = fs
-                .open_with_options(config_path.as_path(), OpenOptions::default().read(true))
-                .map_err(|err| {
-                    CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-                        markup!{
-								"Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
-							}
-                    )
-                })?;
-            let mut content = String::new();
-            file.read_to_string(&mut content).map_err(|err| {
-                CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
-                    markup!{
-							"It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
-						}
-                )
-
-            })?;
-            let deserialized = deserialize_from_json_str::<Configuration>(
-                content.as_str(),
-                JsonParserOptions::default(),
-            );
-            deserialized_configurations.push(deserialized)
+        let mut chain: Vec<_> = self.file_path.iter().cloned().collect();
+        let mut loaded_configurations = vec![];
+        let mut extended_files = vec![];
+        for specifier in extends.iter() {
+            let (configuration, diagnostics, extended_file) =
+                load_extended_file(fs, &directory_path, specifier, &mut chain)?;
+            loaded_configurations.push((configuration, diagnostics));
+            extended_files.push(extended_file);
         }
-        Ok(deserialized_configurations)
+        self.extended_files = extended_files;
+        Ok(loaded_configurations)
     }
 
     /// Return the path of the **directory** where the configuration is
@@ -871,10 +1113,22 @@ impl From<Option<ConfigurationPayload>> for LoadedConfiguration {
                 configuration_directory_path,
                 configuration_file_path,
                 deserialized,
+                content,
             } = value;
             let (configuration, diagnostics) = deserialized.consume();
+            let mut configuration = configuration.unwrap_or_default();
+            resolve_plugin_paths(&mut configuration, &configuration_directory_path);
+            let values = if configuration.root == Some(false) {
+                serde_json::from_str(&content).ok().map(|mut values| {
+                    resolve_plugin_values(&mut values, &configuration_directory_path);
+                    values
+                })
+            } else {
+                None
+            };
             LoadedConfiguration {
-                configuration: configuration.unwrap_or_default(),
+                configuration,
+                values,
                 diagnostics: diagnostics
                     .into_iter()
                     .map(|diagnostic| {
@@ -883,6 +1137,7 @@ impl From<Option<ConfigurationPayload>> for LoadedConfiguration {
                     .collect(),
                 directory_path: Some(configuration_directory_path),
                 file_path: Some(configuration_file_path),
+                extended_files: vec![],
             }
         } else {
             LoadedConfiguration::default()
@@ -0,0 +1,69 @@
+use biome_fs::normalize_path;
+use std::path::{Component, Path, PathBuf};
+
+/// Returns the directories that contain `path` below `root`, from the
+/// outermost one. Their configuration files apply to `path`.
+///
+/// `absolute` resolves a directory of `path` to compare it with `root`, which is
+/// normalized. The paths outside of `root` don't have nested directories, the
+/// configuration files of their parents don't belong to the workspace.
+pub fn nested_directories(
+    root: &Path,
+    path: &Path,
+    absolute: impl Fn(&Path) -> PathBuf,
+) -> Vec<PathBuf> {
+    let path = normalize_path(path);
+    if !is_inside(&absolute(&path), root) {
+        return vec![];
+    }
+
+    let mut directories: Vec<_> = path
+        .ancestors()
+        .skip(1)
+        .take_while(|directory| !directory.as_os_str().is_empty() && absolute(directory) != root)
+        .map(Path::to_path_buf)
+        .collect();
+    directories.reverse();
+    directories
+}
+
+/// Tells if `path` is below `root`, both paths are normalized. An empty root is
+/// the working directory of a file system that doesn't have one, its relative
+/// paths are inside as long as they don't start with `..`.
+fn is_inside(path: &Path, root: &Path) -> bool {
+    path.is_absolute() == root.is_absolute()
+        && path.starts_with(root)
+        && !matches!(path.components().next(), Some(Component::ParentDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nested_directories;
+    use biome_fs::normalize_path;
+    use std::path::{Path, PathBuf};
+
+    fn directories(root: &str, path: &str) -> Vec<PathBuf> {
+        nested_directories(Path::new(root), Path::new(path), normalize_path)
+    }
+
+    #[test]
+    fn returns_the_directories_below_the_root() {
+        assert_eq!(
+            directories("", "./a/b/../c/file.js"),
+            vec![PathBuf::from("a"), PathBuf::from("a/c")]
+        );
+        assert_eq!(
+            directories("/root", "/root/a/file.js"),
+            vec![PathBuf::from("/root/a")]
+        );
+        assert!(directories("", "file.js").is_empty());
+    }
+
+    #[test]
+    fn ignores_the_paths_outside_of_the_root() {
+        assert!(directories("", "../other/a/file.js").is_empty());
+        assert!(directories("", "/other/a/file.js").is_empty());
+        assert!(directories("/root", "/other/a/file.js").is_empty());
+        assert!(directories("/root", "/root/../other/file.js").is_empty());
+    }
+}
@@ -37,6 +37,7 @@ impl DeserializationVisitor for ConfigurationVisitor {
             "css",
             "$schema",
             "organizeImports",
+            "root",
             "extends",
             "overrides",
         ];
@@ -74,6 +75,9 @@ impl DeserializationVisitor for ConfigurationVisitor {
                     result.organize_imports =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "root" => {
+                    result.root = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "extends" => {
                     result.extends = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
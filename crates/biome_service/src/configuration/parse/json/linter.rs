@@ -1,4 +1,7 @@
-use crate::configuration::linter::{RulePlainConfiguration, RuleWithOptions};
+use crate::configuration::linter::{
+    PluginRuleConfiguration, PluginRuleOptions, PluginRuleWithOptions, RuleFixConfiguration,
+    RulePlainConfiguration, RuleWithOptions,
+};
 use crate::configuration::LinterConfiguration;
 use crate::RuleConfiguration;
 use biome_deserialize::{
@@ -30,7 +33,7 @@ impl DeserializationVisitor for LinterConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["enabled", "rules", "include", "ignore"];
+        const ALLOWED_KEYS: &[&str] = &["enabled", "rules", "include", "ignore", "plugins"];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -49,6 +52,9 @@ impl DeserializationVisitor for LinterConfigurationVisitor {
                 "rules" => {
                     result.rules = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "plugins" => {
+                    result.plugins = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -94,7 +100,7 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
         rule_name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["level", "options"];
+        const ALLOWED_KEYS: &[&str] = &["level", "fix", "options"];
         let mut result = RuleWithOptions::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -104,6 +110,9 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
                 "level" => {
                     result.level = Deserializable::deserialize(&value, &key_text, diagnostics)?;
                 }
+                "fix" => {
+                    result.fix = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "options" => {
                     result.options = Deserializable::deserialize(&value, rule_name, diagnostics);
                 }
@@ -118,6 +127,65 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
     }
 }
 
+impl Deserializable for PluginRuleConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        rule_name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PluginRuleConfigurationVisitor, rule_name, diagnostics)
+    }
+}
+
+struct PluginRuleConfigurationVisitor;
+impl DeserializationVisitor for PluginRuleConfigurationVisitor {
+    type Output = PluginRuleConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+
+    fn visit_str(
+        self,
+        value: Text,
+        range: TextRange,
+        _rule_name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)
+            .map(PluginRuleConfiguration::Plain)
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        rule_name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["level", "options"];
+        let mut result = PluginRuleWithOptions::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "level" => {
+                    result.level = Deserializable::deserialize(&value, &key_text, diagnostics)?;
+                }
+                "options" => {
+                    result.options = serde_json::Value::deserialize(&value, rule_name, diagnostics)
+                        .map(PluginRuleOptions::from);
+                }
+                unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    unknown_key,
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(PluginRuleConfiguration::WithOptions(result))
+    }
+}
+
 impl RulePlainConfiguration {
     fn deserialize_from_str(
         value: Text,
@@ -148,3 +216,24 @@ impl Deserializable for RulePlainConfiguration {
         Self::deserialize_from_str(value_text, value.range(), diagnostics)
     }
 }
+
+impl Deserializable for RuleFixConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["none", "safe", "unsafe"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
@@ -75,6 +75,10 @@ impl Deserializable for Rules {
                             result.suspicious =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "plugin" => {
+                            result.plugin =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         unknown_key => {
                             diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                                 unknown_key,
@@ -90,6 +94,7 @@ impl Deserializable for Rules {
                                     "security",
                                     "style",
                                     "suspicious",
+                                    "plugin",
                                 ],
                             ));
                         }
@@ -930,6 +935,13 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noFloatingPromises" => {
+                            result.no_floating_promises = Deserializable::deserialize(
+                                &value,
+                                "noFloatingPromises",
+                                diagnostics,
+                            );
+                        }
                         "noGlobalAssign" => {
                             result.no_global_assign =
                                 Deserializable::deserialize(&value, "noGlobalAssign", diagnostics);
@@ -996,6 +1008,13 @@ impl Deserializable for Nursery {
                             result.use_await =
                                 Deserializable::deserialize(&value, "useAwait", diagnostics);
                         }
+                        "useExhaustiveSwitchCases" => {
+                            result.use_exhaustive_switch_cases = Deserializable::deserialize(
+                                &value,
+                                "useExhaustiveSwitchCases",
+                                diagnostics,
+                            );
+                        }
                         "useExportType" => {
                             result.use_export_type =
                                 Deserializable::deserialize(&value, "useExportType", diagnostics);
@@ -1075,6 +1094,7 @@ impl Deserializable for Nursery {
                                     "noDefaultExport",
                                     "noDuplicateJsonKeys",
                                     "noEmptyBlockStatements",
+                                    "noFloatingPromises",
                                     "noGlobalAssign",
                                     "noGlobalEval",
                                     "noImplicitAnyLet",
@@ -1087,6 +1107,7 @@ impl Deserializable for Nursery {
                                     "noUselessLoneBlockStatements",
                                     "noUselessTernary",
                                     "useAwait",
+                                    "useExhaustiveSwitchCases",
                                     "useExportType",
                                     "useFilenamingConvention",
                                     "useForOf",
@@ -0,0 +1,101 @@
+//! The plugins listed in `linter.plugins`. Their paths are relative to the
+//! configuration file that lists them, so they're resolved when the file is
+//! loaded, before it's merged with the configurations it extends or inherits.
+
+use crate::configuration::linter::{PluginRuleConfiguration, RulePlainConfiguration};
+use crate::settings::LinterSettings;
+use crate::{Configuration, WorkspaceError};
+use biome_deserialize::StringSet;
+use biome_diagnostics::Severity;
+use biome_fs::{normalize_path, FileSystem};
+use biome_plugin::{
+    compile_css_plugins, compile_js_plugins, load_plugin, Plugin, PluginRuleSettings,
+};
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// Reads the plugin files listed in the linter configuration
+pub fn load_plugins(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Vec<Plugin>, WorkspaceError> {
+    let Some(plugins) = configuration
+        .linter
+        .as_ref()
+        .and_then(|linter| linter.plugins.as_ref())
+    else {
+        return Ok(Vec::new());
+    };
+
+    let mut loaded_plugins = Vec::with_capacity(plugins.len());
+    for path in plugins.iter() {
+        loaded_plugins.push(load_plugin(fs, Path::new(path))?);
+    }
+    Ok(loaded_plugins)
+}
+
+/// Resolves the paths of the plugins of `configuration` from `directory`, the
+/// directory of its configuration file
+pub(super) fn resolve_plugin_paths(configuration: &mut Configuration, directory: &Path) {
+    let Some(plugins) = configuration
+        .linter
+        .as_mut()
+        .and_then(|linter| linter.plugins.as_mut())
+    else {
+        return;
+    };
+
+    *plugins = StringSet::new(
+        plugins
+            .iter()
+            .map(|path| resolve_plugin_path(path, directory))
+            .collect(),
+    );
+}
+
+/// Resolves the paths of the plugins of the JSON `values` of a configuration file
+/// from `directory`, the directory of the file
+pub(super) fn resolve_plugin_values(values: &mut serde_json::Value, directory: &Path) {
+    let Some(plugins) = values
+        .pointer_mut("/linter/plugins")
+        .and_then(serde_json::Value::as_array_mut)
+    else {
+        return;
+    };
+
+    for plugin in plugins {
+        if let serde_json::Value::String(path) = plugin {
+            *path = resolve_plugin_path(path, directory);
+        }
+    }
+}
+
+fn resolve_plugin_path(path: &str, directory: &Path) -> String {
+    normalize_path(&directory.join(path)).display().to_string()
+}
+
+/// Compiles the rules of `plugins` into the `linter` settings, with the levels
+/// and options set in `linter.rules.plugin`
+pub(crate) fn compile_plugins(
+    plugins: &[Plugin],
+    plugin_rules: &IndexMap<String, PluginRuleConfiguration>,
+    linter: &mut LinterSettings,
+) -> Result<(), WorkspaceError> {
+    let settings = |name: &str, severity: Severity| {
+        let Some(configuration) = plugin_rules.get(name) else {
+            return Some(severity.into());
+        };
+        match configuration.level() {
+            RulePlainConfiguration::Off => None,
+            level => Some(PluginRuleSettings {
+                severity: level.into(),
+                options: configuration
+                    .options()
+                    .map(|options| options.as_str().to_string()),
+            }),
+        }
+    };
+    linter.plugins = compile_js_plugins(plugins, settings)?;
+    linter.css_plugins = compile_css_plugins(plugins, settings)?;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::configuration::merge::MergeWith;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 const GIT_IGNORE_FILE_NAME: &str = ".gitignore";
@@ -50,6 +51,17 @@ impl VcsConfiguration {
     pub const fn ignore_file_disabled(&self) -> bool {
         matches!(self.use_ignore_file, Some(false))
     }
+
+    /// Resolves the folder where Biome checks for VCS files, from the folder
+    /// where the configuration was found. Returns `None` when neither is known.
+    pub fn resolve_root(&self, vcs_base_path: Option<&Path>) -> Option<PathBuf> {
+        match (vcs_base_path, &self.root) {
+            (Some(vcs_base_path), Some(root)) => Some(vcs_base_path.join(root)),
+            (None, Some(root)) => Some(PathBuf::from(root)),
+            (Some(vcs_base_path), None) => Some(PathBuf::from(vcs_base_path)),
+            (None, None) => None,
+        }
+    }
 }
 
 impl MergeWith<VcsConfiguration> for VcsConfiguration {
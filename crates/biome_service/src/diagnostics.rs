@@ -9,6 +9,7 @@ use biome_formatter::{FormatError, PrintError};
 use biome_fs::{FileSystemDiagnostic, RomePath};
 use biome_js_analyze::utils::rename::RenameError;
 use biome_js_analyze::RuleError;
+use biome_pattern::PatternDiagnostic;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
@@ -56,6 +57,8 @@ pub enum WorkspaceError {
     Vcs(VcsDiagnostic),
     /// Diagnostic raised when a file is protected
     ProtectedFile(ProtectedFile),
+    /// Emitted when a pattern used to search the code isn't valid
+    Pattern(PatternDiagnostic),
 }
 
 impl WorkspaceError {
@@ -150,6 +153,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.category(),
             WorkspaceError::Vcs(error) => error.category(),
             WorkspaceError::ProtectedFile(error) => error.category(),
+            WorkspaceError::Pattern(error) => error.category(),
         }
     }
 
@@ -173,6 +177,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.description(fmt),
             WorkspaceError::Vcs(error) => error.description(fmt),
             WorkspaceError::ProtectedFile(error) => error.description(fmt),
+            WorkspaceError::Pattern(error) => error.description(fmt),
         }
     }
 
@@ -196,6 +201,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.message(fmt),
             WorkspaceError::Vcs(error) => error.message(fmt),
             WorkspaceError::ProtectedFile(error) => error.message(fmt),
+            WorkspaceError::Pattern(error) => error.message(fmt),
         }
     }
 
@@ -219,6 +225,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.severity(),
             WorkspaceError::Vcs(error) => error.severity(),
             WorkspaceError::ProtectedFile(error) => error.severity(),
+            WorkspaceError::Pattern(error) => error.severity(),
         }
     }
 
@@ -242,6 +249,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.tags(),
             WorkspaceError::Vcs(error) => error.tags(),
             WorkspaceError::ProtectedFile(error) => error.tags(),
+            WorkspaceError::Pattern(error) => error.tags(),
         }
     }
 
@@ -265,6 +273,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.location(),
             WorkspaceError::Vcs(error) => error.location(),
             WorkspaceError::ProtectedFile(error) => error.location(),
+            WorkspaceError::Pattern(error) => error.location(),
         }
     }
 
@@ -288,6 +297,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => Diagnostic::source(error),
             WorkspaceError::Vcs(error) => Diagnostic::source(error),
             WorkspaceError::ProtectedFile(error) => Diagnostic::source(error),
+            WorkspaceError::Pattern(error) => Diagnostic::source(error),
         }
     }
 
@@ -311,6 +321,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.advices(visitor),
             WorkspaceError::Vcs(error) => error.advices(visitor),
             WorkspaceError::ProtectedFile(error) => error.advices(visitor),
+            WorkspaceError::Pattern(error) => error.advices(visitor),
         }
     }
     fn verbose_advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
@@ -333,6 +344,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::FileSystem(error) => error.verbose_advices(visitor),
             WorkspaceError::Vcs(error) => error.verbose_advices(visitor),
             WorkspaceError::ProtectedFile(error) => error.verbose_advices(visitor),
+            WorkspaceError::Pattern(error) => error.verbose_advices(visitor),
         }
     }
 }
@@ -361,6 +373,12 @@ impl From<FileSystemDiagnostic> for WorkspaceError {
     }
 }
 
+impl From<PatternDiagnostic> for WorkspaceError {
+    fn from(err: PatternDiagnostic) -> Self {
+        Self::Pattern(err)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
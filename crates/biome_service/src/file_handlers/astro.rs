@@ -0,0 +1,85 @@
+use super::embedded::{self, text_size, EmbeddedScript, EmbeddingLanguage};
+use super::{Capabilities, ExtensionHandler, Language, Mime};
+use biome_js_syntax::{JsFileSource, TextRange};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct AstroFileHandler;
+
+impl ExtensionHandler for AstroFileHandler {
+    fn language(&self) -> Language {
+        Language::Astro
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Html
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        embedded::capabilities::<Self>()
+    }
+}
+
+impl EmbeddingLanguage for AstroFileHandler {
+    /// The global object of the components
+    const GLOBALS: &'static [&'static str] = &["Astro"];
+
+    /// Returns the frontmatter of the component, the TypeScript code between
+    /// the `---` fences at the start of the file.
+    fn scripts(text: &str) -> Vec<EmbeddedScript> {
+        frontmatter(text)
+            .map(|range| EmbeddedScript {
+                range,
+                file_source: JsFileSource::ts(),
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Returns the range of the code between the `---` fences, from the line after
+/// the opening fence to the start of the line of the closing fence
+fn frontmatter(text: &str) -> Option<TextRange> {
+    let fence_start = text.len() - text.trim_start().len();
+    let after_fence = text[fence_start..].strip_prefix("---")?;
+    let line_end = after_fence.find('\n')?;
+    if !after_fence[..line_end].trim().is_empty() {
+        return None;
+    }
+
+    let start = text.len() - after_fence.len() + line_end + 1;
+    let mut line_start = start;
+    for line in text[start..].split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some(TextRange::new(text_size(start), text_size(line_start)));
+        }
+        line_start += line.len();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AstroFileHandler;
+    use crate::file_handlers::embedded::EmbeddingLanguage;
+
+    #[test]
+    fn finds_the_frontmatter() {
+        let text = "\n---\nconst { title } = Astro.props;\n---\n\n<h1>{title}</h1>\n";
+
+        let scripts = AstroFileHandler::scripts(text);
+
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(&text[scripts[0].range], "const { title } = Astro.props;\n");
+    }
+
+    #[test]
+    fn ignores_the_files_without_frontmatter() {
+        assert!(AstroFileHandler::scripts("<h1>---</h1>\n").is_empty());
+        assert!(AstroFileHandler::scripts("---\nconst a = 1;\n").is_empty());
+    }
+}
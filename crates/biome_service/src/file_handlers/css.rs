@@ -1,25 +1,41 @@
 use super::{ExtensionHandler, Mime};
+use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::{
-    AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
+    fix_applicability, is_diagnostic_error, AnalyzerCapabilities, Capabilities, FixAllParams,
+    FormatterCapabilities, LintParams, LintResults, ParserCapabilities, SearchCapabilities,
+    TransformerCapabilities,
 };
 use crate::file_handlers::{DebugCapabilities, Language as LanguageId};
 use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
-    SettingsHandle,
+    SettingsHandle, WorkspaceSettings,
 };
-use crate::workspace::GetSyntaxTreeResult;
-use crate::WorkspaceError;
+use crate::workspace::{
+    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+};
+use crate::{Rules, WorkspaceError};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories,
+    RuleFilter, PLUGIN_GROUP,
+};
+use biome_css_analyze::analyze_with_plugins;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::{can_format_css_yet, format_node};
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::{CssFileSource, CssLanguage, CssRoot, CssSyntaxNode};
+use biome_diagnostics::{category, Applicability, Diagnostic, Severity};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::RomePath;
+use biome_js_analyze::RuleError;
+use biome_js_type_info::ProjectDeclarations;
 use biome_parser::AnyParse;
-use biome_rowan::{FileSource, NodeCache};
+use biome_rowan::{AstNode, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -106,10 +122,10 @@ impl ExtensionHandler for CssFileHandler {
                 debug_formatter_ir: Some(debug_formatter_ir),
             },
             analyzer: AnalyzerCapabilities {
-                lint: None,
-                code_actions: None,
+                lint: Some(lint),
+                code_actions: Some(code_actions),
                 rename: None,
-                fix_all: None,
+                fix_all: Some(fix_all),
                 organize_imports: None,
             },
             // TODO(faulty): Once the CSS formatter is sufficiently stable, we
@@ -130,6 +146,11 @@ impl ExtensionHandler for CssFileHandler {
                     format_on_type: None,
                 }
             },
+            transformer: TransformerCapabilities { transform: None },
+            search: SearchCapabilities {
+                search_pattern: None,
+                rewrite: None,
+            },
         }
     }
 }
@@ -141,19 +162,12 @@ fn parse(
     settings: SettingsHandle,
     cache: &mut NodeCache,
 ) -> AnyParse {
-    let parser = &settings.as_ref().languages.css.parser;
-    let overrides = &settings.as_ref().override_settings;
     let source_type =
         CssFileSource::try_from(rome_path.as_path()).unwrap_or_else(|_| match language_hint {
             LanguageId::Css => CssFileSource::css(),
             _ => CssFileSource::css(),
         });
-    let options: CssParserOptions =
-        overrides
-            .as_css_parser_options(rome_path)
-            .unwrap_or(CssParserOptions {
-                allow_wrong_line_comments: parser.allow_wrong_line_comments,
-            });
+    let options = parser_options(rome_path, settings.as_ref());
     let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -165,6 +179,20 @@ fn parse(
     )
 }
 
+/// Returns the options of the parser for the file `rome_path`
+pub(super) fn parser_options(
+    rome_path: &RomePath,
+    settings: &WorkspaceSettings,
+) -> CssParserOptions {
+    let parser = &settings.languages.css.parser;
+    settings
+        .override_settings
+        .as_css_parser_options(rome_path)
+        .unwrap_or(CssParserOptions {
+            allow_wrong_line_comments: parser.allow_wrong_line_comments,
+        })
+}
+
 fn debug_syntax_tree(_rome_path: &RomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: CssSyntaxNode = parse.syntax();
     let tree: CssRoot = parse.tree();
@@ -255,3 +283,251 @@ fn format_on_type(
     let printed = biome_css_formatter::format_sub_tree(options, &root_node)?;
     Ok(printed)
 }
+
+fn lint(params: LintParams) -> LintResults {
+    tracing::debug_span!("Linting CSS file", path =? params.path, language =? params.language)
+        .in_scope(move || {
+            let root: CssRoot = params.parse.tree();
+            let mut diagnostics = params.parse.into_diagnostics();
+
+            let mut diagnostic_count = diagnostics.len() as u64;
+            let mut errors = diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            // The CSS files are only analyzed by the rules of the plugins
+            let plugins = &params.settings.as_ref().linter.css_plugins;
+            if plugins.is_empty() {
+                return LintResults {
+                    diagnostics,
+                    errors,
+                    skipped_diagnostics: 0,
+                };
+            }
+
+            let has_lint = params.filter.categories.contains(RuleCategories::LINT);
+            let analyzer_options =
+                compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+
+            let (_, analyze_diagnostics) =
+                analyze_with_plugins(&root, params.filter, &analyzer_options, plugins, |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if !has_lint
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
+
+                        diagnostic_count += 1;
+
+                        if is_diagnostic_error(&diagnostic, params.rules) {
+                            errors += 1;
+                        }
+
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            // The severity of the plugin rules is resolved when their plugins are loaded
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(diagnostic));
+                        }
+                    }
+
+                    ControlFlow::<Never>::Continue(())
+                });
+
+            diagnostics.extend(
+                analyze_diagnostics
+                    .into_iter()
+                    .map(biome_diagnostics::serde::Diagnostic::new)
+                    .collect::<Vec<_>>(),
+            );
+            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u64);
+
+            LintResults {
+                diagnostics,
+                errors,
+                skipped_diagnostics,
+            }
+        })
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn code_actions(
+    parse: AnyParse,
+    range: TextRange,
+    _rules: Option<&Rules>,
+    settings: SettingsHandle,
+    path: &RomePath,
+    _declarations: &Arc<ProjectDeclarations>,
+) -> PullActionsResult {
+    let plugins = &settings.as_ref().linter.css_plugins;
+    if plugins.is_empty() {
+        return PullActionsResult {
+            actions: Vec::new(),
+        };
+    }
+
+    let tree: CssRoot = parse.tree();
+    let mut actions = Vec::new();
+
+    let enabled_rules = [RuleFilter::Group(PLUGIN_GROUP)];
+    let mut filter = AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()));
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT | RuleCategories::ACTION;
+    filter.range = Some(range);
+
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
+
+    analyze_with_plugins(&tree, filter, &analyzer_options, plugins, |signal| {
+        actions.extend(signal.actions().into_code_action_iter().map(|item| {
+            CodeAction {
+                category: item.category.clone(),
+                rule_name: item
+                    .rule_name
+                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                suggestion: item.suggestion,
+            }
+        }));
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    PullActionsResult { actions }
+}
+
+/// Applies the fixes of the rules of the plugins to the given syntax tree.
+///
+/// If `should_format` is `true`, the file is formatted at the end
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let FixAllParams {
+        parse,
+        rules,
+        fix_file_mode,
+        settings,
+        should_format,
+        rome_path,
+        declarations: _,
+        mut filter,
+    } = params;
+
+    let mut tree: CssRoot = parse.tree();
+    let mut actions = Vec::new();
+
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    loop {
+        let (action, _) = analyze_with_plugins(
+            &tree,
+            filter,
+            &analyzer_options,
+            &settings.as_ref().linter.css_plugins,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules) {
+                        errors += 1;
+                    }
+                }
+
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
+                    // the configuration of the rule can override the safety of its fixes
+                    let Some(applicability) =
+                        fix_applicability(action.rule_name, action.applicability, rules)
+                    else {
+                        continue;
+                    };
+
+                    match fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                    }
+                }
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        match action {
+            Some(action) => {
+                if let Some((range, _)) = action.mutation.as_text_edits() {
+                    tree = match CssRoot::cast(action.mutation.commit()) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(WorkspaceError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: action.rule_name.map(|(group, rule)| {
+                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
+                                    }),
+                                },
+                            ))
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: action
+                            .rule_name
+                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        range,
+                    });
+                }
+            }
+            None => {
+                let code = if should_format {
+                    format_node(
+                        settings.format_options::<CssLanguage>(rome_path),
+                        tree.syntax(),
+                    )?
+                    .print()?
+                    .into_code()
+                } else {
+                    tree.syntax().to_string()
+                };
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                });
+            }
+        }
+    }
+}
+
+fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
+    let configuration = AnalyzerConfiguration {
+        rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
+        globals: vec![],
+    };
+    AnalyzerOptions {
+        configuration,
+        file_path,
+    }
+}
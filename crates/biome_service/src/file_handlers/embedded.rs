@@ -0,0 +1,612 @@
+//! Support for the languages that embed JavaScript code in blocks of another
+//! syntax, e.g. the `<script>` blocks of Vue files.
+//!
+//! The JavaScript parser only sees the code of the scripts: the rest of the
+//! file is replaced by block comments of the same length before parsing, and
+//! these comments are then swapped in the syntax tree for skipped token trivia
+//! that contain the original text. This way the offsets of the syntax tree are
+//! the offsets of the file, and printing the tree returns the file as it was,
+//! with the markup around the scripts untouched.
+
+use super::astro::AstroFileHandler;
+use super::javascript::{self, JsFileHandler};
+use super::svelte::SvelteFileHandler;
+use super::vue::VueFileHandler;
+use super::{
+    Capabilities, ExtensionHandler, FixAllParams, Language as LanguageId, LintParams, LintResults,
+    ParserCapabilities, SearchCapabilities, TransformerCapabilities,
+};
+use crate::settings::SettingsHandle;
+use crate::workspace::{FixFileResult, OrganizeImportsResult, PullActionsResult};
+use crate::{Rules, WorkspaceError};
+use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_fs::RomePath;
+use biome_js_syntax::{
+    JsFileSource, JsLanguage, JsSyntaxNode, LanguageVariant, TextRange, TextSize,
+};
+use biome_js_type_info::ProjectDeclarations;
+use biome_parser::AnyParse;
+use biome_rowan::{BatchMutation, FileSource, NodeCache, TriviaPieceKind};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use std::path::Path;
+use std::sync::Arc;
+
+/// A language that embeds JavaScript code in blocks of another syntax
+pub(crate) trait EmbeddingLanguage {
+    /// The global variables that the framework makes available to the scripts
+    const GLOBALS: &'static [&'static str];
+
+    /// Returns the scripts of a file, sorted by position
+    fn scripts(text: &str) -> Vec<EmbeddedScript>;
+}
+
+/// A block of JavaScript code embedded in a file
+#[derive(Debug)]
+pub(crate) struct EmbeddedScript {
+    /// The range of the code in the file
+    pub(crate) range: TextRange,
+    /// The kind of code of the script
+    pub(crate) file_source: JsFileSource,
+}
+
+/// Returns the capabilities of the JavaScript handler, which work on the
+/// scripts of the files of the language `E`
+pub(super) fn capabilities<E: EmbeddingLanguage>() -> Capabilities {
+    let Capabilities {
+        debug,
+        mut analyzer,
+        formatter,
+        ..
+    } = JsFileHandler.capabilities();
+
+    analyzer.lint = Some(lint::<E>);
+    analyzer.code_actions = Some(code_actions::<E>);
+    analyzer.fix_all = Some(fix_all::<E>);
+    analyzer.organize_imports = Some(organize_imports::<E>);
+
+    Capabilities {
+        parser: ParserCapabilities {
+            parse: Some(parse::<E>),
+        },
+        debug,
+        analyzer,
+        formatter,
+        transformer: TransformerCapabilities::default(),
+        search: SearchCapabilities::default(),
+    }
+}
+
+/// Returns the global variables that the framework of the file at `path`
+/// makes available to its scripts
+pub(crate) fn embedding_globals(path: &Path) -> &'static [&'static str] {
+    match LanguageId::from_path(path) {
+        LanguageId::Vue => VueFileHandler::GLOBALS,
+        LanguageId::Svelte => SvelteFileHandler::GLOBALS,
+        LanguageId::Astro => AstroFileHandler::GLOBALS,
+        _ => &[],
+    }
+}
+
+fn parse<E: EmbeddingLanguage>(
+    rome_path: &RomePath,
+    _language_hint: LanguageId,
+    text: &str,
+    settings: SettingsHandle,
+    cache: &mut NodeCache,
+) -> AnyParse {
+    let scripts = E::scripts(text);
+    let file_source = scripts
+        .iter()
+        .map(|script| script.file_source)
+        .reduce(|file_source, script_source| {
+            let file_source = if script_source.language().is_typescript() {
+                JsFileSource::ts().with_variant(file_source.variant())
+            } else {
+                file_source
+            };
+            if script_source.variant().is_jsx() {
+                file_source.with_variant(LanguageVariant::Jsx)
+            } else {
+                file_source
+            }
+        })
+        .unwrap_or_default();
+
+    let regions = embedding_regions(text, &scripts);
+    let masked = mask_regions(text, &regions);
+
+    let options = javascript::parser_options(rome_path, settings.as_ref());
+    let parse = biome_js_parser::parse_js_with_cache(&masked, file_source, options, cache);
+    let root = restore_regions(parse.syntax(), text, &regions);
+    let diagnostics = parse.into_diagnostics();
+    AnyParse::new(
+        // SAFETY: the parser should always return a root node
+        root.as_send().unwrap(),
+        diagnostics,
+        file_source.as_any_file_source(),
+    )
+}
+
+fn lint<E: EmbeddingLanguage>(params: LintParams) -> LintResults {
+    let text = params.parse.syntax::<JsLanguage>().to_string();
+    let regions = embedding_regions(&text, &E::scripts(&text));
+    javascript::lint_with_suggestions(params, |action| {
+        let mut advice = CodeSuggestionAdvice::from(action);
+        if let Some(suggestion) = keep_regions_in_edit(&text, &advice.suggestion, &regions) {
+            advice.suggestion = suggestion;
+        }
+        advice
+    })
+}
+
+fn code_actions<E: EmbeddingLanguage>(
+    parse: AnyParse,
+    range: TextRange,
+    rules: Option<&Rules>,
+    settings: SettingsHandle,
+    path: &RomePath,
+    declarations: &Arc<ProjectDeclarations>,
+) -> PullActionsResult {
+    let text = parse.syntax::<JsLanguage>().to_string();
+    let regions = embedding_regions(&text, &E::scripts(&text));
+    let mut result = javascript::code_actions(parse, range, rules, settings, path, declarations);
+
+    for action in &mut result.actions {
+        let edit = &action.suggestion.suggestion;
+        if let Some(suggestion) = keep_regions_in_edit(&text, edit, &regions) {
+            action.suggestion.suggestion = suggestion;
+        }
+    }
+
+    result
+}
+
+fn fix_all<E: EmbeddingLanguage>(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let text = params.parse.syntax::<JsLanguage>().to_string();
+    let mut result = javascript::fix_all(params)?;
+    result.code = keep_regions(
+        &text,
+        &result.code,
+        &embedding_regions(&text, &E::scripts(&text)),
+    );
+    Ok(result)
+}
+
+fn organize_imports<E: EmbeddingLanguage>(
+    parse: AnyParse,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let text = parse.syntax::<JsLanguage>().to_string();
+    let mut result = javascript::organize_imports(parse)?;
+    result.code = keep_regions(
+        &text,
+        &result.code,
+        &embedding_regions(&text, &E::scripts(&text)),
+    );
+    Ok(result)
+}
+
+/// Returns the `<script>` tags at the top level of an HTML-like file that
+/// contain JavaScript code, with the language of their `lang` attribute.
+///
+/// The other tags are skipped along with their content, as are the HTML
+/// comments.
+pub(super) fn script_tags(text: &str) -> Vec<EmbeddedScript> {
+    let mut scripts = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = text[position..].find('<') {
+        let start = position + offset;
+        let rest = &text[start..];
+
+        if rest.starts_with("<!--") {
+            position = rest.find("-->").map_or(text.len(), |end| start + end + 3);
+            continue;
+        }
+
+        let name = tag_name(&rest[1..]);
+        if name.is_empty() {
+            position = start + 1;
+            continue;
+        }
+
+        let attributes_start = start + 1 + name.len();
+        let Some(tag_end) = opening_tag_end(text, attributes_start) else {
+            break;
+        };
+        if text[..tag_end].ends_with("/>") {
+            position = tag_end;
+            continue;
+        }
+
+        if name == "script" {
+            let content_end = text[tag_end..]
+                .find("</script")
+                .map_or(text.len(), |end| tag_end + end);
+            let attributes = &text[attributes_start..tag_end - 1];
+            if let Some(file_source) = script_file_source(attributes) {
+                scripts.push(EmbeddedScript {
+                    range: TextRange::new(text_size(tag_end), text_size(content_end)),
+                    file_source,
+                });
+            }
+            position = content_end;
+        } else {
+            position = closing_tag_end(text, tag_end, name);
+        }
+    }
+
+    scripts
+}
+
+/// Returns the kind of code of a `<script>` tag with the given attributes, or
+/// [None] if its `type` isn't JavaScript, e.g. `application/ld+json`
+fn script_file_source(attributes: &str) -> Option<JsFileSource> {
+    let is_javascript = attribute(attributes, "type").map_or(true, |script_type| {
+        matches!(
+            script_type,
+            "" | "module" | "text/javascript" | "application/javascript"
+        )
+    });
+    if !is_javascript {
+        return None;
+    }
+
+    let file_source = match attribute(attributes, "lang") {
+        Some("ts") => JsFileSource::ts(),
+        Some("tsx") => JsFileSource::tsx(),
+        Some("jsx") => JsFileSource::jsx(),
+        _ => JsFileSource::js_module(),
+    };
+    Some(file_source)
+}
+
+/// Returns the name of the tag at the start of `text`
+fn tag_name(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | ':')))
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+/// Returns the position after the `>` of the tag whose attributes start at `position`
+fn opening_tag_end(text: &str, position: usize) -> Option<usize> {
+    let mut quote = None;
+    for (offset, c) in text[position..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if open == c => quote = None,
+            (None, '>') => return Some(position + offset + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the position after the tag that closes the `name` tag opened
+/// before `position`, taking into account the tags with the same name
+/// nested inside it, e.g. the `<template>` tags inside a Vue template.
+fn closing_tag_end(text: &str, position: usize, name: &str) -> usize {
+    let mut depth = 1;
+    let mut position = position;
+
+    while let Some(offset) = text[position..].find('<') {
+        let start = position + offset;
+        let rest = &text[start + 1..];
+        if let Some(closing) = rest.strip_prefix('/') {
+            if tag_name(closing) == name {
+                depth -= 1;
+                if depth == 0 {
+                    return opening_tag_end(text, start + 2 + name.len()).unwrap_or(text.len());
+                }
+            }
+        } else if tag_name(rest) == name {
+            match opening_tag_end(text, start + 1 + name.len()) {
+                Some(tag_end) if !text[..tag_end].ends_with("/>") => depth += 1,
+                _ => {}
+            }
+        }
+        position = start + 1;
+    }
+
+    text.len()
+}
+
+/// Returns the value of the `name` attribute in a list of attributes.
+/// An attribute without value returns an empty string.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes.trim_start();
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let attribute_name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let mut value = "";
+        if let Some(value_start) = rest.strip_prefix('=') {
+            let value_start = value_start.trim_start();
+            let (value_text, value_rest) = match value_start.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value_end = value_start[1..]
+                        .find(quote)
+                        .map_or(value_start.len(), |end| end + 1);
+                    (
+                        &value_start[1..value_end],
+                        value_start.get(value_end + 1..).unwrap_or_default(),
+                    )
+                }
+                _ => {
+                    let value_end = value_start
+                        .find(char::is_whitespace)
+                        .unwrap_or(value_start.len());
+                    value_start.split_at(value_end)
+                }
+            };
+            value = value_text;
+            rest = value_rest;
+        }
+
+        if attribute_name == name {
+            return Some(value);
+        }
+        rest = rest.trim_start();
+    }
+
+    None
+}
+
+/// Returns the ranges of the file that are outside the scripts. The
+/// whitespace at the end of the file isn't part of the last range, so that
+/// the formatter can manage it.
+fn embedding_regions(text: &str, scripts: &[EmbeddedScript]) -> Vec<TextRange> {
+    let mut regions = Vec::new();
+    let mut start = TextSize::from(0);
+
+    for script in scripts.iter().filter(|script| !script.range.is_empty()) {
+        if start < script.range.start() {
+            regions.push(TextRange::new(start, script.range.start()));
+        }
+        start = script.range.end();
+    }
+
+    let end = text_size(text.trim_end().len());
+    if start < end {
+        regions.push(TextRange::new(start, end));
+    }
+
+    regions
+}
+
+/// Replaces the regions of `text` with block comments of the same length.
+///
+/// The comments of the regions that follow some code start on a new line, so
+/// that the parser attaches them to the leading trivia of the next token and
+/// inserts a semicolon between the code of two script blocks if needed.
+fn mask_regions(text: &str, regions: &[TextRange]) -> String {
+    let mut masked = String::with_capacity(text.len());
+    let mut position = 0;
+
+    for region in regions {
+        let start = usize::from(region.start());
+        let end = usize::from(region.end());
+        masked.push_str(&text[position..start]);
+
+        let mut mask: Vec<u8> = text[start..end]
+            .bytes()
+            .map(|byte| if byte == b'\n' { b'\n' } else { b' ' })
+            .collect();
+
+        // A region too short to contain a comment only happens in a file
+        // without any script, and then it's part of the trivia of the end of
+        // the file anyway
+        if mask.len() >= 4 {
+            let comment_start = if start > 0 && mask.len() >= 5 {
+                mask[0] = b'\n';
+                1
+            } else {
+                0
+            };
+            let len = mask.len();
+            mask[comment_start..comment_start + 2].copy_from_slice(b"/*");
+            mask[len - 2..].copy_from_slice(b"*/");
+        }
+
+        // SAFETY: the mask only contains ASCII characters
+        masked.push_str(std::str::from_utf8(&mask).unwrap());
+        position = end;
+    }
+
+    masked.push_str(&text[position..]);
+    masked
+}
+
+/// Replaces the trivia pieces that cover each region of the masked tree with a
+/// skipped piece that contains the text of the region.
+fn restore_regions(root: JsSyntaxNode, text: &str, regions: &[TextRange]) -> JsSyntaxNode {
+    let mut mutation = BatchMutation::new(root.clone());
+    let mut has_changes = false;
+
+    for token in root.descendants_tokens(biome_rowan::Direction::Next) {
+        let leading_trivia = token.leading_trivia();
+        let intersects_region = leading_trivia
+            .pieces()
+            .any(|piece| region_of(regions, piece.text_range()).is_some());
+        if !intersects_region {
+            continue;
+        }
+
+        let mut pieces: Vec<(TriviaPieceKind, &str)> = Vec::new();
+        let mut current_region = None;
+        for piece in leading_trivia.pieces() {
+            match region_of(regions, piece.text_range()) {
+                Some(region) if current_region == Some(region) => {}
+                Some(region) => {
+                    pieces.push((TriviaPieceKind::Skipped, &text[region]));
+                    current_region = Some(region);
+                }
+                None => {
+                    pieces.push((piece.kind(), &text[piece.text_range()]));
+                    current_region = None;
+                }
+            }
+        }
+
+        let new_token = token.with_leading_trivia(pieces.iter().copied());
+        mutation.replace_element_discard_trivia(token.into(), new_token.into());
+        has_changes = true;
+    }
+
+    if has_changes {
+        mutation.commit()
+    } else {
+        root
+    }
+}
+
+/// Returns the region that contains `range`
+fn region_of(regions: &[TextRange], range: TextRange) -> Option<TextRange> {
+    regions
+        .iter()
+        .find(|region| region.contains_range(range))
+        .copied()
+}
+
+/// Returns `new_text` with the regions of `old_text` that it lost put back.
+///
+/// A fix that removes or replaces the first token of a script block also
+/// removes its leading trivia, and with it the text of the file before the
+/// script block. The deleted parts of the regions are kept in place of the
+/// deletion, so that only the code of the script blocks can change.
+fn keep_regions(old_text: &str, new_text: &str, regions: &[TextRange]) -> String {
+    let mut regions_lost = false;
+    let mut position = 0;
+    for region in regions {
+        match new_text[position..].find(&old_text[*region]) {
+            Some(offset) => position += offset + usize::from(region.len()),
+            None => {
+                regions_lost = true;
+                break;
+            }
+        }
+    }
+    if !regions_lost {
+        return new_text.to_string();
+    }
+
+    let edit = TextEdit::from_unicode_words(old_text, new_text);
+    let mut output = String::with_capacity(new_text.len());
+    let mut old_position = TextSize::from(0);
+
+    for op in &edit {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                output.push_str(edit.get_text(*range));
+                old_position += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                output.push_str(edit.get_text(*range));
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let deleted = TextRange::at(old_position, range.len());
+                for region in regions {
+                    if let Some(kept) = region.intersect(deleted).filter(|kept| !kept.is_empty()) {
+                        output.push_str(&old_text[kept]);
+                    }
+                }
+                old_position += range.len();
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let start = usize::from(old_position);
+                let line_break_count = line_count.get() as usize + 1;
+                for line in old_text[start..]
+                    .split_inclusive('\n')
+                    .take(line_break_count)
+                {
+                    output.push_str(line);
+                    old_position += TextSize::of(line);
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns the edit of `text` that keeps its regions, if `edit` loses some of them
+fn keep_regions_in_edit(text: &str, edit: &TextEdit, regions: &[TextRange]) -> Option<TextEdit> {
+    let new_text = edit.new_string(text);
+    let kept_text = keep_regions(text, &new_text, regions);
+    (kept_text != new_text).then(|| TextEdit::from_unicode_words(text, &kept_text))
+}
+
+pub(super) fn text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).expect("the file to be smaller than 4GiB")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute, embedding_regions, keep_regions, mask_regions, script_tags};
+    use biome_js_syntax::{TextRange, TextSize};
+
+    const HTML: &str = r#"<!-- <script>not a script</script> -->
+<div>
+  <script>not a top-level script</script>
+</div>
+
+<script lang="ts">
+import { a } from "./a";
+</script>
+
+<script type="application/ld+json">{}</script>
+"#;
+
+    fn range(start: u32, end: u32) -> TextRange {
+        TextRange::new(TextSize::from(start), TextSize::from(end))
+    }
+
+    #[test]
+    fn finds_script_tags() {
+        let scripts = script_tags(HTML);
+
+        assert_eq!(scripts.len(), 1);
+        let start = HTML.find("\nimport").unwrap() as u32;
+        let end = HTML.find("</script>\n\n<script").unwrap() as u32;
+        assert_eq!(scripts[0].range, range(start, end));
+        assert!(scripts[0].file_source.language().is_typescript());
+    }
+
+    #[test]
+    fn reads_attributes() {
+        assert_eq!(attribute(r#" setup lang="ts""#, "lang"), Some("ts"));
+        assert_eq!(attribute(r#" lang='tsx' setup"#, "lang"), Some("tsx"));
+        assert_eq!(attribute(" lang = jsx", "lang"), Some("jsx"));
+        assert_eq!(attribute(" setup", "setup"), Some(""));
+        assert_eq!(attribute(r#" src="./a.js""#, "lang"), None);
+    }
+
+    #[test]
+    fn masks_regions_with_comments() {
+        let regions = embedding_regions(HTML, &script_tags(HTML));
+        let masked = mask_regions(HTML, &regions);
+
+        assert_eq!(masked.len(), HTML.len());
+        assert!(masked.starts_with("/*"));
+        assert!(masked.contains("*/\nimport { a } from \"./a\";\n\n/*"));
+        assert!(masked.ends_with("*/\n"));
+        // The comment after the script starts on a new line
+        assert_eq!(masked.lines().count(), HTML.lines().count() + 1);
+    }
+
+    #[test]
+    fn keeps_the_lost_regions() {
+        let old_text = "<script>\nimport a from \"a\";\nb();\n</script>\n";
+        let new_text = "\nb();\n</script>\n";
+        let regions = vec![range(0, 8), range(33, 42)];
+
+        assert_eq!(
+            keep_regions(old_text, new_text, &regions),
+            "<script>\nb();\n</script>\n"
+        );
+    }
+}
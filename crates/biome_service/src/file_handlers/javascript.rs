@@ -1,14 +1,22 @@
+use super::embedded::embedding_globals;
 use super::{
     AnalyzerCapabilities, DebugCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
-    LintResults, Mime, ParserCapabilities,
+    LintResults, Mime, ParserCapabilities, SearchCapabilities, TransformerCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams, Language as LanguageId};
+use crate::file_handlers::{
+    fix_applicability, is_diagnostic_error, FixAllParams, Language as LanguageId,
+};
 use crate::settings::OverrideSettings;
-use crate::workspace::OrganizeImportsResult;
+use crate::workspace::{
+    OrganizeImportsResult, RewriteFileResult, SearchResults, TransformFileResult,
+};
 use crate::{
-    settings::{FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle},
+    settings::{
+        FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle,
+        WorkspaceSettings,
+    },
     workspace::{
         CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
         RenameResult,
@@ -16,17 +24,22 @@ use crate::{
     Rules, WorkspaceError,
 };
 use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
-    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerAction, AnalyzerConfiguration, AnalyzerOptions, ControlFlow,
+    GroupCategory, Never, QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter,
+    RuleGroup, PLUGIN_GROUP,
 };
+use biome_console::MarkupBuf;
+use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
-    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
+    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle, SourceMapV3,
+    SourceMarker,
 };
 use biome_fs::RomePath;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use biome_js_analyze::{
-    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
+    analyze, analyze_with_inspect_matcher, analyze_with_plugins, visit_registry, ControlFlowGraph,
+    RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
@@ -38,11 +51,16 @@ use biome_js_semantic::{semantic_model, SemanticModelOptions};
 use biome_js_syntax::{
     AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
 };
+use biome_js_type_info::ProjectDeclarations;
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, FileSource, NodeCache};
+use biome_pattern::{parse_js_pattern, parse_js_template, rewrite_js, PatternMatch};
+use biome_rowan::{AstNode, BatchMutation, BatchMutationExt, Direction, FileSource, NodeCache};
+use biome_text_edit::TextEdit;
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tracing::{debug, debug_span, error, info, trace};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -164,6 +182,13 @@ impl ExtensionHandler for JsFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
+            transformer: TransformerCapabilities {
+                transform: Some(transform),
+            },
+            search: SearchCapabilities {
+                search_pattern: Some(search_pattern),
+                rewrite: Some(rewrite),
+            },
         }
     }
 }
@@ -181,14 +206,7 @@ fn parse(
             LanguageId::TypeScriptReact => JsFileSource::tsx(),
             _ => JsFileSource::js_module(),
         });
-    let parser_settings = &settings.as_ref().languages.javascript.parser;
-    let overrides = &settings.as_ref().override_settings;
-    let options = overrides.override_js_parser_options(
-        rome_path,
-        JsParserOptions {
-            parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
-        },
-    );
+    let options = parser_options(rome_path, settings.as_ref());
     let parse = biome_js_parser::parse_js_with_cache(text, source_type, options, cache);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -200,6 +218,20 @@ fn parse(
     )
 }
 
+/// Returns the options of the parser for the file `rome_path`
+pub(super) fn parser_options(
+    rome_path: &RomePath,
+    settings: &WorkspaceSettings,
+) -> JsParserOptions {
+    let parser_settings = &settings.languages.javascript.parser;
+    settings.override_settings.override_js_parser_options(
+        rome_path,
+        JsParserOptions {
+            parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
+        },
+    )
+}
+
 fn debug_syntax_tree(_rome_path: &RomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: JsSyntaxNode = parse.syntax();
     let tree: AnyJsRoot = parse.tree();
@@ -245,6 +277,8 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
             }
         },
         &options,
+        &[],
+        &Arc::default(),
         JsFileSource::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
@@ -267,6 +301,15 @@ fn debug_formatter_ir(
 }
 
 fn lint(params: LintParams) -> LintResults {
+    lint_with_suggestions(params, CodeSuggestionAdvice::from)
+}
+
+/// Lints a file, and uses `suggestion` to create the code suggestion of each
+/// action emitted by the rules
+pub(crate) fn lint_with_suggestions(
+    params: LintParams,
+    suggestion: impl Fn(AnalyzerAction<JsLanguage>) -> CodeSuggestionAdvice<MarkupBuf>,
+) -> LintResults {
     debug_span!("Linting JavaScript file", path =? params.path, language =? params.language)
         .in_scope(move || {
             let file_source = match params.parse.file_source(params.path) {
@@ -298,10 +341,12 @@ fn lint(params: LintParams) -> LintResults {
             let has_lint = params.filter.categories.contains(RuleCategories::LINT);
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(
+            let (_, analyze_diagnostics) = analyze_with_plugins(
                 &tree,
                 params.filter,
                 &analyzer_options,
+                &params.settings.as_ref().linter.plugins,
+                params.declarations,
                 file_source,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
@@ -318,7 +363,10 @@ fn lint(params: LintParams) -> LintResults {
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
                         let severity = diagnostic
                             .category()
-                            .filter(|category| category.name().starts_with("lint/"))
+                            // The severity of the plugin rules is resolved when their plugins are loaded
+                            .filter(|category| {
+                                category.name().starts_with("lint/") && !category.is_plugin_rule()
+                            })
                             .map(|category| {
                                 params
                                     .rules
@@ -334,7 +382,7 @@ fn lint(params: LintParams) -> LintResults {
                         if diagnostic_count <= params.max_diagnostics {
                             for action in signal.actions() {
                                 if !action.is_suppression() {
-                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                    diagnostic = diagnostic.add_code_suggestion(suggestion(action));
                                 }
                             }
 
@@ -393,44 +441,41 @@ impl RegistryVisitor<JsLanguage> for ActionsVisitor<'_> {
 }
 
 #[tracing::instrument(level = "debug", skip(parse, settings))]
-fn code_actions(
+pub(crate) fn code_actions(
     parse: AnyParse,
     range: TextRange,
     rules: Option<&Rules>,
     settings: SettingsHandle,
     path: &RomePath,
+    declarations: &Arc<ProjectDeclarations>,
 ) -> PullActionsResult {
     let tree = parse.tree();
 
     let mut actions = Vec::new();
 
-    let mut enabled_rules = vec![];
-    if settings.as_ref().organize_imports.enabled {
-        enabled_rules.push(RuleFilter::Rule("correctness", "organizeImports"));
-    }
-    if let Some(rules) = rules {
-        let rules = rules.as_enabled_rules().into_iter().collect();
-
-        // The rules in the assist category do not have configuration entries,
-        // always add them all to the enabled rules list
-        let mut visitor = ActionsVisitor {
-            enabled_rules: rules,
-        };
-        visit_registry(&mut visitor);
-
-        enabled_rules.extend(visitor.enabled_rules);
-    }
+    let rules = rules
+        .map(|rules| rules.as_enabled_rules().into_iter().collect())
+        .unwrap_or_default();
 
-    let mut filter = if !enabled_rules.is_empty() {
-        AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()))
-    } else {
-        AnalysisFilter::default()
+    // The rules in the assist category do not have configuration entries,
+    // always add them all to the enabled rules list
+    let mut visitor = ActionsVisitor {
+        enabled_rules: rules,
     };
+    visit_registry(&mut visitor);
 
-    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
-    if settings.as_ref().organize_imports.enabled {
-        filter.categories |= RuleCategories::ACTION;
+    let mut enabled_rules = visitor.enabled_rules;
+    if !settings.as_ref().organize_imports.enabled {
+        enabled_rules
+            .retain(|filter| *filter != RuleFilter::Rule("correctness", "organizeImports"));
     }
+    let plugins = &settings.as_ref().linter.plugins;
+    if !plugins.is_empty() {
+        enabled_rules.push(RuleFilter::Group(PLUGIN_GROUP));
+    }
+
+    let mut filter = AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()));
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT | RuleCategories::ACTION;
     filter.range = Some(range);
 
     trace!("Filter applied for code actions: {:?}", &filter);
@@ -439,19 +484,27 @@ fn code_actions(
         return PullActionsResult { actions: vec![] };
     };
 
-    analyze(&tree, filter, &analyzer_options, source_type, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    analyze_with_plugins(
+        &tree,
+        filter,
+        &analyzer_options,
+        plugins,
+        declarations,
+        source_type,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -459,7 +512,7 @@ fn code_actions(
 /// If applies all the safe fixes to the given syntax tree.
 ///
 /// If `indent_style` is [Some], it means that the formatting should be applied at the end
-fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let FixAllParams {
         parse,
         rules,
@@ -467,6 +520,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         settings,
         should_format,
         rome_path,
+        declarations,
         mut filter,
     } = params;
 
@@ -482,45 +536,59 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut errors: u16 = 0;
     let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules) {
-                    errors += 1;
+        let (action, _) = analyze_with_plugins(
+            &tree,
+            filter,
+            &analyzer_options,
+            &settings.as_ref().linter.plugins,
+            declarations,
+            file_source,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
+                    // the configuration of the rule can override the safety of its fixes
+                    let Some(applicability) =
+                        fix_applicability(action.rule_name, action.applicability, rules)
+                    else {
+                        continue;
+                    };
 
-                match fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                    match fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
                     }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -680,7 +748,7 @@ fn rename(
     }
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
     let filter = AnalysisFilter {
@@ -730,17 +798,257 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
     }
 }
 
+/// Applies the transformations until none of them emits a mutation, then
+/// prints the resulting tree with the formatter.
+///
+/// Each pass applies together the mutations that don't overlap, the other ones
+/// are emitted again by the next pass.
+///
+/// When `source_map` is `true`, the edits of the transformations are tracked
+/// to map the printed code back to the original code.
+#[tracing::instrument(level = "trace", skip(parse, settings))]
+fn transform(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+    source_map: bool,
+) -> Result<TransformFileResult, WorkspaceError> {
+    let file_source = parse
+        .file_source(rome_path)
+        .map_err(|_| extension_error(rome_path))?;
+    let mut tree: AnyJsRoot = parse.tree();
+    let original_tree = tree.clone();
+    // The edits of each pass
+    let mut passes: Vec<Vec<SourceEdit>> = Vec::new();
+
+    let filter = AnalysisFilter {
+        categories: RuleCategories::TRANSFORMATION,
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    let mut guard = TransformGuard::new(tree.syntax());
+    loop {
+        let mut mutation: Option<BatchMutation<JsLanguage>> = None;
+        let mut changed_ranges = Vec::new();
+        biome_js_transform::transform(&tree, filter, &analyzer_options, file_source, |signal| {
+            for transformation in signal.transformations() {
+                let Some(range) = transformation.mutation.changed_range() else {
+                    continue;
+                };
+                if changed_ranges
+                    .iter()
+                    .any(|changed_range| overlaps(*changed_range, range))
+                {
+                    continue;
+                }
+                changed_ranges.push(range);
+                match &mut mutation {
+                    Some(mutation) => mutation.extend(transformation.mutation),
+                    None => mutation = Some(transformation.mutation),
+                }
+            }
+            ControlFlow::<Never>::Continue(())
+        });
+
+        match mutation {
+            Some(mutation) => {
+                if source_map {
+                    passes.push(
+                        mutation
+                            .replacements()
+                            .into_iter()
+                            .map(|(range, text)| SourceEdit::new(tree.syntax(), range, text))
+                            .collect(),
+                    );
+                }
+                tree = match AnyJsRoot::cast(mutation.commit()) {
+                    Some(tree) => tree,
+                    None => {
+                        return Err(WorkspaceError::RuleError(
+                            RuleError::ReplacedRootWithNonRootError { rule_name: None },
+                        ))
+                    }
+                };
+                if !guard.check(tree.syntax()) {
+                    return Err(WorkspaceError::RuleError(
+                        RuleError::NonConvergingTransformationsError,
+                    ));
+                }
+            }
+            None => {
+                let printed = format_node(
+                    settings.format_options::<JsLanguage>(rome_path),
+                    tree.syntax(),
+                )?
+                .print()?;
+
+                let source_map = source_map.then(|| {
+                    let markers = printed.sourcemap().iter().map(|marker| SourceMarker {
+                        source: passes.iter().rev().fold(marker.source, |position, edits| {
+                            SourceEdit::old_position(edits, position)
+                        }),
+                        dest: marker.dest,
+                    });
+                    let source_name = rome_path
+                        .file_name()
+                        .and_then(OsStr::to_str)
+                        .unwrap_or_default();
+                    SourceMapV3::from_markers(
+                        markers,
+                        &original_tree.syntax().to_string(),
+                        source_name,
+                        printed.as_code(),
+                    )
+                });
+
+                return Ok(TransformFileResult {
+                    code: printed.into_code(),
+                    source_map,
+                });
+            }
+        }
+    }
+}
+
+/// A replacement performed by a transformation, used to map the positions
+/// of the transformed code back to the original code
+struct SourceEdit {
+    /// The range of the replaced code
+    range: TextRange,
+    /// The replaced code
+    old: String,
+    /// The length of the code replacing it
+    new_len: TextSize,
+    /// The edit from the replaced code to the code replacing it
+    edit: TextEdit,
+}
+
+impl SourceEdit {
+    fn new(root: &JsSyntaxNode, range: TextRange, new: String) -> Self {
+        let old = root.text().slice(range).to_string();
+        Self {
+            range,
+            new_len: TextSize::of(&new),
+            edit: TextEdit::from_unicode_words(&old, &new),
+            old,
+        }
+    }
+
+    /// Returns the position before `edits`, sorted by range, that corresponds
+    /// to `position` after them
+    fn old_position(edits: &[SourceEdit], position: TextSize) -> TextSize {
+        // The difference between the positions after and before the edits
+        let mut delta = 0i64;
+        for edit in edits {
+            let new_start = i64::from(u32::from(edit.range.start())) + delta;
+            let offset = i64::from(u32::from(position)) - new_start;
+            if offset < 0 {
+                break;
+            }
+            if offset < i64::from(u32::from(edit.new_len)) {
+                let offset = TextSize::from(offset as u32);
+                return edit.range.start() + edit.edit.old_position(&edit.old, offset);
+            }
+            delta += i64::from(u32::from(edit.new_len)) - i64::from(u32::from(edit.range.len()));
+        }
+        TextSize::from((i64::from(u32::from(position)) - delta) as u32)
+    }
+}
+
+/// Stops the passes of the transformations when they don't converge, e.g.
+/// because two transformations undo the changes of each other, or because a
+/// transformation keeps growing the file.
+struct TransformGuard {
+    /// The number of passes that can still be applied
+    remaining: usize,
+    /// The maximum length of the file once the passes are applied
+    max_len: u32,
+}
+
+impl TransformGuard {
+    fn new(root: &JsSyntaxNode) -> Self {
+        // A pass transforms at least one node, the margin lets the passes
+        // transform the nodes created by the previous ones
+        let nodes = root.descendants().count();
+        let len = u32::from(root.text_range().len());
+        Self {
+            remaining: nodes + 64,
+            max_len: len.saturating_mul(4).saturating_add(4096),
+        }
+    }
+
+    /// Records that a pass was applied to the file, and returns `false` if the
+    /// passes must be stopped
+    fn check(&mut self, root: &JsSyntaxNode) -> bool {
+        self.remaining = self.remaining.saturating_sub(1);
+        self.remaining > 0 && u32::from(root.text_range().len()) <= self.max_len
+    }
+}
+
+/// Returns `true` if the mutations that changed `first` and `second` can't be
+/// applied together
+fn overlaps(first: TextRange, second: TextRange) -> bool {
+    // An empty range can be the range of an ancestor of the nodes that touch it
+    first.intersect(second).is_some_and(|intersection| {
+        !intersection.is_empty() || first.is_empty() || second.is_empty()
+    })
+}
+
+#[tracing::instrument(level = "trace", skip(parse))]
+fn search_pattern(parse: AnyParse, pattern: &str) -> Result<SearchResults, WorkspaceError> {
+    let pattern = parse_js_pattern(pattern)?;
+    let matches = pattern
+        .find_matches(&parse.syntax::<JsLanguage>())
+        .iter()
+        .map(PatternMatch::range)
+        .collect();
+
+    Ok(SearchResults { matches })
+}
+
+#[tracing::instrument(level = "trace", skip(parse))]
+fn rewrite(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    pattern: &str,
+    replacement: &str,
+) -> Result<RewriteFileResult, WorkspaceError> {
+    let file_source = parse
+        .file_source(rome_path)
+        .map_err(|_| extension_error(rome_path))?;
+    let pattern = parse_js_pattern(pattern)?;
+    let template = parse_js_template(replacement, &pattern)?;
+
+    let (mutation, rewritten) = rewrite_js(
+        &parse.syntax::<JsLanguage>(),
+        &pattern,
+        &template,
+        file_source,
+    )?;
+
+    Ok(RewriteFileResult {
+        code: mutation.commit().to_string(),
+        rewritten,
+    })
+}
+
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
-        globals: if let Some(globals) = settings.as_ref().languages.javascript.globals.as_ref() {
-            globals
-                .iter()
-                .map(|global| global.to_string())
-                .collect::<Vec<_>>()
-        } else {
-            vec![]
-        },
+        globals: settings
+            .as_ref()
+            .languages
+            .javascript
+            .globals
+            .iter()
+            .flatten()
+            .map(|global| global.to_string())
+            .chain(
+                embedding_globals(file_path.as_path())
+                    .iter()
+                    .map(|global| (*global).to_string()),
+            )
+            .collect(),
     };
 
     AnalyzerOptions {
@@ -2,12 +2,12 @@ use super::{ExtensionHandler, Mime};
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    LintResults, ParserCapabilities, SearchCapabilities, TransformerCapabilities,
 };
 use crate::file_handlers::{DebugCapabilities, Language as LanguageId};
 use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
-    SettingsHandle,
+    SettingsHandle, WorkspaceSettings,
 };
 use crate::workspace::{
     FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
@@ -18,6 +18,7 @@ use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::{RomePath, BIOME_JSON, ROME_JSON};
+use biome_js_type_info::ProjectDeclarations;
 use biome_json_analyze::analyze;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_formatter::format_node;
@@ -27,6 +28,7 @@ use biome_parser::AnyParse;
 use biome_rowan::{AstNode, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -123,6 +125,11 @@ impl ExtensionHandler for JsonFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
+            transformer: TransformerCapabilities { transform: None },
+            search: SearchCapabilities {
+                search_pattern: None,
+                rewrite: None,
+            },
         }
     }
 }
@@ -142,23 +149,13 @@ fn parse(
     settings: SettingsHandle,
     cache: &mut NodeCache,
 ) -> AnyParse {
-    let parser = &settings.as_ref().languages.json.parser;
-    let overrides = &settings.as_ref().override_settings;
     let source_type =
         JsonFileSource::try_from(rome_path.as_path()).unwrap_or_else(|_| match language_hint {
             LanguageId::Json => JsonFileSource::json(),
             LanguageId::Jsonc => JsonFileSource::jsonc(),
             _ => JsonFileSource::json(),
         });
-    let options: JsonParserOptions =
-        overrides
-            .as_json_parser_options(rome_path)
-            .unwrap_or(JsonParserOptions {
-                allow_comments: parser.allow_comments
-                    || source_type.is_jsonc()
-                    || is_file_allowed(rome_path),
-                allow_trailing_commas: parser.allow_trailing_commas || is_file_allowed(rome_path),
-            });
+    let options = parser_options(rome_path, &source_type, settings.as_ref());
     let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -170,6 +167,24 @@ fn parse(
     )
 }
 
+/// Returns the options of the parser for the file `rome_path`
+pub(super) fn parser_options(
+    rome_path: &RomePath,
+    source_type: &JsonFileSource,
+    settings: &WorkspaceSettings,
+) -> JsonParserOptions {
+    let parser = &settings.languages.json.parser;
+    settings
+        .override_settings
+        .as_json_parser_options(rome_path)
+        .unwrap_or(JsonParserOptions {
+            allow_comments: parser.allow_comments
+                || source_type.is_jsonc()
+                || is_file_allowed(rome_path),
+            allow_trailing_commas: parser.allow_trailing_commas || is_file_allowed(rome_path),
+        })
+}
+
 fn debug_syntax_tree(_rome_path: &RomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: JsonSyntaxNode = parse.syntax();
     let tree: JsonRoot = parse.tree();
@@ -356,6 +371,7 @@ fn code_actions(
     _rules: Option<&Rules>,
     _settings: SettingsHandle,
     _path: &RomePath,
+    _declarations: &Arc<ProjectDeclarations>,
 ) -> PullActionsResult {
     PullActionsResult {
         actions: Vec::new(),
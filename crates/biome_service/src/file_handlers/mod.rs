@@ -1,9 +1,12 @@
 use self::{
-    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
-    unknown::UnknownFileHandler,
+    astro::AstroFileHandler, css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
+    svelte::SvelteFileHandler, unknown::UnknownFileHandler, vue::VueFileHandler,
+};
+use crate::workspace::{
+    FixFileMode, OrganizeImportsResult, RewriteFileResult, SearchResults, TransformFileResult,
 };
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
 use crate::{
+    configuration::linter::RuleFixConfiguration,
     settings::SettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     Rules, WorkspaceError,
@@ -11,20 +14,28 @@ use crate::{
 use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic};
 use biome_console::fmt::Formatter;
 use biome_console::markup;
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_diagnostics::{Applicability, Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::RomePath;
 use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_js_type_info::ProjectDeclarations;
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 pub use javascript::JsFormatterSettings;
+pub use resolved::resolve_configuration;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 
+mod astro;
 mod css;
+mod embedded;
 mod javascript;
 mod json;
+mod resolved;
+mod svelte;
 mod unknown;
+mod vue;
 
 /// Supported languages by Biome
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, serde::Serialize, serde::Deserialize)]
@@ -44,6 +55,12 @@ pub enum Language {
     Jsonc,
     /// CSS
     Css,
+    /// Vue single-file component
+    Vue,
+    /// Svelte component
+    Svelte,
+    /// Astro component
+    Astro,
     /// Any language that is not supported
     #[default]
     Unknown,
@@ -76,6 +93,9 @@ impl Language {
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
             "css" => Language::Css,
+            "vue" => Language::Vue,
+            "svelte" => Language::Svelte,
+            "astro" => Language::Astro,
             _ => Language::Unknown,
         }
     }
@@ -111,6 +131,9 @@ impl Language {
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
             "css" => Language::Css,
+            "vue" => Language::Vue,
+            "svelte" => Language::Svelte,
+            "astro" => Language::Astro,
             _ => Language::Unknown,
         }
     }
@@ -163,12 +186,18 @@ impl Language {
         matches!(self, Language::Css)
     }
 
+    /// Whether the language embeds JavaScript code in blocks of another syntax
+    pub const fn is_embedding_javascript(&self) -> bool {
+        matches!(self, Language::Vue | Language::Svelte | Language::Astro)
+    }
+
     pub fn as_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             Language::JavaScript => Some(JsFileSource::js_module()),
             Language::JavaScriptReact => Some(JsFileSource::jsx()),
             Language::TypeScript => Some(JsFileSource::tsx()),
             Language::TypeScriptReact => Some(JsFileSource::tsx()),
+            Language::Vue | Language::Svelte | Language::Astro => Some(JsFileSource::ts()),
             Language::Json | Language::Jsonc | Language::Css | Language::Unknown => None,
         }
     }
@@ -184,6 +213,9 @@ impl biome_console::fmt::Display for Language {
             Language::Json => fmt.write_markup(markup! { "JSON" }),
             Language::Jsonc => fmt.write_markup(markup! { "JSONC" }),
             Language::Css => fmt.write_markup(markup! { "CSS" }),
+            Language::Vue => fmt.write_markup(markup! { "Vue" }),
+            Language::Svelte => fmt.write_markup(markup! { "Svelte" }),
+            Language::Astro => fmt.write_markup(markup! { "Astro" }),
             Language::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -195,6 +227,7 @@ pub(crate) enum Mime {
     Javascript,
     Json,
     Css,
+    Html,
     Text,
 }
 
@@ -202,6 +235,7 @@ impl std::fmt::Display for Mime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Mime::Css => write!(f, "text/css"),
+            Mime::Html => write!(f, "text/html"),
             Mime::Json => write!(f, "application/json"),
             Mime::Javascript => write!(f, "application/javascript"),
             Mime::Text => write!(f, "text/plain"),
@@ -224,6 +258,8 @@ pub struct FixAllParams<'a> {
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) rome_path: &'a RomePath,
+    /// The declarations of the project, used by the type-aware rules
+    pub(crate) declarations: &'a Arc<ProjectDeclarations>,
 }
 
 #[derive(Default)]
@@ -233,6 +269,8 @@ pub struct Capabilities {
     pub(crate) debug: DebugCapabilities,
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
+    pub(crate) transformer: TransformerCapabilities,
+    pub(crate) search: SearchCapabilities,
 }
 
 type Parse = fn(&RomePath, Language, &str, SettingsHandle, &mut NodeCache) -> AnyParse;
@@ -265,6 +303,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) language: Language,
     pub(crate) max_diagnostics: u64,
     pub(crate) path: &'a RomePath,
+    /// The declarations of the project, used by the type-aware rules
+    pub(crate) declarations: &'a Arc<ProjectDeclarations>,
 }
 
 pub(crate) struct LintResults {
@@ -274,8 +314,14 @@ pub(crate) struct LintResults {
 }
 
 type Lint = fn(LintParams) -> LintResults;
-type CodeActions =
-    fn(AnyParse, TextRange, Option<&Rules>, SettingsHandle, &RomePath) -> PullActionsResult;
+type CodeActions = fn(
+    AnyParse,
+    TextRange,
+    Option<&Rules>,
+    SettingsHandle,
+    &RomePath,
+    &Arc<ProjectDeclarations>,
+) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
@@ -310,6 +356,26 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_on_type: Option<FormatOnType>,
 }
 
+type Transform =
+    fn(&RomePath, AnyParse, SettingsHandle, bool) -> Result<TransformFileResult, WorkspaceError>;
+
+#[derive(Default)]
+pub(crate) struct TransformerCapabilities {
+    /// It compiles the TypeScript and JSX syntax of a file
+    pub(crate) transform: Option<Transform>,
+}
+
+type SearchPattern = fn(AnyParse, &str) -> Result<SearchResults, WorkspaceError>;
+type Rewrite = fn(&RomePath, AnyParse, &str, &str) -> Result<RewriteFileResult, WorkspaceError>;
+
+#[derive(Default)]
+pub(crate) struct SearchCapabilities {
+    /// It finds the code that matches a structural pattern
+    pub(crate) search_pattern: Option<SearchPattern>,
+    /// It replaces the code that matches a structural pattern with a template
+    pub(crate) rewrite: Option<Rewrite>,
+}
+
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
     /// The language of the file. It can be a super language.
@@ -342,6 +408,9 @@ pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
     css: CssFileHandler,
+    vue: VueFileHandler,
+    svelte: SvelteFileHandler,
+    astro: AstroFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -351,6 +420,9 @@ impl Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
             css: CssFileHandler {},
+            vue: VueFileHandler {},
+            svelte: SvelteFileHandler {},
+            astro: AstroFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -381,6 +453,9 @@ impl Features {
             | Language::TypeScriptReact => self.js.capabilities(),
             Language::Json | Language::Jsonc => self.json.capabilities(),
             Language::Css => self.css.capabilities(),
+            Language::Vue => self.vue.capabilities(),
+            Language::Svelte => self.svelte.capabilities(),
+            Language::Astro => self.astro.capabilities(),
             Language::Unknown => self.unknown.capabilities(),
         }
     }
@@ -395,7 +470,8 @@ pub(crate) fn is_diagnostic_error(
 ) -> bool {
     let severity = diagnostic
         .category()
-        .filter(|category| category.name().starts_with("lint/"))
+        // The severity of the plugin rules is resolved when their plugins are loaded
+        .filter(|category| category.name().starts_with("lint/") && !category.is_plugin_rule())
         .map(|category| {
             rules
                 .and_then(|rules| rules.get_severity_from_code(category))
@@ -405,3 +481,26 @@ pub(crate) fn is_diagnostic_error(
 
     severity >= Severity::Error
 }
+
+/// Returns the applicability of an action of the rule `rule_name`, once the
+/// `fix` policy of the rule in the configuration overrides it
+///
+/// The function returns [None] when the fixes of the rule must never be applied.
+pub(crate) fn fix_applicability(
+    rule_name: Option<(&str, &str)>,
+    applicability: Applicability,
+    rules: Option<&'_ Rules>,
+) -> Option<Applicability> {
+    let fix = rule_name.and_then(|(group, rule_name)| {
+        rules?
+            .get_rule_configuration(group, rule_name)?
+            .fix()
+            .cloned()
+    });
+    match fix {
+        None => Some(applicability),
+        Some(RuleFixConfiguration::None) => None,
+        Some(RuleFixConfiguration::Safe) => Some(Applicability::Always),
+        Some(RuleFixConfiguration::Unsafe) => Some(Applicability::MaybeIncorrect),
+    }
+}
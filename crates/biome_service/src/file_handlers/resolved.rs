@@ -0,0 +1,187 @@
+//! The configuration that applies to a file, once the configuration files and
+//! their overrides are resolved. It's the configuration used by the formatter,
+//! the linter and the parser of the file.
+
+use super::{css, javascript, json, Features, Language};
+use crate::settings::WorkspaceSettings;
+use crate::workspace::{FeatureName, FileFeaturesResult};
+use biome_analyze::RuleFilter;
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::{Category, Severity};
+use biome_formatter::{FormatOptions, IndentStyle};
+use biome_fs::RomePath;
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Returns the configuration that applies to the file `path`.
+///
+/// The value has the shape of a configuration file, e.g. the width of the
+/// indentation of a JavaScript file is `javascript.formatter.indentWidth`. It
+/// only contains the options of the language of the file and, when the linter
+/// is enabled for the file, the rules that are enabled with their severity.
+pub fn resolve_configuration(settings: &WorkspaceSettings, path: &Path) -> Value {
+    let rome_path = RomePath::new(path);
+    let language = Features::get_language(&rome_path);
+    let capabilities = Features::new().get_capabilities(&rome_path, language);
+    let file_features = FileFeaturesResult::new()
+        .with_capabilities(&capabilities)
+        .with_settings_and_language(settings, &language, path);
+
+    let mut configuration = Map::new();
+    configuration.insert(
+        "formatter".to_string(),
+        json!({
+            "enabled": file_features.supports_for(&FeatureName::Format),
+            "formatWithErrors": settings.formatter.format_with_errors,
+        }),
+    );
+    let linter_enabled = file_features.supports_for(&FeatureName::Lint);
+    let mut linter = json!({ "enabled": linter_enabled });
+    if linter_enabled {
+        linter["rules"] = resolve_rules(settings, path);
+    }
+    configuration.insert("linter".to_string(), linter);
+    configuration.insert(
+        "organizeImports".to_string(),
+        json!({
+            "enabled": file_features.supports_for(&FeatureName::OrganizeImports),
+        }),
+    );
+
+    if language.is_javascript_like() || language.is_embedding_javascript() {
+        let options = settings.format_options::<JsLanguage>(&rome_path);
+        let mut formatter = resolve_format_options(&options);
+        formatter.extend([
+            ("quoteStyle".to_string(), json!(options.quote_style())),
+            (
+                "jsxQuoteStyle".to_string(),
+                json!(options.jsx_quote_style()),
+            ),
+            (
+                "quoteProperties".to_string(),
+                json!(options.quote_properties()),
+            ),
+            ("trailingComma".to_string(), json!(options.trailing_comma())),
+            ("semicolons".to_string(), json!(options.semicolons())),
+            (
+                "arrowParentheses".to_string(),
+                json!(options.arrow_parentheses()),
+            ),
+            (
+                "bracketSpacing".to_string(),
+                json!(options.bracket_spacing().value()),
+            ),
+            (
+                "bracketSameLine".to_string(),
+                json!(options.bracket_same_line().value()),
+            ),
+        ]);
+        let parser = javascript::parser_options(&rome_path, settings);
+        let mut javascript = json!({
+            "formatter": formatter,
+            "parser": {
+                "unsafeParameterDecoratorsEnabled": parser.parse_class_parameter_decorators,
+            },
+        });
+        if let Some(globals) = settings.languages.javascript.globals.as_ref() {
+            javascript["globals"] = json!(globals);
+        }
+        configuration.insert("javascript".to_string(), javascript);
+    } else if language.is_json_like() {
+        let options = settings.format_options::<JsonLanguage>(&rome_path);
+        let source_type = JsonFileSource::try_from(path).unwrap_or_else(|_| {
+            if language == Language::Jsonc {
+                JsonFileSource::jsonc()
+            } else {
+                JsonFileSource::json()
+            }
+        });
+        let parser = json::parser_options(&rome_path, &source_type, settings);
+        configuration.insert(
+            "json".to_string(),
+            json!({
+                "formatter": resolve_format_options(&options),
+                "parser": {
+                    "allowComments": parser.allow_comments,
+                    "allowTrailingCommas": parser.allow_trailing_commas,
+                },
+            }),
+        );
+    } else if language.is_css_like() {
+        let options = settings.format_options::<CssLanguage>(&rome_path);
+        let mut formatter = resolve_format_options(&options);
+        formatter.insert("quoteStyle".to_string(), json!(options.quote_style()));
+        let parser = css::parser_options(&rome_path, settings);
+        configuration.insert(
+            "css".to_string(),
+            json!({
+                "formatter": formatter,
+                "parser": {
+                    "allowWrongLineComments": parser.allow_wrong_line_comments,
+                },
+            }),
+        );
+    }
+
+    Value::Object(configuration)
+}
+
+/// Returns the options shared by the formatters of all the languages
+fn resolve_format_options(options: &impl FormatOptions) -> Map<String, Value> {
+    let indent_style = match options.indent_style() {
+        IndentStyle::Tab => "tab",
+        IndentStyle::Space => "space",
+    };
+    let mut formatter = Map::new();
+    formatter.insert("indentStyle".to_string(), json!(indent_style));
+    formatter.insert(
+        "indentWidth".to_string(),
+        json!(options.indent_width().value()),
+    );
+    formatter.insert("lineEnding".to_string(), json!(options.line_ending()));
+    formatter.insert("lineWidth".to_string(), json!(options.line_width().get()));
+    formatter
+}
+
+/// Returns the rules that are enabled for `path`, by group. A rule is mapped
+/// to its severity, or to its severity and its options when it has some.
+fn resolve_rules(settings: &WorkspaceSettings, path: &Path) -> Value {
+    let Some(rules) = settings.as_rules(path) else {
+        return json!({});
+    };
+    let enabled_rules = settings
+        .linter
+        .rules
+        .as_ref()
+        .map(|rules| {
+            settings
+                .override_settings
+                .overrides_enabled_rules(path, rules.as_enabled_rules())
+        })
+        .unwrap_or_default();
+    let configured_rules = serde_json::to_value(&rules).unwrap_or_default();
+
+    let mut groups: BTreeMap<&str, BTreeMap<&str, Value>> = BTreeMap::new();
+    for rule_filter in enabled_rules {
+        let RuleFilter::Rule(group, rule_name) = rule_filter else {
+            continue;
+        };
+        let Ok(category) = format!("lint/{group}/{rule_name}").parse::<&'static Category>() else {
+            continue;
+        };
+        let level = match rules.get_severity_from_code(category) {
+            Some(Severity::Error) => "error",
+            _ => "warn",
+        };
+        let value = match configured_rules[group][rule_name].get("options") {
+            Some(options) => json!({ "level": level, "options": options }),
+            None => json!(level),
+        };
+        groups.entry(group).or_default().insert(rule_name, value);
+    }
+
+    json!(groups)
+}
@@ -0,0 +1,75 @@
+use super::embedded::{self, script_tags, EmbeddedScript, EmbeddingLanguage};
+use super::{Capabilities, ExtensionHandler, Language, Mime};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct SvelteFileHandler;
+
+impl ExtensionHandler for SvelteFileHandler {
+    fn language(&self) -> Language {
+        Language::Svelte
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Html
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        embedded::capabilities::<Self>()
+    }
+}
+
+impl EmbeddingLanguage for SvelteFileHandler {
+    /// The runes and the special variables of the components
+    const GLOBALS: &'static [&'static str] = &[
+        "$$props",
+        "$$restProps",
+        "$$slots",
+        "$bindable",
+        "$derived",
+        "$effect",
+        "$host",
+        "$inspect",
+        "$props",
+        "$state",
+    ];
+
+    /// Returns the instance `<script>` and the `<script context="module">` of
+    /// the component. The markup and the `<style>` are skipped.
+    fn scripts(text: &str) -> Vec<EmbeddedScript> {
+        script_tags(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SvelteFileHandler;
+    use crate::file_handlers::embedded::EmbeddingLanguage;
+
+    #[test]
+    fn finds_the_component_scripts() {
+        let text = r#"<script context="module">
+export const a = 1;
+</script>
+
+<script lang="ts">
+let { b } = $props();
+</script>
+
+{#if b < a}
+<svelte:head><script src="./c.js"></script></svelte:head>
+{/if}
+"#;
+
+        let scripts = SvelteFileHandler::scripts(text);
+
+        assert_eq!(scripts.len(), 2);
+        assert_eq!(&text[scripts[0].range], "\nexport const a = 1;\n");
+        assert!(!scripts[0].file_source.language().is_typescript());
+        assert_eq!(&text[scripts[1].range], "\nlet { b } = $props();\n");
+        assert!(scripts[1].file_source.language().is_typescript());
+    }
+}
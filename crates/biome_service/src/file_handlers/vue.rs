@@ -0,0 +1,68 @@
+use super::embedded::{self, script_tags, EmbeddedScript, EmbeddingLanguage};
+use super::{Capabilities, ExtensionHandler, Language, Mime};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct VueFileHandler;
+
+impl ExtensionHandler for VueFileHandler {
+    fn language(&self) -> Language {
+        Language::Vue
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Html
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        embedded::capabilities::<Self>()
+    }
+}
+
+impl EmbeddingLanguage for VueFileHandler {
+    /// The compiler macros of `<script setup>`
+    const GLOBALS: &'static [&'static str] = &[
+        "defineEmits",
+        "defineExpose",
+        "defineModel",
+        "defineOptions",
+        "defineProps",
+        "defineSlots",
+        "withDefaults",
+    ];
+
+    /// Returns the `<script>` and `<script setup>` blocks at the top level of
+    /// the file. The other blocks, e.g. `<template>` and `<style>`, are skipped
+    /// along with their content.
+    fn scripts(text: &str) -> Vec<EmbeddedScript> {
+        script_tags(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VueFileHandler;
+    use crate::file_handlers::embedded::EmbeddingLanguage;
+
+    #[test]
+    fn finds_the_top_level_scripts() {
+        let text = r#"<template>
+  <template v-if="a"><div>{{ a }}</div></template>
+  <script>not a script</script>
+</template>
+
+<script setup lang="ts">
+import { a } from "./a";
+</script>
+"#;
+
+        let scripts = VueFileHandler::scripts(text);
+
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(&text[scripts[0].range], "\nimport { a } from \"./a\";\n");
+        assert!(scripts[0].file_source.language().is_typescript());
+    }
+}
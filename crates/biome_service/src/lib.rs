@@ -20,7 +20,7 @@ pub use crate::configuration::{
     create_config, Configuration, ConfigurationBasePath, ConfigurationDiagnostic,
     JavascriptFormatter, MergeWith, RuleConfiguration, Rules,
 };
-pub use crate::matcher::Matcher;
+pub use crate::matcher::{IgnoreFiles, Matcher};
 
 pub use crate::diagnostics::{TransportError, WorkspaceError};
 /// Exports only for this crate
@@ -0,0 +1,215 @@
+use biome_fs::{normalize_path, FileSystem, FileSystemExt};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use rustc_hash::FxHashMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// The name of the ignore file that isn't tied to a VCS, it has precedence over
+/// the ignore file of the VCS declared in the same directory
+const IGNORE_FILE_NAME: &str = ".ignore";
+
+/// The ignore files declared in the directories of a traversal: the ignore file
+/// of the VCS, e.g. `.gitignore`, and `.ignore`. The patterns of a file are
+/// relative to the directory that declares it.
+///
+/// The files are read the first time a path of their directory is checked.
+/// A clone doesn't share the files read by the original.
+#[derive(Debug)]
+pub struct IgnoreFiles {
+    /// The directory where the lookup of the ignore files stops, usually the
+    /// root of the VCS
+    root: PathBuf,
+    /// The ignore files to look for, by decreasing precedence
+    file_names: [&'static str; 2],
+    /// The parsed ignore files of each directory visited so far
+    directories: RwLock<FxHashMap<PathBuf, Arc<Vec<Gitignore>>>>,
+}
+
+impl Clone for IgnoreFiles {
+    fn clone(&self) -> Self {
+        Self::new(self.root.clone(), self.file_names[1])
+    }
+}
+
+impl IgnoreFiles {
+    pub fn new(root: PathBuf, vcs_ignore_file: &'static str) -> Self {
+        Self {
+            root: normalize_path(&root),
+            file_names: [IGNORE_FILE_NAME, vcs_ignore_file],
+            directories: RwLock::default(),
+        }
+    }
+
+    /// Checks if `path` is ignored by the ignore files of its directory and its
+    /// parents, up to the root. Like git, the paths inside an ignored directory
+    /// are ignored too.
+    pub fn is_ignored(&self, fs: &dyn FileSystem, path: &Path, is_dir: bool) -> bool {
+        let path = match fs.working_directory() {
+            Some(working_directory) if path.is_relative() => {
+                normalize_path(&working_directory.join(path))
+            }
+            _ => normalize_path(path),
+        };
+        if !path.starts_with(&self.root) {
+            return false;
+        }
+
+        self.is_matched(fs, &path, is_dir)
+            || path
+                .ancestors()
+                .skip(1)
+                .take_while(|directory| *directory != self.root)
+                .any(|directory| self.is_matched(fs, directory, true))
+    }
+
+    /// Checks if `path` is one of the ignore files read by the traversal
+    pub fn is_ignore_file(&self, path: &Path) -> bool {
+        path.file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|file_name| self.file_names.contains(&file_name))
+    }
+
+    /// The nearest ignore file that has a pattern matching `path` decides,
+    /// which means that a negated pattern of a nested file includes again a
+    /// path that is ignored by a parent directory.
+    fn is_matched(&self, fs: &dyn FileSystem, path: &Path, is_dir: bool) -> bool {
+        for directory in path.ancestors().skip(1) {
+            for ignore_file in self.ignore_files(fs, directory).iter() {
+                match ignore_file.matched(path, is_dir) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+            if directory == self.root {
+                break;
+            }
+        }
+
+        false
+    }
+
+    /// Returns the ignore files of `directory`, by decreasing precedence
+    fn ignore_files(&self, fs: &dyn FileSystem, directory: &Path) -> Arc<Vec<Gitignore>> {
+        if let Some(ignore_files) = self.directories.read().unwrap().get(directory) {
+            return ignore_files.clone();
+        }
+
+        let ignore_files: Vec<_> = self
+            .file_names
+            .iter()
+            .filter_map(|file_name| read_ignore_file(fs, directory, file_name))
+            .collect();
+        let ignore_files = Arc::new(ignore_files);
+        self.directories
+            .write()
+            .unwrap()
+            .insert(directory.to_path_buf(), ignore_files.clone());
+        ignore_files
+    }
+}
+
+/// Reads and parses the ignore file `file_name` of `directory`, the invalid
+/// patterns are skipped
+fn read_ignore_file(fs: &dyn FileSystem, directory: &Path, file_name: &str) -> Option<Gitignore> {
+    let file_path = directory.join(file_name);
+    let mut content = String::new();
+    fs.open(&file_path)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .ok()?;
+
+    let mut builder = GitignoreBuilder::new(directory);
+    for line in content.lines() {
+        if let Err(error) = builder.add_line(Some(file_path.clone()), line) {
+            tracing::warn!("Invalid pattern in {}: {error}", file_path.display());
+        }
+    }
+    builder
+        .build()
+        .map_err(|error| tracing::warn!("Failed to read {}: {error}", file_path.display()))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IgnoreFiles;
+    use biome_fs::MemoryFileSystem;
+    use std::path::{Path, PathBuf};
+
+    fn ignore_files(files: &[(&str, &str)]) -> (MemoryFileSystem, IgnoreFiles) {
+        let mut fs = MemoryFileSystem::default();
+        for (path, content) in files {
+            fs.insert(PathBuf::from(path), content.as_bytes());
+        }
+        (fs, IgnoreFiles::new(PathBuf::new(), ".gitignore"))
+    }
+
+    #[test]
+    fn scopes_the_patterns_to_their_directory() {
+        let (fs, ignore_files) = ignore_files(&[
+            (".gitignore", "*.log\n"),
+            ("packages/a/.gitignore", "/build\ndist/\n"),
+        ]);
+
+        let is_ignored = |path: &str, is_dir| ignore_files.is_ignored(&fs, Path::new(path), is_dir);
+        assert!(is_ignored("debug.log", false));
+        assert!(is_ignored("packages/a/debug.log", false));
+        assert!(is_ignored("packages/a/build", true));
+        assert!(is_ignored("packages/a/build/index.js", false));
+        assert!(is_ignored("./packages/a/src/dist", true));
+        assert!(!is_ignored("packages/a/src/build", true));
+        assert!(!is_ignored("packages/a/src/dist", false));
+        assert!(!is_ignored("packages/b/build", true));
+        assert!(!is_ignored("packages/b/dist", true));
+    }
+
+    #[test]
+    fn the_nearest_ignore_file_has_precedence() {
+        let (fs, ignore_files) = ignore_files(&[
+            (".gitignore", "dist\n*.js\n"),
+            ("packages/a/.gitignore", "!dist\n"),
+            ("packages/a/src/.gitignore", "!*.js\ngenerated.js\n"),
+        ]);
+
+        let is_ignored = |path: &str, is_dir| ignore_files.is_ignored(&fs, Path::new(path), is_dir);
+        assert!(is_ignored("dist", true));
+        assert!(is_ignored("packages/b/dist", true));
+        assert!(!is_ignored("packages/a/dist", true));
+        assert!(!is_ignored("packages/a/dist/index.json", false));
+        assert!(is_ignored("packages/a/index.js", false));
+        assert!(!is_ignored("packages/a/src/index.js", false));
+        assert!(is_ignored("packages/a/src/generated.js", false));
+    }
+
+    #[test]
+    fn reads_the_ignore_files_that_arent_tied_to_the_vcs() {
+        let (fs, ignore_files) = ignore_files(&[
+            (".gitignore", "dist\n"),
+            (".ignore", "!dist\nfixtures\n"),
+            ("packages/a/.ignore", "*.snap\n"),
+        ]);
+
+        let is_ignored = |path: &str, is_dir| ignore_files.is_ignored(&fs, Path::new(path), is_dir);
+        assert!(!is_ignored("dist", true));
+        assert!(is_ignored("fixtures", true));
+        assert!(is_ignored("packages/a/test.snap", false));
+        assert!(!is_ignored("test.snap", false));
+        assert!(ignore_files.is_ignore_file(Path::new("packages/a/.ignore")));
+        assert!(ignore_files.is_ignore_file(Path::new(".gitignore")));
+        assert!(!ignore_files.is_ignore_file(Path::new("ignore")));
+    }
+
+    #[test]
+    fn doesnt_read_the_ignore_files_outside_of_the_root() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from(".gitignore"), "*.js\n".as_bytes());
+        fs.insert(PathBuf::from("packages/.gitignore"), "*.ts\n".as_bytes());
+        let ignore_files = IgnoreFiles::new(PathBuf::from("packages/a"), ".gitignore");
+
+        assert!(!ignore_files.is_ignored(&fs, Path::new("packages/a/index.js"), false));
+        assert!(!ignore_files.is_ignored(&fs, Path::new("packages/a/index.ts"), false));
+        assert!(!ignore_files.is_ignored(&fs, Path::new("index.js"), false));
+    }
+}
@@ -1,3 +1,5 @@
+mod ignore_files;
+
 use crate::configuration::diagnostics::InvalidIgnorePattern;
 use crate::{ConfigurationDiagnostic, WorkspaceError};
 use globset::GlobSet;
@@ -6,6 +8,8 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
+pub use ignore_files::IgnoreFiles;
+
 /// A data structure to use when there's need to match a string or a path a against
 /// a unix shell style patterns
 #[derive(Debug)]
@@ -196,4 +200,26 @@ mod test {
 
         assert!(result);
     }
+
+    #[test]
+    fn matches_the_patterns_of_nested_ignore_files() {
+        let root = env::current_dir().unwrap();
+        let mut ignore = Matcher::new(GlobSetBuilder::new().build().unwrap());
+        ignore
+            .add_gitignore_matches(
+                root.clone(),
+                &[
+                    "dist".to_string(),
+                    "/packages/a/build".to_string(),
+                    "!/packages/a/**/dist".to_string(),
+                ],
+            )
+            .unwrap();
+
+        assert!(ignore.matches_path(&root.join("dist")));
+        assert!(ignore.matches_path(&root.join("packages/b/dist")));
+        assert!(!ignore.matches_path(&root.join("packages/a/src/dist")));
+        assert!(ignore.matches_path(&root.join("packages/a/build")));
+        assert!(!ignore.matches_path(&root.join("packages/b/build")));
+    }
 }
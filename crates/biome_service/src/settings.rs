@@ -10,14 +10,14 @@ use crate::{
     configuration::FilesConfiguration, Configuration, ConfigurationDiagnostic, Matcher, MergeWith,
     Rules, WorkspaceError,
 };
-use biome_analyze::{AnalyzerRules, RuleFilter};
+use biome_analyze::{AnalyzerPlugin, AnalyzerRules, RuleFilter};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_deserialize::StringSet;
 use biome_diagnostics::Category;
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
-use biome_fs::RomePath;
+use biome_fs::{normalize_path, RomePath};
 use biome_js_analyze::metadata;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::JsParserOptions;
@@ -31,7 +31,7 @@ use std::ops::{BitOr, Sub};
 use std::path::{Path, PathBuf};
 use std::{
     num::NonZeroU64,
-    sync::{RwLock, RwLockReadGuard},
+    sync::{Arc, RwLock, RwLockReadGuard},
 };
 
 /// Global settings for the entire workspace
@@ -157,6 +157,19 @@ impl WorkspaceSettings {
         }
     }
 
+    /// Resolve the formatting context of the file `path` for the given language
+    pub(crate) fn format_options<L>(&self, path: &RomePath) -> L::FormatOptions
+    where
+        L: Language,
+    {
+        L::resolve_format_options(
+            &self.formatter,
+            &self.override_settings,
+            &L::lookup_settings(&self.languages).formatter,
+            path,
+        )
+    }
+
     /// Returns rules
     pub fn as_rules(&self, path: &Path) -> Option<Rules> {
         let overrides = &self.override_settings;
@@ -228,6 +241,12 @@ pub struct LinterSettings {
 
     /// List of included paths/files to match
     pub included_files: Matcher,
+
+    /// The lint rules defined by the plugins, compiled to run on JavaScript files
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin<JsLanguage>>>,
+
+    /// The lint rules defined by the plugins, compiled to run on CSS files
+    pub css_plugins: Vec<Arc<dyn AnalyzerPlugin<CssLanguage>>>,
 }
 
 impl Default for LinterSettings {
@@ -237,6 +256,8 @@ impl Default for LinterSettings {
             rules: Some(Rules::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            plugins: Vec::new(),
+            css_plugins: Vec::new(),
         }
     }
 }
@@ -476,24 +497,88 @@ fn to_file_settings(
     })
 }
 
+/// The settings of the workspace, along with the ones of the directories that
+/// have their own configuration file
+#[derive(Debug, Default)]
+pub struct SettingsScopes {
+    /// The settings applied to the files that aren't in one of the directories
+    workspace: WorkspaceSettings,
+    /// The settings of each directory, the nested directories come first
+    directories: Vec<(PathBuf, WorkspaceSettings)>,
+}
+
+impl SettingsScopes {
+    /// Returns the settings of `directory`, or the ones of the workspace if
+    /// it's [None]. The settings of a directory are created if they don't exist.
+    pub(crate) fn get_mut(&mut self, directory: Option<&Path>) -> &mut WorkspaceSettings {
+        let Some(directory) = directory else {
+            return &mut self.workspace;
+        };
+        let directory = normalize_path(directory);
+        let index = match self
+            .directories
+            .iter()
+            .position(|(scope, _)| *scope == directory)
+        {
+            Some(index) => index,
+            None => {
+                let depth = directory.components().count();
+                let index = self
+                    .directories
+                    .iter()
+                    .position(|(scope, _)| scope.components().count() < depth)
+                    .unwrap_or(self.directories.len());
+                self.directories
+                    .insert(index, (directory, WorkspaceSettings::default()));
+                index
+            }
+        };
+        &mut self.directories[index].1
+    }
+
+    /// Removes the settings of all the directories
+    pub(crate) fn clear_directories(&mut self) {
+        self.directories.clear();
+    }
+
+    /// Returns the index of the settings of the nearest directory that
+    /// contains `path`, or [None] if the settings of the workspace apply
+    fn position(&self, path: &Path) -> Option<usize> {
+        if self.directories.is_empty() {
+            return None;
+        }
+        let path = normalize_path(path);
+        self.directories
+            .iter()
+            .position(|(directory, _)| path.starts_with(directory))
+    }
+
+    fn get(&self, position: Option<usize>) -> &WorkspaceSettings {
+        position.map_or(&self.workspace, |index| &self.directories[index].1)
+    }
+}
+
 /// Handle object holding a temporary lock on the workspace settings until
 /// the deferred language-specific options resolution is called
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
-    inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    inner: RwLockReadGuard<'a, SettingsScopes>,
+    /// The settings that apply to the path of the handle
+    position: Option<usize>,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
-        Self {
-            inner: settings.read().unwrap(),
-        }
+    /// Returns the handle of the settings that apply to `path`
+    pub(crate) fn new(settings: &'a RwLock<SettingsScopes>, path: &Path) -> Self {
+        let inner = settings.read().unwrap();
+        let position = inner.position(path);
+        Self { inner, position }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        self.inner.get(self.position)
     }
 }
 
@@ -503,12 +588,7 @@ impl<'a> SettingsHandle<'a> {
     where
         L: Language,
     {
-        L::resolve_format_options(
-            &self.inner.formatter,
-            &self.inner.override_settings,
-            &L::lookup_settings(&self.inner.languages).formatter,
-            path,
-        )
+        self.as_ref().format_options::<L>(path)
     }
 }
 
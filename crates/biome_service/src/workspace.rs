@@ -58,9 +58,10 @@ pub use biome_analyze::RuleCategories;
 use biome_console::{markup, Markup, MarkupBuf};
 use biome_css_formatter::can_format_css_yet;
 use biome_diagnostics::CodeSuggestion;
-use biome_formatter::Printed;
+use biome_formatter::{Printed, SourceMapV3};
 use biome_fs::RomePath;
 use biome_js_syntax::{TextRange, TextSize};
+use biome_plugin::Plugin;
 use biome_text_edit::TextEdit;
 use std::collections::HashMap;
 use std::ffi::OsStr;
@@ -96,7 +97,7 @@ pub struct FileFeaturesResult {
 
 impl FileFeaturesResult {
     /// Files that should not be processed no matter the cases
-    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 12] = &[
+    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 13] = &[
         "package.json",
         "package-lock.json",
         "npm-shrinkwrap.json",
@@ -108,8 +109,9 @@ impl FileFeaturesResult {
         "jsconfig.json",
         "deno.json",
         "deno.jsonc",
-        // TODO: remove this when are able to handle nested .gitignore files
+        // ignore files, see `IgnoreFiles`
         ".gitignore",
+        ".ignore",
     ];
 
     /// Checks whether this file can be processed
@@ -160,7 +162,7 @@ impl FileFeaturesResult {
             let formatter_disabled =
                 if let Some(disabled) = settings.override_settings.formatter_disabled(path) {
                     disabled
-                } else if language.is_javascript_like() {
+                } else if language.is_javascript_like() || language.is_embedding_javascript() {
                     !settings.formatter().enabled || settings.javascript_formatter_disabled()
                 } else if language.is_json_like() {
                     !settings.formatter().enabled || settings.json_formatter_disabled()
@@ -185,6 +187,14 @@ impl FileFeaturesResult {
                 self.features_supported
                     .insert(FeatureName::Lint, SupportKind::FeatureNotEnabled);
             }
+            // The CSS files are only linted by the rules of the plugins
+            if language.is_css_like()
+                && settings.linter().css_plugins.is_empty()
+                && self.features_supported.get(&FeatureName::Lint) == Some(&SupportKind::Supported)
+            {
+                self.features_supported
+                    .insert(FeatureName::Lint, SupportKind::FileNotSupported);
+            }
 
             // organize imports
             if let Some(disabled) = settings.override_settings.organize_imports_disabled(path) {
@@ -378,6 +388,14 @@ pub struct UpdateSettingsParams {
     pub vcs_base_path: Option<PathBuf>,
     // @ematipico TODO: have a better data structure for this
     pub gitignore_matches: Vec<String>,
+    /// The directory whose files the configuration applies to, instead of the
+    /// whole workspace. Updating the settings of the workspace removes the
+    /// settings of all the directories.
+    pub directory: Option<PathBuf>,
+    /// The plugins listed in `linter.plugins`, read by the client since the
+    /// workspace doesn't access the file system
+    #[serde(default)]
+    pub plugins: Vec<Plugin>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -487,6 +505,9 @@ pub struct CodeAction {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FormatFileParams {
     pub path: RomePath,
+    /// Whether the printed code should be returned with its source map
+    #[serde(default)]
+    pub source_map: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -603,6 +624,61 @@ pub struct OrganizeImportsResult {
     pub code: String,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileParams {
+    pub path: RomePath,
+    /// Whether the new code should be returned with its source map
+    #[serde(default)]
+    pub source_map: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileResult {
+    /// The new code of the file, without the TypeScript and JSX syntax
+    pub code: String,
+    /// The source map between the new code and the original code of the file,
+    /// when it's requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_map: Option<SourceMapV3>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchPatternParams {
+    pub path: RomePath,
+    /// The code to search, where the metavariables, e.g. `$name`, match any node
+    pub pattern: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchResults {
+    /// The ranges of the code that matches the pattern, in the order of the file
+    pub matches: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RewriteFileParams {
+    pub path: RomePath,
+    /// The code to search, where the metavariables, e.g. `$name`, match any node
+    pub pattern: String,
+    /// The code that replaces the matches, where the metavariables are replaced
+    /// by the code they captured
+    pub replacement: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RewriteFileResult {
+    /// The new code of the file
+    pub code: String,
+    /// The number of matches that were replaced
+    pub rewritten: usize,
+}
+
 impl RageEntry {
     pub fn section(name: &str) -> Self {
         Self::Section(name.to_string())
@@ -725,6 +801,19 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError>;
+
+    /// Compiles the TypeScript and JSX syntax of the file and returns the resulting source code
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError>;
+
+    /// Returns the ranges of the code of the file that matches a structural pattern
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError>;
+
+    /// Returns the code of the file where the matches of a structural pattern
+    /// are replaced by a template
+    fn rewrite_file(&self, params: RewriteFileParams) -> Result<RewriteFileResult, WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -807,9 +896,10 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
-    pub fn format_file(&self) -> Result<Printed, WorkspaceError> {
+    pub fn format_file(&self, source_map: bool) -> Result<Printed, WorkspaceError> {
         self.workspace.format_file(FormatFileParams {
             path: self.path.clone(),
+            source_map,
         })
     }
 
@@ -844,6 +934,32 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn transform_file(&self, source_map: bool) -> Result<TransformFileResult, WorkspaceError> {
+        self.workspace.transform_file(TransformFileParams {
+            path: self.path.clone(),
+            source_map,
+        })
+    }
+
+    pub fn search_pattern(&self, pattern: &str) -> Result<SearchResults, WorkspaceError> {
+        self.workspace.search_pattern(SearchPatternParams {
+            path: self.path.clone(),
+            pattern: pattern.to_string(),
+        })
+    }
+
+    pub fn rewrite_file(
+        &self,
+        pattern: &str,
+        replacement: &str,
+    ) -> Result<RewriteFileResult, WorkspaceError> {
+        self.workspace.rewrite_file(RewriteFileParams {
+            path: self.path.clone(),
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+        })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
@@ -1,7 +1,8 @@
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
     OrganizeImportsResult, ProjectFeaturesParams, ProjectFeaturesResult, RageParams, RageResult,
-    ServerInfo,
+    RewriteFileParams, RewriteFileResult, SearchPatternParams, SearchResults, ServerInfo,
+    TransformFileParams, TransformFileResult,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -202,4 +203,19 @@ where
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.request("biome/organize_imports", params)
     }
+
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        self.request("biome/transform_file", params)
+    }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        self.request("biome/search_pattern", params)
+    }
+
+    fn rewrite_file(&self, params: RewriteFileParams) -> Result<RewriteFileResult, WorkspaceError> {
+        self.request("biome/rewrite_file", params)
+    }
 }
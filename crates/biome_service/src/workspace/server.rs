@@ -5,37 +5,44 @@ use super::{
     PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
     UpdateSettingsParams,
 };
+use crate::configuration::plugins::compile_plugins;
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::project_handlers::{ProjectCapabilities, ProjectHandlers};
 use crate::settings::OverrideSettings;
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    OrganizeImportsResult, RageEntry, RageParams, RageResult, RewriteFileParams, RewriteFileResult,
+    SearchPatternParams, SearchResults, ServerInfo, TransformFileParams, TransformFileResult,
 };
 use crate::{
     file_handlers::Features,
-    settings::{SettingsHandle, WorkspaceSettings},
+    settings::{SettingsHandle, SettingsScopes},
     Rules, Workspace, WorkspaceError,
 };
-use biome_analyze::{AnalysisFilter, RuleFilter};
+use biome_analyze::{AnalysisFilter, RuleFilter, PLUGIN_GROUP};
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::RomePath;
+use biome_js_syntax::AnyJsRoot;
+use biome_js_type_info::{is_declaration_file, is_typescript_file, ProjectDeclarations};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use dashmap::{mapref::entry::Entry, DashMap};
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span, trace};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
     features: Features,
-    /// global settings object for this workspace
-    settings: RwLock<WorkspaceSettings>,
+    /// global settings object for this workspace, along with the settings of
+    /// the directories that have their own configuration
+    settings: RwLock<SettingsScopes>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<RomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
@@ -44,6 +51,13 @@ pub(super) struct WorkspaceServer {
     file_features: DashMap<RomePath, FileFeaturesResult>,
     /// Handlers that know how to handle a specific project
     project_handlers: ProjectHandlers,
+    /// Stores the declarations of the TypeScript files opened in the
+    /// workspace, used by the type-aware lint rules
+    declarations: Arc<ProjectDeclarations>,
+    /// Whether the configuration enables a type-aware lint rule, in which case
+    /// the declarations of all the TypeScript files are recorded, not only the
+    /// ones of the `.d.ts` files
+    type_aware_rules: AtomicBool,
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -76,11 +90,34 @@ impl WorkspaceServer {
             syntax: DashMap::default(),
             file_features: DashMap::default(),
             project_handlers: ProjectHandlers::new(),
+            declarations: Arc::default(),
+            type_aware_rules: AtomicBool::new(false),
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Records the declarations of the file at `rome_path` if it's a
+    /// TypeScript file, so that the other files can import them
+    fn update_declarations(&self, rome_path: &RomePath) {
+        let records_declarations = if self.type_aware_rules.load(Ordering::Relaxed) {
+            is_typescript_file(rome_path)
+        } else {
+            is_declaration_file(rome_path)
+        };
+        if !records_declarations {
+            return;
+        }
+
+        match self.get_parse(rome_path.clone(), None) {
+            Ok(parse) => self
+                .declarations
+                .insert(rome_path, &parse.tree::<AnyJsRoot>()),
+            Err(_) => self.declarations.remove(rome_path),
+        }
+    }
+
+    /// Get the settings that apply to a given file path
+    fn settings(&self, path: &Path) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, path)
     }
 
     /// Get the supported capabilities for a given file path
@@ -189,7 +226,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(rome_path))?;
 
                 let size_limit = {
-                    let settings = self.settings();
+                    let settings = self.settings(rome_path);
                     let settings = settings.as_ref();
                     let limit = settings.files.max_size.get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
@@ -205,7 +242,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let settings = self.settings();
+                let settings = self.settings(rome_path);
                 let parsed = parse(
                     rome_path,
                     document.language_hint,
@@ -221,7 +258,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(path);
 
         if !settings.as_ref().files.ignored_files.is_empty() {
             return settings.as_ref().files.ignored_files.matches_path(path);
@@ -251,7 +288,8 @@ impl Workspace for WorkspaceServer {
                     .path
                     .file_name()
                     .and_then(|file_name| file_name.to_str());
-                let settings = self.settings.read().unwrap();
+                let settings = self.settings(&params.path);
+                let settings = settings.as_ref();
                 let mut file_features = FileFeaturesResult::new();
 
                 if let Some(file_name) = file_name {
@@ -263,7 +301,7 @@ impl Workspace for WorkspaceServer {
 
                 file_features = file_features
                     .with_capabilities(&capabilities)
-                    .with_settings_and_language(&settings, &language, params.path.as_path());
+                    .with_settings_and_language(settings, &language, params.path.as_path());
 
                 if settings.files.ignore_unknown {
                     let language = self.get_language(&params.path);
@@ -289,8 +327,8 @@ impl Workspace for WorkspaceServer {
     }
 
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
-        let settings = self.settings();
         let path = params.rome_path.as_path();
+        let settings = self.settings(path);
 
         let excluded_by_override = settings.as_ref().override_settings.is_path_excluded(path);
         let included_by_override = settings.as_ref().override_settings.is_path_included(path);
@@ -346,12 +384,35 @@ impl Workspace for WorkspaceServer {
     #[tracing::instrument(level = "debug", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         let mut settings = self.settings.write().unwrap();
+        // the settings of the directories are derived from the ones of the workspace
+        if params.directory.is_none() {
+            settings.clear_directories();
+        }
 
+        let plugin_rules = params.configuration.linter.as_ref().map(|linter| {
+            linter
+                .rules
+                .as_ref()
+                .and_then(|rules| rules.plugin.clone())
+                .unwrap_or_default()
+        });
+        let type_aware_rules = params.configuration.has_type_aware_rules();
+        if params.directory.is_none() {
+            self.type_aware_rules
+                .store(type_aware_rules, Ordering::Relaxed);
+        } else {
+            self.type_aware_rules
+                .fetch_or(type_aware_rules, Ordering::Relaxed);
+        }
+        let settings = settings.get_mut(params.directory.as_deref());
         settings.merge_with_configuration(
             params.configuration,
             params.vcs_base_path,
             params.gitignore_matches.as_slice(),
         )?;
+        if let Some(plugin_rules) = plugin_rules {
+            compile_plugins(&params.plugins, &plugin_rules, &mut settings.linter)?;
+        }
 
         // settings changed, hence everything that is computed from the settings needs to be purged
         self.file_features.clear();
@@ -362,7 +423,7 @@ impl Workspace for WorkspaceServer {
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
         self.syntax.remove(&params.path);
         self.documents.insert(
-            params.path,
+            params.path.clone(),
             Document {
                 content: params.content,
                 version: params.version,
@@ -370,6 +431,7 @@ impl Workspace for WorkspaceServer {
                 node_cache: NodeCache::default(),
             },
         );
+        self.update_declarations(&params.path);
         Ok(())
     }
 
@@ -412,7 +474,7 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -440,8 +502,10 @@ impl Workspace for WorkspaceServer {
         debug_assert!(params.version > document.version);
         document.version = params.version;
         document.content = params.content;
+        drop(document);
 
         self.syntax.remove(&params.path);
+        self.update_declarations(&params.path);
         Ok(())
     }
 
@@ -468,7 +532,8 @@ impl Workspace for WorkspaceServer {
         };
 
         let parse = self.get_parse(params.path.clone(), Some(feature))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
 
         let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) =
             self.get_file_capabilities(&params.path).analyzer.lint
@@ -480,6 +545,9 @@ impl Workspace for WorkspaceServer {
             if settings.organize_imports.enabled && !params.categories.is_syntax() {
                 rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
             }
+            if !settings.linter().plugins.is_empty() || !settings.linter().css_plugins.is_empty() {
+                rule_filter_list.push(RuleFilter::Group(PLUGIN_GROUP));
+            }
             let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
             filter.categories = params.categories;
 
@@ -490,9 +558,10 @@ impl Workspace for WorkspaceServer {
                     parse,
                     filter,
                     rules,
-                    settings: self.settings(),
+                    settings: self.settings(&params.path),
                     max_diagnostics: params.max_diagnostics,
                     path: &params.path,
+                    declarations: &self.declarations,
                     language: self.get_language(&params.path),
                 });
 
@@ -537,14 +606,15 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
-        let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
+        let settings = self.settings(&params.path);
+        let rules = settings.as_ref().linter().rules.as_ref();
         Ok(code_actions(
             parse,
             params.range,
             rules,
-            self.settings(),
+            self.settings(&params.path),
             &params.path,
+            &self.declarations,
         ))
     }
 
@@ -556,14 +626,27 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
-        format(&params.path, parse, settings)
+        let printed = format(&params.path, parse, settings)?;
+        if !params.source_map {
+            return Ok(printed);
+        }
+
+        let content = self.get_file_content(GetFileContentParams {
+            path: params.path.clone(),
+        })?;
+        let source_name = params
+            .path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default();
+        Ok(printed.with_source_map_v3(&content, source_name))
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
@@ -572,7 +655,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -589,7 +672,7 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
@@ -604,22 +687,27 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
 
         let rules = settings.as_rules(params.path.as_path());
         let overrides = &settings.override_settings;
-        let rule_filter_list =
+        let mut rule_filter_list =
             self.build_rule_filter_list(rules.as_ref(), overrides, params.path.as_path());
+        if !settings.linter().plugins.is_empty() || !settings.linter().css_plugins.is_empty() {
+            rule_filter_list.push(RuleFilter::Group(PLUGIN_GROUP));
+        }
         let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref(),
             fix_file_mode: params.fix_file_mode,
             filter,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             should_format: params.should_format,
             rome_path: &params.path,
+            declarations: &self.declarations,
         })
     }
 
@@ -664,4 +752,47 @@ impl Workspace for WorkspaceServer {
 
         Ok(result)
     }
+
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let transform = capabilities
+            .transformer
+            .transform
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        let settings = self.settings(&params.path);
+        let result = transform(&params.path, parse, settings, params.source_map)?;
+
+        Ok(result)
+    }
+
+    fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let search_pattern = capabilities
+            .search
+            .search_pattern
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path, None)?;
+        let result = search_pattern(parse, &params.pattern)?;
+
+        Ok(result)
+    }
+
+    fn rewrite_file(&self, params: RewriteFileParams) -> Result<RewriteFileResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let rewrite = capabilities
+            .search
+            .rewrite
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        let result = rewrite(&params.path, parse, &params.pattern, &params.replacement)?;
+
+        Ok(result)
+    }
 }
@@ -453,7 +453,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 18] {
+pub fn methods() -> [WorkspaceMethod; 21] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
@@ -473,5 +473,8 @@ pub fn methods() -> [WorkspaceMethod; 18] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(transform_file),
+        workspace_method!(search_pattern),
+        workspace_method!(rewrite_file),
     ]
 }
@@ -1,6 +1,11 @@
 use biome_fs::RomePath;
 use biome_js_syntax::TextSize;
-use biome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use biome_service::configuration::{FormatterConfiguration, PlainIndentStyle};
+use biome_service::workspace::{
+    server, FileGuard, Language, OpenFileParams, UpdateSettingsParams, Workspace,
+};
+use biome_service::Configuration;
+use std::path::PathBuf;
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +32,83 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn applies_the_settings_of_the_nearest_directory() {
+    fn indent_with(indent_style: PlainIndentStyle, indent_width: u8) -> Configuration {
+        Configuration {
+            formatter: Some(FormatterConfiguration {
+                indent_style: Some(indent_style),
+                indent_width: Some(indent_width),
+                ..FormatterConfiguration::default()
+            }),
+            ..Configuration::default()
+        }
+    }
+
+    fn update_settings(
+        workspace: &dyn Workspace,
+        directory: Option<&str>,
+        configuration: Configuration,
+    ) {
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                directory: directory.map(PathBuf::from),
+                plugins: vec![],
+            })
+            .unwrap();
+    }
+
+    fn format(workspace: &dyn Workspace, path: &str) -> String {
+        let file = FileGuard::open(
+            workspace,
+            OpenFileParams {
+                path: RomePath::new(path),
+                content: "if (a) {\nb;\n}\n".into(),
+                version: 0,
+                language_hint: Language::JavaScript,
+            },
+        )
+        .unwrap();
+
+        file.format_file(false).unwrap().into_code()
+    }
+
+    let workspace = server();
+    let workspace = workspace.as_ref();
+    update_settings(workspace, None, indent_with(PlainIndentStyle::Space, 2));
+    update_settings(
+        workspace,
+        Some("packages/a"),
+        indent_with(PlainIndentStyle::Space, 4),
+    );
+    update_settings(
+        workspace,
+        Some("./packages/a/nested"),
+        indent_with(PlainIndentStyle::Tab, 2),
+    );
+
+    assert_eq!(format(workspace, "index.js"), "if (a) {\n  b;\n}\n");
+    assert_eq!(
+        format(workspace, "packages/ab/index.js"),
+        "if (a) {\n  b;\n}\n"
+    );
+    assert_eq!(
+        format(workspace, "./packages/a/index.js"),
+        "if (a) {\n    b;\n}\n"
+    );
+    assert_eq!(
+        format(workspace, "packages/a/nested/index.js"),
+        "if (a) {\n\tb;\n}\n"
+    );
+
+    // the settings of the directories are dropped along with the ones of the workspace
+    update_settings(workspace, None, indent_with(PlainIndentStyle::Space, 2));
+    assert_eq!(
+        format(workspace, "packages/a/index.js"),
+        "if (a) {\n  b;\n}\n"
+    );
+}
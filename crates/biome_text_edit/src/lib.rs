@@ -153,6 +153,50 @@ impl TextEdit {
     }
 }
 
+impl TextEdit {
+    /// Returns the position in the "old" revision of the text that corresponds
+    /// to `position` in the "new" revision. This methods needs to be provided
+    /// with the "old" revision of the string, like [TextEdit::new_string].
+    ///
+    /// A position inside an inserted section is mapped to the position where
+    /// the section was inserted.
+    pub fn old_position(&self, old_string: &str, position: TextSize) -> TextSize {
+        let mut input_position = TextSize::from(0);
+        let mut output_position = TextSize::from(0);
+
+        for op in &self.ops {
+            let (input_len, output_len) = match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => (range.len(), range.len()),
+                CompressedOp::DiffOp(DiffOp::Insert { range }) => (TextSize::from(0), range.len()),
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => (range.len(), TextSize::from(0)),
+                CompressedOp::EqualLines { line_count } => {
+                    let start = u32::from(input_position) as usize;
+                    let line_break_count = line_count.get() as usize + 1;
+                    let len = old_string[start..]
+                        .split_inclusive('\n')
+                        .take(line_break_count)
+                        .map(TextSize::of)
+                        .sum();
+                    (len, len)
+                }
+            };
+
+            if position < output_position + output_len {
+                return if input_len == output_len {
+                    input_position + (position - output_position)
+                } else {
+                    input_position
+                };
+            }
+
+            input_position += input_len;
+            output_position += output_len;
+        }
+
+        input_position
+    }
+}
+
 impl IntoIterator for TextEdit {
     type Item = CompressedOp;
     type IntoIter = std::vec::IntoIter<CompressedOp>;
@@ -302,6 +346,7 @@ mod tests {
     use std::num::NonZeroU32;
 
     use crate::{compress_equal_op, TextEdit};
+    use biome_text_size::TextSize;
 
     #[test]
     fn compress_short() {
@@ -341,6 +386,37 @@ end 2
         );
     }
 
+    #[test]
+    fn old_position() {
+        const OLD: &str = "let a: string = b;";
+        const NEW: &str = "let a = c(b);";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+
+        // `let a`
+        assert_eq!(diff.old_position(OLD, TextSize::from(4)), TextSize::from(4));
+        // `=`
+        assert_eq!(
+            diff.old_position(OLD, TextSize::from(6)),
+            TextSize::from(14)
+        );
+        // `c(` is inserted before `b`
+        assert_eq!(
+            diff.old_position(OLD, TextSize::from(8)),
+            TextSize::from(16)
+        );
+        // `b`
+        assert_eq!(
+            diff.old_position(OLD, TextSize::from(10)),
+            TextSize::from(16)
+        );
+        // The end of the text
+        assert_eq!(
+            diff.old_position(OLD, TextSize::from(13)),
+            TextSize::from(18)
+        );
+    }
+
     #[test]
     fn new_string_compressed() {
         const OLD: &str = "line 1 old
@@ -5,7 +5,8 @@ use biome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
     GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    RenameParams, RewriteFileParams, SearchPatternParams, TransformFileParams,
+    UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -140,27 +141,27 @@ impl Workspace {
     }
 
     #[wasm_bindgen(js_name = formatFile)]
-    pub fn format_file(&self, params: IFormatFileParams) -> Result<JsValue, Error> {
+    pub fn format_file(&self, params: IFormatFileParams) -> Result<IPrinted, Error> {
         let params: FormatFileParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
         let result = self.inner.format_file(params).map_err(into_error)?;
-        to_value(&result).map_err(into_error)
+        to_value(&result).map(IPrinted::from).map_err(into_error)
     }
 
     #[wasm_bindgen(js_name = formatRange)]
-    pub fn format_range(&self, params: IFormatRangeParams) -> Result<JsValue, Error> {
+    pub fn format_range(&self, params: IFormatRangeParams) -> Result<IPrinted, Error> {
         let params: FormatRangeParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
         let result = self.inner.format_range(params).map_err(into_error)?;
-        to_value(&result).map_err(into_error)
+        to_value(&result).map(IPrinted::from).map_err(into_error)
     }
 
     #[wasm_bindgen(js_name = formatOnType)]
-    pub fn format_on_type(&self, params: IFormatOnTypeParams) -> Result<JsValue, Error> {
+    pub fn format_on_type(&self, params: IFormatOnTypeParams) -> Result<IPrinted, Error> {
         let params: FormatOnTypeParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
         let result = self.inner.format_on_type(params).map_err(into_error)?;
-        to_value(&result).map_err(into_error)
+        to_value(&result).map(IPrinted::from).map_err(into_error)
     }
 
     #[wasm_bindgen(js_name = fixFile)]
@@ -194,6 +195,39 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = transformFile)]
+    pub fn transform_file(
+        &self,
+        params: ITransformFileParams,
+    ) -> Result<ITransformFileResult, Error> {
+        let params: TransformFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.transform_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ITransformFileResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = searchPattern)]
+    pub fn search_pattern(&self, params: ISearchPatternParams) -> Result<ISearchResults, Error> {
+        let params: SearchPatternParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.search_pattern(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISearchResults::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = rewriteFile)]
+    pub fn rewrite_file(&self, params: IRewriteFileParams) -> Result<IRewriteFileResult, Error> {
+        let params: RewriteFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.rewrite_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IRewriteFileResult::from)
+            .map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
@@ -19,7 +19,15 @@ export type SupportKind =
 	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
+	/**
+	 * The directory whose files the configuration applies to, instead of the whole workspace. Updating the settings of the workspace removes the settings of all the directories.
+	 */
+	directory?: string;
 	gitignore_matches: string[];
+	/**
+	 * The plugins listed in `linter.plugins`, read by the client since the workspace doesn't access the file system
+	 */
+	plugins?: Plugin[];
 	vcs_base_path?: string;
 }
 /**
@@ -35,7 +43,7 @@ export interface Configuration {
 	 */
 	css?: CssConfiguration;
 	/**
-	 * A list of paths to other JSON files, used to extends the current configuration.
+	 * A list of paths to other JSON files, or names of packages that provide them, used to extends the current configuration.
 	 */
 	extends?: StringSet;
 	/**
@@ -66,11 +74,32 @@ export interface Configuration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Whether this is the configuration of the whole project. A configuration file of a sub-directory with `root` set to `false` inherits the configuration of its parent directory.
+	 */
+	root?: boolean;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
 }
+/**
+ * The lint rules defined by a plugin file
+ */
+export interface Plugin {
+	/**
+	 * The WebAssembly module of the plugin, when it's a `.wasm` file. The module declares its own rules.
+	 */
+	module?: number[];
+	/**
+	 * The path of the plugin file
+	 */
+	path?: string;
+	/**
+	 * The rules defined by the plugin
+	 */
+	rules: PluginRule[];
+}
 /**
  * Options applied to CSS files
  */
@@ -190,6 +219,10 @@ export interface LinterConfiguration {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * A list of paths to plugin files, relative to the configuration file. The lint rules defined by the plugins are run on JavaScript, TypeScript and JSX files, or on CSS files when their `language` is `css`.
+	 */
+	plugins?: StringSet;
 	/**
 	 * List of rules
 	 */
@@ -237,6 +270,47 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+/**
+ * A lint rule that reports the code matching a pattern
+ */
+export interface PluginRule {
+	/**
+	 * The code that replaces the reported code, where the metavariables are replaced by the code they captured
+	 */
+	fix?: string;
+	/**
+	 * The code is only reported inside the code matching this pattern
+	 */
+	inside?: string;
+	/**
+	 * The language of the pattern and of the files checked by the rule
+	 */
+	language?: PluginRuleLanguage;
+	/**
+	 * The severity of the diagnostics, when the configuration doesn't set it
+	 */
+	level?: PluginRuleLevel;
+	/**
+	 * The message of the diagnostic
+	 */
+	message: string;
+	/**
+	 * The name of the rule, e.g. `noConsoleLog`
+	 */
+	name: string;
+	/**
+	 * The code isn't reported inside the code matching this pattern
+	 */
+	notInside?: string;
+	/**
+	 * The pattern of the code reported by the rule, e.g. `console.log($$$args)`
+	 */
+	pattern: string;
+	/**
+	 * The conditions on the code captured by the metavariables of the pattern
+	 */
+	where: {};
+}
 export interface CssFormatter {
 	/**
 	 * Control the formatter for CSS (and its super languages) files.
@@ -403,6 +477,10 @@ export interface Rules {
 	correctness?: Correctness;
 	nursery?: Nursery;
 	performance?: Performance;
+	/**
+	 * The lint rules defined by the plugins, by rule name. A rule that isn't listed has the level defined by its plugin, and its options are only read by the rules of WebAssembly plugins.
+	 */
+	plugin?: {};
 	/**
 	 * It enables the lint rules recommended by Biome. `true` by default.
 	 */
@@ -446,6 +524,14 @@ export interface OverridePattern {
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
 export type VcsClientKind = "git";
+/**
+ * The language of a plugin rule. A JavaScript rule also checks the TypeScript and JSX files.
+ */
+export type PluginRuleLanguage = "js" | "css";
+/**
+ * The severity of the diagnostics of a plugin rule
+ */
+export type PluginRuleLevel = "warn" | "error";
 export type QuoteStyle = "double" | "single";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteProperties = "asNeeded" | "preserve";
@@ -857,6 +943,10 @@ export interface Nursery {
 	 * Disallow empty block statements and static blocks.
 	 */
 	noEmptyBlockStatements?: RuleConfiguration;
+	/**
+	 * Require the promises to be handled.
+	 */
+	noFloatingPromises?: RuleConfiguration;
 	/**
 	 * Disallow assignments to native objects and read-only global variables.
 	 */
@@ -909,6 +999,10 @@ export interface Nursery {
 	 * Ensure async functions utilize await.
 	 */
 	useAwait?: RuleConfiguration;
+	/**
+	 * Require the switch statements over a union of literals or an enum to handle all the cases.
+	 */
+	useExhaustiveSwitchCases?: RuleConfiguration;
 	/**
 	 * Promotes the use of export type for types.
 	 */
@@ -1376,9 +1470,17 @@ export interface OverrideOrganizeImportsConfiguration {
 export type RuleConfiguration = RulePlainConfiguration | RuleWithOptions;
 export type RulePlainConfiguration = "warn" | "error" | "off";
 export interface RuleWithOptions {
+	/**
+	 * Overrides the safety of the fixes of the rule
+	 */
+	fix?: RuleFixConfiguration;
 	level: RulePlainConfiguration;
 	options?: PossibleOptions;
 }
+/**
+ * Overrides the safety of the fixes of a rule, when the linter applies them
+ */
+export type RuleFixConfiguration = "none" | "safe" | "unsafe";
 export type PossibleOptions =
 	| ComplexityOptions
 	| FilenamingConventionOptions
@@ -1499,6 +1601,9 @@ export type Language =
 	| "Json"
 	| "Jsonc"
 	| "Css"
+	| "Vue"
+	| "Svelte"
+	| "Astro"
 	| "Unknown";
 export interface ChangeFileParams {
 	content: string;
@@ -1655,6 +1760,7 @@ export type Category =
 	| "lint/nursery/noDefaultExport"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noEmptyBlockStatements"
+	| "lint/nursery/noFloatingPromises"
 	| "lint/nursery/noGlobalEval"
 	| "lint/nursery/noGlobalAssign"
 	| "lint/nursery/noImplicitAnyLet"
@@ -1669,6 +1775,7 @@ export type Category =
 	| "lint/nursery/noUselessTernary"
 	| "lint/nursery/useAwait"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useExhaustiveSwitchCases"
 	| "lint/nursery/useExportType"
 	| "lint/nursery/useFilenamingConvention"
 	| "lint/nursery/useForOf"
@@ -1762,12 +1869,17 @@ export type Category =
 	| "lint/suspicious/useNamespaceKeyword"
 	| "lint/suspicious/useValidTypeof"
 	| "files/missingHandler"
+	| "files/unstagedChanges"
 	| "format"
 	| "check"
 	| "ci"
 	| "configuration"
 	| "organizeImports"
 	| "migrate"
+	| "transform"
+	| "search"
+	| "rewrite"
+	| "plugin"
 	| "deserialize"
 	| "project"
 	| "internalError/io"
@@ -1783,6 +1895,7 @@ export type Category =
 	| "lint/correctness"
 	| "lint/nursery"
 	| "lint/performance"
+	| "lint/plugin"
 	| "lint/security"
 	| "lint/style"
 	| "lint/suspicious"
@@ -1938,13 +2051,49 @@ export type SourceActionKind =
 export type Applicability = "Always" | "MaybeIncorrect";
 export interface FormatFileParams {
 	path: RomePath;
+	/**
+	 * Whether the printed code should be returned with its source map
+	 */
+	source_map?: boolean;
 }
 export interface Printed {
 	code: string;
 	range?: TextRange;
+	source_map_v3?: SourceMapV3;
 	sourcemap: SourceMarker[];
 	verbatim_ranges: TextRange[];
 }
+/**
+	* A source map in the [revision 3 format](https://sourcemaps.info/spec.html), mapping the positions of the printed code back to the positions in the source code.
+
+The columns are counted in UTF-16 code units, like the runtimes that consume the source maps do. 
+	 */
+export interface SourceMapV3 {
+	/**
+	 * The name of the generated file
+	 */
+	file?: string;
+	/**
+	 * The mappings between the printed code and the source code, encoded as Base64 VLQ segments
+	 */
+	mappings: string;
+	/**
+	 * The symbol names used by the mappings, which are never emitted
+	 */
+	names: string[];
+	/**
+	 * The names of the original files. The printed code always has a single source.
+	 */
+	sources: string[];
+	/**
+	 * The content of the original files
+	 */
+	sourcesContent: string[];
+	/**
+	 * The version of the format, always `3`
+	 */
+	version: number;
+}
 /**
  * Lightweight sourcemap marker between source and output tokens
  */
@@ -2018,6 +2167,57 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface TransformFileParams {
+	path: RomePath;
+	/**
+	 * Whether the new code should be returned with its source map
+	 */
+	source_map?: boolean;
+}
+export interface TransformFileResult {
+	/**
+	 * The new code of the file, without the TypeScript and JSX syntax
+	 */
+	code: string;
+	/**
+	 * The source map between the new code and the original code of the file, when it's requested
+	 */
+	source_map?: SourceMapV3;
+}
+export interface SearchPatternParams {
+	path: RomePath;
+	/**
+	 * The code to search, where the metavariables, e.g. `$name`, match any node
+	 */
+	pattern: string;
+}
+export interface SearchResults {
+	/**
+	 * The ranges of the code that matches the pattern, in the order of the file
+	 */
+	matches: TextRange[];
+}
+export interface RewriteFileParams {
+	path: RomePath;
+	/**
+	 * The code to search, where the metavariables, e.g. `$name`, match any node
+	 */
+	pattern: string;
+	/**
+	 * The code that replaces the matches, where the metavariables are replaced by the code they captured
+	 */
+	replacement: string;
+}
+export interface RewriteFileResult {
+	/**
+	 * The new code of the file
+	 */
+	code: string;
+	/**
+	 * The number of matches that were replaced
+	 */
+	rewritten: number;
+}
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
@@ -2043,6 +2243,9 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	transformFile(params: TransformFileParams): Promise<TransformFileResult>;
+	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
+	rewriteFile(params: RewriteFileParams): Promise<RewriteFileResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -2101,6 +2304,15 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		transformFile(params) {
+			return transport.request("biome/transform_file", params);
+		},
+		searchPattern(params) {
+			return transport.request("biome/search_pattern", params);
+		},
+		rewriteFile(params) {
+			return transport.request("biome/rewrite_file", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -28,6 +28,9 @@ fn generate_js_analyzer() -> Result<()> {
     let mut aria_analyzers = BTreeMap::new();
     generate_category("aria_analyzers", &mut aria_analyzers, base_path.clone())?;
 
+    let mut type_analyzers = BTreeMap::new();
+    generate_category("type_analyzers", &mut type_analyzers, base_path.clone())?;
+
     let mut assists = BTreeMap::new();
     generate_category("assists", &mut assists, base_path.clone())?;
 
@@ -38,6 +41,7 @@ fn generate_js_analyzer() -> Result<()> {
         analyzers,
         semantic_analyzers,
         aria_analyzers,
+        type_analyzers,
         assists,
         syntax,
     )
@@ -101,7 +105,9 @@ fn generate_category(
 
     let kind = match name {
         "syntax" => format_ident!("Syntax"),
-        "analyzers" | "semantic_analyzers" | "aria_analyzers" => format_ident!("Lint"),
+        "analyzers" | "semantic_analyzers" | "aria_analyzers" | "type_analyzers" => {
+            format_ident!("Lint")
+        }
         "assists" => format_ident!("Action"),
         _ => panic!("unimplemented analyzer category {name:?}"),
     };
@@ -213,6 +219,7 @@ fn update_js_registry_builder(
     analyzers: BTreeMap<&'static str, TokenStream>,
     semantic_analyzers: BTreeMap<&'static str, TokenStream>,
     aria_analyzers: BTreeMap<&'static str, TokenStream>,
+    type_analyzers: BTreeMap<&'static str, TokenStream>,
     assists: BTreeMap<&'static str, TokenStream>,
     syntax: BTreeMap<&'static str, TokenStream>,
 ) -> Result<()> {
@@ -222,6 +229,7 @@ fn update_js_registry_builder(
         .into_iter()
         .chain(semantic_analyzers)
         .chain(aria_analyzers)
+        .chain(type_analyzers)
         .chain(assists)
         .chain(syntax)
         .map(|(_, tokens)| tokens);
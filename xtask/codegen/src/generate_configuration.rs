@@ -76,6 +76,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let mut group_as_disabled_rules = Vec::new();
     let mut group_match_code = Vec::new();
     let mut group_get_severity = Vec::new();
+    let mut group_get_rule_configuration = Vec::new();
     let mut group_name_list = vec!["recommended", "all"];
     let mut rule_visitor_call = Vec::new();
     let mut visitor_rule_list = Vec::new();
@@ -130,6 +131,13 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             }
         });
 
+        group_get_rule_configuration.push(quote! {
+            #group => self
+                .#property_group_name
+                .as_ref()
+                .and_then(|#property_group_name| #property_group_name.get_rule_configuration(rule_name))
+        });
+
         group_get_severity.push(quote! {
             #group => self
                 .#property_group_name
@@ -155,13 +163,17 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         });
     }
 
+    // The rules defined by the plugins are configured in their own group
+    group_name_list.push("plugin");
+
     let groups = quote! {
         use serde::{Deserialize, Serialize};
         #[cfg(feature = "schema")]
         use schemars::JsonSchema;
+        use crate::configuration::linter::PluginRuleConfiguration;
         use crate::{MergeWith, RuleConfiguration};
         use biome_analyze::RuleFilter;
-        use indexmap::IndexSet;
+        use indexmap::{IndexMap, IndexSet};
         use biome_diagnostics::{Category, Severity};
 
         #[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
@@ -176,7 +188,13 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             #[serde(skip_serializing_if = "Option::is_none")]
             pub all: Option<bool>,
 
-            #( #line_groups ),*
+            #( #line_groups ),*,
+
+            /// The lint rules defined by the plugins, by rule name. A rule that isn't
+            /// listed has the level defined by its plugin, and its options are only
+            /// read by the rules of WebAssembly plugins.
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub plugin: Option<IndexMap<String, PluginRuleConfiguration>>,
         }
 
         impl Default for Rules {
@@ -184,7 +202,8 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 Self {
                     recommended: Some(true),
                     all: None,
-                    #( #default_for_groups ),*
+                    #( #default_for_groups ),*,
+                    plugin: None,
                 }
             }
         }
@@ -200,6 +219,10 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 }
 
                 #( #merge_width_groups )*
+
+                if let Some(other) = other.plugin {
+                    self.plugin.get_or_insert_with(IndexMap::default).extend(other);
+                }
             }
 
             fn merge_with_if_not_default(&mut self, other: Rules)
@@ -258,6 +281,16 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 }
             }
 
+            /// Returns the configuration of the rule `rule_name` of the group `group`,
+            /// if the configuration sets it.
+            pub fn get_rule_configuration(&self, group: &str, rule_name: &str) -> Option<&RuleConfiguration> {
+                match group {
+                    #( #group_get_rule_configuration ),*,
+
+                    _ => None,
+                }
+            }
+
             pub(crate) const fn is_recommended(&self) -> bool {
                 // It is only considered _not_ recommended when
                 // the configuration is `"recommended": false`.
@@ -336,6 +369,9 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                                     result.all = Deserializable::deserialize(&value, &key_text, diagnostics);
                                 }
                                 #( #rule_visitor_call ),*,
+                                "plugin" => {
+                                    result.plugin = Deserializable::deserialize(&value, &key_text, diagnostics);
+                                }
                                 unknown_key => {
                                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                                         unknown_key,